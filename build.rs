@@ -0,0 +1,58 @@
+//! Build script resolving the extension embedded by `bundled-extension`.
+//!
+//! With the feature enabled, `FIREFOX_WEBDRIVER_EXTENSION_XPI` names the
+//! `.xpi` to embed (relative paths resolve against the crate root):
+//!
+//! - Set to a readable file: exports its absolute path as
+//!   `FIREFOX_WEBDRIVER_BUNDLED_XPI` and sets `cfg(bundled_xpi)`.
+//! - Set to anything else: exports the reason as
+//!   `FIREFOX_WEBDRIVER_BUNDLED_XPI_ERROR` and sets `cfg(bundled_xpi_error)`,
+//!   which `driver::assets` turns into a `compile_error!`.
+//! - Unset: warns and embeds nothing, so `--all-features` checks still build.
+
+use std::env;
+use std::path::PathBuf;
+
+const XPI_VAR: &str = "FIREFOX_WEBDRIVER_EXTENSION_XPI";
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(bundled_xpi)");
+    println!("cargo::rustc-check-cfg=cfg(bundled_xpi_error)");
+    println!("cargo::rerun-if-env-changed={XPI_VAR}");
+
+    if env::var_os("CARGO_FEATURE_BUNDLED_EXTENSION").is_none() {
+        return;
+    }
+
+    let Some(value) = env::var_os(XPI_VAR) else {
+        println!(
+            "cargo::warning=`bundled-extension` is enabled but {XPI_VAR} is not set; \
+             no extension is embedded"
+        );
+        return;
+    };
+
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let path = manifest_dir.join(value);
+    println!("cargo::rerun-if-changed={}", path.display());
+
+    match path.canonicalize() {
+        Ok(path) if path.is_file() => {
+            println!("cargo::rustc-cfg=bundled_xpi");
+            println!(
+                "cargo::rustc-env=FIREFOX_WEBDRIVER_BUNDLED_XPI={}",
+                path.display()
+            );
+        }
+        Ok(path) => fail(&format!("{XPI_VAR} is not a file: {}", path.display())),
+        Err(e) => fail(&format!(
+            "{XPI_VAR} cannot be read ({e}): {}",
+            path.display()
+        )),
+    }
+}
+
+fn fail(reason: &str) {
+    println!("cargo::rustc-cfg=bundled_xpi_error");
+    println!("cargo::rustc-env=FIREFOX_WEBDRIVER_BUNDLED_XPI_ERROR={reason}");
+}
@@ -1,7 +1,7 @@
 //! Storage operations demonstration.
 //!
 //! Demonstrates:
-//! - Cookie operations (get, set, delete, getAll)
+//! - Cookie operations (get, set, delete, getAll, HttpOnly, clear)
 //! - localStorage operations (get, set, delete, clear)
 //! - sessionStorage operations (get, set, delete, clear)
 //!
@@ -127,6 +127,26 @@ async fn test_cookies(tab: &firefox_webdriver::Tab) -> Result<()> {
         println!("      - {}={}", c.name, c.value);
     }
 
+    // HttpOnly cookies are only visible through the browser's cookie store
+    let http_only = Cookie::new("http_only_session", "secret")
+        .with_path("/")
+        .with_http_only(true);
+    tab.set_cookie(http_only).await?;
+    println!("    ✓ Set HttpOnly cookie: http_only_session=secret");
+
+    let with_http_only = tab.get_all_cookies_including_http_only().await?;
+    let found = with_http_only
+        .iter()
+        .find(|c| c.name == "http_only_session");
+    assert!(
+        found.is_some_and(|c| c.http_only == Some(true)),
+        "HttpOnly cookie missing from get_all_cookies_including_http_only"
+    );
+    println!(
+        "    ✓ Got {} cookies including HttpOnly",
+        with_http_only.len()
+    );
+
     // Delete cookie
     tab.delete_cookie("test_session").await?;
     println!("    ✓ Deleted cookie: test_session");
@@ -140,7 +160,21 @@ async fn test_cookies(tab: &firefox_webdriver::Tab) -> Result<()> {
 
     // Cleanup
     tab.delete_cookie("user_id").await?;
-    println!("    ✓ Cleaned up test cookies\n");
+    tab.delete_cookie("http_only_session").await?;
+    println!("    ✓ Cleaned up test cookies");
+
+    // Bulk clearing also removes HttpOnly cookies
+    tab.set_cookie(Cookie::new("bulk_a", "1").with_path("/"))
+        .await?;
+    tab.set_cookie(
+        Cookie::new("bulk_b", "2")
+            .with_path("/")
+            .with_http_only(true),
+    )
+    .await?;
+    let removed = tab.clear_cookies_for_domain("example.com").await?;
+    assert!(removed >= 2, "Expected at least 2 cookies removed");
+    println!("    ✓ Cleared {removed} cookies for example.com\n");
 
     Ok(())
 }
@@ -91,13 +91,9 @@ pub async fn print_logs(
     count: usize,
 ) -> firefox_webdriver::Result<()> {
     println!("[Logs] Extension logs (last {count}):");
-    let logs = window.steal_logs().await?;
+    let logs = window.steal_log_records().await?;
     for log in logs.iter().rev().take(count).rev() {
-        if let Some(msg) = log.get("message").and_then(|v| v.as_str()) {
-            let level = log.get("level").and_then(|v| v.as_str()).unwrap_or("?");
-            let module = log.get("module").and_then(|v| v.as_str()).unwrap_or("?");
-            println!("        [{level}] [{module}] {msg}");
-        }
+        println!("        [{}] [{}] {}", log.level, log.target, log.message);
     }
     Ok(())
 }
@@ -0,0 +1,120 @@
+//! Accessibility tree types.
+//!
+//! A simplified accessibility tree computed in the content script from
+//! ARIA attributes and HTML semantics.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let tree = tab.accessibility_snapshot().await?;
+//!
+//! if let Some(button) = tree.find("button", "Sign in") {
+//!     println!("found {:?}", button);
+//! }
+//! println!("{}", serde_json::to_string_pretty(&tree)?);
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// AxNode
+// ============================================================================
+
+/// A node in the accessibility tree.
+///
+/// The root has role `document` and the page title as its name. Text
+/// outside named controls appears as nodes with role `text`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AxNode {
+    /// ARIA role, explicit or implied by the element (e.g. `button`, `link`).
+    pub role: String,
+
+    /// Accessible name (empty if none).
+    #[serde(default)]
+    pub name: String,
+
+    /// Current value for form controls, sliders and progress bars.
+    #[serde(default)]
+    pub value: Option<String>,
+
+    /// Child nodes in document order.
+    #[serde(default)]
+    pub children: Vec<AxNode>,
+}
+
+impl AxNode {
+    /// Returns the first node (depth-first, including this one) with the
+    /// given role and name.
+    #[must_use]
+    pub fn find(&self, role: &str, name: &str) -> Option<&AxNode> {
+        if self.role == role && self.name == name {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .find_map(|child| child.find(role, name))
+    }
+
+    /// Returns all nodes (depth-first, including this one) with the given role.
+    #[must_use]
+    pub fn find_all(&self, role: &str) -> Vec<&AxNode> {
+        let mut found = Vec::new();
+        self.collect_role(role, &mut found);
+        found
+    }
+
+    /// Appends nodes with `role` to `found`.
+    fn collect_role<'a>(&'a self, role: &str, found: &mut Vec<&'a AxNode>) {
+        if self.role == role {
+            found.push(self);
+        }
+        for child in &self.children {
+            child.collect_role(role, found);
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::AxNode;
+
+    fn sample() -> AxNode {
+        serde_json::from_value(serde_json::json!({
+            "role": "document",
+            "name": "Login",
+            "children": [
+                { "role": "heading", "name": "Sign in", "children": [] },
+                { "role": "textbox", "name": "Email", "value": "a@b.c" },
+                { "role": "button", "name": "Sign in" }
+            ]
+        }))
+        .expect("deserialize")
+    }
+
+    #[test]
+    fn test_deserialize_defaults() {
+        let tree = sample();
+        assert_eq!(tree.children.len(), 3);
+        assert_eq!(tree.children[1].value.as_deref(), Some("a@b.c"));
+        assert!(tree.children[2].children.is_empty());
+    }
+
+    #[test]
+    fn test_find() {
+        let tree = sample();
+        assert_eq!(
+            tree.find("button", "Sign in").map(|n| n.role.as_str()),
+            Some("button")
+        );
+        assert!(tree.find("link", "Sign in").is_none());
+        assert_eq!(tree.find_all("textbox").len(), 1);
+    }
+}
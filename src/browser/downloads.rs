@@ -0,0 +1,47 @@
+//! File download types.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::path::Path;
+//! use std::time::Duration;
+//!
+//! tab.move_downloads_to(Path::new("./downloads")).await?;
+//!
+//! let (download, _) = tokio::try_join!(
+//!     tab.wait_for_download(Duration::from_secs(30)),
+//!     tab.find_element("a.export").await?.click(),
+//! )?;
+//! println!("{} ({} bytes)", download.path.display(), download.bytes);
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::path::PathBuf;
+
+// ============================================================================
+// DownloadInfo
+// ============================================================================
+
+/// A finished download reported by [`Tab::wait_for_download`].
+///
+/// [`Tab::wait_for_download`]: crate::Tab::wait_for_download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadInfo {
+    /// URL the file was downloaded from.
+    pub url: String,
+
+    /// File name Firefox saved the download under.
+    pub filename: String,
+
+    /// Absolute path of the file on disk.
+    pub path: PathBuf,
+
+    /// File size in bytes.
+    pub bytes: u64,
+
+    /// MIME type reported by the server, if any.
+    pub mime: Option<String>,
+}
@@ -28,18 +28,77 @@
 // ============================================================================
 
 use std::fmt;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as Base64Standard;
+use parking_lot::Mutex as ParkingMutex;
 use serde_json::Value;
+use tokio::sync::oneshot;
+use tokio::time::timeout;
 use tracing::debug;
 
 use crate::error::{Error, Result};
 use crate::identifiers::{ElementId, FrameId, SessionId, TabId};
-use crate::protocol::{Command, ElementCommand, InputCommand, Request, Response};
-
-use super::Window;
-use super::keyboard::Key;
+use crate::protocol::event::ParsedEvent;
+use crate::protocol::{
+    Command, ElementCommand, Event, FilePayload, InputCommand, Request, Response, ScriptCommand,
+};
+use crate::transport::connection::DEFAULT_COMMAND_TIMEOUT;
+
+use super::keyboard::{Key, KeyInput};
+use super::mouse::{MousePathOptions, human_path};
 use super::selector::By;
+use super::tab::{ImageFormat, decode_image, encode_image, pixel_rect};
+use super::{Tab, Window};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Error code the extension returns for a malformed CSS selector.
+const INVALID_SELECTOR: &str = "invalid selector";
+
+/// Error code the extension returns for a parameter it cannot use.
+const INVALID_ARGUMENT: &str = "invalid argument";
+
+/// Error code the extension returns when an element ID is not in the
+/// content script's store or its node is no longer connected to the DOM.
+const STALE_ELEMENT: &str = "stale element";
+
+/// Attribute that marks an element for a one-script measurement.
+///
+/// The content script's element store is not reachable from page scripts,
+/// so the element is tagged, found, and untagged by the same script.
+const MEASURE_ATTRIBUTE: &str = "data-firefox-webdriver-measure";
+
+/// Finds the marked element (including inside open shadow roots), removes
+/// the marker, and returns its rect together with the frame's viewport.
+const MEASURE_SCRIPT: &str = r#"
+const [attr, token] = __ARGS__;
+const find = (root) => {
+    for (const node of root.querySelectorAll(`[${attr}]`)) {
+        if (node.getAttribute(attr) === token) return node;
+    }
+    for (const host of root.querySelectorAll("*")) {
+        const found = host.shadowRoot && find(host.shadowRoot);
+        if (found) return found;
+    }
+    return null;
+};
+const element = find(document);
+if (!element) return null;
+element.removeAttribute(attr);
+const rect = element.getBoundingClientRect();
+return {
+    x: rect.x, y: rect.y, width: rect.width, height: rect.height,
+    scrollX: window.scrollX, scrollY: window.scrollY,
+    innerWidth: window.innerWidth, innerHeight: window.innerHeight,
+};
+"#;
 
 // ============================================================================
 // Types
@@ -61,6 +120,103 @@ pub(crate) struct ElementInner {
 
     /// Parent window.
     pub window: Option<Window>,
+
+    /// How this element was found, for [`Element::refetch`].
+    pub locator: Option<ElementLocator>,
+
+    /// Whether the content script's entry was already released.
+    pub released: AtomicBool,
+}
+
+impl Drop for ElementInner {
+    fn drop(&mut self) {
+        if self.released.load(Ordering::SeqCst) {
+            return;
+        }
+
+        // Best effort: without a runtime the entry stays until the page unloads
+        if let Some(window) = self.window.take()
+            && let Ok(handle) = tokio::runtime::Handle::try_current()
+        {
+            let request = Request::new(
+                self.tab_id,
+                self.frame_id,
+                Command::Element(ElementCommand::Release {
+                    element_id: self.id.clone(),
+                }),
+            );
+            let element_id = self.id.clone();
+            handle.spawn(async move {
+                if let Err(e) = window
+                    .inner
+                    .pool
+                    .send(window.inner.session_id, request)
+                    .await
+                {
+                    debug!(%element_id, error = %e, "Failed to release dropped element");
+                }
+            });
+        }
+    }
+}
+
+/// The search that produced an element.
+#[derive(Debug, Clone)]
+pub(crate) struct ElementLocator {
+    /// Locator strategy.
+    pub by: By,
+
+    /// Element the search was scoped to (`None` = whole document).
+    pub parent: Option<Element>,
+
+    /// Position in `find_elements` results (`None` = first match).
+    pub index: Option<usize>,
+}
+
+/// Scroll offset and size of a frame's viewport, in CSS pixels.
+#[derive(Debug)]
+struct Viewport {
+    scroll_x: f64,
+    scroll_y: f64,
+    width: f64,
+    height: f64,
+}
+
+// ============================================================================
+// ScrollAlign
+// ============================================================================
+
+/// Alignment of an element within the viewport after scrolling.
+///
+/// Maps to the `block`/`inline` options of `Element.scrollIntoView()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAlign {
+    /// Align to the top (block) or left (inline) edge.
+    Start,
+
+    /// Center in the viewport.
+    #[default]
+    Center,
+
+    /// Align to the bottom (block) or right (inline) edge.
+    End,
+
+    /// Scroll the minimum amount needed to make the element visible.
+    Nearest,
+}
+
+impl ScrollAlign {
+    /// Returns the `scrollIntoView` option value.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Center => "center",
+            Self::End => "end",
+            Self::Nearest => "nearest",
+        }
+    }
 }
 
 // ============================================================================
@@ -121,9 +277,21 @@ impl Element {
                 frame_id,
                 session_id,
                 window,
+                locator: None,
+                released: AtomicBool::new(false),
             }),
         }
     }
+
+    /// Records the search that produced this element.
+    ///
+    /// Must be called before the handle is cloned.
+    pub(crate) fn with_locator(mut self, locator: ElementLocator) -> Self {
+        if let Some(inner) = Arc::get_mut(&mut self.inner) {
+            inner.locator = Some(locator);
+        }
+        self
+    }
 }
 
 // ============================================================================
@@ -215,11 +383,54 @@ impl Element {
     }
 
     /// Sets the element's value (for input elements).
+    ///
+    /// Assigns `element.value` directly and fires no events. Framework
+    /// controlled inputs (React, Vue) may not notice the change; use
+    /// [`Element::set_value_react_safe`] for those.
     pub async fn set_value(&self, value: &str) -> Result<()> {
         self.set_property("value", Value::String(value.to_string()))
             .await
     }
 
+    /// Sets the value so framework-controlled inputs update their state.
+    ///
+    /// React and Vue track the value through the native setter, so a plain
+    /// `element.value = ...` is overwritten on the next render. The extension
+    /// calls the `value` setter from the element's prototype
+    /// (`HTMLInputElement`, `HTMLTextAreaElement` or `HTMLSelectElement`)
+    /// and then dispatches bubbling `input` and `change` events.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the element is not an input,
+    /// textarea or select.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let email = tab.find_element("input[name=email]").await?;
+    /// email.set_value_react_safe("user@example.com").await?;
+    /// ```
+    pub async fn set_value_react_safe(&self, value: &str) -> Result<()> {
+        debug!(element_id = %self.inner.id, value_len = value.len(), "Setting value natively");
+
+        let command = Command::Element(ElementCommand::SetValue {
+            element_id: self.inner.id.clone(),
+            value: value.to_string(),
+        });
+
+        let response = self.send_command(command).await?;
+
+        if response.error.as_deref() == Some(INVALID_ARGUMENT) {
+            return Err(Error::invalid_argument(
+                "set_value_react_safe requires an input, textarea or select element",
+            ));
+        }
+
+        response.into_result()?;
+        Ok(())
+    }
+
     /// Gets an attribute value.
     ///
     /// Returns `None` if the attribute doesn't exist.
@@ -245,6 +456,120 @@ impl Element {
         let disabled = self.get_property("disabled").await?;
         Ok(!disabled.as_bool().unwrap_or(false))
     }
+
+    /// Checks if this reference no longer points to an element in the DOM.
+    ///
+    /// Returns `true` once the element has been removed (or its page
+    /// navigated away). Operations on a stale element fail with
+    /// [`Error::StaleElement`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if row.is_stale().await? {
+    ///     row = tab.find_element(By::css("tr.selected")).await?;
+    /// }
+    /// ```
+    pub async fn is_stale(&self) -> Result<bool> {
+        match self.get_property("isConnected").await {
+            Ok(connected) => Ok(!connected.as_bool().unwrap_or(false)),
+            Err(Error::StaleElement { .. }) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Waits until this element is removed from the DOM.
+    ///
+    /// Uses the extension's removal watcher (no polling) and resolves
+    /// immediately if the element is already gone. Other event handlers on
+    /// the window keep running while waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the element is still attached after
+    /// `timeout_duration`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// delete_button.click().await?;
+    /// row.wait_until_gone(Duration::from_secs(5)).await?;
+    /// ```
+    pub async fn wait_until_gone(&self, timeout_duration: Duration) -> Result<()> {
+        debug!(
+            element_id = %self.inner.id,
+            timeout_ms = timeout_duration.as_millis(),
+            "Waiting for element removal"
+        );
+
+        let window = self
+            .inner
+            .window
+            .as_ref()
+            .ok_or_else(|| Error::protocol("Element has no associated window"))?;
+
+        let (tx, rx) = oneshot::channel::<()>();
+        let tx = Arc::new(ParkingMutex::new(Some(tx)));
+        let tx_clone = Arc::clone(&tx);
+        let watched_id = self.inner.id.as_str().to_string();
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "element.removed" {
+                return None;
+            }
+
+            if let ParsedEvent::ElementRemoved { element_id, .. } = event.parse()
+                && element_id == watched_id
+                && let Some(tx) = tx_clone.lock().take()
+            {
+                let _ = tx.send(());
+            }
+
+            None
+        }));
+
+        let command = Command::Element(ElementCommand::WatchRemoval {
+            element_id: self.inner.id.clone(),
+        });
+
+        match self
+            .send_command(command)
+            .await
+            .and_then(Response::into_result)
+        {
+            Ok(_) => {}
+            Err(Error::StaleElement { .. }) => {
+                window.remove_event_handler(handler_id);
+                return Ok(());
+            }
+            Err(e) => {
+                window.remove_event_handler(handler_id);
+                return Err(e);
+            }
+        }
+
+        let result = timeout(timeout_duration, rx).await;
+
+        window.remove_event_handler(handler_id);
+
+        match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(_)) => Err(Error::protocol("Channel closed unexpectedly")),
+            Err(_) => {
+                let command = Command::Element(ElementCommand::UnwatchRemoval {
+                    element_id: self.inner.id.clone(),
+                });
+                let _ = self.send_command(command).await;
+
+                Err(Error::timeout(
+                    format!("wait_until_gone({})", self.inner.id),
+                    timeout_duration.as_millis() as u64,
+                ))
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -273,6 +598,54 @@ impl Element {
         .await
     }
 
+    /// Types text mixed with special keys, Selenium-style.
+    ///
+    /// Special keys are embedded as WebDriver code points, e.g.
+    /// `"\u{E007}"` for Enter (see [`Key::webdriver_char`]). Text runs are
+    /// typed with [`type_text`](Self::type_text) and keys pressed with
+    /// [`press`](Self::press), in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] for WebDriver code points with no
+    /// [`Key`] equivalent (e.g. modifiers); nothing is typed in that case.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// username.send_keys("alice\u{E004}hunter2\u{E007}").await?;
+    /// ```
+    pub async fn send_keys(&self, keys: &str) -> Result<()> {
+        self.send_key_inputs(&KeyInput::parse(keys)?).await
+    }
+
+    /// Types a sequence of text runs and special keys, in order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{Key, KeyInput};
+    ///
+    /// field
+    ///     .send_key_inputs(&[
+    ///         Key::End.into(),
+    ///         KeyInput::from(" (edited)"),
+    ///         Key::Enter.into(),
+    ///     ])
+    ///     .await?;
+    /// ```
+    pub async fn send_key_inputs(&self, inputs: &[KeyInput]) -> Result<()> {
+        debug!(element_id = %self.inner.id, count = inputs.len(), "Sending key inputs");
+
+        for input in inputs {
+            match input {
+                KeyInput::Text(text) => self.type_text(text).await?,
+                KeyInput::Key(key) => self.press(*key).await?,
+            }
+        }
+        Ok(())
+    }
+
     /// Types a single key with optional modifiers (low-level API).
     ///
     /// Prefer using [`press`](Self::press) for common keys or [`type_text`](Self::type_text) for text.
@@ -340,11 +713,84 @@ impl Element {
         let command = Command::Input(InputCommand::TypeText {
             element_id: self.inner.id.clone(),
             text: text.to_string(),
+            min_delay_ms: None,
+            max_delay_ms: None,
         });
 
         self.send_command(command).await?;
         Ok(())
     }
+
+    /// Types a text string with a fixed pause between characters.
+    ///
+    /// The pause is applied by the extension, so timing is not affected by
+    /// round trips. Useful for inputs that debounce or react per keystroke.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to type
+    /// * `delay` - Pause between characters
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// search.type_text_delayed("firefox", Duration::from_millis(80)).await?;
+    /// ```
+    pub async fn type_text_delayed(&self, text: &str, delay: Duration) -> Result<()> {
+        self.type_text_human(text, delay, delay).await
+    }
+
+    /// Types a text string with a random pause between characters.
+    ///
+    /// Each pause is picked uniformly between `min` and `max` by the
+    /// extension, mimicking a person typing.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to type
+    /// * `min` - Shortest pause between characters
+    /// * `max` - Longest pause between characters
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// email
+    ///     .type_text_human("user@example.com", Duration::from_millis(60), Duration::from_millis(220))
+    ///     .await?;
+    /// ```
+    pub async fn type_text_human(&self, text: &str, min: Duration, max: Duration) -> Result<()> {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let min_delay_ms = min.as_millis() as u64;
+        let max_delay_ms = max.as_millis() as u64;
+
+        debug!(
+            element_id = %self.inner.id,
+            text_len = text.len(),
+            min_delay_ms,
+            max_delay_ms,
+            "Typing text with delay"
+        );
+
+        let command = Command::Input(InputCommand::TypeText {
+            element_id: self.inner.id.clone(),
+            text: text.to_string(),
+            min_delay_ms: Some(min_delay_ms),
+            max_delay_ms: Some(max_delay_ms),
+        });
+
+        // Typing can outlast the default timeout; allow for the pauses
+        let pauses = max.saturating_mul(text.chars().count() as u32);
+        let request_timeout = DEFAULT_COMMAND_TIMEOUT.saturating_add(pauses);
+
+        self.send_command_with_timeout(command, request_timeout)
+            .await?
+            .into_result()?;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -374,6 +820,56 @@ impl Element {
         Ok(())
     }
 
+    /// Clicks the left mouse button at an offset from the element's
+    /// top-left corner.
+    ///
+    /// Reaches a specific spot in a large element, such as a point on a
+    /// canvas or a position along a slider track. Dispatches at viewport
+    /// coordinates, so whatever is on top at that point receives the click.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset_x` - CSS pixels from the element's left edge
+    /// * `offset_y` - CSS pixels from the element's top edge
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the offset falls outside the
+    /// element's bounding rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Click 75% of the way along a slider track
+    /// let track = tab.find_element(".slider-track").await?;
+    /// let (_, _, width, height) = track.get_bounding_rect().await?;
+    /// track.click_at(width * 0.75, height / 2.0).await?;
+    /// ```
+    pub async fn click_at(&self, offset_x: f64, offset_y: f64) -> Result<()> {
+        debug!(element_id = %self.inner.id, offset_x, offset_y, "Clicking at element offset");
+
+        let rect = self.get_bounding_rect().await?;
+        let (x, y) = offset_point(rect, offset_x, offset_y).ok_or_else(|| {
+            Error::invalid_argument(format!(
+                "Offset ({offset_x}, {offset_y}) is outside the element ({}x{})",
+                rect.2, rect.3
+            ))
+        })?;
+
+        let command = Command::Input(InputCommand::MouseClick {
+            element_id: None,
+            x: Some(x),
+            y: Some(y),
+            button: 0,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        if let Some(window) = &self.inner.window {
+            window.set_mouse_position(self.inner.tab_id, (x, y));
+        }
+        Ok(())
+    }
+
     /// Double-clicks the element.
     ///
     /// Dispatches two click sequences followed by dblclick event.
@@ -418,6 +914,53 @@ impl Element {
         Ok(())
     }
 
+    /// Moves the mouse to the element along a curved, human-like path.
+    ///
+    /// The path starts where the previous human-like move in this tab
+    /// ended and finishes at a random point near the element's center.
+    /// Uses default [`MousePathOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// button.mouse_move_human().await?;
+    /// button.mouse_click(0).await?;
+    /// ```
+    pub async fn mouse_move_human(&self) -> Result<()> {
+        self.mouse_move_human_with(MousePathOptions::new()).await
+    }
+
+    /// Moves the mouse to the element with custom path options.
+    pub async fn mouse_move_human_with(&self, options: MousePathOptions) -> Result<()> {
+        debug!(element_id = %self.inner.id, "Moving mouse to element along path");
+
+        let window = self
+            .inner
+            .window
+            .as_ref()
+            .ok_or_else(|| Error::protocol("Element has no associated window"))?;
+
+        let (x, y, width, height) = self.get_bounding_rect().await?;
+
+        // Aim within the middle half of the box rather than the exact center
+        let target = (
+            (x + width * (0.25 + fastrand::f64() * 0.5)).round() as i32,
+            (y + height * (0.25 + fastrand::f64() * 0.5)).round() as i32,
+        );
+
+        let from = window.mouse_position(self.inner.tab_id);
+        let points = human_path(from, target, &options);
+
+        let command = Command::Input(InputCommand::MouseMovePath {
+            points,
+            interval_ms: options.interval.as_millis() as u64,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        window.set_mouse_position(self.inner.tab_id, target);
+        Ok(())
+    }
+
     /// Presses mouse button down on the element (without release).
     ///
     /// Dispatches only mousedown event.
@@ -461,6 +1004,79 @@ impl Element {
         self.send_command(command).await?;
         Ok(())
     }
+
+    /// Drags the element by a pixel offset with the left mouse button.
+    ///
+    /// Presses at the element's center, moves through `steps` evenly
+    /// spaced points to the center plus `(dx, dy)`, then releases there.
+    /// Drag handlers that only react to `mousemove` between press and
+    /// release (sliders, sortable lists, resize handles) need the
+    /// intermediate points; `duration` spreads them out for handlers that
+    /// debounce or measure velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `dx`, `dy` - Offset in CSS pixels
+    /// * `steps` - Number of `mousemove` events (at least one is sent)
+    /// * `duration` - Total time spent moving
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let handle = tab.find_element(".slider-handle").await?;
+    /// handle.drag_by(120, 0, 10, Duration::from_millis(300)).await?;
+    /// ```
+    pub async fn drag_by(&self, dx: i32, dy: i32, steps: usize, duration: Duration) -> Result<()> {
+        debug!(element_id = %self.inner.id, dx, dy, steps, "Dragging element");
+
+        let (x, y, width, height) = self.get_bounding_rect().await?;
+        let start = (
+            (x + width / 2.0).round() as i32,
+            (y + height / 2.0).round() as i32,
+        );
+        let points = drag_path(start, (dx, dy), steps);
+        let end = points.last().copied().unwrap_or(start);
+        let interval = duration / points.len() as u32;
+
+        self.send_command(Command::Input(InputCommand::MouseDown {
+            element_id: None,
+            x: Some(start.0),
+            y: Some(start.1),
+            button: 0,
+        }))
+        .await?
+        .into_result()?;
+
+        let command = Command::Input(InputCommand::MouseMovePath {
+            points,
+            interval_ms: interval.as_millis() as u64,
+        });
+        let moved = self
+            .send_command_with_timeout(command, DEFAULT_COMMAND_TIMEOUT.saturating_add(duration))
+            .await
+            .and_then(|response| response.into_result());
+
+        // Release even if the move failed, so the button is not left held
+        let released = self
+            .send_command(Command::Input(InputCommand::MouseUp {
+                element_id: None,
+                x: Some(end.0),
+                y: Some(end.1),
+                button: 0,
+            }))
+            .await
+            .and_then(|response| response.into_result());
+
+        if let Some(window) = &self.inner.window {
+            window.set_mouse_position(self.inner.tab_id, end);
+        }
+
+        moved?;
+        released?;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -494,23 +1110,198 @@ impl Element {
         Ok(())
     }
 
-    /// Gets the element's bounding rectangle.
+    /// Scrolls the element into view with explicit alignment.
     ///
-    /// # Returns
+    /// Scrolls instantly, then shifts the frame's viewport up by `offset_y`
+    /// pixels so the element ends up below a fixed or sticky header.
+    /// Negative values shift it down.
     ///
-    /// Tuple of (x, y, width, height) in pixels.
-    pub async fn get_bounding_rect(&self) -> Result<(f64, f64, f64, f64)> {
-        let result = self.call_method("getBoundingClientRect", vec![]).await?;
+    /// # Arguments
+    ///
+    /// * `block` - Vertical alignment
+    /// * `inline` - Horizontal alignment
+    /// * `offset_y` - Space to leave above the element, in pixels
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::ScrollAlign;
+    ///
+    /// // Align to top, leaving room for a 64px sticky header
+    /// element
+    ///     .scroll_into_view_with_options(ScrollAlign::Start, ScrollAlign::Nearest, 64)
+    ///     .await?;
+    /// ```
+    pub async fn scroll_into_view_with_options(
+        &self,
+        block: ScrollAlign,
+        inline: ScrollAlign,
+        offset_y: i32,
+    ) -> Result<()> {
+        debug!(
+            element_id = %self.inner.id,
+            block = block.as_str(),
+            inline = inline.as_str(),
+            offset_y,
+            "Scrolling element into view with options"
+        );
 
-        let x = result.get("x").and_then(|v| v.as_f64()).unwrap_or(0.0);
-        let y = result.get("y").and_then(|v| v.as_f64()).unwrap_or(0.0);
-        let width = result.get("width").and_then(|v| v.as_f64()).unwrap_or(0.0);
-        let height = result.get("height").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        self.call_method(
+            "scrollIntoView",
+            vec![serde_json::json!({
+                "behavior": "instant",
+                "block": block.as_str(),
+                "inline": inline.as_str(),
+            })],
+        )
+        .await?;
 
-        debug!(element_id = %self.inner.id, x = x, y = y, width = width, height = height, "Got bounding rect");
-        Ok((x, y, width, height))
+        if offset_y != 0 {
+            let command = Command::Script(ScriptCommand::Evaluate {
+                script: format!("window.scrollBy(0, {});", -offset_y),
+                args: vec![],
+                isolated: false,
+                timeout: None,
+            });
+            self.send_command(command).await?;
+        }
+
+        Ok(())
     }
-}
+
+    /// Dispatches a mouse wheel event at the element's center.
+    ///
+    /// Fires a real bubbling `WheelEvent` (`deltaMode` = pixel) targeted at
+    /// this element, so delegated wheel handlers run and scrollable
+    /// containers scroll the way they do for a user. Unlike
+    /// [`Element::scroll_into_view`], the page's wheel handlers decide how
+    /// far to scroll.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_x` - Horizontal delta in pixels (positive = right)
+    /// * `delta_y` - Vertical delta in pixels (positive = down)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let map = tab.find_element("#map").await?;
+    /// map.scroll_wheel(0, -300).await?; // zoom in
+    /// ```
+    pub async fn scroll_wheel(&self, delta_x: i32, delta_y: i32) -> Result<()> {
+        debug!(element_id = %self.inner.id, delta_x, delta_y, "Dispatching wheel event on element");
+
+        let command = Command::Input(InputCommand::Wheel {
+            element_id: Some(self.inner.id.clone()),
+            x: None,
+            y: None,
+            delta_x,
+            delta_y,
+            delta_mode: 0, // DOM_DELTA_PIXEL
+        });
+
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Gets the element's bounding rectangle in viewport coordinates.
+    ///
+    /// Uses `getBoundingClientRect()`: the origin is the top-left corner of
+    /// the visible area, so the values change whenever the page scrolls.
+    /// This is the space mouse input uses. For full-page screenshots or
+    /// positions that survive scrolling, use [`Element::get_page_rect`].
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (x, y, width, height) in CSS pixels.
+    pub async fn get_bounding_rect(&self) -> Result<(f64, f64, f64, f64)> {
+        let result = self.call_method("getBoundingClientRect", vec![]).await?;
+
+        let x = result.get("x").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let y = result.get("y").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let width = result.get("width").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let height = result.get("height").and_then(|v| v.as_f64()).unwrap_or(0.0);
+
+        debug!(element_id = %self.inner.id, x = x, y = y, width = width, height = height, "Got bounding rect");
+        Ok((x, y, width, height))
+    }
+
+    /// Gets the element's bounding rectangle in page (document) coordinates.
+    ///
+    /// Adds the current scroll offset to [`Element::get_bounding_rect`], so
+    /// the origin is the top-left corner of the document and the values do
+    /// not change when the page scrolls. Matches the pixel layout of a
+    /// full-page screenshot. Inside an iframe the origin is the frame's
+    /// document.
+    ///
+    /// The rect and scroll offset are read by one script, which briefly
+    /// tags the element with a `data-firefox-webdriver-measure` attribute.
+    ///
+    /// Do not pass these coordinates to mouse input after scrolling; mouse
+    /// input is viewport-relative.
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (x, y, width, height) in CSS pixels.
+    pub async fn get_page_rect(&self) -> Result<(f64, f64, f64, f64)> {
+        let ((x, y, width, height), viewport) = self.measure().await?;
+
+        let rect = (x + viewport.scroll_x, y + viewport.scroll_y, width, height);
+        debug!(element_id = %self.inner.id, ?rect, "Got page rect");
+        Ok(rect)
+    }
+
+    /// Checks if any part of the element is inside the visible viewport.
+    ///
+    /// Returns `false` for zero-size elements and for elements scrolled out
+    /// of view. Does not check whether other elements cover it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if !button.is_in_viewport().await? {
+    ///     button.scroll_into_view_instant().await?;
+    /// }
+    /// button.mouse_click(0).await?;
+    /// ```
+    pub async fn is_in_viewport(&self) -> Result<bool> {
+        let (rect, viewport) = self.measure().await?;
+
+        let visible = rect_intersects_viewport(rect, viewport.width, viewport.height);
+        debug!(element_id = %self.inner.id, visible, "Checked viewport visibility");
+        Ok(visible)
+    }
+
+    /// Reads the element's viewport rect and its frame's viewport in one
+    /// script, so a scroll cannot land between the two readings.
+    async fn measure(&self) -> Result<((f64, f64, f64, f64), Viewport)> {
+        let token = self.inner.id.as_str().to_string();
+        self.call_method(
+            "setAttribute",
+            vec![Value::from(MEASURE_ATTRIBUTE), Value::from(token.clone())],
+        )
+        .await?;
+
+        let args = serde_json::json!([MEASURE_ATTRIBUTE, token]);
+        let script = MEASURE_SCRIPT.replace("__ARGS__", &args.to_string());
+
+        let result = match self.tab().execute_script(&script).await {
+            Ok(result) => result,
+            Err(e) => {
+                let _ = self
+                    .call_method("removeAttribute", vec![Value::from(MEASURE_ATTRIBUTE)])
+                    .await;
+                return Err(e);
+            }
+        };
+
+        if result.is_null() {
+            return Err(Error::stale_element(self.inner.id.clone()));
+        }
+
+        parse_measurement(&result)
+    }
+}
 
 // ============================================================================
 // Element - Checkbox/Radio
@@ -649,6 +1440,68 @@ impl Element {
     }
 }
 
+// ============================================================================
+// Element - File Drop
+// ============================================================================
+
+impl Element {
+    /// Drops files onto this element, as if dragged from the desktop.
+    ///
+    /// For drag-and-drop upload zones built on a `<div>`, which ignore a
+    /// hidden `<input type=file>`. Reads each file and fires bubbling
+    /// `dragenter`, `dragover` and `drop` events, in that order, whose
+    /// `dataTransfer.files` holds `File` objects with the same name,
+    /// contents and a MIME type guessed from the extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Files to drop
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Io`] if a file cannot be read
+    /// - [`Error::InvalidArgument`] if `paths` is empty
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::path::PathBuf;
+    ///
+    /// let zone = tab.find_element(".dropzone").await?;
+    /// zone.drop_files(&[PathBuf::from("fixtures/avatar.png")]).await?;
+    /// ```
+    pub async fn drop_files(&self, paths: &[PathBuf]) -> Result<()> {
+        debug!(element_id = %self.inner.id, count = paths.len(), "Dropping files on element");
+
+        if paths.is_empty() {
+            return Err(Error::invalid_argument(
+                "drop_files requires at least one file",
+            ));
+        }
+
+        let mut files = Vec::with_capacity(paths.len());
+        for path in paths {
+            let bytes = tokio::fs::read(path).await?;
+            files.push(FilePayload {
+                name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                mime_type: guess_mime_type(path).to_string(),
+                data: Base64Standard.encode(bytes),
+            });
+        }
+
+        let command = Command::Element(ElementCommand::DropFiles {
+            element_id: self.inner.id.clone(),
+            files,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Element - Nested Search
 // ============================================================================
@@ -668,6 +1521,7 @@ impl Element {
         let command = Command::Element(ElementCommand::Find {
             strategy: by.strategy().to_string(),
             value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
             parent_id: Some(self.inner.id.clone()),
         });
 
@@ -692,7 +1546,12 @@ impl Element {
             self.inner.frame_id,
             self.inner.session_id,
             self.inner.window.clone(),
-        ))
+        )
+        .with_locator(ElementLocator {
+            by,
+            parent: Some(self.clone()),
+            index: None,
+        }))
     }
 
     /// Finds all child elements using a locator strategy.
@@ -709,6 +1568,7 @@ impl Element {
         let command = Command::Element(ElementCommand::FindAll {
             strategy: by.strategy().to_string(),
             value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
             parent_id: Some(self.inner.id.clone()),
         });
 
@@ -722,7 +1582,8 @@ impl Element {
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
-                    .map(|id| {
+                    .enumerate()
+                    .map(|(index, id)| {
                         Element::new(
                             ElementId::new(id),
                             self.inner.tab_id,
@@ -730,6 +1591,11 @@ impl Element {
                             self.inner.session_id,
                             self.inner.window.clone(),
                         )
+                        .with_locator(ElementLocator {
+                            by: by.clone(),
+                            parent: Some(self.clone()),
+                            index: Some(index),
+                        })
                     })
                     .collect()
             })
@@ -739,6 +1605,227 @@ impl Element {
     }
 }
 
+// ============================================================================
+// Element - Refetch
+// ============================================================================
+
+impl Element {
+    /// Re-runs the search that produced this element and returns a fresh
+    /// handle.
+    ///
+    /// Recovers from [`Error::StaleElement`] on pages that re-render
+    /// elements. Elements from `find_elements` are re-found at the same
+    /// position; a stale parent element is refetched first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the element was not created
+    /// by a find (e.g. from [`Element::parent`] or an event), or
+    /// [`Error::ElementNotFound`] if the search no longer matches.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let price = match price.get_text().await {
+    ///     Err(Error::StaleElement { .. }) => price.refetch().await?.get_text().await?,
+    ///     other => other?,
+    /// };
+    /// ```
+    pub async fn refetch(&self) -> Result<Element> {
+        debug!(element_id = %self.inner.id, "Refetching element");
+
+        let locator = self.inner.locator.as_ref().ok_or_else(|| {
+            Error::invalid_argument(format!(
+                "Element {} was not created by a find and cannot be refetched",
+                self.inner.id
+            ))
+        })?;
+
+        let parent = match &locator.parent {
+            Some(parent) if parent.is_stale().await? => Some(Box::pin(parent.refetch()).await?),
+            parent => parent.clone(),
+        };
+
+        let by = locator.by.clone();
+        let Some(index) = locator.index else {
+            return match parent {
+                Some(parent) => parent.find_element(by).await,
+                None => self.tab().find_element(by).await,
+            };
+        };
+
+        let mut elements = match parent {
+            Some(parent) => parent.find_elements(by.clone()).await?,
+            None => self.tab().find_elements(by.clone()).await?,
+        };
+
+        if index >= elements.len() {
+            return Err(Error::element_not_found(
+                format!("{}:{}[{}]", by.strategy(), by.value(), index),
+                self.inner.tab_id,
+                self.inner.frame_id,
+            ));
+        }
+
+        Ok(elements.swap_remove(index))
+    }
+
+    /// Returns a tab handle for this element's tab and frame.
+    fn tab(&self) -> Tab {
+        Tab::new(
+            self.inner.tab_id,
+            self.inner.frame_id,
+            self.inner.session_id,
+            self.inner.window.clone(),
+        )
+    }
+}
+
+// ============================================================================
+// Element - Traversal
+// ============================================================================
+
+impl Element {
+    /// Returns the parent element, or `None` for the root element.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::By;
+    ///
+    /// // "the price next to this label"
+    /// let label = tab.find_element(By::Text("Price")).await?;
+    /// if let Some(row) = label.parent().await? {
+    ///     let price = row.find_element(By::Css(".amount")).await?;
+    ///     println!("{}", price.get_text().await?);
+    /// }
+    /// ```
+    pub async fn parent(&self) -> Result<Option<Element>> {
+        debug!(element_id = %self.inner.id, "Getting parent element");
+        let value = self.get_property("parentElement").await?;
+        Ok(self.element_from_value(&value))
+    }
+
+    /// Returns the child elements in document order.
+    ///
+    /// Text and comment nodes are skipped.
+    pub async fn children(&self) -> Result<Vec<Element>> {
+        debug!(element_id = %self.inner.id, "Getting child elements");
+        let value = self.get_property("children").await?;
+
+        Ok(value
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| self.element_from_value(item))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Returns the next sibling element, or `None` if this is the last.
+    pub async fn next_sibling(&self) -> Result<Option<Element>> {
+        debug!(element_id = %self.inner.id, "Getting next sibling");
+        let value = self.get_property("nextElementSibling").await?;
+        Ok(self.element_from_value(&value))
+    }
+
+    /// Returns the previous sibling element, or `None` if this is the first.
+    pub async fn previous_sibling(&self) -> Result<Option<Element>> {
+        debug!(element_id = %self.inner.id, "Getting previous sibling");
+        let value = self.get_property("previousElementSibling").await?;
+        Ok(self.element_from_value(&value))
+    }
+
+    /// Returns the host of the shadow root containing this element.
+    ///
+    /// Returns `None` if the element is not inside a shadow tree.
+    pub async fn get_shadow_host(&self) -> Result<Option<Element>> {
+        debug!(element_id = %self.inner.id, "Getting shadow host");
+        let root = self.call_method("getRootNode", vec![]).await?;
+
+        let Some(root) = self.element_from_value(&root) else {
+            return Ok(None);
+        };
+
+        let host = root.get_property("host").await?;
+        Ok(self.element_from_value(&host))
+    }
+
+    /// Returns `true` if the element matches a CSS selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the selector is not valid CSS.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if button.matches(".primary:not([disabled])").await? {
+    ///     button.click().await?;
+    /// }
+    /// ```
+    pub async fn matches(&self, selector: &str) -> Result<bool> {
+        debug!(element_id = %self.inner.id, selector, "Matching selector");
+        let value = self.call_selector_method("matches", selector).await?;
+        Ok(value.as_bool().unwrap_or(false))
+    }
+
+    /// Returns the nearest ancestor (or this element) matching a CSS selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the selector is not valid CSS.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cell = tab.find_element(By::Text("Total")).await?;
+    /// if let Some(row) = cell.closest("tr").await? {
+    ///     println!("{}", row.get_text().await?);
+    /// }
+    /// ```
+    pub async fn closest(&self, selector: &str) -> Result<Option<Element>> {
+        debug!(element_id = %self.inner.id, selector, "Finding closest ancestor");
+        let value = self.call_selector_method("closest", selector).await?;
+        Ok(self.element_from_value(&value))
+    }
+
+    /// Calls a method taking a CSS selector, surfacing invalid selectors.
+    async fn call_selector_method(&self, name: &str, selector: &str) -> Result<Value> {
+        let command = Command::Element(ElementCommand::CallMethod {
+            element_id: self.inner.id.clone(),
+            name: name.to_string(),
+            args: vec![Value::String(selector.to_string())],
+        });
+
+        let response = self.send_command(command).await?;
+
+        if response.error.as_deref() == Some(INVALID_SELECTOR) {
+            return Err(Error::invalid_argument(format!(
+                "Invalid selector: {selector}"
+            )));
+        }
+
+        let result = response.into_result()?;
+        Ok(result.get("value").cloned().unwrap_or(Value::Null))
+    }
+
+    /// Creates a handle for a node returned as `{ "elementId": ... }`.
+    fn element_from_value(&self, value: &Value) -> Option<Element> {
+        let element_id = parse_element_id(value)?;
+
+        Some(Element::new(
+            ElementId::new(element_id),
+            self.inner.tab_id,
+            self.inner.frame_id,
+            self.inner.session_id,
+            self.inner.window.clone(),
+        ))
+    }
+}
+
 // ============================================================================
 // Element - Generic Property Access
 // ============================================================================
@@ -818,7 +1905,8 @@ impl Element {
     /// let screenshot = element.screenshot().await?;
     /// ```
     pub async fn screenshot(&self) -> Result<String> {
-        self.screenshot_with_format("png", None).await
+        let bytes = self.screenshot_bytes_format(ImageFormat::Png).await?;
+        Ok(Base64Standard.encode(bytes))
     }
 
     /// Captures a JPEG screenshot of this element with specified quality.
@@ -827,23 +1915,37 @@ impl Element {
     ///
     /// * `quality` - JPEG quality (0-100)
     pub async fn screenshot_jpeg(&self, quality: u8) -> Result<String> {
-        self.screenshot_with_format("jpeg", Some(quality.min(100)))
-            .await
+        let bytes = self
+            .screenshot_bytes_format(ImageFormat::jpeg(quality))
+            .await?;
+        Ok(Base64Standard.encode(bytes))
     }
 
-    /// Captures a screenshot with specified format.
-    ///
-    /// The extension returns full page screenshot + clip info.
-    /// Rust handles the cropping to avoid canvas security issues.
-    async fn screenshot_with_format(&self, format: &str, quality: Option<u8>) -> Result<String> {
-        use base64::Engine;
-        use base64::engine::general_purpose::STANDARD as Base64Standard;
-        use image::GenericImageView;
+    /// Captures a PNG screenshot and returns raw bytes.
+    pub async fn screenshot_bytes(&self) -> Result<Vec<u8>> {
+        self.screenshot_bytes_format(ImageFormat::Png).await
+    }
 
+    /// Captures a screenshot in the given format and returns raw bytes.
+    ///
+    /// The extension returns a page screenshot plus the element's clip
+    /// rectangle; the image is decoded once, cropped and encoded straight to
+    /// bytes, without an intermediate base64 string. To capture many
+    /// elements, [`Tab::screenshot_elements`](crate::Tab::screenshot_elements)
+    /// shares one page capture between them.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::ImageFormat;
+    ///
+    /// let jpeg = element.screenshot_bytes_format(ImageFormat::jpeg(80)).await?;
+    /// ```
+    pub async fn screenshot_bytes_format(&self, format: ImageFormat) -> Result<Vec<u8>> {
         let command = Command::Element(ElementCommand::CaptureScreenshot {
             element_id: self.inner.id.clone(),
-            format: format.to_string(),
-            quality,
+            format: format.format_str().to_string(),
+            quality: format.quality(),
         });
 
         let response = self.send_command(command).await?;
@@ -864,88 +1966,30 @@ impl Element {
             .and_then(|v| v.as_str())
             .ok_or_else(|| Error::script_error("Screenshot response missing data field"))?;
 
-        // Check if clip info is provided (new format)
-        if let Some(clip) = result.get("clip") {
-            let x = clip.get("x").and_then(|v| v.as_f64()).unwrap_or(0.0) as u32;
-            let y = clip.get("y").and_then(|v| v.as_f64()).unwrap_or(0.0) as u32;
-            let width = clip.get("width").and_then(|v| v.as_f64()).unwrap_or(0.0) as u32;
-            let height = clip.get("height").and_then(|v| v.as_f64()).unwrap_or(0.0) as u32;
-            let scale = clip.get("scale").and_then(|v| v.as_f64()).unwrap_or(1.0);
-
-            // Apply scale to coordinates
-            let x = (x as f64 * scale) as u32;
-            let y = (y as f64 * scale) as u32;
-            let width = (width as f64 * scale) as u32;
-            let height = (height as f64 * scale) as u32;
-
-            if width == 0 || height == 0 {
-                return Err(Error::script_error("Element has zero dimensions"));
-            }
+        let bytes = Base64Standard
+            .decode(data)
+            .map_err(|e| Error::script_error(format!("Failed to decode base64: {}", e)))?;
 
-            // Decode full page image
-            let image_bytes = Base64Standard
-                .decode(data)
-                .map_err(|e| Error::script_error(format!("Failed to decode base64: {}", e)))?;
-
-            let img = image::load_from_memory(&image_bytes)
-                .map_err(|e| Error::script_error(format!("Failed to load image: {}", e)))?;
-
-            // Clamp crop region to image bounds
-            let (img_width, img_height) = img.dimensions();
-            let x = x.min(img_width.saturating_sub(1));
-            let y = y.min(img_height.saturating_sub(1));
-            let width = width.min(img_width.saturating_sub(x));
-            let height = height.min(img_height.saturating_sub(y));
-
-            // Crop
-            let cropped = img.crop_imm(x, y, width, height);
-
-            // Encode back to base64
-            let mut output = std::io::Cursor::new(Vec::new());
-            match format {
-                "jpeg" => {
-                    let q = quality.unwrap_or(85);
-                    cropped
-                        .write_to(&mut output, image::ImageFormat::Jpeg)
-                        .map_err(|e| {
-                            Error::script_error(format!("Failed to encode JPEG: {}", e))
-                        })?;
-                    // Note: image crate doesn't support quality param directly in write_to
-                    // For proper quality control, would need jpeg encoder directly
-                    let _ = q; // suppress unused warning
-                }
-                _ => {
-                    cropped
-                        .write_to(&mut output, image::ImageFormat::Png)
-                        .map_err(|e| Error::script_error(format!("Failed to encode PNG: {}", e)))?;
-                }
-            }
+        // Without clip info (old format) the data is already cropped
+        let Some(clip) = result.get("clip") else {
+            return Ok(bytes);
+        };
 
-            Ok(Base64Standard.encode(output.into_inner()))
-        } else {
-            // Old format: data is already cropped
-            Ok(data.to_string())
-        }
-    }
+        let field = |name: &str| clip.get(name).and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let rect = (field("x"), field("y"), field("width"), field("height"));
+        let scale = clip.get("scale").and_then(|v| v.as_f64()).unwrap_or(1.0);
 
-    /// Captures a screenshot and returns raw bytes.
-    pub async fn screenshot_bytes(&self) -> Result<Vec<u8>> {
-        use base64::Engine;
-        use base64::engine::general_purpose::STANDARD as Base64Standard;
+        let page = decode_image(&bytes)?;
+        let (x, y, width, height) = pixel_rect(rect, scale, &page)
+            .ok_or_else(|| Error::script_error("Element has zero dimensions"))?;
 
-        let base64_data = self.screenshot().await?;
-        Base64Standard
-            .decode(&base64_data)
-            .map_err(|e| Error::script_error(format!("Failed to decode base64: {}", e)))
+        encode_image(&page.crop_imm(x, y, width, height), format)
     }
 
     /// Captures a screenshot and saves to a file.
     ///
     /// Format is determined by file extension (.png or .jpg/.jpeg).
     pub async fn save_screenshot(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
-        use base64::Engine;
-        use base64::engine::general_purpose::STANDARD as Base64Standard;
-
         let path = path.as_ref();
         let ext = path
             .extension()
@@ -953,20 +1997,66 @@ impl Element {
             .unwrap_or("png")
             .to_lowercase();
 
-        let base64_data = match ext.as_str() {
-            "jpg" | "jpeg" => self.screenshot_jpeg(85).await?,
-            _ => self.screenshot().await?,
+        let format = match ext.as_str() {
+            "jpg" | "jpeg" => ImageFormat::jpeg(85),
+            _ => ImageFormat::Png,
         };
 
-        let bytes = Base64Standard
-            .decode(&base64_data)
-            .map_err(|e| Error::script_error(format!("Failed to decode base64: {}", e)))?;
-
+        let bytes = self.screenshot_bytes_format(format).await?;
         std::fs::write(path, bytes).map_err(Error::Io)?;
         Ok(())
     }
 }
 
+// ============================================================================
+// Element - Release
+// ============================================================================
+
+impl Element {
+    /// Removes this element from the content script's element store.
+    ///
+    /// Every find registers the node in the content script so later
+    /// commands can reach it. Handles release their entry automatically
+    /// when the last clone is dropped (a fire-and-forget command, skipped
+    /// outside a Tokio runtime); call this to release it deterministically.
+    /// Afterwards every operation on this handle fails with
+    /// [`Error::StaleElement`].
+    ///
+    /// Releasing an element that is already gone succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for row in tab.find_elements(By::css("tr")).await? {
+    ///     rows.push(row.get_text().await?);
+    ///     row.release().await?;
+    /// }
+    /// ```
+    pub async fn release(&self) -> Result<()> {
+        debug!(element_id = %self.inner.id, "Releasing element");
+
+        if self.inner.released.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let command = Command::Element(ElementCommand::Release {
+            element_id: self.inner.id.clone(),
+        });
+
+        match self
+            .send_command(command)
+            .await
+            .and_then(Response::into_result)
+        {
+            Ok(_) | Err(Error::StaleElement { .. }) => Ok(()),
+            Err(e) => {
+                self.inner.released.store(false, Ordering::SeqCst);
+                Err(e)
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Element - Internal
 // ============================================================================
@@ -974,6 +2064,16 @@ impl Element {
 impl Element {
     /// Sends a command and returns the response.
     async fn send_command(&self, command: Command) -> Result<Response> {
+        self.send_command_with_timeout(command, DEFAULT_COMMAND_TIMEOUT)
+            .await
+    }
+
+    /// Sends a command with a custom timeout.
+    async fn send_command_with_timeout(
+        &self,
+        command: Command,
+        request_timeout: Duration,
+    ) -> Result<Response> {
         let window = self
             .inner
             .window
@@ -982,11 +2082,129 @@ impl Element {
 
         let request = Request::new(self.inner.tab_id, self.inner.frame_id, command);
 
-        window
+        let response = window
             .inner
             .pool
-            .send(window.inner.session_id, request)
-            .await
+            .send_with_timeout(window.inner.session_id, request, request_timeout)
+            .await?;
+
+        if is_stale_response(&response) {
+            debug!(element_id = %self.inner.id, "Element is stale");
+            return Err(Error::stale_element(self.inner.id.clone()));
+        }
+
+        Ok(response)
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Returns `true` if the extension reported the element as stale.
+fn is_stale_response(response: &Response) -> bool {
+    response.error.as_deref() == Some(STALE_ELEMENT)
+}
+
+/// Parses the result of [`MEASURE_SCRIPT`], rejecting missing fields.
+fn parse_measurement(value: &Value) -> Result<((f64, f64, f64, f64), Viewport)> {
+    let number = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_f64)
+            .ok_or_else(|| Error::protocol(format!("Measurement has no numeric '{key}'")))
+    };
+
+    let rect = (
+        number("x")?,
+        number("y")?,
+        number("width")?,
+        number("height")?,
+    );
+    let viewport = Viewport {
+        scroll_x: number("scrollX")?,
+        scroll_y: number("scrollY")?,
+        width: number("innerWidth")?,
+        height: number("innerHeight")?,
+    };
+
+    Ok((rect, viewport))
+}
+
+/// Returns `true` if a viewport-relative rect overlaps a viewport of the
+/// given size.
+fn rect_intersects_viewport(
+    (x, y, width, height): (f64, f64, f64, f64),
+    viewport_width: f64,
+    viewport_height: f64,
+) -> bool {
+    width > 0.0
+        && height > 0.0
+        && x < viewport_width
+        && y < viewport_height
+        && x + width > 0.0
+        && y + height > 0.0
+}
+
+/// Returns `steps` evenly spaced points from `start` to `start + delta`,
+/// excluding `start`.
+fn drag_path(start: (i32, i32), (dx, dy): (i32, i32), steps: usize) -> Vec<(i32, i32)> {
+    let steps = steps.max(1);
+    (1..=steps)
+        .map(|step| {
+            let t = step as f64 / steps as f64;
+            (
+                start.0 + (f64::from(dx) * t).round() as i32,
+                start.1 + (f64::from(dy) * t).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/// Converts an offset from a rect's top-left corner to a viewport point.
+///
+/// Returns `None` if the offset lies outside the rect.
+fn offset_point(
+    (x, y, width, height): (f64, f64, f64, f64),
+    offset_x: f64,
+    offset_y: f64,
+) -> Option<(i32, i32)> {
+    let inside = (0.0..=width).contains(&offset_x) && (0.0..=height).contains(&offset_y);
+    inside.then(|| ((x + offset_x).round() as i32, (y + offset_y).round() as i32))
+}
+
+/// Extracts the element ID from a node value returned by the extension.
+fn parse_element_id(value: &Value) -> Option<&str> {
+    value.get("elementId")?.as_str()
+}
+
+/// Guesses a file's MIME type from its extension, as browsers do for `File.type`.
+fn guess_mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "txt" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "xml" => "application/xml",
+        "mp4" => "video/mp4",
+        "mp3" => "audio/mpeg",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        _ => "application/octet-stream",
     }
 }
 
@@ -996,7 +2214,62 @@ impl Element {
 
 #[cfg(test)]
 mod tests {
-    use super::Element;
+    use super::{
+        Element, ElementLocator, ScrollAlign, drag_path, guess_mime_type, is_stale_response,
+        offset_point, parse_element_id, parse_measurement, rect_intersects_viewport,
+    };
+    use crate::browser::By;
+    use crate::error::Error;
+    use crate::identifiers::{ElementId, FrameId, SessionId, TabId};
+    use crate::protocol::Response;
+
+    fn detached_element() -> Element {
+        Element::new(
+            ElementId::new("element-1"),
+            TabId::new(1).expect("tab id"),
+            FrameId::main(),
+            SessionId::next(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_refetch_without_locator() {
+        let result = detached_element().refetch().await;
+        assert!(matches!(result, Err(Error::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_with_locator() {
+        let element = detached_element().with_locator(ElementLocator {
+            by: By::css(".row"),
+            parent: None,
+            index: Some(2),
+        });
+        let locator = element.inner.locator.as_ref().expect("locator");
+        assert_eq!(locator.by, By::css(".row"));
+        assert_eq!(locator.index, Some(2));
+    }
+
+    #[test]
+    fn test_is_stale_response() {
+        let stale: Response = serde_json::from_value(serde_json::json!({
+            "id": "00000000-0000-0000-0000-000000000000",
+            "type": "error",
+            "error": "stale element",
+            "message": "Element not found in store"
+        }))
+        .expect("deserialize");
+        assert!(is_stale_response(&stale));
+
+        let other: Response = serde_json::from_value(serde_json::json!({
+            "id": "00000000-0000-0000-0000-000000000000",
+            "type": "error",
+            "error": "unknown error"
+        }))
+        .expect("deserialize");
+        assert!(!is_stale_response(&other));
+    }
 
     #[test]
     fn test_element_is_clone() {
@@ -1009,4 +2282,104 @@ mod tests {
         fn assert_debug<T: std::fmt::Debug>() {}
         assert_debug::<Element>();
     }
+
+    #[test]
+    fn test_scroll_align_as_str() {
+        assert_eq!(ScrollAlign::Start.as_str(), "start");
+        assert_eq!(ScrollAlign::default().as_str(), "center");
+        assert_eq!(ScrollAlign::End.as_str(), "end");
+        assert_eq!(ScrollAlign::Nearest.as_str(), "nearest");
+    }
+
+    #[test]
+    fn test_drag_path() {
+        assert_eq!(
+            drag_path((10, 10), (100, -10), 4),
+            [(35, 7), (60, 5), (85, 2), (110, 0)]
+        );
+        assert_eq!(drag_path((0, 0), (5, 5), 0), [(5, 5)]);
+    }
+
+    #[test]
+    fn test_offset_point() {
+        let rect = (100.0, 50.0, 200.0, 20.0);
+        assert_eq!(offset_point(rect, 0.0, 0.0), Some((100, 50)));
+        assert_eq!(offset_point(rect, 150.4, 10.6), Some((250, 61)));
+        assert_eq!(offset_point(rect, 200.0, 20.0), Some((300, 70)));
+        assert_eq!(offset_point(rect, 201.0, 10.0), None);
+        assert_eq!(offset_point(rect, 10.0, -1.0), None);
+    }
+
+    #[test]
+    fn test_parse_measurement() {
+        let value = serde_json::json!({
+            "x": 10.0, "y": 20.0, "width": 30.0, "height": 40.0,
+            "scrollX": 0.0, "scrollY": 500.0, "innerWidth": 800.0, "innerHeight": 600.0
+        });
+
+        let (rect, viewport) = parse_measurement(&value).unwrap();
+        assert_eq!(rect, (10.0, 20.0, 30.0, 40.0));
+        assert_eq!(viewport.scroll_y, 500.0);
+        assert_eq!(viewport.width, 800.0);
+    }
+
+    #[test]
+    fn test_parse_measurement_rejects_missing_fields() {
+        let value = serde_json::json!({ "x": 10.0, "y": 20.0, "width": 30.0, "height": 40.0 });
+
+        let err = parse_measurement(&value).unwrap_err();
+        assert!(err.to_string().contains("scrollX"));
+    }
+
+    #[test]
+    fn test_rect_intersects_viewport() {
+        assert!(rect_intersects_viewport(
+            (10.0, 10.0, 50.0, 20.0),
+            800.0,
+            600.0
+        ));
+        assert!(rect_intersects_viewport(
+            (-40.0, 590.0, 50.0, 20.0),
+            800.0,
+            600.0
+        ));
+        assert!(!rect_intersects_viewport(
+            (10.0, -30.0, 50.0, 20.0),
+            800.0,
+            600.0
+        ));
+        assert!(!rect_intersects_viewport(
+            (10.0, 600.0, 50.0, 20.0),
+            800.0,
+            600.0
+        ));
+        assert!(!rect_intersects_viewport(
+            (10.0, 10.0, 0.0, 20.0),
+            800.0,
+            600.0
+        ));
+    }
+
+    #[test]
+    fn test_parse_element_id() {
+        let value = serde_json::json!({ "elementId": "abc-123" });
+        assert_eq!(parse_element_id(&value), Some("abc-123"));
+        assert_eq!(parse_element_id(&serde_json::Value::Null), None);
+        assert_eq!(parse_element_id(&serde_json::json!("text")), None);
+    }
+
+    #[test]
+    fn test_guess_mime_type() {
+        use std::path::Path;
+
+        assert_eq!(guess_mime_type(Path::new("photo.JPG")), "image/jpeg");
+        assert_eq!(
+            guess_mime_type(Path::new("dir/report.pdf")),
+            "application/pdf"
+        );
+        assert_eq!(
+            guess_mime_type(Path::new("README")),
+            "application/octet-stream"
+        );
+    }
 }
@@ -0,0 +1,145 @@
+//! Media emulation types.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use firefox_webdriver::{ColorScheme, MediaFeature, MediaType};
+//!
+//! tab.emulate_media(vec![
+//!     MediaFeature::PrefersColorScheme(ColorScheme::Dark),
+//!     MediaFeature::MediaType(MediaType::Print),
+//! ])
+//! .await?;
+//! ```
+
+// ============================================================================
+// MediaFeature
+// ============================================================================
+
+/// A CSS media feature to emulate in a tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaFeature {
+    /// `prefers-color-scheme`.
+    PrefersColorScheme(ColorScheme),
+    /// `prefers-reduced-motion`.
+    PrefersReducedMotion(ReducedMotion),
+    /// Media type (`screen` or `print`).
+    MediaType(MediaType),
+}
+
+/// Value of `prefers-color-scheme`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// `light`.
+    Light,
+    /// `dark`.
+    Dark,
+}
+
+/// Value of `prefers-reduced-motion`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReducedMotion {
+    /// `no-preference`.
+    NoPreference,
+    /// `reduce`.
+    Reduce,
+}
+
+/// CSS media type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    /// `screen`.
+    Screen,
+    /// `print`.
+    Print,
+}
+
+impl ColorScheme {
+    /// Returns the CSS keyword.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+impl ReducedMotion {
+    /// Returns the CSS keyword.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoPreference => "no-preference",
+            Self::Reduce => "reduce",
+        }
+    }
+}
+
+impl MediaType {
+    /// Returns the CSS keyword.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Screen => "screen",
+            Self::Print => "print",
+        }
+    }
+}
+
+// ============================================================================
+// EmulatedMedia
+// ============================================================================
+
+/// Media features grouped by kind, as sent to the extension.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct EmulatedMedia {
+    pub color_scheme: Option<ColorScheme>,
+    pub reduced_motion: Option<ReducedMotion>,
+    pub media_type: Option<MediaType>,
+}
+
+impl EmulatedMedia {
+    /// Groups features by kind; a later feature of the same kind wins.
+    pub(crate) fn from_features(features: &[MediaFeature]) -> Self {
+        features.iter().fold(Self::default(), |mut media, feature| {
+            match *feature {
+                MediaFeature::PrefersColorScheme(scheme) => media.color_scheme = Some(scheme),
+                MediaFeature::PrefersReducedMotion(motion) => media.reduced_motion = Some(motion),
+                MediaFeature::MediaType(media_type) => media.media_type = Some(media_type),
+            }
+            media
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorScheme, EmulatedMedia, MediaFeature, MediaType, ReducedMotion};
+
+    #[test]
+    fn test_from_features_last_wins() {
+        let media = EmulatedMedia::from_features(&[
+            MediaFeature::PrefersColorScheme(ColorScheme::Light),
+            MediaFeature::MediaType(MediaType::Print),
+            MediaFeature::PrefersColorScheme(ColorScheme::Dark),
+        ]);
+        assert_eq!(media.color_scheme, Some(ColorScheme::Dark));
+        assert_eq!(media.media_type, Some(MediaType::Print));
+        assert_eq!(media.reduced_motion, None);
+    }
+
+    #[test]
+    fn test_keywords() {
+        assert_eq!(ColorScheme::Dark.as_str(), "dark");
+        assert_eq!(ReducedMotion::NoPreference.as_str(), "no-preference");
+        assert_eq!(MediaType::Print.as_str(), "print");
+    }
+}
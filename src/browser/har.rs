@@ -0,0 +1,749 @@
+//! HAR (HTTP Archive) capture types.
+//!
+//! Types for recording network activity as a HAR 1.2 document.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use firefox_webdriver::HarCaptureOptions;
+//!
+//! tab.start_har_capture().await?;
+//! tab.goto("https://example.com").await?;
+//! let har = tab.stop_har_capture().await?;
+//!
+//! std::fs::write("example.har", har.to_json()?)?;
+//!
+//! // Headers only, no response bodies
+//! tab.start_har_capture_with(HarCaptureOptions::new().without_bodies()).await?;
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::error::{Error, Result};
+use crate::identifiers::InterceptId;
+
+use super::network::RequestBody;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// HAR specification version produced.
+const HAR_VERSION: &str = "1.2";
+
+/// Creator name recorded in the HAR log.
+const CREATOR_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Creator version recorded in the HAR log.
+const CREATOR_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ============================================================================
+// HarCaptureOptions
+// ============================================================================
+
+/// Options controlling what a HAR capture records.
+///
+/// Response bodies can dominate memory use on media-heavy pages; omit them
+/// or cap their size for long captures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HarCaptureOptions {
+    /// Record response bodies.
+    pub include_bodies: bool,
+
+    /// Maximum bytes kept per body (request or response); longer bodies are truncated.
+    pub max_body_size: Option<usize>,
+}
+
+impl Default for HarCaptureOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HarCaptureOptions {
+    /// Creates options that record everything, including full bodies.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            include_bodies: true,
+            max_body_size: None,
+        }
+    }
+
+    /// Omits response bodies from the capture.
+    #[inline]
+    #[must_use]
+    pub const fn without_bodies(mut self) -> Self {
+        self.include_bodies = false;
+        self
+    }
+
+    /// Truncates bodies longer than `bytes`.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+}
+
+// ============================================================================
+// HAR Types
+// ============================================================================
+
+/// Root of a HAR document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Har {
+    /// The HAR log.
+    pub log: HarLog,
+}
+
+/// HAR log containing all entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HarLog {
+    /// HAR format version.
+    pub version: String,
+    /// Tool that produced the log.
+    pub creator: HarCreator,
+    /// Recorded requests in start order.
+    pub entries: Vec<HarEntry>,
+}
+
+/// Tool that produced a HAR log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HarCreator {
+    /// Tool name.
+    pub name: String,
+    /// Tool version.
+    pub version: String,
+}
+
+/// A single request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    /// Request start time (ISO 8601).
+    pub started_date_time: String,
+    /// Total elapsed time in milliseconds.
+    pub time: f64,
+    /// Request details.
+    pub request: HarRequest,
+    /// Response details.
+    pub response: HarResponse,
+    /// Cache details (always empty).
+    pub cache: HarCache,
+    /// Timing breakdown.
+    pub timings: HarTimings,
+    /// Resource type (document, script, xhr, etc.).
+    #[serde(rename = "_resourceType", skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+}
+
+/// Request details of a HAR entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    /// HTTP method.
+    pub method: String,
+    /// Absolute URL.
+    pub url: String,
+    /// HTTP version (empty if unknown).
+    pub http_version: String,
+    /// Request cookies.
+    pub cookies: Vec<HarNameValue>,
+    /// Request headers.
+    pub headers: Vec<HarNameValue>,
+    /// Parsed query string parameters.
+    pub query_string: Vec<HarNameValue>,
+    /// Posted data, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+    /// Header size in bytes (-1 if unknown).
+    pub headers_size: i64,
+    /// Body size in bytes (-1 if unknown).
+    pub body_size: i64,
+}
+
+/// Response details of a HAR entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarResponse {
+    /// HTTP status code (0 if no response was received).
+    pub status: u16,
+    /// HTTP status text.
+    pub status_text: String,
+    /// HTTP version (empty if unknown).
+    pub http_version: String,
+    /// Response cookies.
+    pub cookies: Vec<HarNameValue>,
+    /// Response headers.
+    pub headers: Vec<HarNameValue>,
+    /// Response content.
+    pub content: HarContent,
+    /// Redirect target from the `Location` header.
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    /// Header size in bytes (-1 if unknown).
+    pub headers_size: i64,
+    /// Body size in bytes (-1 if unknown).
+    pub body_size: i64,
+}
+
+/// Name/value pair used for headers, cookies and query parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HarNameValue {
+    /// Name.
+    pub name: String,
+    /// Value.
+    pub value: String,
+}
+
+/// Posted request data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    /// MIME type of the posted data.
+    pub mime_type: String,
+    /// Posted data as text.
+    pub text: String,
+}
+
+/// Response content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    /// Length of the returned content in bytes.
+    pub size: i64,
+    /// MIME type of the response.
+    pub mime_type: String,
+    /// Response body text, if captured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Note about the content (e.g. truncation).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Cache details (not recorded).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HarCache {}
+
+/// Timing breakdown in milliseconds (-1 for phases that are not measured).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HarTimings {
+    /// Time spent queued.
+    pub blocked: f64,
+    /// DNS resolution time.
+    pub dns: f64,
+    /// Connection time.
+    pub connect: f64,
+    /// Time sending the request.
+    pub send: f64,
+    /// Time waiting for the first response byte.
+    pub wait: f64,
+    /// Time reading the response.
+    pub receive: f64,
+    /// TLS negotiation time.
+    pub ssl: f64,
+}
+
+// ============================================================================
+// Har - Implementation
+// ============================================================================
+
+impl Har {
+    /// Serializes the HAR document as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(Error::Json)
+    }
+
+    /// Returns the number of recorded entries.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.log.entries.len()
+    }
+
+    /// Returns `true` if no requests were recorded.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.log.entries.is_empty()
+    }
+}
+
+// ============================================================================
+// HarRecorder
+// ============================================================================
+
+/// A request whose response may still be in flight.
+#[derive(Debug, Default)]
+struct PendingEntry {
+    started_ms: f64,
+    response_started_ms: Option<f64>,
+    completed_ms: Option<f64>,
+    url: String,
+    method: String,
+    resource_type: Option<String>,
+    request_headers: Vec<HarNameValue>,
+    post_data: Option<HarPostData>,
+    request_body_size: i64,
+    status: u16,
+    status_text: String,
+    response_headers: Vec<HarNameValue>,
+    body: Option<String>,
+    body_size: i64,
+    body_truncated: bool,
+}
+
+/// Accumulates network events into HAR entries.
+#[derive(Debug)]
+pub(crate) struct HarRecorder {
+    /// Capture options.
+    options: HarCaptureOptions,
+    /// Entries in request start order.
+    entries: Vec<PendingEntry>,
+    /// Request ID to entry index.
+    index: FxHashMap<String, usize>,
+}
+
+impl HarRecorder {
+    /// Creates an empty recorder.
+    pub(crate) fn new(options: HarCaptureOptions) -> Self {
+        Self {
+            options,
+            entries: Vec::new(),
+            index: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the entry for `request_id`, creating it on first sight.
+    fn entry(&mut self, request_id: &str) -> &mut PendingEntry {
+        let index = match self.index.get(request_id) {
+            Some(&index) => index,
+            None => {
+                self.entries.push(PendingEntry {
+                    started_ms: now_ms(),
+                    request_body_size: -1,
+                    body_size: -1,
+                    ..PendingEntry::default()
+                });
+                let index = self.entries.len() - 1;
+                self.index.insert(request_id.to_string(), index);
+                index
+            }
+        };
+        &mut self.entries[index]
+    }
+
+    /// Records the request line and headers.
+    pub(crate) fn record_request(
+        &mut self,
+        request_id: &str,
+        url: &str,
+        method: &str,
+        headers: &HashMap<String, String>,
+    ) {
+        let entry = self.entry(request_id);
+        entry.url = url.to_string();
+        entry.method = method.to_string();
+        entry.request_headers = to_name_values(headers);
+    }
+
+    /// Records the request body.
+    pub(crate) fn record_request_body(
+        &mut self,
+        request_id: &str,
+        url: &str,
+        method: &str,
+        resource_type: &str,
+        body: Option<&RequestBody>,
+    ) {
+        let max_body_size = self.options.max_body_size;
+        let entry = self.entry(request_id);
+
+        if entry.url.is_empty() {
+            entry.url = url.to_string();
+            entry.method = method.to_string();
+        }
+        entry.resource_type = Some(resource_type.to_string());
+
+        if let Some((mime_type, text)) = body.and_then(post_data_text) {
+            entry.request_body_size = text.len() as i64;
+            let (text, _) = truncate_body(text, max_body_size);
+            entry.post_data = Some(HarPostData { mime_type, text });
+        }
+    }
+
+    /// Records the response status line and headers.
+    pub(crate) fn record_response(
+        &mut self,
+        request_id: &str,
+        status: u16,
+        status_text: &str,
+        headers: &HashMap<String, String>,
+    ) {
+        let entry = self.entry(request_id);
+        entry.response_started_ms = Some(now_ms());
+        entry.status = status;
+        entry.status_text = status_text.to_string();
+        entry.response_headers = to_name_values(headers);
+    }
+
+    /// Records the response body.
+    pub(crate) fn record_response_body(&mut self, request_id: &str, body: String) {
+        if !self.options.include_bodies {
+            return;
+        }
+
+        let max_body_size = self.options.max_body_size;
+        let entry = self.entry(request_id);
+        entry.body_size = body.len() as i64;
+        let (body, truncated) = truncate_body(body, max_body_size);
+        entry.body = Some(body);
+        entry.body_truncated = truncated;
+        entry.completed_ms = Some(now_ms());
+    }
+
+    /// Builds the HAR document from all recorded entries.
+    pub(crate) fn finish(self) -> Har {
+        let max_body_size = self.options.max_body_size;
+        let entries = self
+            .entries
+            .into_iter()
+            .map(|entry| build_entry(entry, max_body_size))
+            .collect();
+
+        Har {
+            log: HarLog {
+                version: HAR_VERSION.to_string(),
+                creator: HarCreator {
+                    name: CREATOR_NAME.to_string(),
+                    version: CREATOR_VERSION.to_string(),
+                },
+                entries,
+            },
+        }
+    }
+}
+
+// ============================================================================
+// HarCapture
+// ============================================================================
+
+/// An active HAR capture on a tab.
+pub(crate) struct HarCapture {
+    /// Shared recorder fed by the event handler.
+    pub recorder: Arc<Mutex<HarRecorder>>,
+    /// Intercept registered for the capture.
+    pub intercept_id: InterceptId,
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Converts a pending entry into a HAR entry.
+fn build_entry(entry: PendingEntry, max_body_size: Option<usize>) -> HarEntry {
+    let response_started = entry.response_started_ms.unwrap_or(entry.started_ms);
+    let completed = entry.completed_ms.unwrap_or(response_started);
+    let wait = (response_started - entry.started_ms).max(0.0);
+    let receive = (completed - response_started).max(0.0);
+
+    let mime_type = find_header(&entry.response_headers, "content-type").unwrap_or_default();
+    let redirect_url = find_header(&entry.response_headers, "location").unwrap_or_default();
+
+    let comment = entry.body_truncated.then(|| {
+        format!(
+            "Body truncated to {} of {} bytes",
+            max_body_size.unwrap_or_default(),
+            entry.body_size
+        )
+    });
+
+    HarEntry {
+        started_date_time: format_iso8601(entry.started_ms),
+        time: wait + receive,
+        request: HarRequest {
+            query_string: parse_query_string(&entry.url),
+            method: entry.method,
+            url: entry.url,
+            http_version: String::new(),
+            cookies: Vec::new(),
+            headers: entry.request_headers,
+            post_data: entry.post_data,
+            headers_size: -1,
+            body_size: entry.request_body_size,
+        },
+        response: HarResponse {
+            status: entry.status,
+            status_text: entry.status_text,
+            http_version: String::new(),
+            cookies: Vec::new(),
+            headers: entry.response_headers,
+            content: HarContent {
+                size: entry.body_size.max(0),
+                mime_type,
+                text: entry.body,
+                comment,
+            },
+            redirect_url,
+            headers_size: -1,
+            body_size: entry.body_size,
+        },
+        cache: HarCache::default(),
+        timings: HarTimings {
+            blocked: -1.0,
+            dns: -1.0,
+            connect: -1.0,
+            send: 0.0,
+            wait,
+            receive,
+            ssl: -1.0,
+        },
+        resource_type: entry.resource_type,
+    }
+}
+
+/// Converts a header map into sorted HAR name/value pairs.
+fn to_name_values(headers: &HashMap<String, String>) -> Vec<HarNameValue> {
+    let mut pairs: Vec<HarNameValue> = headers
+        .iter()
+        .map(|(name, value)| HarNameValue {
+            name: name.clone(),
+            value: value.clone(),
+        })
+        .collect();
+    pairs.sort_by(|a, b| a.name.cmp(&b.name));
+    pairs
+}
+
+/// Finds a header value by case-insensitive name.
+fn find_header(headers: &[HarNameValue], name: &str) -> Option<String> {
+    headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case(name))
+        .map(|h| h.value.clone())
+}
+
+/// Parses query string parameters from a URL.
+fn parse_query_string(url: &str) -> Vec<HarNameValue> {
+    Url::parse(url)
+        .map(|url| {
+            url.query_pairs()
+                .map(|(name, value)| HarNameValue {
+                    name: name.into_owned(),
+                    value: value.into_owned(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Converts a request body into a MIME type and text.
+fn post_data_text(body: &RequestBody) -> Option<(String, String)> {
+    match body {
+        RequestBody::FormData(fields) => {
+            let mut names: Vec<&String> = fields.keys().collect();
+            names.sort();
+
+            let text = names
+                .into_iter()
+                .flat_map(|name| {
+                    fields[name].iter().map(move |value| {
+                        format!(
+                            "{}={}",
+                            urlencoding::encode(name),
+                            urlencoding::encode(value)
+                        )
+                    })
+                })
+                .collect::<Vec<_>>()
+                .join("&");
+
+            Some(("application/x-www-form-urlencoded".to_string(), text))
+        }
+        RequestBody::Raw(bytes) => Some((
+            "application/octet-stream".to_string(),
+            String::from_utf8_lossy(bytes).into_owned(),
+        )),
+        RequestBody::Error(_) => None,
+    }
+}
+
+/// Truncates a body to at most `max` bytes on a character boundary.
+///
+/// Returns the (possibly shortened) body and whether it was truncated.
+fn truncate_body(mut body: String, max: Option<usize>) -> (String, bool) {
+    match max {
+        Some(max) if body.len() > max => {
+            let mut end = max;
+            while !body.is_char_boundary(end) {
+                end -= 1;
+            }
+            body.truncate(end);
+            (body, true)
+        }
+        _ => (body, false),
+    }
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+fn now_ms() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64() * 1000.0)
+        .unwrap_or_default()
+}
+
+/// Formats milliseconds since the Unix epoch as an ISO 8601 UTC timestamp.
+fn format_iso8601(epoch_ms: f64) -> String {
+    let total_ms = epoch_ms.max(0.0) as u64;
+    let millis = total_ms % 1000;
+    let total_secs = total_ms / 1000;
+    let days = (total_secs / 86_400) as i64;
+    let secs_of_day = total_secs % 86_400;
+
+    // Civil date from days since epoch (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60,
+        secs_of_day % 60,
+        millis
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_iso8601() {
+        assert_eq!(format_iso8601(0.0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            format_iso8601(1_735_171_200_123.0),
+            "2024-12-26T00:00:00.123Z"
+        );
+        assert_eq!(
+            format_iso8601(951_782_400_000.0),
+            "2000-02-29T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_truncate_body() {
+        let (body, truncated) = truncate_body("hello world".to_string(), Some(5));
+        assert_eq!(body, "hello");
+        assert!(truncated);
+
+        let (body, truncated) = truncate_body("héllo".to_string(), Some(2));
+        assert_eq!(body, "h");
+        assert!(truncated);
+
+        let (body, truncated) = truncate_body("short".to_string(), None);
+        assert_eq!(body, "short");
+        assert!(!truncated);
+    }
+
+    #[test]
+    fn test_recorder_builds_entry() {
+        let mut recorder = HarRecorder::new(HarCaptureOptions::new());
+
+        let mut request_headers = HashMap::new();
+        request_headers.insert("Accept".to_string(), "*/*".to_string());
+        recorder.record_request(
+            "1",
+            "https://example.com/api?q=rust",
+            "GET",
+            &request_headers,
+        );
+
+        let mut response_headers = HashMap::new();
+        response_headers.insert("Content-Type".to_string(), "application/json".to_string());
+        recorder.record_response("1", 200, "OK", &response_headers);
+        recorder.record_response_body("1", r#"{"ok":true}"#.to_string());
+
+        let har = recorder.finish();
+        assert_eq!(har.log.version, "1.2");
+        assert_eq!(har.len(), 1);
+
+        let entry = &har.log.entries[0];
+        assert_eq!(entry.request.method, "GET");
+        assert_eq!(entry.request.query_string[0].name, "q");
+        assert_eq!(entry.response.status, 200);
+        assert_eq!(entry.response.content.mime_type, "application/json");
+        assert_eq!(
+            entry.response.content.text.as_deref(),
+            Some(r#"{"ok":true}"#)
+        );
+    }
+
+    #[test]
+    fn test_recorder_without_bodies() {
+        let mut recorder = HarRecorder::new(HarCaptureOptions::new().without_bodies());
+        recorder.record_request("1", "https://example.com/", "GET", &HashMap::new());
+        recorder.record_response_body("1", "body".to_string());
+
+        let har = recorder.finish();
+        assert!(har.log.entries[0].response.content.text.is_none());
+    }
+
+    #[test]
+    fn test_recorder_caps_body() {
+        let options = HarCaptureOptions::new().with_max_body_size(4);
+        let mut recorder = HarRecorder::new(options);
+        recorder.record_request("1", "https://example.com/", "GET", &HashMap::new());
+        recorder.record_response_body("1", "0123456789".to_string());
+
+        let har = recorder.finish();
+        let content = &har.log.entries[0].response.content;
+        assert_eq!(content.text.as_deref(), Some("0123"));
+        assert_eq!(content.size, 10);
+        assert!(content.comment.is_some());
+    }
+
+    #[test]
+    fn test_har_serialization() {
+        let mut recorder = HarRecorder::new(HarCaptureOptions::new());
+        recorder.record_request("1", "https://example.com/", "GET", &HashMap::new());
+        let json = recorder.finish().to_json().expect("serialize");
+
+        assert!(json.contains(r#""startedDateTime""#));
+        assert!(json.contains(r#""redirectURL""#));
+        assert!(json.contains(r#""queryString""#));
+    }
+}
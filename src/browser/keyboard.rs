@@ -14,8 +14,17 @@
 //!
 //! // For typing text, use type_text instead:
 //! element.type_text("Hello, World!").await?;
+//!
+//! // Text and keys in one call (WebDriver key code points)
+//! element.send_keys("hello\u{E004}world\u{E007}").await?;
 //! ```
 
+// ============================================================================
+// Imports
+// ============================================================================
+
+use crate::error::{Error, Result};
+
 // ============================================================================
 // Key Enum
 // ============================================================================
@@ -116,6 +125,131 @@ impl Key {
     pub fn is_printable(self) -> bool {
         self.properties().3
     }
+
+    /// Returns the WebDriver code point for this key (e.g. `'\u{E007}'`
+    /// for Enter), as used in Selenium's `Keys` constants.
+    #[must_use]
+    pub const fn webdriver_char(self) -> char {
+        match self {
+            Key::Enter => '\u{E007}',
+            Key::Tab => '\u{E004}',
+            Key::Escape => '\u{E00C}',
+            Key::Backspace => '\u{E003}',
+            Key::Delete => '\u{E017}',
+            Key::Space => '\u{E00D}',
+            Key::ArrowUp => '\u{E013}',
+            Key::ArrowDown => '\u{E015}',
+            Key::ArrowLeft => '\u{E012}',
+            Key::ArrowRight => '\u{E014}',
+            Key::Home => '\u{E011}',
+            Key::End => '\u{E010}',
+            Key::PageUp => '\u{E00E}',
+            Key::PageDown => '\u{E00F}',
+        }
+    }
+
+    /// Returns the key for a WebDriver code point, if it is supported.
+    ///
+    /// `'\u{E006}'` (Return) maps to [`Key::Enter`].
+    #[must_use]
+    pub const fn from_webdriver_char(c: char) -> Option<Self> {
+        Some(match c {
+            '\u{E006}' | '\u{E007}' => Key::Enter,
+            '\u{E004}' => Key::Tab,
+            '\u{E00C}' => Key::Escape,
+            '\u{E003}' => Key::Backspace,
+            '\u{E017}' => Key::Delete,
+            '\u{E00D}' => Key::Space,
+            '\u{E013}' => Key::ArrowUp,
+            '\u{E015}' => Key::ArrowDown,
+            '\u{E012}' => Key::ArrowLeft,
+            '\u{E014}' => Key::ArrowRight,
+            '\u{E011}' => Key::Home,
+            '\u{E010}' => Key::End,
+            '\u{E00E}' => Key::PageUp,
+            '\u{E00F}' => Key::PageDown,
+            _ => return None,
+        })
+    }
+}
+
+// ============================================================================
+// KeyInput
+// ============================================================================
+
+/// First code point of the WebDriver special-key range.
+const WEBDRIVER_KEYS_START: char = '\u{E000}';
+
+/// Last code point of the WebDriver special-key range.
+const WEBDRIVER_KEYS_END: char = '\u{E05D}';
+
+/// One step of an [`Element::send_key_inputs`] sequence.
+///
+/// Converts from `&str`, `String` and [`Key`], so sequences can be
+/// written as `[KeyInput::from("user"), Key::Tab.into()]`.
+///
+/// [`Element::send_key_inputs`]: crate::Element::send_key_inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyInput {
+    /// Text typed character by character.
+    Text(String),
+    /// A navigation or control key press.
+    Key(Key),
+}
+
+impl KeyInput {
+    /// Splits a string with embedded WebDriver key code points into steps.
+    ///
+    /// Runs of ordinary characters become [`KeyInput::Text`]; each
+    /// supported code point (see [`Key::webdriver_char`]) becomes a
+    /// [`KeyInput::Key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] for WebDriver code points with no
+    /// [`Key`] equivalent, such as modifiers (`'\u{E008}'` Shift).
+    pub fn parse(keys: &str) -> Result<Vec<Self>> {
+        let mut inputs = Vec::new();
+        let mut text = String::new();
+
+        for c in keys.chars() {
+            if !(WEBDRIVER_KEYS_START..=WEBDRIVER_KEYS_END).contains(&c) {
+                text.push(c);
+                continue;
+            }
+
+            let key = Key::from_webdriver_char(c).ok_or_else(|| {
+                Error::invalid_argument(format!("Unsupported WebDriver key U+{:04X}", u32::from(c)))
+            })?;
+            if !text.is_empty() {
+                inputs.push(Self::Text(std::mem::take(&mut text)));
+            }
+            inputs.push(Self::Key(key));
+        }
+
+        if !text.is_empty() {
+            inputs.push(Self::Text(text));
+        }
+        Ok(inputs)
+    }
+}
+
+impl From<Key> for KeyInput {
+    fn from(key: Key) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<&str> for KeyInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for KeyInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
 }
 
 // ============================================================================
@@ -140,4 +274,40 @@ mod tests {
         assert!(Key::Space.is_printable());
         assert!(!Key::Enter.is_printable());
     }
+
+    #[test]
+    fn test_webdriver_char_round_trip() {
+        for key in [
+            Key::Enter,
+            Key::Tab,
+            Key::Delete,
+            Key::ArrowLeft,
+            Key::PageDown,
+        ] {
+            assert_eq!(Key::from_webdriver_char(key.webdriver_char()), Some(key));
+        }
+        assert_eq!(Key::from_webdriver_char('\u{E006}'), Some(Key::Enter));
+        assert_eq!(Key::from_webdriver_char('a'), None);
+    }
+
+    #[test]
+    fn test_key_input_parse() {
+        assert_eq!(
+            KeyInput::parse("user\u{E004}pa ss\u{E007}").expect("parse"),
+            [
+                KeyInput::from("user"),
+                KeyInput::Key(Key::Tab),
+                KeyInput::from("pa ss"),
+                KeyInput::Key(Key::Enter),
+            ]
+        );
+        assert_eq!(
+            KeyInput::parse("\u{E003}\u{E003}").expect("parse"),
+            [KeyInput::Key(Key::Backspace), KeyInput::Key(Key::Backspace)]
+        );
+        assert!(KeyInput::parse("").expect("parse").is_empty());
+
+        let err = KeyInput::parse("a\u{E008}b").expect_err("shift unsupported");
+        assert!(matches!(err, Error::InvalidArgument { .. }));
+    }
 }
@@ -0,0 +1,118 @@
+//! Extension log types.
+//!
+//! Structured log records emitted by the WebDriver extension.
+//!
+//! # Example
+//!
+//! ```ignore
+//! window.subscribe_logs(|record| {
+//!     println!("[{}] [{}] {}", record.level, record.target, record.message);
+//! }).await?;
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use serde_json::Value;
+
+// ============================================================================
+// LogRecord
+// ============================================================================
+
+/// A single log line from the extension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// Log level (e.g. "debug", "info", "warn", "error").
+    pub level: String,
+
+    /// Extension module that produced the log line.
+    pub target: String,
+
+    /// Log message.
+    pub message: String,
+
+    /// Timestamp in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// LogRecord - Implementation
+// ============================================================================
+
+impl LogRecord {
+    /// Parses a log record from its JSON representation.
+    ///
+    /// Accepts both `target` and the older `module` key for the source.
+    /// Returns `None` if the record has no message.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let message = value.get("message").and_then(|v| v.as_str())?.to_string();
+
+        let level = value
+            .get("level")
+            .and_then(|v| v.as_str())
+            .unwrap_or("info")
+            .to_string();
+
+        let target = value
+            .get("target")
+            .or_else(|| value.get("module"))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let timestamp = value
+            .get("timestamp")
+            .and_then(|v| v.as_f64())
+            .map(|v| v as u64)
+            .unwrap_or_default();
+
+        Some(Self {
+            level,
+            target,
+            message,
+            timestamp,
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::LogRecord;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_from_value() {
+        let value = json!({
+            "level": "warn",
+            "target": "network",
+            "message": "Intercept removed",
+            "timestamp": 1735171200000_u64
+        });
+
+        let record = LogRecord::from_value(&value).expect("record");
+        assert_eq!(record.level, "warn");
+        assert_eq!(record.target, "network");
+        assert_eq!(record.message, "Intercept removed");
+        assert_eq!(record.timestamp, 1735171200000);
+    }
+
+    #[test]
+    fn test_from_value_module_fallback() {
+        let value = json!({ "level": "debug", "module": "element", "message": "found" });
+        let record = LogRecord::from_value(&value).expect("record");
+        assert_eq!(record.target, "element");
+        assert_eq!(record.timestamp, 0);
+    }
+
+    #[test]
+    fn test_from_value_requires_message() {
+        assert!(LogRecord::from_value(&json!({ "level": "info" })).is_none());
+    }
+}
@@ -43,42 +43,92 @@
 // Submodules
 // ============================================================================
 
+/// Accessibility tree types.
+pub mod accessibility;
+
+/// File download types.
+pub mod downloads;
+
 /// DOM element interaction.
 pub mod element;
 
+/// Media emulation types.
+pub mod emulation;
+
+/// HAR capture types.
+pub mod har;
+
 /// Keyboard key definitions.
 pub mod keyboard;
 
+/// Extension log types.
+pub mod logs;
+
+/// Human-like mouse movement paths.
+pub mod mouse;
+
 /// Network interception types.
 pub mod network;
 
+/// Site permission types.
+pub mod permissions;
+
 /// Proxy configuration types.
 pub mod proxy;
 
+/// Passive request log types.
+pub mod request_log;
+
 /// Element locator strategies.
 pub mod selector;
 
+/// Browser storage and service worker types.
+pub mod storage;
+
 /// Browser tab automation.
 pub mod tab;
 
 /// Browser window management.
 pub mod window;
 
+/// Reusable window pool.
+pub mod window_pool;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
-pub use element::Element;
-pub use keyboard::Key;
+pub use accessibility::AxNode;
+pub use downloads::DownloadInfo;
+pub use element::{Element, ScrollAlign};
+pub use emulation::{ColorScheme, MediaFeature, MediaType, ReducedMotion};
+pub use har::{
+    Har, HarCache, HarCaptureOptions, HarContent, HarCreator, HarEntry, HarLog, HarNameValue,
+    HarPostData, HarRequest, HarResponse, HarTimings,
+};
+pub use keyboard::{Key, KeyInput};
+pub use logs::LogRecord;
+pub use mouse::MousePathOptions;
 pub use network::{
     BodyAction, HeadersAction, InterceptedRequest, InterceptedRequestBody,
-    InterceptedRequestHeaders, InterceptedResponse, InterceptedResponseBody, RequestAction,
-    RequestBody, ResponseAction,
+    InterceptedRequestHeaders, InterceptedResponse, InterceptedResponseBody, NetworkConditions,
+    RequestAction, RequestBody, ResourceType, ResponseAction, SseMessage, WsDirection, WsFrame,
+    WsFrameAction,
 };
-pub use proxy::{ProxyConfig, ProxyType};
+pub use permissions::{Permission, PermissionState};
+pub use proxy::{DEFAULT_PROXY_BYPASS, ProxyConfig, ProxyType};
+pub use request_log::{DEFAULT_REQUEST_LOG_LIMIT, RequestLogOptions, RequestRecord};
 pub use selector::By;
-pub use tab::{FrameInfo, ImageFormat, ScreenshotBuilder, Tab};
-pub use window::{Window, WindowBuilder};
+pub use storage::{ClearDataOptions, CookieChange, ServiceWorkerInfo};
+pub use tab::{
+    BatchBuilder, ElementSnapshot, FrameInfo, ImageFormat, NavigationResponse, PerformanceTiming,
+    RetryPolicy, ScreenshotBuilder, Tab, TabInfo,
+};
+pub use window::{
+    CrashInfo, NewTabOptions, ResourceUsage, SessionHealth, SessionStatus, Window, WindowBounds,
+    WindowBuilder, WindowState,
+};
+pub use window_pool::{PooledWindow, WindowPool};
 
 // Re-export Cookie from protocol for convenience
 pub use crate::protocol::Cookie;
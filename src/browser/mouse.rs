@@ -0,0 +1,178 @@
+//! Human-like mouse movement paths.
+//!
+//! Paths follow a cubic Bézier curve with randomly offset control points.
+//! Points are spaced with an ease-in-out profile, so replaying them at a
+//! fixed interval accelerates away from the start and slows near the target.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use firefox_webdriver::MousePathOptions;
+//!
+//! let options = MousePathOptions::new()
+//!     .with_steps(40)
+//!     .with_jitter(0.2)
+//!     .with_interval(Duration::from_millis(12));
+//!
+//! tab.move_mouse_human_with(640, 360, options).await?;
+//! button.mouse_move_human().await?;
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::time::Duration;
+
+// ============================================================================
+// MousePathOptions
+// ============================================================================
+
+/// Shape and timing of a human-like mouse path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MousePathOptions {
+    /// Number of intermediate `mousemove` events.
+    pub steps: usize,
+
+    /// How far the curve bows away from the straight line, as a fraction of
+    /// the distance travelled (0.0 = straight).
+    pub jitter: f64,
+
+    /// Pause between replayed points.
+    pub interval: Duration,
+}
+
+impl Default for MousePathOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MousePathOptions {
+    /// Creates options with 25 steps, 0.3 jitter and an 8ms interval.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            steps: 25,
+            jitter: 0.3,
+            interval: Duration::from_millis(8),
+        }
+    }
+
+    /// Sets the number of intermediate points (at least 1).
+    #[inline]
+    #[must_use]
+    pub const fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Sets how far the curve bows, as a fraction of the distance.
+    #[inline]
+    #[must_use]
+    pub const fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the pause between replayed points.
+    #[inline]
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+// ============================================================================
+// Path Generation
+// ============================================================================
+
+/// Generates a curved path from `from` to `to`, ending exactly at `to`.
+///
+/// The starting point itself is not included.
+pub(crate) fn human_path(
+    from: (i32, i32),
+    to: (i32, i32),
+    options: &MousePathOptions,
+) -> Vec<(i32, i32)> {
+    let steps = options.steps.max(1);
+    let (x0, y0) = (f64::from(from.0), f64::from(from.1));
+    let (x3, y3) = (f64::from(to.0), f64::from(to.1));
+
+    let (dx, dy) = (x3 - x0, y3 - y0);
+    let distance = dx.hypot(dy);
+
+    // Unit normal to the straight line, for bowing the curve sideways
+    let (nx, ny) = if distance > 0.0 {
+        (-dy / distance, dx / distance)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let spread = distance * options.jitter.max(0.0);
+    let offset = || (fastrand::f64() * 2.0 - 1.0) * spread;
+    let (o1, o2) = (offset(), offset());
+
+    let (x1, y1) = (x0 + dx / 3.0 + nx * o1, y0 + dy / 3.0 + ny * o1);
+    let (x2, y2) = (x0 + dx * 2.0 / 3.0 + nx * o2, y0 + dy * 2.0 / 3.0 + ny * o2);
+
+    let mut points: Vec<(i32, i32)> = (1..=steps)
+        .map(|i| {
+            let t = ease_in_out(i as f64 / steps as f64);
+            let u = 1.0 - t;
+
+            let x = u * u * u * x0 + 3.0 * u * u * t * x1 + 3.0 * u * t * t * x2 + t * t * t * x3;
+            let y = u * u * u * y0 + 3.0 * u * u * t * y1 + 3.0 * u * t * t * y2 + t * t * t * y3;
+
+            (x.round() as i32, y.round() as i32)
+        })
+        .collect();
+
+    // Rounding can repeat points on short paths; repeated moves look robotic
+    points.dedup();
+
+    if let Some(last) = points.last_mut() {
+        *last = to;
+    }
+
+    points
+}
+
+/// Smoothstep easing: slow start, fast middle, slow finish.
+fn ease_in_out(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{MousePathOptions, human_path};
+
+    #[test]
+    fn test_path_ends_at_target() {
+        let path = human_path((0, 0), (400, 300), &MousePathOptions::new());
+        assert_eq!(path.last(), Some(&(400, 300)));
+        assert!(path.len() > 1);
+        assert!(path.len() <= 25);
+    }
+
+    #[test]
+    fn test_path_without_jitter_is_straight() {
+        let options = MousePathOptions::new().with_jitter(0.0).with_steps(10);
+        let path = human_path((0, 0), (100, 0), &options);
+        assert!(path.iter().all(|&(_, y)| y == 0));
+        assert!(path.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_path_to_same_point() {
+        let path = human_path((50, 50), (50, 50), &MousePathOptions::new());
+        assert_eq!(path, vec![(50, 50)]);
+    }
+}
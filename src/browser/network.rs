@@ -1,6 +1,6 @@
 //! Network interception types.
 //!
-//! Types for request/response interception callbacks.
+//! Types for request/response, WebSocket and Server-Sent Events callbacks.
 //!
 //! # Request Interception
 //!
@@ -97,6 +97,26 @@ pub enum RequestAction {
 
     /// Redirect to a different URL.
     Redirect(String),
+
+    /// Hold the request, then apply another action.
+    Delay {
+        /// Delay in milliseconds.
+        ms: u64,
+        /// Action applied after the delay.
+        then: Box<RequestAction>,
+    },
+
+    /// Rewrite the outgoing request; `None` fields are left unchanged.
+    ///
+    /// The extension recomputes `Content-Length` for a new body.
+    Modify {
+        /// New HTTP method (e.g. `POST`).
+        method: Option<String>,
+        /// New request body.
+        body: Option<Vec<u8>>,
+        /// New URL.
+        url: Option<String>,
+    },
 }
 
 // ============================================================================
@@ -124,6 +144,45 @@ impl RequestAction {
     pub fn redirect(url: impl Into<String>) -> Self {
         Self::Redirect(url.into())
     }
+
+    /// Creates a Delay action that allows the request after `ms` milliseconds.
+    #[inline]
+    #[must_use]
+    pub fn delay(ms: u64) -> Self {
+        Self::delay_then(ms, Self::Allow)
+    }
+
+    /// Creates a Delay action that applies `then` after `ms` milliseconds.
+    #[inline]
+    #[must_use]
+    pub fn delay_then(ms: u64, then: RequestAction) -> Self {
+        Self::Delay {
+            ms,
+            then: Box::new(then),
+        }
+    }
+
+    /// Creates a Modify action that changes the HTTP method.
+    #[inline]
+    #[must_use]
+    pub fn modify_method(method: impl Into<String>) -> Self {
+        Self::Modify {
+            method: Some(method.into()),
+            body: None,
+            url: None,
+        }
+    }
+
+    /// Creates a Modify action that replaces the request body.
+    #[inline]
+    #[must_use]
+    pub fn modify_body(body: impl Into<Vec<u8>>) -> Self {
+        Self::Modify {
+            method: None,
+            body: Some(body.into()),
+            url: None,
+        }
+    }
 }
 
 // ============================================================================
@@ -315,13 +374,287 @@ impl BodyAction {
     }
 }
 
+// ============================================================================
+// WsFrame
+// ============================================================================
+
+/// Direction of a WebSocket frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsDirection {
+    /// Frame sent by the page.
+    Sent,
+
+    /// Frame received from the server.
+    Received,
+}
+
+/// Intercepted WebSocket frame.
+#[derive(Debug, Clone)]
+pub struct WsFrame {
+    /// Socket URL.
+    pub url: String,
+
+    /// Frame direction.
+    pub direction: WsDirection,
+
+    /// Frame opcode (1 = text, 2 = binary).
+    pub opcode: u8,
+
+    /// Frame payload.
+    pub payload: Vec<u8>,
+
+    /// Tab ID.
+    pub tab_id: u32,
+}
+
+impl WsFrame {
+    /// Text frame opcode.
+    pub const OPCODE_TEXT: u8 = 1;
+
+    /// Binary frame opcode.
+    pub const OPCODE_BINARY: u8 = 2;
+
+    /// Returns `true` for text frames.
+    #[inline]
+    #[must_use]
+    pub fn is_text(&self) -> bool {
+        self.opcode == Self::OPCODE_TEXT
+    }
+
+    /// Returns the payload as UTF-8 text, if it is valid UTF-8.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.payload).ok()
+    }
+}
+
+// ============================================================================
+// WsFrameAction
+// ============================================================================
+
+/// Action to take for an intercepted WebSocket frame.
+#[derive(Debug, Clone)]
+pub enum WsFrameAction {
+    /// Deliver the frame unchanged.
+    Allow,
+
+    /// Drop the frame.
+    Block,
+
+    /// Replace the frame payload.
+    Modify(Vec<u8>),
+}
+
+// ============================================================================
+// WsFrameAction - Constructors
+// ============================================================================
+
+impl WsFrameAction {
+    /// Creates an Allow action.
+    #[inline]
+    #[must_use]
+    pub fn allow() -> Self {
+        Self::Allow
+    }
+
+    /// Creates a Block action.
+    #[inline]
+    #[must_use]
+    pub fn block() -> Self {
+        Self::Block
+    }
+
+    /// Creates a Modify action.
+    #[inline]
+    #[must_use]
+    pub fn modify(payload: impl Into<Vec<u8>>) -> Self {
+        Self::Modify(payload.into())
+    }
+}
+
+// ============================================================================
+// SseMessage
+// ============================================================================
+
+/// Message received by a page `EventSource` stream.
+#[derive(Debug, Clone)]
+pub struct SseMessage {
+    /// Stream URL.
+    pub url: String,
+
+    /// Event type (`message` unless the server set one).
+    pub event: String,
+
+    /// Event data.
+    pub data: String,
+
+    /// Last event ID, if the server sent one.
+    pub id: Option<String>,
+}
+
+// ============================================================================
+// NetworkConditions
+// ============================================================================
+
+/// Emulated network conditions for a tab.
+///
+/// Throughput is in bytes per second; `-1` means unlimited. Firefox has no
+/// built-in throttle, so the extension emulates bandwidth by delaying and
+/// chunking responses, and latency by holding each request before it is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkConditions {
+    /// Fail all requests as if the network were down.
+    pub offline: bool,
+
+    /// Maximum download throughput in bytes per second (`-1` = unlimited).
+    pub download_throughput: i64,
+
+    /// Maximum upload throughput in bytes per second (`-1` = unlimited).
+    pub upload_throughput: i64,
+
+    /// Minimum added latency per request in milliseconds.
+    pub latency_ms: u64,
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        Self::no_throttling()
+    }
+}
+
+// ============================================================================
+// NetworkConditions - Constructors
+// ============================================================================
+
+impl NetworkConditions {
+    /// No throttling: unlimited throughput, no added latency.
+    #[inline]
+    #[must_use]
+    pub const fn no_throttling() -> Self {
+        Self {
+            offline: false,
+            download_throughput: -1,
+            upload_throughput: -1,
+            latency_ms: 0,
+        }
+    }
+
+    /// Offline: every request fails.
+    #[inline]
+    #[must_use]
+    pub const fn offline() -> Self {
+        Self {
+            offline: true,
+            ..Self::no_throttling()
+        }
+    }
+
+    /// Slow 3G preset (~400 kbit/s, 2 s latency).
+    #[inline]
+    #[must_use]
+    pub const fn slow_3g() -> Self {
+        Self {
+            offline: false,
+            download_throughput: 50_000,
+            upload_throughput: 50_000,
+            latency_ms: 2_000,
+        }
+    }
+
+    /// Fast 3G preset (~1.4 Mbit/s down, 675 kbit/s up, 563 ms latency).
+    #[inline]
+    #[must_use]
+    pub const fn fast_3g() -> Self {
+        Self {
+            offline: false,
+            download_throughput: 180_000,
+            upload_throughput: 84_375,
+            latency_ms: 563,
+        }
+    }
+
+    /// Returns `true` if these conditions neither throttle nor block.
+    #[inline]
+    #[must_use]
+    pub const fn is_unthrottled(&self) -> bool {
+        !self.offline
+            && self.download_throughput < 0
+            && self.upload_throughput < 0
+            && self.latency_ms == 0
+    }
+}
+
+// ============================================================================
+// ResourceType
+// ============================================================================
+
+/// Resource type for [`Tab::block_resource_types`].
+///
+/// Names follow the `declarativeNetRequest` resource types. Top-level
+/// documents cannot be blocked this way.
+///
+/// [`Tab::block_resource_types`]: crate::Tab::block_resource_types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// Images (`<img>`, CSS backgrounds, favicons).
+    Image,
+    /// Web fonts.
+    Font,
+    /// Audio and video.
+    Media,
+    /// CSS stylesheets.
+    Stylesheet,
+    /// JavaScript files.
+    Script,
+    /// `XMLHttpRequest` and `fetch()`.
+    Xhr,
+    /// Iframe documents.
+    SubFrame,
+    /// WebSocket connections.
+    WebSocket,
+    /// `<object>` and `<embed>` content.
+    Object,
+    /// Beacons and `<a ping>`.
+    Ping,
+    /// CSP reports.
+    CspReport,
+    /// Anything else.
+    Other,
+}
+
+impl ResourceType {
+    /// Returns the `declarativeNetRequest` resource type name.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Font => "font",
+            Self::Media => "media",
+            Self::Stylesheet => "stylesheet",
+            Self::Script => "script",
+            Self::Xhr => "xmlhttprequest",
+            Self::SubFrame => "sub_frame",
+            Self::WebSocket => "websocket",
+            Self::Object => "object",
+            Self::Ping => "ping",
+            Self::CspReport => "csp_report",
+            Self::Other => "other",
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::{BodyAction, HeadersAction, RequestAction};
+    use super::{
+        BodyAction, HeadersAction, NetworkConditions, RequestAction, ResourceType, WsDirection,
+        WsFrame, WsFrameAction,
+    };
 
     use std::collections::HashMap;
 
@@ -347,6 +680,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_request_action_modify() {
+        let action = RequestAction::modify_method("POST");
+        assert!(matches!(
+            action,
+            RequestAction::Modify { method: Some(ref m), body: None, url: None } if m == "POST"
+        ));
+
+        let action = RequestAction::modify_body(&b"{}"[..]);
+        assert!(matches!(
+            action,
+            RequestAction::Modify { method: None, body: Some(ref b), url: None } if b == b"{}"
+        ));
+    }
+
+    #[test]
+    fn test_request_action_delay() {
+        let action = RequestAction::delay(500);
+        if let RequestAction::Delay { ms, then } = action {
+            assert_eq!(ms, 500);
+            assert!(matches!(*then, RequestAction::Allow));
+        } else {
+            panic!("Expected Delay action");
+        }
+
+        let action = RequestAction::delay_then(100, RequestAction::block());
+        assert!(matches!(
+            action,
+            RequestAction::Delay { ms: 100, ref then } if matches!(**then, RequestAction::Block)
+        ));
+    }
+
     #[test]
     fn test_headers_action_allow() {
         let action = HeadersAction::allow();
@@ -381,4 +746,46 @@ mod tests {
             panic!("Expected ModifyBody action");
         }
     }
+
+    #[test]
+    fn test_network_conditions_presets() {
+        assert!(NetworkConditions::default().is_unthrottled());
+        assert!(NetworkConditions::offline().offline);
+        assert!(!NetworkConditions::offline().is_unthrottled());
+
+        let slow = NetworkConditions::slow_3g();
+        assert!(!slow.offline);
+        assert!(slow.download_throughput < NetworkConditions::fast_3g().download_throughput);
+        assert!(slow.latency_ms > NetworkConditions::fast_3g().latency_ms);
+    }
+
+    #[test]
+    fn test_ws_frame_text() {
+        let frame = WsFrame {
+            url: "wss://example.com/socket".to_string(),
+            direction: WsDirection::Received,
+            opcode: WsFrame::OPCODE_TEXT,
+            payload: b"hello".to_vec(),
+            tab_id: 1,
+        };
+        assert!(frame.is_text());
+        assert_eq!(frame.text(), Some("hello"));
+    }
+
+    #[test]
+    fn test_ws_frame_action_modify() {
+        let action = WsFrameAction::modify("patched");
+        if let WsFrameAction::Modify(payload) = action {
+            assert_eq!(payload, b"patched");
+        } else {
+            panic!("Expected Modify action");
+        }
+    }
+
+    #[test]
+    fn test_resource_type_names() {
+        assert_eq!(ResourceType::Xhr.as_str(), "xmlhttprequest");
+        assert_eq!(ResourceType::SubFrame.as_str(), "sub_frame");
+        assert_eq!(ResourceType::Image.as_str(), "image");
+    }
 }
@@ -0,0 +1,183 @@
+//! Site permission types.
+//!
+//! Permissions are Firefox's browser-wide defaults, written into the
+//! window's profile at launch. WebExtensions cannot edit site permissions
+//! at runtime, so they are set on the window builder rather than a tab.
+//!
+//! Two things are not covered:
+//!
+//! - **Clipboard read.** Firefox has no `permissions.default.*` preference
+//!   for it; `navigator.clipboard.readText()` shows a paste prompt that no
+//!   profile setting suppresses, so there is no `Permission` variant.
+//! - **Per-origin overrides.** Only browser-wide defaults are written.
+//!   Per-site exceptions live in the profile's `permissions.sqlite`, which
+//!   the driver does not edit; there is also no way to reset a permission
+//!   in a running window. Use a cloned profile that already has the
+//!   exceptions if a site needs different settings.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use firefox_webdriver::{Permission, PermissionState};
+//!
+//! let window = driver
+//!     .window()
+//!     .permission(Permission::Geolocation, PermissionState::Granted)
+//!     .spawn()
+//!     .await?;
+//!
+//! // Deny everything, so no prompt can ever block the script
+//! let locked = driver
+//!     .window()
+//!     .permissions(&Permission::all_denied())
+//!     .spawn()
+//!     .await?;
+//! ```
+
+// ============================================================================
+// Permission
+// ============================================================================
+
+/// A site permission pages request with a prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// `navigator.geolocation`.
+    Geolocation,
+    /// `Notification.requestPermission()`.
+    Notifications,
+    /// Video from `getUserMedia()`.
+    Camera,
+    /// Audio from `getUserMedia()`.
+    Microphone,
+}
+
+impl Permission {
+    /// Every permission, in declaration order.
+    pub const ALL: [Self; 4] = [
+        Self::Geolocation,
+        Self::Notifications,
+        Self::Camera,
+        Self::Microphone,
+    ];
+
+    /// Returns the Permissions API name (as passed to `navigator.permissions.query`).
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Geolocation => "geolocation",
+            Self::Notifications => "notifications",
+            Self::Camera => "camera",
+            Self::Microphone => "microphone",
+        }
+    }
+
+    /// Returns the Firefox preference holding the browser-wide default.
+    ///
+    /// [`WindowBuilder::permission`](crate::browser::WindowBuilder::permission)
+    /// writes it with [`PermissionState::pref_value`].
+    #[inline]
+    #[must_use]
+    pub const fn default_pref(&self) -> &'static str {
+        match self {
+            Self::Geolocation => "permissions.default.geo",
+            Self::Notifications => "permissions.default.desktop-notification",
+            Self::Camera => "permissions.default.camera",
+            Self::Microphone => "permissions.default.microphone",
+        }
+    }
+
+    /// Returns every permission paired with [`PermissionState::Denied`].
+    ///
+    /// Pass it to
+    /// [`WindowBuilder::permissions`](crate::browser::WindowBuilder::permissions) so
+    /// no permission prompt can stall automation.
+    #[must_use]
+    pub fn all_denied() -> Vec<(Self, PermissionState)> {
+        Self::ALL
+            .into_iter()
+            .map(|permission| (permission, PermissionState::Denied))
+            .collect()
+    }
+}
+
+// ============================================================================
+// PermissionState
+// ============================================================================
+
+/// What Firefox does when a page requests a [`Permission`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PermissionState {
+    /// Requests succeed without a prompt.
+    Granted,
+    /// Requests fail immediately, as if the user blocked the site.
+    Denied,
+    /// Firefox asks the user (its usual behavior).
+    Prompt,
+}
+
+impl PermissionState {
+    /// Returns the Permissions API state name.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Granted => "granted",
+            Self::Denied => "denied",
+            Self::Prompt => "prompt",
+        }
+    }
+
+    /// Returns the `permissions.default.*` preference value
+    /// (`1` = allow, `2` = block, `0` = ask).
+    #[inline]
+    #[must_use]
+    pub const fn pref_value(&self) -> i32 {
+        match self {
+            Self::Granted => 1,
+            Self::Denied => 2,
+            Self::Prompt => 0,
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Permission, PermissionState};
+
+    #[test]
+    fn test_names() {
+        let names: Vec<_> = Permission::ALL.iter().map(Permission::as_str).collect();
+        assert_eq!(
+            names,
+            ["geolocation", "notifications", "camera", "microphone"]
+        );
+        assert_eq!(PermissionState::Prompt.as_str(), "prompt");
+        assert_eq!(
+            Permission::Notifications.default_pref(),
+            "permissions.default.desktop-notification"
+        );
+    }
+
+    #[test]
+    fn test_pref_values() {
+        assert_eq!(PermissionState::Granted.pref_value(), 1);
+        assert_eq!(PermissionState::Denied.pref_value(), 2);
+        assert_eq!(PermissionState::Prompt.pref_value(), 0);
+    }
+
+    #[test]
+    fn test_all_denied() {
+        let preset = Permission::all_denied();
+        assert_eq!(preset.len(), Permission::ALL.len());
+        assert!(
+            preset
+                .iter()
+                .all(|(_, state)| *state == PermissionState::Denied)
+        );
+    }
+}
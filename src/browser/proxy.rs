@@ -14,6 +14,10 @@
 //! let proxy = ProxyConfig::socks5("proxy.example.com", 1080)
 //!     .with_credentials("user", "pass")
 //!     .with_proxy_dns(true);
+//!
+//! // Keep internal hosts off the proxy (loopback is bypassed by default)
+//! let proxy = ProxyConfig::http("proxy.example.com", 8080)
+//!     .with_bypass(&[".corp.example.com", "10.0.0.0/8"]);
 //! ```
 
 // ============================================================================
@@ -22,6 +26,13 @@
 
 use serde::{Deserialize, Serialize};
 
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Hosts every new [`ProxyConfig`] connects to directly.
+pub const DEFAULT_PROXY_BYPASS: [&str; 3] = ["localhost", "127.0.0.1", "[::1]"];
+
 // ============================================================================
 // ProxyType
 // ============================================================================
@@ -109,6 +120,15 @@ pub struct ProxyConfig {
     /// Whether to proxy DNS queries (SOCKS4/SOCKS5 only).
     #[serde(rename = "proxyDns", default)]
     pub proxy_dns: bool,
+
+    /// Hosts connected to directly instead of through the proxy.
+    ///
+    /// Same syntax as Firefox's `network.proxy.no_proxies_on`: host names
+    /// (`localhost`), domain suffixes (`.example.com`), IP addresses and
+    /// CIDR ranges (`10.0.0.0/8`), optionally with `:port`. Starts as
+    /// [`DEFAULT_PROXY_BYPASS`].
+    #[serde(default = "default_bypass", skip_serializing_if = "Vec::is_empty")]
+    pub bypass: Vec<String>,
 }
 
 // ============================================================================
@@ -132,6 +152,7 @@ impl ProxyConfig {
             username: None,
             password: None,
             proxy_dns: false,
+            bypass: default_bypass(),
         }
     }
 
@@ -170,6 +191,7 @@ impl ProxyConfig {
             username: None,
             password: None,
             proxy_dns: true, // Enable by default to prevent DNS leaks
+            bypass: default_bypass(),
         }
     }
 
@@ -184,6 +206,7 @@ impl ProxyConfig {
             username: None,
             password: None,
             proxy_dns: false,
+            bypass: Vec::new(),
         }
     }
 }
@@ -218,6 +241,49 @@ impl ProxyConfig {
         self.proxy_dns = proxy_dns;
         self
     }
+
+    /// Adds hosts that bypass the proxy.
+    ///
+    /// Appends to the list, which starts with loopback
+    /// ([`DEFAULT_PROXY_BYPASS`]). See [`ProxyConfig::bypass`] for the
+    /// pattern syntax.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use firefox_webdriver::ProxyConfig;
+    ///
+    /// let proxy = ProxyConfig::http("proxy.example.com", 8080)
+    ///     .with_bypass(&[".internal", "192.168.0.0/16"]);
+    /// assert!(proxy.bypass.iter().any(|host| host == "localhost"));
+    /// ```
+    #[must_use]
+    pub fn with_bypass(mut self, hosts: &[&str]) -> Self {
+        self.bypass
+            .extend(hosts.iter().map(|host| host.to_string()));
+        self
+    }
+
+    /// Removes every bypass entry, including loopback.
+    ///
+    /// Use this to send local traffic through the proxy too.
+    #[must_use]
+    pub fn clear_bypass(mut self) -> Self {
+        self.bypass.clear();
+        self
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Returns [`DEFAULT_PROXY_BYPASS`] as owned strings.
+fn default_bypass() -> Vec<String> {
+    DEFAULT_PROXY_BYPASS
+        .iter()
+        .map(|host| host.to_string())
+        .collect()
 }
 
 // ============================================================================
@@ -253,7 +319,7 @@ impl ProxyConfig {
 
 #[cfg(test)]
 mod tests {
-    use super::{ProxyConfig, ProxyType};
+    use super::{DEFAULT_PROXY_BYPASS, ProxyConfig, ProxyType};
 
     // ------------------------------------------------------------------------
     // ProxyType Tests
@@ -368,6 +434,27 @@ mod tests {
         assert!(json.contains(r#""username":"user""#));
     }
 
+    #[test]
+    fn test_proxy_config_bypass() {
+        let proxy = ProxyConfig::socks5("proxy.example.com", 1080).with_bypass(&[".internal"]);
+        assert_eq!(
+            proxy.bypass,
+            ["localhost", "127.0.0.1", "[::1]", ".internal"]
+        );
+
+        let json = serde_json::to_string(&proxy).unwrap();
+        assert!(json.contains(r#""bypass":["localhost","127.0.0.1","[::1]",".internal"]"#));
+
+        let cleared = proxy.clear_bypass();
+        assert!(cleared.bypass.is_empty());
+        assert!(!serde_json::to_string(&cleared).unwrap().contains("bypass"));
+
+        let parsed: ProxyConfig =
+            serde_json::from_str(r#"{"type":"http","host":"p","port":1}"#).unwrap();
+        assert_eq!(parsed.bypass, DEFAULT_PROXY_BYPASS);
+        assert!(ProxyConfig::direct().bypass.is_empty());
+    }
+
     #[test]
     fn test_proxy_config_clone() {
         let proxy = ProxyConfig::http("proxy.example.com", 8080);
@@ -0,0 +1,208 @@
+//! Passive request log types.
+//!
+//! A request log records one [`RequestRecord`] per finished request without
+//! headers or bodies, and without holding requests for allow/block
+//! decisions. Use HAR capture when headers and bodies are needed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! tab.start_request_log().await?;
+//! tab.goto("https://example.com").await?;
+//!
+//! for record in tab.take_request_log() {
+//!     println!("{} {} {:?}", record.method, record.url, record.status);
+//! }
+//! tab.stop_request_log().await?;
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::identifiers::SubscriptionId;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Default maximum number of records kept by a request log.
+pub const DEFAULT_REQUEST_LOG_LIMIT: usize = 10_000;
+
+// ============================================================================
+// RequestRecord
+// ============================================================================
+
+/// A finished request recorded by [`Tab::start_request_log`].
+///
+/// [`Tab::start_request_log`]: crate::Tab::start_request_log
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestRecord {
+    /// Request URL.
+    pub url: String,
+
+    /// HTTP method.
+    pub method: String,
+
+    /// HTTP status code, or `None` if the request failed.
+    pub status: Option<u16>,
+
+    /// Resource type (`main_frame`, `script`, `xmlhttprequest`, ...).
+    pub resource_type: String,
+
+    /// Network error (e.g. `NS_ERROR_UNKNOWN_HOST`) if the request failed.
+    pub error: Option<String>,
+
+    /// Request start in milliseconds since the Unix epoch.
+    pub start_time_ms: f64,
+
+    /// Time from request start to completion or failure, in milliseconds.
+    pub duration_ms: f64,
+}
+
+// ============================================================================
+// RequestLogOptions
+// ============================================================================
+
+/// Options for [`Tab::start_request_log_with`].
+///
+/// When the log holds `max_entries` records, each new record evicts the
+/// oldest one. [`Tab::take_request_log`] empties the log, so taking it
+/// periodically keeps every record.
+///
+/// [`Tab::start_request_log_with`]: crate::Tab::start_request_log_with
+/// [`Tab::take_request_log`]: crate::Tab::take_request_log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestLogOptions {
+    /// Maximum records kept before the oldest are dropped (at least 1).
+    pub max_entries: usize,
+}
+
+impl Default for RequestLogOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestLogOptions {
+    /// Creates options keeping up to [`DEFAULT_REQUEST_LOG_LIMIT`] records.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_entries: DEFAULT_REQUEST_LOG_LIMIT,
+        }
+    }
+
+    /// Sets the maximum number of records kept.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+}
+
+// ============================================================================
+// RequestLogBuffer
+// ============================================================================
+
+/// Bounded record buffer that drops the oldest records when full.
+#[derive(Debug)]
+pub(crate) struct RequestLogBuffer {
+    /// Records in completion order.
+    records: VecDeque<RequestRecord>,
+    /// Maximum records kept.
+    max_entries: usize,
+    /// Records evicted since the last take.
+    dropped: usize,
+}
+
+impl RequestLogBuffer {
+    /// Creates an empty buffer.
+    pub(crate) fn new(options: RequestLogOptions) -> Self {
+        Self {
+            records: VecDeque::new(),
+            max_entries: options.max_entries.max(1),
+            dropped: 0,
+        }
+    }
+
+    /// Appends a record, evicting the oldest if the buffer is full.
+    pub(crate) fn push(&mut self, record: RequestRecord) {
+        if self.records.len() >= self.max_entries {
+            self.records.pop_front();
+            self.dropped += 1;
+        }
+        self.records.push_back(record);
+    }
+
+    /// Removes and returns all records and the number dropped since the last take.
+    pub(crate) fn take(&mut self) -> (Vec<RequestRecord>, usize) {
+        let dropped = std::mem::take(&mut self.dropped);
+        (self.records.drain(..).collect(), dropped)
+    }
+}
+
+// ============================================================================
+// RequestLogCapture
+// ============================================================================
+
+/// An active request log on a tab.
+pub(crate) struct RequestLogCapture {
+    /// Shared buffer fed by the event handler.
+    pub buffer: Arc<Mutex<RequestLogBuffer>>,
+    /// Subscription registered for the log.
+    pub subscription_id: SubscriptionId,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestLogBuffer, RequestLogOptions, RequestRecord};
+
+    fn record(url: &str) -> RequestRecord {
+        RequestRecord {
+            url: url.to_string(),
+            method: "GET".to_string(),
+            status: Some(200),
+            resource_type: "script".to_string(),
+            error: None,
+            start_time_ms: 0.0,
+            duration_ms: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_buffer_drops_oldest() {
+        let mut buffer = RequestLogBuffer::new(RequestLogOptions::new().with_max_entries(2));
+        for url in ["a", "b", "c"] {
+            buffer.push(record(url));
+        }
+
+        let (records, dropped) = buffer.take();
+        let urls: Vec<_> = records.iter().map(|r| r.url.as_str()).collect();
+        assert_eq!(urls, ["b", "c"]);
+        assert_eq!(dropped, 1);
+
+        let (records, dropped) = buffer.take();
+        assert!(records.is_empty());
+        assert_eq!(dropped, 0);
+    }
+
+    #[test]
+    fn test_zero_limit_keeps_one() {
+        let mut buffer = RequestLogBuffer::new(RequestLogOptions::new().with_max_entries(0));
+        buffer.push(record("a"));
+        buffer.push(record("b"));
+        assert_eq!(buffer.take().0, vec![record("b")]);
+    }
+}
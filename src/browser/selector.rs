@@ -13,11 +13,15 @@
 //! // By ID (shorthand for CSS #id)
 //! let form = tab.find_element(By::Id("login-form")).await?;
 //!
-//! // By text content
-//! let link = tab.find_element(By::Text("Click here")).await?;
+//! // By exact text content
+//! let link = tab.find_element(By::text_exact("Click here")).await?;
 //!
 //! // By partial text
-//! let link = tab.find_element(By::PartialText("Click")).await?;
+//! let link = tab.find_element(By::text_contains("Click")).await?;
+//!
+//! // Case-insensitive, or restricted to a tag
+//! let btn = tab.find_element(By::text_ci("submit")).await?;
+//! let save = tab.find_element(By::text_in("button", "Save")).await?;
 //!
 //! // By XPath
 //! let btn = tab.find_element(By::XPath("//button[@type='submit']")).await?;
@@ -35,6 +39,20 @@ use serde::{Deserialize, Serialize};
 /// Element locator strategy (like Selenium's `By`).
 ///
 /// Supports multiple strategies for finding elements in the DOM.
+///
+/// # Text Matching
+///
+/// All text strategies compare against `textContent` with leading and
+/// trailing whitespace trimmed. When an element and its ancestors all
+/// match (a `<button>` inside a `<div>` with no other text), only the
+/// innermost element is returned.
+///
+/// | Strategy | Match |
+/// |----------|-------|
+/// | [`By::Text`] | Exact, case-sensitive |
+/// | [`By::PartialText`] | Substring, case-sensitive |
+/// | [`By::TextCi`] | Exact, case-insensitive |
+/// | [`By::TextIn`] | Exact, case-sensitive, only elements with the given tag |
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "strategy", content = "value")]
 pub enum By {
@@ -84,6 +102,45 @@ pub enum By {
     #[serde(rename = "partialText")]
     PartialText(String),
 
+    /// Case-insensitive exact text content match.
+    ///
+    /// Finds element where `textContent.trim().toLowerCase() === value.toLowerCase()`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// By::TextCi("submit")  // matches "Submit", "SUBMIT"
+    /// ```
+    #[serde(rename = "textCi")]
+    TextCi(String),
+
+    /// Exact text content match restricted to one tag name.
+    ///
+    /// # Example
+    /// ```ignore
+    /// By::TextIn { tag: "button".into(), text: "Save".into() }
+    /// ```
+    #[serde(rename = "textIn")]
+    TextIn {
+        /// Tag name the element must have (e.g. `button`).
+        tag: String,
+        /// Exact trimmed text content.
+        text: String,
+    },
+
+    /// Form control by its label text.
+    ///
+    /// Matches the control associated with a `<label>` whose trimmed text
+    /// equals the value, via `for`/`id` or by nesting the control inside the
+    /// label. Falls back to controls whose `aria-label` equals the value, or
+    /// whose `aria-labelledby` points at an element with that text.
+    ///
+    /// # Example
+    /// ```ignore
+    /// By::Label("Email address")
+    /// ```
+    #[serde(rename = "label")]
+    Label(String),
+
     /// Element ID (shorthand for `#id` CSS selector).
     ///
     /// # Example
@@ -153,18 +210,61 @@ impl By {
         Self::XPath(expr.into())
     }
 
-    /// Creates a text content selector.
+    /// Creates an exact text content selector.
+    ///
+    /// Same as [`By::text_exact`].
     #[inline]
     pub fn text(text: impl Into<String>) -> Self {
         Self::Text(text.into())
     }
 
+    /// Creates an exact, case-sensitive text content selector.
+    #[inline]
+    pub fn text_exact(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
     /// Creates a partial text content selector.
     #[inline]
     pub fn partial_text(text: impl Into<String>) -> Self {
         Self::PartialText(text.into())
     }
 
+    /// Creates a case-sensitive substring text selector.
+    ///
+    /// Same as [`By::partial_text`].
+    #[inline]
+    pub fn text_contains(text: impl Into<String>) -> Self {
+        Self::PartialText(text.into())
+    }
+
+    /// Creates an exact, case-insensitive text content selector.
+    #[inline]
+    pub fn text_ci(text: impl Into<String>) -> Self {
+        Self::TextCi(text.into())
+    }
+
+    /// Creates a form control selector by label text.
+    #[inline]
+    pub fn label(text: impl Into<String>) -> Self {
+        Self::Label(text.into())
+    }
+
+    /// Creates an exact text selector restricted to a tag name.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let save = tab.find_element(By::text_in("button", "Save")).await?;
+    /// ```
+    #[inline]
+    pub fn text_in(tag: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::TextIn {
+            tag: tag.into(),
+            text: text.into(),
+        }
+    }
+
     /// Creates an ID selector.
     #[inline]
     pub fn id(id: impl Into<String>) -> Self {
@@ -207,8 +307,10 @@ impl By {
         match self {
             Self::Css(_) => "css",
             Self::XPath(_) => "xpath",
-            Self::Text(_) => "text",
+            Self::Text(_) | Self::TextIn { .. } => "text",
             Self::PartialText(_) => "partialText",
+            Self::TextCi(_) => "textCi",
+            Self::Label(_) => "label",
             Self::Id(_) => "id",
             Self::Tag(_) => "tag",
             Self::Name(_) => "name",
@@ -226,12 +328,24 @@ impl By {
             | Self::XPath(v)
             | Self::Text(v)
             | Self::PartialText(v)
+            | Self::TextCi(v)
+            | Self::Label(v)
             | Self::Id(v)
             | Self::Tag(v)
             | Self::Name(v)
             | Self::Class(v)
             | Self::LinkText(v)
             | Self::PartialLinkText(v) => v,
+            Self::TextIn { text, .. } => text,
+        }
+    }
+
+    /// Returns the tag name matches are restricted to, if any.
+    #[must_use]
+    pub fn tag_filter(&self) -> Option<&str> {
+        match self {
+            Self::TextIn { tag, .. } => Some(tag),
+            _ => None,
         }
     }
 }
@@ -290,6 +404,29 @@ mod tests {
         assert_eq!(by.value(), "Submit");
     }
 
+    #[test]
+    fn test_text_variants() {
+        assert_eq!(By::text_exact("Save"), By::Text("Save".to_string()));
+        assert_eq!(By::text_contains("Sa"), By::PartialText("Sa".to_string()));
+
+        let ci = By::text_ci("save");
+        assert_eq!(ci.strategy(), "textCi");
+        assert_eq!(ci.value(), "save");
+        assert_eq!(ci.tag_filter(), None);
+
+        let scoped = By::text_in("button", "Save");
+        assert_eq!(scoped.strategy(), "text");
+        assert_eq!(scoped.value(), "Save");
+        assert_eq!(scoped.tag_filter(), Some("button"));
+    }
+
+    #[test]
+    fn test_by_label() {
+        let by = By::label("Email");
+        assert_eq!(by.strategy(), "label");
+        assert_eq!(by.value(), "Email");
+    }
+
     #[test]
     fn test_from_str() {
         let by: By = "#login".into();
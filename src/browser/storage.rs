@@ -0,0 +1,239 @@
+//! Browser storage and service worker types.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use firefox_webdriver::ClearDataOptions;
+//!
+//! // Everything for the current origin
+//! tab.clear_browser_data(ClearDataOptions::new()).await?;
+//!
+//! // Only cookies and IndexedDB, for every site
+//! let options = ClearDataOptions::none()
+//!     .with_cookies(true)
+//!     .with_indexed_db(true)
+//!     .with_all_origins(true);
+//! tab.clear_browser_data(options).await?;
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+use crate::protocol::Cookie;
+
+// ============================================================================
+// ClearDataOptions
+// ============================================================================
+
+/// Selects which browsing data [`Tab::clear_browser_data`] removes.
+///
+/// [`Tab::clear_browser_data`]: crate::Tab::clear_browser_data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClearDataOptions {
+    /// Remove cookies, including HttpOnly cookies.
+    pub cookies: bool,
+
+    /// Remove localStorage.
+    pub local_storage: bool,
+
+    /// Remove sessionStorage (tab-scoped; cleared in this tab only).
+    pub session_storage: bool,
+
+    /// Remove IndexedDB databases.
+    pub indexed_db: bool,
+
+    /// Remove the HTTP cache (always for all origins).
+    pub cache: bool,
+
+    /// Unregister service workers and remove their caches.
+    pub service_workers: bool,
+
+    /// Clear data for every site instead of only the current page's origin.
+    pub all_origins: bool,
+}
+
+impl Default for ClearDataOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClearDataOptions {
+    /// Creates options that clear every data type for the current origin.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cookies: true,
+            local_storage: true,
+            session_storage: true,
+            indexed_db: true,
+            cache: true,
+            service_workers: true,
+            all_origins: false,
+        }
+    }
+
+    /// Creates options that clear nothing; enable data types individually.
+    #[inline]
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            cookies: false,
+            local_storage: false,
+            session_storage: false,
+            indexed_db: false,
+            cache: false,
+            service_workers: false,
+            all_origins: false,
+        }
+    }
+
+    /// Sets whether cookies are cleared.
+    #[inline]
+    #[must_use]
+    pub const fn with_cookies(mut self, clear: bool) -> Self {
+        self.cookies = clear;
+        self
+    }
+
+    /// Sets whether localStorage is cleared.
+    #[inline]
+    #[must_use]
+    pub const fn with_local_storage(mut self, clear: bool) -> Self {
+        self.local_storage = clear;
+        self
+    }
+
+    /// Sets whether sessionStorage is cleared.
+    #[inline]
+    #[must_use]
+    pub const fn with_session_storage(mut self, clear: bool) -> Self {
+        self.session_storage = clear;
+        self
+    }
+
+    /// Sets whether IndexedDB is cleared.
+    #[inline]
+    #[must_use]
+    pub const fn with_indexed_db(mut self, clear: bool) -> Self {
+        self.indexed_db = clear;
+        self
+    }
+
+    /// Sets whether the HTTP cache is cleared.
+    #[inline]
+    #[must_use]
+    pub const fn with_cache(mut self, clear: bool) -> Self {
+        self.cache = clear;
+        self
+    }
+
+    /// Sets whether service workers are unregistered.
+    #[inline]
+    #[must_use]
+    pub const fn with_service_workers(mut self, clear: bool) -> Self {
+        self.service_workers = clear;
+        self
+    }
+
+    /// Sets whether data is cleared for every site.
+    #[inline]
+    #[must_use]
+    pub const fn with_all_origins(mut self, all_origins: bool) -> Self {
+        self.all_origins = all_origins;
+        self
+    }
+
+    /// Returns the protocol names of the selected data types.
+    pub(crate) fn data_types(&self) -> Vec<String> {
+        [
+            (self.cookies, "cookies"),
+            (self.local_storage, "localStorage"),
+            (self.session_storage, "sessionStorage"),
+            (self.indexed_db, "indexedDB"),
+            (self.cache, "cache"),
+            (self.service_workers, "serviceWorkers"),
+        ]
+        .into_iter()
+        .filter(|(selected, _)| *selected)
+        .map(|(_, name)| name.to_string())
+        .collect()
+    }
+}
+
+// ============================================================================
+// ServiceWorkerInfo
+// ============================================================================
+
+/// A service worker registration for the page's origin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceWorkerInfo {
+    /// Registration scope URL.
+    pub scope: String,
+
+    /// Script URL of the newest worker (active, waiting or installing).
+    pub script_url: Option<String>,
+
+    /// State of that worker (e.g. `activated`, `installing`).
+    pub state: Option<String>,
+}
+
+// ============================================================================
+// CookieChange
+// ============================================================================
+
+/// A cookie change reported by [`Tab::on_cookie_changed`].
+///
+/// [`Tab::on_cookie_changed`]: crate::Tab::on_cookie_changed
+#[derive(Debug, Clone)]
+pub struct CookieChange {
+    /// The cookie after the change, or as it was before removal.
+    pub cookie: Cookie,
+
+    /// Firefox change cause: `explicit`, `overwrite`, `expired`,
+    /// `expired_overwrite` or `evicted`.
+    pub cause: String,
+
+    /// `true` if the cookie was removed.
+    pub removed: bool,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ClearDataOptions, ServiceWorkerInfo};
+
+    #[test]
+    fn test_service_worker_info_deserialize() {
+        let info: ServiceWorkerInfo = serde_json::from_value(serde_json::json!({
+            "scope": "https://example.com/",
+            "scriptUrl": "https://example.com/sw.js",
+            "state": "activated"
+        }))
+        .expect("deserialize");
+        assert_eq!(
+            info.script_url.as_deref(),
+            Some("https://example.com/sw.js")
+        );
+        assert_eq!(info.state.as_deref(), Some("activated"));
+    }
+
+    #[test]
+    fn test_data_types() {
+        assert_eq!(ClearDataOptions::new().data_types().len(), 6);
+        assert!(ClearDataOptions::none().data_types().is_empty());
+
+        let options = ClearDataOptions::none()
+            .with_cookies(true)
+            .with_indexed_db(true);
+        assert_eq!(options.data_types(), vec!["cookies", "indexedDB"]);
+    }
+}
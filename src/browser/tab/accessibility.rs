@@ -0,0 +1,357 @@
+//! Accessibility tree snapshot methods.
+
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::browser::accessibility::AxNode;
+use crate::error::{Error, Result};
+
+use super::Tab;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Content-script function building the accessibility tree.
+///
+/// `__INTERESTING_ONLY__` is replaced with `true` or `false`. The tree is
+/// returned as a flat pre-order list with parent indices, since deeply
+/// nested pages would exceed the JSON nesting limit of the response
+/// parser.
+const SNAPSHOT_SCRIPT: &str = r#"
+const interestingOnly = __INTERESTING_ONLY__;
+const SKIP = new Set(["SCRIPT", "STYLE", "NOSCRIPT", "TEMPLATE", "HEAD", "META", "LINK"]);
+const NAME_FROM_CONTENT = new Set([
+    "button", "link", "heading", "cell", "columnheader", "rowheader", "option",
+    "menuitem", "menuitemcheckbox", "menuitemradio", "tab", "treeitem", "tooltip",
+    "checkbox", "radio", "switch"
+]);
+const UNINTERESTING = new Set(["generic", "presentation", "none"]);
+const TAG_ROLES = {
+    ARTICLE: "article", ASIDE: "complementary", BUTTON: "button", DIALOG: "dialog",
+    FOOTER: "contentinfo", FORM: "form", H1: "heading", H2: "heading", H3: "heading",
+    H4: "heading", H5: "heading", H6: "heading", HEADER: "banner", HR: "separator",
+    LI: "listitem", MAIN: "main", NAV: "navigation", OL: "list", UL: "list",
+    OPTION: "option", P: "paragraph", PROGRESS: "progressbar", SECTION: "region",
+    TABLE: "table", TBODY: "rowgroup", THEAD: "rowgroup", TFOOT: "rowgroup",
+    TD: "cell", TH: "columnheader", TR: "row", TEXTAREA: "textbox", LABEL: "generic",
+    SUMMARY: "button", FIELDSET: "group", FIGURE: "figure"
+};
+const INPUT_ROLES = {
+    checkbox: "checkbox", radio: "radio", button: "button", submit: "button",
+    reset: "button", image: "button", range: "slider", number: "spinbutton",
+    search: "searchbox", hidden: "none"
+};
+
+const clean = (s) => (s || "").replace(/\s+/g, " ").trim();
+
+const implicitRole = (el) => {
+    switch (el.tagName) {
+        case "A": return el.hasAttribute("href") ? "link" : "generic";
+        case "IMG": return el.getAttribute("alt") === "" ? "presentation" : "img";
+        case "INPUT": return INPUT_ROLES[(el.type || "text").toLowerCase()] || "textbox";
+        case "SELECT": return el.multiple || el.size > 1 ? "listbox" : "combobox";
+        default: return TAG_ROLES[el.tagName] || "generic";
+    }
+};
+
+const isHidden = (el) => {
+    if (el.hidden || el.getAttribute("aria-hidden") === "true") return true;
+    const style = getComputedStyle(el);
+    return style.display === "none" || style.visibility === "hidden";
+};
+
+const nameOf = (el, role) => {
+    const labelledBy = el.getAttribute("aria-labelledby");
+    if (labelledBy) {
+        const name = clean(labelledBy.split(/\s+/)
+            .map((id) => document.getElementById(id))
+            .filter(Boolean)
+            .map((ref) => ref.textContent)
+            .join(" "));
+        if (name) return name;
+    }
+    const ariaLabel = clean(el.getAttribute("aria-label"));
+    if (ariaLabel) return ariaLabel;
+    if (el.labels && el.labels.length) {
+        const name = clean([...el.labels].map((label) => label.textContent).join(" "));
+        if (name) return name;
+    }
+    if (el.tagName === "IMG" || (el.tagName === "INPUT" && el.type === "image")) {
+        const alt = clean(el.getAttribute("alt"));
+        if (alt) return alt;
+    }
+    if (el.tagName === "INPUT" && ["submit", "reset", "button"].includes(el.type)) {
+        const value = clean(el.value);
+        if (value) return value;
+    }
+    if (NAME_FROM_CONTENT.has(role)) {
+        const text = clean(el.innerText || el.textContent);
+        if (text) return text;
+    }
+    return clean(el.getAttribute("title") || el.getAttribute("placeholder"));
+};
+
+const valueOf = (el, role) => {
+    if (el.tagName === "INPUT") {
+        if (["checkbox", "radio", "button", "submit", "reset", "image", "password"].includes(el.type)) {
+            return null;
+        }
+        return el.value;
+    }
+    if (el.tagName === "TEXTAREA") return el.value;
+    if (el.tagName === "SELECT") {
+        return [...el.selectedOptions].map((option) => clean(option.textContent)).join(", ");
+    }
+    if (role === "progressbar" || role === "slider" || role === "spinbutton") {
+        const value = el.getAttribute("aria-valuenow") ?? el.value;
+        return value == null ? null : String(value);
+    }
+    return null;
+};
+
+const walk = (node, named) => {
+    if (node.nodeType === Node.TEXT_NODE) {
+        const text = clean(node.textContent);
+        return !named && text ? [{ role: "text", name: text, value: null, children: [] }] : [];
+    }
+    if (node.nodeType !== Node.ELEMENT_NODE || SKIP.has(node.tagName) || isHidden(node)) {
+        return [];
+    }
+
+    const explicit = clean(node.getAttribute("role")).split(" ")[0];
+    const role = explicit || implicitRole(node);
+    const children = childrenOf(node, named || NAME_FROM_CONTENT.has(role));
+    const name = nameOf(node, role);
+    const focusable = node.tabIndex >= 0 && !node.disabled;
+
+    if (interestingOnly && UNINTERESTING.has(role) && !name && !focusable) {
+        return children;
+    }
+    return [{ role, name, value: valueOf(node, role), children }];
+};
+
+const childrenOf = (node, named) => {
+    const root = node.shadowRoot || node;
+    return [...root.childNodes].flatMap((child) => walk(child, named));
+};
+
+const tree = {
+    role: "document",
+    name: clean(document.title),
+    value: null,
+    children: childrenOf(document.body || document.documentElement, false)
+};
+
+const nodes = [];
+const stack = [[tree, null]];
+while (stack.length) {
+    const [node, parent] = stack.pop();
+    const index = nodes.length;
+    nodes.push({ role: node.role, name: node.name, value: node.value, parent });
+    for (let i = node.children.length - 1; i >= 0; i--) {
+        stack.push([node.children[i], index]);
+    }
+}
+return nodes;
+"#;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A node as returned by the snapshot script.
+#[derive(Debug, Deserialize)]
+struct FlatAxNode {
+    /// ARIA role.
+    role: String,
+
+    /// Accessible name.
+    #[serde(default)]
+    name: String,
+
+    /// Current value.
+    #[serde(default)]
+    value: Option<String>,
+
+    /// Index of the parent node (`None` for the root).
+    #[serde(default)]
+    parent: Option<usize>,
+}
+
+// ============================================================================
+// Tab - Accessibility
+// ============================================================================
+
+impl Tab {
+    /// Returns the page's accessibility tree, pruned to interesting nodes.
+    ///
+    /// Roles come from `role` attributes or the element's implicit ARIA
+    /// role; names from `aria-labelledby`, `aria-label`, associated labels,
+    /// `alt`, content (for buttons, links, headings, ...) and `title`.
+    /// Hidden elements (`hidden`, `aria-hidden`, `display: none`) are
+    /// omitted, and open shadow roots are included.
+    ///
+    /// Presentational nodes (`generic`, `presentation`, `none`) without a
+    /// name that cannot take focus are replaced by their children. Use
+    /// [`Tab::accessibility_snapshot_with`] to keep them.
+    ///
+    /// The tree is built in the extension's isolated world, so page
+    /// scripts cannot tamper with it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let tree = tab.accessibility_snapshot().await?;
+    /// for link in tree.find_all("link") {
+    ///     println!("{}", link.name);
+    /// }
+    /// ```
+    pub async fn accessibility_snapshot(&self) -> Result<AxNode> {
+        self.accessibility_snapshot_with(true).await
+    }
+
+    /// Returns the page's accessibility tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `interesting_only` - Prune unnamed presentational nodes
+    pub async fn accessibility_snapshot_with(&self, interesting_only: bool) -> Result<AxNode> {
+        debug!(tab_id = %self.inner.tab_id, interesting_only, "Taking accessibility snapshot");
+
+        let script = snapshot_script(interesting_only);
+        let value = self.execute_script_isolated(&script).await?;
+        let nodes: Vec<FlatAxNode> = serde_json::from_value(value)?;
+        let tree = build_tree(nodes)?;
+
+        debug!(
+            tab_id = %self.inner.tab_id,
+            children = tree.children.len(),
+            "Accessibility snapshot taken"
+        );
+        Ok(tree)
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Builds the snapshot script for the given pruning mode.
+fn snapshot_script(interesting_only: bool) -> String {
+    SNAPSHOT_SCRIPT.replace("__INTERESTING_ONLY__", &interesting_only.to_string())
+}
+
+/// Rebuilds the tree from a pre-order list with parent indices.
+///
+/// Works bottom-up without recursion, so tree depth is not limited.
+fn build_tree(nodes: Vec<FlatAxNode>) -> Result<AxNode> {
+    let mut children = vec![Vec::new(); nodes.len()];
+
+    for (index, node) in nodes.iter().enumerate() {
+        match node.parent {
+            None if index == 0 => {}
+            Some(parent) if parent < index => children[parent].push(index),
+            _ => {
+                return Err(Error::protocol(format!(
+                    "Accessibility node {index} has an invalid parent"
+                )));
+            }
+        }
+    }
+
+    let mut built: Vec<Option<AxNode>> = nodes
+        .into_iter()
+        .map(|node| {
+            Some(AxNode {
+                role: node.role,
+                name: node.name,
+                value: node.value,
+                children: Vec::new(),
+            })
+        })
+        .collect();
+
+    // Children always follow their parent, so they are complete by the
+    // time the parent is reached.
+    for index in (0..built.len()).rev() {
+        let node_children: Vec<AxNode> = children[index]
+            .iter()
+            .filter_map(|&child| built[child].take())
+            .collect();
+        if let Some(node) = &mut built[index] {
+            node.children = node_children;
+        }
+    }
+
+    built
+        .into_iter()
+        .next()
+        .flatten()
+        .ok_or_else(|| Error::protocol("Accessibility snapshot is empty"))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{FlatAxNode, build_tree, snapshot_script};
+
+    fn flat(role: &str, parent: Option<usize>) -> FlatAxNode {
+        FlatAxNode {
+            role: role.to_string(),
+            name: String::new(),
+            value: None,
+            parent,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_script_flag() {
+        assert!(snapshot_script(true).contains("const interestingOnly = true;"));
+        assert!(snapshot_script(false).contains("const interestingOnly = false;"));
+        assert!(!snapshot_script(true).contains("__INTERESTING_ONLY__"));
+    }
+
+    #[test]
+    fn test_build_tree() {
+        let nodes = vec![
+            flat("document", None),
+            flat("list", Some(0)),
+            flat("listitem", Some(1)),
+            flat("listitem", Some(1)),
+            flat("button", Some(0)),
+        ];
+
+        let tree = build_tree(nodes).expect("tree");
+        assert_eq!(tree.role, "document");
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[0].children.len(), 2);
+        assert_eq!(tree.children[1].role, "button");
+    }
+
+    #[test]
+    fn test_build_tree_deep() {
+        let depth: usize = 1_000;
+        let nodes = (0..depth)
+            .map(|index| flat("group", index.checked_sub(1)))
+            .collect();
+
+        let mut node = &build_tree(nodes).expect("tree");
+        let mut levels = 1;
+        while let Some(child) = node.children.first() {
+            node = child;
+            levels += 1;
+        }
+        assert_eq!(levels, depth);
+    }
+
+    #[test]
+    fn test_build_tree_rejects_bad_parent() {
+        assert!(build_tree(vec![flat("document", None), flat("button", Some(1))]).is_err());
+        assert!(build_tree(vec![flat("document", Some(0))]).is_err());
+        assert!(build_tree(Vec::new()).is_err());
+    }
+}
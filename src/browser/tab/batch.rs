@@ -0,0 +1,298 @@
+//! Command batching.
+
+use serde_json::Value;
+use tracing::debug;
+
+use crate::browser::Element;
+use crate::error::{Error, Result};
+use crate::identifiers::RequestId;
+use crate::protocol::command::{
+    BrowsingContextCommand, Command, ElementCommand, ScriptCommand, SessionCommand,
+};
+use crate::protocol::{Response, ResponseType};
+
+use super::Tab;
+
+// ============================================================================
+// BatchBuilder
+// ============================================================================
+
+/// Builder that queues commands and sends them in one round trip.
+///
+/// The extension runs the queued commands in order against the tab's current
+/// frame and returns one result per command. Commands cannot use each
+/// other's results, so queue operations on elements you already hold.
+///
+/// # Example
+///
+/// ```ignore
+/// let username = tab.find_element("#username").await?;
+/// let password = tab.find_element("#password").await?;
+/// let submit = tab.find_element("#submit").await?;
+///
+/// let results = tab
+///     .batch()
+///     .set_property(&username, "value", json!("alice"))
+///     .set_property(&password, "value", json!("secret"))
+///     .click(&submit)
+///     .stop_on_error(true)
+///     .send()
+///     .await?;
+///
+/// for result in results {
+///     result?;
+/// }
+/// ```
+pub struct BatchBuilder<'a> {
+    tab: &'a Tab,
+    commands: Vec<Command>,
+    stop_on_error: bool,
+}
+
+impl<'a> BatchBuilder<'a> {
+    /// Creates an empty batch.
+    pub(crate) fn new(tab: &'a Tab) -> Self {
+        Self {
+            tab,
+            commands: Vec::new(),
+            stop_on_error: false,
+        }
+    }
+
+    /// Queues a raw protocol command.
+    #[must_use]
+    pub fn command(mut self, command: Command) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Queues a navigation to `url`.
+    #[must_use]
+    pub fn navigate(self, url: &str) -> Self {
+        self.command(Command::BrowsingContext(BrowsingContextCommand::Navigate {
+            url: url.to_string(),
+        }))
+    }
+
+    /// Queues synchronous JavaScript in the page context.
+    ///
+    /// The command's result is `{ "value": ... }`.
+    #[must_use]
+    pub fn execute_script(self, script: &str) -> Self {
+        self.command(Command::Script(ScriptCommand::Evaluate {
+            script: script.to_string(),
+            args: vec![],
+            isolated: false,
+            timeout: None,
+        }))
+    }
+
+    /// Queues a click on `element`.
+    #[must_use]
+    pub fn click(self, element: &Element) -> Self {
+        self.call_method(element, "click", vec![])
+    }
+
+    /// Queues `element[name]`; the command's result is `{ "value": ... }`.
+    #[must_use]
+    pub fn get_property(self, element: &Element, name: &str) -> Self {
+        self.command(Command::Element(ElementCommand::GetProperty {
+            element_id: element.id().clone(),
+            name: name.to_string(),
+        }))
+    }
+
+    /// Queues `element[name] = value`.
+    #[must_use]
+    pub fn set_property(self, element: &Element, name: &str, value: Value) -> Self {
+        self.command(Command::Element(ElementCommand::SetProperty {
+            element_id: element.id().clone(),
+            name: name.to_string(),
+            value,
+        }))
+    }
+
+    /// Queues `element[name](...args)`; the command's result is
+    /// `{ "value": ... }`.
+    #[must_use]
+    pub fn call_method(self, element: &Element, name: &str, args: Vec<Value>) -> Self {
+        self.command(Command::Element(ElementCommand::CallMethod {
+            element_id: element.id().clone(),
+            name: name.to_string(),
+            args,
+        }))
+    }
+
+    /// Skips the remaining commands after the first failure.
+    ///
+    /// Skipped commands report an [`Error::Protocol`] in the results.
+    #[must_use]
+    pub fn stop_on_error(mut self, stop: bool) -> Self {
+        self.stop_on_error = stop;
+        self
+    }
+
+    /// Returns the number of queued commands.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns `true` if no commands are queued.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Sends the queued commands and returns one result per command, in
+    /// queue order.
+    ///
+    /// Each entry holds the command's raw `result` object or its error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch itself fails (e.g. connection closed or
+    /// the extension rejects the batch). Failures of individual commands are
+    /// reported in the returned vector.
+    pub async fn send(self) -> Result<Vec<Result<Value>>> {
+        let count = self.commands.len();
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        debug!(
+            tab_id = %self.tab.inner.tab_id,
+            count,
+            stop_on_error = self.stop_on_error,
+            "Sending command batch"
+        );
+
+        let command = Command::Session(SessionCommand::Batch {
+            commands: self.commands,
+            stop_on_error: self.stop_on_error,
+        });
+
+        let response = self.tab.send_command(command).await?;
+        let request_id = response.id;
+        let result = response.into_result()?;
+        let results = parse_batch_responses(request_id, &result, count)?;
+
+        debug!(
+            tab_id = %self.tab.inner.tab_id,
+            failed = results.iter().filter(|r| r.is_err()).count(),
+            "Command batch completed"
+        );
+        Ok(results)
+    }
+}
+
+// ============================================================================
+// Tab - Batch
+// ============================================================================
+
+impl Tab {
+    /// Starts a batch of commands sent in a single round trip.
+    ///
+    /// Useful for multi-step interactions on high-latency connections.
+    /// See [`BatchBuilder`].
+    #[must_use]
+    pub fn batch(&self) -> BatchBuilder<'_> {
+        BatchBuilder::new(self)
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Splits a `session.batch` result into per-command results.
+///
+/// Commands the extension skipped after an earlier failure get an error.
+fn parse_batch_responses(
+    request_id: RequestId,
+    result: &Value,
+    count: usize,
+) -> Result<Vec<Result<Value>>> {
+    let entries = result
+        .get("responses")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| Error::protocol("Batch result has no responses array"))?;
+
+    if entries.len() > count {
+        return Err(Error::protocol(format!(
+            "Batch returned {} responses for {count} commands",
+            entries.len()
+        )));
+    }
+
+    let mut results: Vec<Result<Value>> = entries
+        .iter()
+        .map(|entry| {
+            let response_type = serde_json::from_value::<ResponseType>(
+                entry.get("type").cloned().unwrap_or(Value::Null),
+            )?;
+            let string = |key: &str| entry.get(key).and_then(|v| v.as_str()).map(String::from);
+
+            Response {
+                id: request_id,
+                response_type,
+                result: entry.get("result").cloned(),
+                error: string("error"),
+                message: string("message"),
+                stack: string("stack"),
+            }
+            .into_result()
+        })
+        .collect();
+
+    results.resize_with(count, || {
+        Err(Error::protocol(
+            "Skipped: an earlier command in the batch failed",
+        ))
+    });
+
+    Ok(results)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::parse_batch_responses;
+
+    use serde_json::json;
+
+    use crate::identifiers::RequestId;
+
+    #[test]
+    fn test_parse_batch_responses() {
+        let result = json!({
+            "responses": [
+                { "type": "success", "result": { "value": "hello" } },
+                { "type": "error", "error": "no such element", "message": "Element not found" }
+            ]
+        });
+
+        let results = parse_batch_responses(RequestId::generate(), &result, 3).expect("parse");
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().expect("success"),
+            &json!({ "value": "hello" })
+        );
+        assert!(results[1].is_err());
+        assert!(results[2].is_err());
+    }
+
+    #[test]
+    fn test_parse_batch_responses_invalid() {
+        let id = RequestId::generate();
+        assert!(parse_batch_responses(id, &json!({}), 1).is_err());
+
+        let too_many = json!({ "responses": [{ "type": "success" }, { "type": "success" }] });
+        assert!(parse_batch_responses(id, &too_many, 1).is_err());
+    }
+}
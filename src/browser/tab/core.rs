@@ -2,6 +2,7 @@
 
 use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::error::{Error, Result};
 use crate::identifiers::{FrameId, SessionId, TabId};
@@ -24,6 +25,17 @@ pub struct FrameInfo {
     pub url: String,
 }
 
+/// Information about an open tab in a window.
+#[derive(Debug, Clone)]
+pub struct TabInfo {
+    /// Tab ID.
+    pub tab_id: TabId,
+    /// Current URL.
+    pub url: String,
+    /// Page title.
+    pub title: String,
+}
+
 /// Internal shared state for a tab.
 pub(crate) struct TabInner {
     /// Tab ID.
@@ -127,6 +139,21 @@ impl Tab {
             .await
     }
 
+    /// Sends a command with a custom timeout.
+    pub(crate) async fn send_command_with_timeout(
+        &self,
+        command: Command,
+        request_timeout: Duration,
+    ) -> Result<Response> {
+        let window = self.get_window()?;
+        let request = Request::new(self.inner.tab_id, self.inner.frame_id, command);
+        window
+            .inner
+            .pool
+            .send_with_timeout(window.inner.session_id, request, request_timeout)
+            .await
+    }
+
     /// Gets the window reference or returns an error.
     pub(crate) fn get_window(&self) -> Result<&Window> {
         self.inner
@@ -0,0 +1,376 @@
+//! File download methods.
+
+use std::io::{Error as IoError, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::fs::{self, OpenOptions};
+use tokio::sync::oneshot;
+use tokio::time::timeout;
+use tracing::debug;
+
+use crate::browser::downloads::DownloadInfo;
+use crate::error::{Error, Result};
+use crate::protocol::{Command, DownloadsCommand, Event, ParsedEvent};
+
+use super::Tab;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Numbered names tried before giving up on a free download file name.
+const MAX_NAME_ATTEMPTS: u32 = 1000;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A `downloads.finished` event.
+struct FinishedDownload {
+    url: String,
+    path: String,
+    bytes: u64,
+    mime: Option<String>,
+    state: String,
+    error: Option<String>,
+}
+
+// ============================================================================
+// Tab - Downloads
+// ============================================================================
+
+impl Tab {
+    /// Moves downloads reported by [`Tab::wait_for_download`] into `path`.
+    ///
+    /// Firefox keeps saving into its own download directory: the profile's
+    /// `downloads` directory, or the one set at launch with
+    /// [`WindowBuilder::download_dir`](crate::browser::WindowBuilder::download_dir).
+    /// WebExtensions cannot change that preference, so each download
+    /// `wait_for_download` reports is moved once it completes. Downloads
+    /// nobody waits for stay where Firefox put them.
+    ///
+    /// A file that already exists in `path` is never replaced; the moved
+    /// download gets a numbered name instead (`report (1).csv`).
+    ///
+    /// Applies to the whole window: the downloads API does not say which
+    /// tab started a download. Creates `path` if missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the directory cannot be created.
+    pub async fn move_downloads_to(&self, path: &Path) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, path = %path.display(), "Setting download target directory");
+
+        let window = self.get_window()?;
+        fs::create_dir_all(path).await?;
+        *window.inner.download_target.lock() = Some(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Waits for the next download in this window to finish.
+    ///
+    /// Start waiting before triggering the download. The extension needs
+    /// the `downloads` permission.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Timeout`] if no download finishes within `timeout`
+    /// - [`Error::Io`] if the download was interrupted or could not be
+    ///   moved to the directory set with [`Tab::move_downloads_to`]
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let (download, _) = tokio::try_join!(
+    ///     tab.wait_for_download(Duration::from_secs(30)),
+    ///     export_button.click(),
+    /// )?;
+    /// assert_eq!(download.mime.as_deref(), Some("text/csv"));
+    /// ```
+    pub async fn wait_for_download(&self, timeout_duration: Duration) -> Result<DownloadInfo> {
+        debug!(tab_id = %self.inner.tab_id, timeout_ms = timeout_duration.as_millis(), "Waiting for download");
+
+        let window = self.get_window()?;
+
+        let (tx, rx) = oneshot::channel::<FinishedDownload>();
+        let tx = Mutex::new(Some(tx));
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "downloads.finished" {
+                return None;
+            }
+
+            if let ParsedEvent::DownloadFinished {
+                url,
+                path,
+                bytes,
+                mime,
+                state,
+                error,
+            } = event.parse()
+                && let Some(tx) = tx.lock().take()
+            {
+                let _ = tx.send(FinishedDownload {
+                    url,
+                    path,
+                    bytes,
+                    mime,
+                    state,
+                    error,
+                });
+            }
+
+            None
+        }));
+
+        let subscription_id = match self
+            .send_command(Command::Downloads(DownloadsCommand::Subscribe))
+            .await
+            .and_then(|response| response.require_str("subscriptionId").map(str::to_string))
+        {
+            Ok(subscription_id) => subscription_id,
+            Err(e) => {
+                window.remove_event_handler(handler_id);
+                return Err(e);
+            }
+        };
+
+        let result = timeout(timeout_duration, rx).await;
+
+        window.remove_event_handler(handler_id);
+
+        let command = Command::Downloads(DownloadsCommand::Unsubscribe { subscription_id });
+        if let Err(e) = self.send_command(command).await {
+            debug!(tab_id = %self.inner.tab_id, error = %e, "Failed to unsubscribe from downloads");
+        }
+
+        let download = match result {
+            Ok(Ok(download)) => download,
+            Ok(Err(_)) => return Err(Error::protocol("Channel closed unexpectedly")),
+            Err(_) => {
+                return Err(Error::timeout(
+                    "wait_for_download",
+                    timeout_duration.as_millis() as u64,
+                ));
+            }
+        };
+
+        if download.state != "complete" {
+            return Err(Error::Io(IoError::new(
+                ErrorKind::Interrupted,
+                format!(
+                    "Download of {} interrupted: {}",
+                    download.url,
+                    download.error.as_deref().unwrap_or("unknown reason")
+                ),
+            )));
+        }
+
+        let mut path = PathBuf::from(&download.path);
+        let target_dir = window.inner.download_target.lock().clone();
+        if let Some(dir) = target_dir {
+            path = move_download(&path, &dir).await?;
+        }
+
+        debug!(tab_id = %self.inner.tab_id, path = %path.display(), bytes = download.bytes, "Download finished");
+
+        Ok(DownloadInfo {
+            url: download.url,
+            filename: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path,
+            bytes: download.bytes,
+            mime: download.mime,
+        })
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Moves a downloaded file into `dir`, returning its new path.
+///
+/// Never replaces an existing file: taken names get a number, as Firefox
+/// does. Hard links claim a name atomically; across filesystems the file
+/// is copied into a newly created one instead.
+async fn move_download(path: &Path, dir: &Path) -> Result<PathBuf> {
+    let Some(name) = path.file_name().map(Path::new) else {
+        return Ok(path.to_path_buf());
+    };
+
+    if dir.join(name) == path {
+        return Ok(path.to_path_buf());
+    }
+
+    for attempt in 0..MAX_NAME_ATTEMPTS {
+        let target = dir.join(numbered_name(name, attempt));
+
+        let claimed = match fs::hard_link(path, &target).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => Err(e),
+            Err(_) => copy_new(path, &target).await,
+        };
+
+        match claimed {
+            Ok(()) => {
+                fs::remove_file(path).await?;
+                return Ok(target);
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(Error::Io(e)),
+        }
+    }
+
+    Err(Error::Io(IoError::new(
+        ErrorKind::AlreadyExists,
+        format!("No free name for {} in {}", name.display(), dir.display()),
+    )))
+}
+
+/// Returns `name` for attempt 0, then `stem (n).ext`.
+fn numbered_name(name: &Path, attempt: u32) -> PathBuf {
+    if attempt == 0 {
+        return name.to_path_buf();
+    }
+
+    let stem = name
+        .file_stem()
+        .unwrap_or(name.as_os_str())
+        .to_string_lossy();
+    match name.extension() {
+        Some(ext) => PathBuf::from(format!("{stem} ({attempt}).{}", ext.to_string_lossy())),
+        None => PathBuf::from(format!("{stem} ({attempt})")),
+    }
+}
+
+/// Copies `from` into `to`, failing if `to` already exists.
+async fn copy_new(from: &Path, to: &Path) -> std::io::Result<()> {
+    let mut source = fs::File::open(from).await?;
+    let mut target = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(to)
+        .await?;
+
+    if let Err(e) = tokio::io::copy(&mut source, &mut target).await {
+        drop(target);
+        let _ = fs::remove_file(to).await;
+        return Err(e);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{move_download, numbered_name};
+
+    use std::path::{Path, PathBuf};
+
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::error::Error;
+    use crate::{Driver, MockTransport};
+
+    #[tokio::test]
+    async fn test_move_download() {
+        let staging = tempfile::tempdir().expect("staging");
+        let target = tempfile::tempdir().expect("target");
+        let file = staging.path().join("report.csv");
+        std::fs::write(&file, "a,b\n").expect("write");
+
+        let moved = move_download(&file, target.path()).await.expect("move");
+        assert_eq!(moved, target.path().join("report.csv"));
+        assert!(moved.is_file());
+        assert!(!file.exists());
+    }
+
+    #[tokio::test]
+    async fn test_move_download_keeps_existing_files() {
+        let staging = tempfile::tempdir().expect("staging");
+        let target = tempfile::tempdir().expect("target");
+        std::fs::write(target.path().join("report.csv"), "old").expect("write");
+        std::fs::write(target.path().join("report (1).csv"), "old").expect("write");
+
+        let file = staging.path().join("report.csv");
+        std::fs::write(&file, "new").expect("write");
+
+        let moved = move_download(&file, target.path()).await.expect("move");
+        assert_eq!(moved, target.path().join("report (2).csv"));
+        assert_eq!(std::fs::read_to_string(&moved).expect("read"), "new");
+        assert_eq!(
+            std::fs::read_to_string(target.path().join("report.csv")).expect("read"),
+            "old"
+        );
+    }
+
+    #[test]
+    fn test_numbered_name() {
+        let name = Path::new("report.csv");
+        assert_eq!(numbered_name(name, 0), PathBuf::from("report.csv"));
+        assert_eq!(numbered_name(name, 3), PathBuf::from("report (3).csv"));
+        assert_eq!(
+            numbered_name(Path::new("README"), 1),
+            PathBuf::from("README (1)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_download() {
+        let mock = MockTransport::new()
+            .respond("downloads.subscribe", json!({ "subscriptionId": "dl-1" }))
+            .respond("downloads.unsubscribe", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+
+        let staging = tempfile::tempdir().expect("staging");
+        let target = tempfile::tempdir().expect("target");
+        let file = staging.path().join("export.csv");
+        std::fs::write(&file, "x").expect("write");
+        tab.move_downloads_to(target.path()).await.expect("dir");
+
+        let waiter = {
+            let tab = tab.clone();
+            tokio::spawn(async move { tab.wait_for_download(Duration::from_secs(5)).await })
+        };
+        while mock.methods().is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        mock.emit_event(
+            "downloads.finished",
+            json!({
+                "url": "https://example.com/export",
+                "filename": file.to_string_lossy(),
+                "fileSize": 1,
+                "mime": "text/csv",
+                "state": "complete"
+            }),
+        );
+
+        let download = waiter.await.expect("join").expect("download");
+        assert_eq!(download.filename, "export.csv");
+        assert_eq!(download.path, target.path().join("export.csv"));
+        assert_eq!(download.mime.as_deref(), Some("text/csv"));
+        assert_eq!(
+            mock.methods(),
+            ["downloads.subscribe", "downloads.unsubscribe"]
+        );
+
+        let timed_out = tab.wait_for_download(Duration::from_millis(10)).await;
+        assert!(matches!(timed_out, Err(Error::Timeout { .. })));
+    }
+}
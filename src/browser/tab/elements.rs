@@ -1,14 +1,17 @@
-//! Element search and observation methods.
+//! Element search, observation and bulk extraction methods.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use parking_lot::Mutex as ParkingMutex;
+use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
-use tokio::time::timeout;
+use tokio::time::{Instant, timeout};
 use tracing::debug;
 
 use crate::browser::Element;
+use crate::browser::element::ElementLocator;
 use crate::browser::selector::By;
 use crate::error::{Error, Result};
 use crate::identifiers::{ElementId, SubscriptionId};
@@ -16,6 +19,7 @@ use crate::protocol::event::ParsedEvent;
 use crate::protocol::{Command, ElementCommand, Event};
 
 use super::Tab;
+use super::script::json_string;
 
 // ============================================================================
 // Constants
@@ -24,6 +28,34 @@ use super::Tab;
 /// Default timeout for wait_for_element (30 seconds).
 const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Maximum iframe nesting searched by find_element_deep.
+const MAX_FRAME_DEPTH: usize = 8;
+
+/// Whether each child frame's document is readable (same-origin), in
+/// document order.
+const SAME_ORIGIN_FRAMES_SCRIPT: &str = "return [...document.querySelectorAll('iframe, frame')]\
+    .map((frame) => { try { return !!frame.contentDocument; } catch (e) { return false; } });";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Data of one element captured by [`Tab::dom_snapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElementSnapshot {
+    /// Lowercase tag name.
+    pub tag: String,
+
+    /// Rendered text (`innerText`), trimmed.
+    pub text: String,
+
+    /// Attribute names and values.
+    pub attributes: HashMap<String, String>,
+
+    /// Bounding rect `(x, y, width, height)` relative to the viewport.
+    pub rect: (f64, f64, f64, f64),
+}
+
 // ============================================================================
 // Tab - Element Search
 // ============================================================================
@@ -52,6 +84,7 @@ impl Tab {
         let command = Command::Element(ElementCommand::Find {
             strategy: by.strategy().to_string(),
             value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
             parent_id: None,
         });
 
@@ -76,7 +109,37 @@ impl Tab {
             self.inner.frame_id,
             self.inner.session_id,
             self.inner.window.clone(),
-        ))
+        )
+        .with_locator(ElementLocator {
+            by,
+            parent: None,
+            index: None,
+        }))
+    }
+
+    /// Finds a form control by its visible label text.
+    ///
+    /// Resolves, in order:
+    /// - `<label for="id">Text</label>` to the element with that ID
+    /// - `<label>Text <input></label>` to the nested control
+    /// - a control with `aria-label="Text"`
+    /// - a control whose `aria-labelledby` names an element with that text
+    ///
+    /// Label text is compared exactly after trimming whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ElementNotFound`] if no control has that label.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.find_input_by_label("Email address").await?.type_text("user@example.com").await?;
+    /// tab.find_input_by_label("Password").await?.type_text("hunter2").await?;
+    /// ```
+    pub async fn find_input_by_label(&self, label_text: &str) -> Result<Element> {
+        debug!(tab_id = %self.inner.tab_id, label = label_text, "Finding input by label");
+        self.find_element(By::label(label_text)).await
     }
 
     /// Finds all elements using a locator strategy.
@@ -93,6 +156,7 @@ impl Tab {
         let command = Command::Element(ElementCommand::FindAll {
             strategy: by.strategy().to_string(),
             value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
             parent_id: None,
         });
 
@@ -106,7 +170,8 @@ impl Tab {
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str())
-                    .map(|id| {
+                    .enumerate()
+                    .map(|(index, id)| {
                         Element::new(
                             ElementId::new(id),
                             self.inner.tab_id,
@@ -114,6 +179,11 @@ impl Tab {
                             self.inner.session_id,
                             self.inner.window.clone(),
                         )
+                        .with_locator(ElementLocator {
+                            by: by.clone(),
+                            parent: None,
+                            index: Some(index),
+                        })
                     })
                     .collect()
             })
@@ -121,6 +191,185 @@ impl Tab {
 
         Ok(elements)
     }
+
+    /// Counts elements matching a locator strategy.
+    ///
+    /// Unlike [`find_elements`](Self::find_elements), no element handles are
+    /// registered in the content script, so this stays cheap on pages with
+    /// thousands of matches.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let results = tab.count_elements(By::css(".search-result")).await?;
+    /// println!("{results} results");
+    /// ```
+    pub async fn count_elements(&self, by: By) -> Result<usize> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            strategy = by.strategy(),
+            value = by.value(),
+            "Counting elements"
+        );
+
+        let command = Command::Element(ElementCommand::Count {
+            strategy: by.strategy().to_string(),
+            value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
+            parent_id: None,
+        });
+
+        let count: u64 = self.send_command(command).await?.decode("count")?;
+        Ok(count as usize)
+    }
+
+    /// Releases every element handle registered in this tab's current frame.
+    ///
+    /// Frees the content script's element store in one round trip, for
+    /// long-running scrapers that find many elements on the same page.
+    /// Existing [`Element`] handles for this frame become stale.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for page in 1..=100 {
+    ///     scrape_rows(&tab).await?;
+    ///     tab.release_all_elements().await?;
+    ///     tab.find_element(By::css("a.next")).await?.click().await?;
+    /// }
+    /// ```
+    pub async fn release_all_elements(&self) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, frame_id = %self.inner.frame_id, "Releasing all elements");
+
+        let command = Command::Element(ElementCommand::ReleaseAll);
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tab - Deep Search
+// ============================================================================
+
+impl Tab {
+    /// Finds an element in this frame or any nested same-origin iframe.
+    ///
+    /// Searches the current frame first, then child frames depth-first in
+    /// document order (up to 8 levels). The returned element is bound to
+    /// the frame it was found in, so it can be used without switching
+    /// frames.
+    ///
+    /// Cross-origin iframes are skipped: their documents are not readable
+    /// from the parent, so switch to them explicitly (e.g. with
+    /// [`Tab::switch_to_frame_by_url`]) to search inside.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ElementNotFound`] if no searched frame contains a
+    /// match.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let button = tab.find_element_deep(By::css("button.accept-cookies")).await?;
+    /// button.click().await?;
+    /// ```
+    pub async fn find_element_deep(&self, by: By) -> Result<Element> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            strategy = by.strategy(),
+            value = by.value(),
+            "Finding element across frames"
+        );
+
+        match self.find_in_frame_tree(&by, 0).await? {
+            Some((element, _)) => Ok(element),
+            None => Err(Error::element_not_found(
+                format!("{}:{}", by.strategy(), by.value()),
+                self.inner.tab_id,
+                self.inner.frame_id,
+            )),
+        }
+    }
+
+    /// Finds an element in the main frame or any nested same-origin iframe.
+    ///
+    /// Like [`Tab::find_element_deep`], but always starts from the
+    /// top-level document and also returns a `Tab` bound to the frame the
+    /// element was found in, for follow-up lookups and scripts in that
+    /// frame. Cross-origin iframes are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ElementNotFound`] if no searched frame contains a
+    /// match.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (card_number, frame) = tab.find_element_any_frame(By::css("#card-number")).await?;
+    /// card_number.type_text("4242424242424242").await?;
+    /// frame.find_element(By::css("#cvc")).await?.type_text("123").await?;
+    /// ```
+    pub async fn find_element_any_frame(&self, by: By) -> Result<(Element, Tab)> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            strategy = by.strategy(),
+            value = by.value(),
+            "Finding element in any frame"
+        );
+
+        let main = self.switch_to_main_frame();
+        match main.find_in_frame_tree(&by, 0).await? {
+            Some(found) => Ok(found),
+            None => Err(Error::element_not_found(
+                format!("{}:{}", by.strategy(), by.value()),
+                main.inner.tab_id,
+                main.inner.frame_id,
+            )),
+        }
+    }
+
+    /// Searches this frame, then its same-origin child frames.
+    ///
+    /// Returns the element with the tab handle of the frame containing it.
+    async fn find_in_frame_tree(&self, by: &By, depth: usize) -> Result<Option<(Element, Tab)>> {
+        match self.find_element(by.clone()).await {
+            Ok(element) => return Ok(Some((element, self.clone()))),
+            Err(Error::ElementNotFound { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        if depth >= MAX_FRAME_DEPTH {
+            return Ok(None);
+        }
+
+        let iframes = self.find_elements(By::css("iframe, frame")).await?;
+        if iframes.is_empty() {
+            return Ok(None);
+        }
+
+        let same_origin: Vec<bool> =
+            serde_json::from_value(self.execute_script(SAME_ORIGIN_FRAMES_SCRIPT).await?)
+                .unwrap_or_default();
+
+        for (iframe, _) in iframes
+            .iter()
+            .zip(same_origin)
+            .filter(|(_, same_origin)| *same_origin)
+        {
+            // Frames may be detached or still loading; skip them
+            let Ok(frame) = self.switch_to_frame(iframe).await else {
+                continue;
+            };
+
+            if let Some(found) = Box::pin(frame.find_in_frame_tree(by, depth + 1)).await? {
+                return Ok(Some(found));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 // ============================================================================
@@ -170,49 +419,59 @@ impl Tab {
         let frame_id = self.inner.frame_id;
         let session_id = self.inner.session_id;
         let window_clone = self.inner.window.clone();
+        let locator = ElementLocator {
+            by: by.clone(),
+            parent: None,
+            index: None,
+        };
         let tx_clone = Arc::clone(&tx);
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "element.added" {
-                    return None;
-                }
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "element.added" {
+                return None;
+            }
+
+            let parsed = event.parse();
+            if let ParsedEvent::ElementAdded {
+                strategy,
+                value,
+                element_id,
+                ..
+            } = parsed
+                && strategy == expected_strategy
+                && value == expected_value
+            {
+                let element = Element::new(
+                    ElementId::new(&element_id),
+                    tab_id,
+                    frame_id,
+                    session_id,
+                    window_clone.clone(),
+                )
+                .with_locator(locator.clone());
 
-                let parsed = event.parse();
-                if let ParsedEvent::ElementAdded {
-                    strategy,
-                    value,
-                    element_id,
-                    ..
-                } = parsed
-                    && strategy == expected_strategy
-                    && value == expected_value
-                {
-                    let element = Element::new(
-                        ElementId::new(&element_id),
-                        tab_id,
-                        frame_id,
-                        session_id,
-                        window_clone.clone(),
-                    );
-
-                    if let Some(tx) = tx_clone.lock().take() {
-                        let _ = tx.send(Ok(element));
-                    }
+                if let Some(tx) = tx_clone.lock().take() {
+                    let _ = tx.send(Ok(element));
                 }
+            }
 
-                None
-            }),
-        );
+            None
+        }));
 
         let command = Command::Element(ElementCommand::Subscribe {
             strategy: by.strategy().to_string(),
             value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
             one_shot: true,
             timeout: Some(timeout_duration.as_millis() as u64),
         });
-        let response = self.send_command(command).await?;
+        let response = match self.send_command(command).await {
+            Ok(response) => response,
+            Err(e) => {
+                window.remove_event_handler(handler_id);
+                return Err(e);
+            }
+        };
 
         // Check if element already exists
         if let Some(element_id) = response
@@ -221,10 +480,7 @@ impl Tab {
             .and_then(|v| v.get("elementId"))
             .and_then(|v| v.as_str())
         {
-            window
-                .inner
-                .pool
-                .clear_event_handler(window.inner.session_id);
+            window.remove_event_handler(handler_id);
 
             return Ok(Element::new(
                 ElementId::new(element_id),
@@ -232,15 +488,17 @@ impl Tab {
                 self.inner.frame_id,
                 self.inner.session_id,
                 self.inner.window.clone(),
-            ));
+            )
+            .with_locator(ElementLocator {
+                by,
+                parent: None,
+                index: None,
+            }));
         }
 
         let result = timeout(timeout_duration, rx).await;
 
-        window
-            .inner
-            .pool
-            .clear_event_handler(window.inner.session_id);
+        window.remove_event_handler(handler_id);
 
         match result {
             Ok(Ok(element)) => element,
@@ -252,6 +510,57 @@ impl Tab {
         }
     }
 
+    /// Waits until no element matches a locator strategy.
+    ///
+    /// Resolves immediately if nothing matches. Otherwise waits for the
+    /// first match to be removed (see [`Element::wait_until_gone`]) and
+    /// checks again, so a spinner that is swapped for another one keeps
+    /// the wait going.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if a match is still present after
+    /// `timeout_duration`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// tab.wait_for_element_gone(By::css(".spinner"), Duration::from_secs(10)).await?;
+    /// ```
+    pub async fn wait_for_element_gone(&self, by: By, timeout_duration: Duration) -> Result<()> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            strategy = by.strategy(),
+            value = by.value(),
+            timeout_ms = timeout_duration.as_millis(),
+            "Waiting for element to disappear"
+        );
+
+        let deadline = Instant::now() + timeout_duration;
+
+        loop {
+            let element = match self.find_element(by.clone()).await {
+                Ok(element) => element,
+                Err(Error::ElementNotFound { .. }) => return Ok(()),
+                Err(e) => return Err(e),
+            };
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match element.wait_until_gone(remaining).await {
+                Ok(()) => {}
+                Err(Error::Timeout { .. }) => {
+                    return Err(Error::timeout(
+                        format!("wait_for_gone({}:{})", by.strategy(), by.value()),
+                        timeout_duration.as_millis() as u64,
+                    ));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     /// Registers a callback for when elements matching the selector appear.
     ///
     /// # Returns
@@ -278,54 +587,63 @@ impl Tab {
         let window_clone = self.inner.window.clone();
         let callback = Arc::new(callback);
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "element.added" {
-                    return None;
-                }
-
-                let parsed = event.parse();
-                if let ParsedEvent::ElementAdded {
-                    strategy,
-                    value,
-                    element_id,
-                    ..
-                } = parsed
-                    && strategy == expected_strategy
-                    && value == expected_value
-                {
-                    let element = Element::new(
-                        ElementId::new(&element_id),
-                        tab_id,
-                        frame_id,
-                        session_id,
-                        window_clone.clone(),
-                    );
-                    callback(element);
-                }
-
-                None
-            }),
-        );
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "element.added" {
+                return None;
+            }
+
+            let parsed = event.parse();
+            if let ParsedEvent::ElementAdded {
+                strategy,
+                value,
+                element_id,
+                ..
+            } = parsed
+                && strategy == expected_strategy
+                && value == expected_value
+            {
+                let element = Element::new(
+                    ElementId::new(&element_id),
+                    tab_id,
+                    frame_id,
+                    session_id,
+                    window_clone.clone(),
+                );
+                callback(element);
+            }
+
+            None
+        }));
 
         let command = Command::Element(ElementCommand::Subscribe {
             strategy: by.strategy().to_string(),
             value: by.value().to_string(),
+            tag: by.tag_filter().map(str::to_string),
             one_shot: false,
             timeout: None,
         });
 
-        let response = self.send_command(command).await?;
-
-        let subscription_id = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("subscriptionId"))
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| Error::protocol("No subscriptionId in response"))?;
+        let subscription_id =
+            match self.send_command(command).await.and_then(|response| {
+                Ok(SubscriptionId::new(response.require_str("subscriptionId")?))
+            }) {
+                Ok(subscription_id) => subscription_id,
+                Err(e) => {
+                    window.remove_event_handler(handler_id);
+                    return Err(e);
+                }
+            };
+        let teardown = Command::Element(ElementCommand::Unsubscribe {
+            subscription_id: subscription_id.as_str().to_string(),
+        });
+        window.track_subscription(
+            subscription_id.as_str(),
+            self.inner.tab_id,
+            handler_id,
+            teardown,
+        );
 
-        Ok(SubscriptionId::new(subscription_id))
+        Ok(subscription_id)
     }
 
     /// Registers a callback for when a specific element is removed.
@@ -340,26 +658,23 @@ impl Tab {
         let element_id_clone = element_id.as_str().to_string();
         let callback = Arc::new(callback);
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "element.removed" {
-                    return None;
-                }
+        window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "element.removed" {
+                return None;
+            }
 
-                let parsed = event.parse();
-                if let ParsedEvent::ElementRemoved {
-                    element_id: removed_id,
-                    ..
-                } = parsed
-                    && removed_id == element_id_clone
-                {
-                    callback();
-                }
+            let parsed = event.parse();
+            if let ParsedEvent::ElementRemoved {
+                element_id: removed_id,
+                ..
+            } = parsed
+                && removed_id == element_id_clone
+            {
+                callback();
+            }
 
-                None
-            }),
-        );
+            None
+        }));
 
         let command = Command::Element(ElementCommand::WatchRemoval {
             element_id: element_id.clone(),
@@ -378,12 +693,310 @@ impl Tab {
         self.send_command(command).await?;
 
         if let Some(window) = &self.inner.window {
-            window
-                .inner
-                .pool
-                .clear_event_handler(window.inner.session_id);
+            window.untrack_subscription(subscription_id.as_str());
         }
 
         Ok(())
     }
 }
+
+// ============================================================================
+// Tab - DOM Snapshot
+// ============================================================================
+
+impl Tab {
+    /// Returns tag, text, attributes and bounding rect of every element
+    /// matching a CSS selector, in one round trip.
+    ///
+    /// Much faster than finding elements and reading each property
+    /// separately when extracting tables or lists. The snapshot is taken
+    /// in the extension's isolated world, in the current frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the selector is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for row in tab.dom_snapshot("table#prices tr").await? {
+    ///     println!("{}", row.text);
+    /// }
+    /// ```
+    pub async fn dom_snapshot(&self, selector: &str) -> Result<Vec<ElementSnapshot>> {
+        self.dom_snapshot_impl(selector, None).await
+    }
+
+    /// Like [`Tab::dom_snapshot`], but returns only the named attributes.
+    ///
+    /// Attributes missing on an element are left out of its map.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let links = tab.dom_snapshot_with_attributes("a.result", &["href"]).await?;
+    /// let urls: Vec<_> = links.iter().filter_map(|l| l.attributes.get("href")).collect();
+    /// ```
+    pub async fn dom_snapshot_with_attributes(
+        &self,
+        selector: &str,
+        attributes: &[&str],
+    ) -> Result<Vec<ElementSnapshot>> {
+        self.dom_snapshot_impl(selector, Some(attributes)).await
+    }
+
+    /// Takes a DOM snapshot, optionally limited to some attributes.
+    async fn dom_snapshot_impl(
+        &self,
+        selector: &str,
+        attributes: Option<&[&str]>,
+    ) -> Result<Vec<ElementSnapshot>> {
+        debug!(tab_id = %self.inner.tab_id, selector, "Taking DOM snapshot");
+
+        let value = self
+            .execute_script_isolated(&dom_snapshot_script(selector, attributes))
+            .await?;
+
+        if value.get("invalidSelector").is_some() {
+            return Err(Error::invalid_argument(format!(
+                "Invalid selector: {selector}"
+            )));
+        }
+
+        let snapshots: Vec<ElementSnapshot> = serde_json::from_value(value)?;
+
+        debug!(tab_id = %self.inner.tab_id, count = snapshots.len(), "DOM snapshot taken");
+        Ok(snapshots)
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Builds the DOM snapshot script.
+fn dom_snapshot_script(selector: &str, attributes: Option<&[&str]>) -> String {
+    let attributes = attributes.map_or_else(
+        || "null".to_string(),
+        |names| serde_json::to_string(names).unwrap_or_else(|_| "[]".to_string()),
+    );
+
+    format!(
+        r#"const names = {attributes};
+let elements;
+try {{
+    elements = document.querySelectorAll({selector});
+}} catch (e) {{
+    return {{ invalidSelector: true }};
+}}
+return [...elements].map((el) => {{
+    const attributes = {{}};
+    if (names === null) {{
+        for (const attr of el.attributes) attributes[attr.name] = attr.value;
+    }} else {{
+        for (const name of names) {{
+            const value = el.getAttribute(name);
+            if (value !== null) attributes[name] = value;
+        }}
+    }}
+    const rect = el.getBoundingClientRect();
+    return {{
+        tag: el.tagName.toLowerCase(),
+        text: (el.innerText ?? el.textContent ?? "").trim(),
+        attributes,
+        rect: [rect.x, rect.y, rect.width, rect.height]
+    }};
+}});"#,
+        selector = json_string(selector)
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ElementSnapshot, dom_snapshot_script};
+
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::browser::By;
+    use crate::error::Error;
+    use crate::identifiers::FrameId;
+    use crate::{Driver, MockTransport};
+
+    #[test]
+    fn test_dom_snapshot_script() {
+        let all = dom_snapshot_script("td[data-x=\"1\"]", None);
+        assert!(all.contains("const names = null;"));
+        assert!(all.contains(r#"querySelectorAll("td[data-x=\"1\"]")"#));
+
+        let some = dom_snapshot_script("a", Some(&["href", "title"]));
+        assert!(some.contains(r#"const names = ["href","title"];"#));
+    }
+
+    #[test]
+    fn test_element_snapshot_deserialize() {
+        let snapshot: ElementSnapshot = serde_json::from_value(serde_json::json!({
+            "tag": "a",
+            "text": "Next",
+            "attributes": { "href": "/page/2" },
+            "rect": [10.0, 20.5, 40.0, 16.0]
+        }))
+        .expect("deserialize");
+
+        assert_eq!(
+            snapshot.attributes.get("href").map(String::as_str),
+            Some("/page/2")
+        );
+        assert_eq!(snapshot.rect, (10.0, 20.5, 40.0, 16.0));
+    }
+
+    #[tokio::test]
+    async fn test_find_element_any_frame() {
+        let mock = MockTransport::new()
+            .respond("element.find", json!({}))
+            .respond("element.find", json!({ "elementId": "card" }))
+            .respond("element.findAll", json!({ "elementIds": ["frame-el"] }))
+            .respond("element.findAll", json!({ "elementIds": [] }))
+            .respond("script.evaluate", json!({ "value": [true] }))
+            .respond("browsingContext.switchToFrame", json!({ "frameId": 7 }));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+
+        let (element, frame) = tab
+            .find_element_any_frame(By::css("#card"))
+            .await
+            .expect("found");
+        assert_eq!(element.id().as_str(), "card");
+        assert_eq!(element.frame_id(), FrameId::new(7));
+        assert_eq!(frame.frame_id(), FrameId::new(7));
+
+        let mock = MockTransport::new()
+            .respond("element.find", json!({}))
+            .respond("element.findAll", json!({ "elementIds": [] }));
+        let driver = Driver::with_mock(mock);
+        let window = driver.window().spawn().await.expect("window");
+        let missing = window.tab().find_element_any_frame(By::css("#none")).await;
+        assert!(matches!(missing, Err(Error::ElementNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_count_elements() {
+        let mock = MockTransport::new().respond("element.count", json!({ "count": 1200 }));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        mock.clear_requests();
+
+        let count = window
+            .tab()
+            .count_elements(By::css(".result"))
+            .await
+            .expect("count");
+        assert_eq!(count, 1200);
+        assert_eq!(mock.methods(), ["element.count"]);
+    }
+
+    #[tokio::test]
+    async fn test_release_elements() {
+        let mock = MockTransport::new()
+            .respond("element.find", json!({ "elementId": "row" }))
+            .respond("element.release", json!({}))
+            .respond("element.releaseAll", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+        mock.clear_requests();
+
+        // Explicit release is sent once, even after the last clone drops
+        let row = tab.find_element(By::css(".row")).await.expect("row");
+        let clone = row.clone();
+        row.release().await.expect("release");
+        row.release().await.expect("release again");
+        drop((row, clone));
+        tokio::task::yield_now().await;
+        assert_eq!(mock.methods(), ["element.find", "element.release"]);
+
+        // Dropping the last clone releases in the background
+        mock.clear_requests();
+        drop(tab.find_element(By::css(".row")).await.expect("row"));
+        while mock.methods().len() < 2 {
+            tokio::task::yield_now().await;
+        }
+        let requests = mock.requests();
+        assert_eq!(requests[1].command.method_name(), "element.release");
+        assert_eq!(
+            serde_json::to_value(&requests[1].command).expect("serialize")["params"]["elementId"],
+            "row"
+        );
+
+        mock.clear_requests();
+        tab.release_all_elements().await.expect("release all");
+        assert_eq!(mock.methods(), ["element.releaseAll"]);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_element_gone() {
+        let mock = MockTransport::new()
+            .respond("element.find", json!({ "elementId": "spinner" }))
+            .respond("element.find", json!({}))
+            .respond("element.watchRemoval", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+
+        let waiter = tokio::spawn(async move {
+            tab.wait_for_element_gone(By::css(".spinner"), Duration::from_secs(5))
+                .await
+        });
+        while !mock.methods().iter().any(|m| m == "element.watchRemoval") {
+            tokio::task::yield_now().await;
+        }
+
+        mock.emit_event("element.removed", json!({ "elementId": "other" }));
+        assert!(!waiter.is_finished());
+        mock.emit_event("element.removed", json!({ "elementId": "spinner" }));
+
+        waiter.await.expect("join").expect("gone");
+        // The dropped spinner handle may be released in the background
+        assert_eq!(
+            mock.methods()
+                .iter()
+                .filter(|m| *m != "element.release")
+                .collect::<Vec<_>>(),
+            ["element.find", "element.watchRemoval", "element.find"]
+        );
+
+        // Already removed: the watch reports a stale element
+        let mock = MockTransport::new()
+            .respond("element.find", json!({ "elementId": "row" }))
+            .respond_error("element.watchRemoval", "stale element", "Element not found");
+        let driver = Driver::with_mock(mock);
+        let window = driver.window().spawn().await.expect("window");
+        let row = window
+            .tab()
+            .find_element(By::css(".row"))
+            .await
+            .expect("row");
+        row.wait_until_gone(Duration::from_secs(5))
+            .await
+            .expect("already gone");
+
+        let mock = MockTransport::new()
+            .respond("element.find", json!({ "elementId": "row" }))
+            .respond("element.watchRemoval", json!({}))
+            .respond("element.unwatchRemoval", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let timed_out = window
+            .tab()
+            .wait_for_element_gone(By::css(".row"), Duration::from_millis(10))
+            .await;
+        assert!(matches!(timed_out, Err(Error::Timeout { .. })));
+        assert!(mock.methods().iter().any(|m| m == "element.unwatchRemoval"));
+    }
+}
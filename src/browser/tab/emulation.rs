@@ -0,0 +1,67 @@
+//! Media emulation methods.
+
+use tracing::debug;
+
+use crate::browser::emulation::{EmulatedMedia, MediaFeature};
+use crate::error::Result;
+use crate::protocol::{BrowsingContextCommand, Command};
+
+use super::Tab;
+
+// ============================================================================
+// Tab - Emulation
+// ============================================================================
+
+impl Tab {
+    /// Emulates CSS media features for this tab.
+    ///
+    /// Firefox has no per-tab media override, so the extension rewrites the
+    /// page's `@media` rules and patches `matchMedia()` to answer with the
+    /// emulated values. The emulation is reapplied on every navigation
+    /// until cleared. Replaces any previous emulation; features not listed
+    /// use the browser's real values.
+    ///
+    /// To switch the whole browser to dark mode instead, set the
+    /// `ui.systemUsesDarkTheme` preference to `1` when building the driver.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{ColorScheme, MediaFeature};
+    ///
+    /// tab.emulate_media(vec![MediaFeature::PrefersColorScheme(ColorScheme::Dark)])
+    ///     .await?;
+    /// let dark = tab
+    ///     .execute_script("return matchMedia('(prefers-color-scheme: dark)').matches")
+    ///     .await?;
+    /// assert_eq!(dark, true);
+    /// ```
+    pub async fn emulate_media(&self, features: Vec<MediaFeature>) -> Result<()> {
+        let media = EmulatedMedia::from_features(&features);
+
+        debug!(
+            tab_id = %self.inner.tab_id,
+            color_scheme = ?media.color_scheme,
+            reduced_motion = ?media.reduced_motion,
+            media_type = ?media.media_type,
+            "Emulating media"
+        );
+
+        let command = Command::BrowsingContext(BrowsingContextCommand::SetEmulatedMedia {
+            color_scheme: media.color_scheme.map(|v| v.as_str().to_string()),
+            reduced_motion: media.reduced_motion.map(|v| v.as_str().to_string()),
+            media_type: media.media_type.map(|v| v.as_str().to_string()),
+        });
+
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Clears media emulation set via [`Tab::emulate_media`].
+    pub async fn clear_media_emulation(&self) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, "Clearing media emulation");
+        let command = Command::BrowsingContext(BrowsingContextCommand::ClearEmulatedMedia);
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+}
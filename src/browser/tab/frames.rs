@@ -9,6 +9,18 @@ use crate::protocol::{BrowsingContextCommand, Command, Response};
 
 use super::{FrameInfo, Tab};
 
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Error code the extension returns when no frame matches.
+const NO_SUCH_FRAME: &str = "no such frame";
+
+/// Lists `name` (or else `id`) of the current frame's child frames.
+const FRAME_NAMES_SCRIPT: &str = "return [...document.querySelectorAll('iframe, frame')]\
+    .map((frame) => frame.name || frame.id)\
+    .filter(Boolean);";
+
 // ============================================================================
 // Tab - Frame Switching
 // ============================================================================
@@ -93,6 +105,49 @@ impl Tab {
         ))
     }
 
+    /// Switches to a child frame by its iframe `name` or `id` attribute.
+    ///
+    /// Searches the current frame's direct children, matching `name` first
+    /// and then `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ElementNotFound`] listing the available frame names
+    /// if no child frame matches.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let payment = tab.switch_to_frame_by_name("payment-frame").await?;
+    /// payment.find_element(By::css("#card-number")).await?;
+    /// ```
+    pub async fn switch_to_frame_by_name(&self, name: &str) -> Result<Tab> {
+        debug!(tab_id = %self.inner.tab_id, name, "Switching to frame by name");
+
+        let command = Command::BrowsingContext(BrowsingContextCommand::SwitchToFrameByName {
+            name: name.to_string(),
+        });
+        let response = self.send_command(command).await?;
+
+        if response.error.as_deref() == Some(NO_SUCH_FRAME) {
+            let available = self.child_frame_names().await.unwrap_or_default();
+            return Err(Error::element_not_found(
+                frame_name_selector(name, &available),
+                self.inner.tab_id,
+                self.inner.frame_id,
+            ));
+        }
+
+        let frame_id = extract_frame_id(&response)?;
+
+        Ok(Tab::new(
+            self.inner.tab_id,
+            FrameId::new(frame_id),
+            self.inner.session_id,
+            self.inner.window.clone(),
+        ))
+    }
+
     /// Switches to the parent frame.
     pub async fn switch_to_parent_frame(&self) -> Result<Tab> {
         debug!(tab_id = %self.inner.tab_id, "Switching to parent frame");
@@ -129,12 +184,7 @@ impl Tab {
         let command = Command::BrowsingContext(BrowsingContextCommand::GetFrameCount);
         let response = self.send_command(command).await?;
 
-        let count = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("count"))
-            .and_then(|v| v.as_u64())
-            .ok_or_else(|| Error::protocol("No count in response"))?;
+        let count = response.require_u64("count")?;
 
         debug!(tab_id = %self.inner.tab_id, count = count, "Got frame count");
         Ok(count as usize)
@@ -157,6 +207,12 @@ impl Tab {
         debug!(tab_id = %self.inner.tab_id, count = frames.len(), "Got all frames");
         Ok(frames)
     }
+
+    /// Returns `name` (or else `id`) of each child frame that has one.
+    async fn child_frame_names(&self) -> Result<Vec<String>> {
+        let value = self.execute_script(FRAME_NAMES_SCRIPT).await?;
+        Ok(serde_json::from_value(value)?)
+    }
 }
 
 // ============================================================================
@@ -165,12 +221,19 @@ impl Tab {
 
 /// Extracts frame ID from response.
 fn extract_frame_id(response: &Response) -> Result<u64> {
-    response
-        .result
-        .as_ref()
-        .and_then(|v| v.get("frameId"))
-        .and_then(|v| v.as_u64())
-        .ok_or_else(|| Error::protocol("No frameId in response"))
+    response.require_u64("frameId")
+}
+
+/// Describes a failed frame name lookup, listing the names available.
+fn frame_name_selector(name: &str, available: &[String]) -> String {
+    if available.is_empty() {
+        format!("frame[name|id={name:?}] (no named frames)")
+    } else {
+        format!(
+            "frame[name|id={name:?}] (available: {})",
+            available.join(", ")
+        )
+    }
 }
 
 /// Parses frame info from JSON value.
@@ -190,3 +253,25 @@ fn parse_frame_info(v: &Value) -> Option<FrameInfo> {
         url: v.get("url")?.as_str()?.to_string(),
     })
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::frame_name_selector;
+
+    #[test]
+    fn test_frame_name_selector_lists_available() {
+        let available = vec!["ads".to_string(), "checkout".to_string()];
+        assert_eq!(
+            frame_name_selector("payment", &available),
+            r#"frame[name|id="payment"] (available: ads, checkout)"#
+        );
+        assert_eq!(
+            frame_name_selector("payment", &[]),
+            r#"frame[name|id="payment"] (no named frames)"#
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! HAR capture methods.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tracing::debug;
+
+use crate::browser::har::{Har, HarCapture, HarCaptureOptions, HarRecorder};
+use crate::error::{Error, Result};
+use crate::protocol::{Command, Event, NetworkCommand};
+
+use super::Tab;
+use super::network::{
+    parse_intercepted_request_body, parse_intercepted_request_headers, parse_intercepted_response,
+    parse_intercepted_response_body, remove_intercept_command,
+};
+
+// ============================================================================
+// Tab - HAR Capture
+// ============================================================================
+
+impl Tab {
+    /// Starts recording this tab's network activity as HAR.
+    ///
+    /// Records request and response headers, request bodies and response
+    /// bodies. Use [`Tab::start_har_capture_with`] to omit or cap bodies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a capture is already running on this tab.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.start_har_capture().await?;
+    /// tab.goto("https://example.com").await?;
+    /// let har = tab.stop_har_capture().await?;
+    /// std::fs::write("example.har", har.to_json()?)?;
+    /// ```
+    pub async fn start_har_capture(&self) -> Result<()> {
+        self.start_har_capture_with(HarCaptureOptions::new()).await
+    }
+
+    /// Starts recording this tab's network activity as HAR with options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a capture is already running on this tab.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::HarCaptureOptions;
+    ///
+    /// let options = HarCaptureOptions::new().with_max_body_size(64 * 1024);
+    /// tab.start_har_capture_with(options).await?;
+    /// ```
+    pub async fn start_har_capture_with(&self, options: HarCaptureOptions) -> Result<()> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            include_bodies = options.include_bodies,
+            "Starting HAR capture"
+        );
+
+        let window = self.get_window()?;
+        let tab_id = self.inner.tab_id;
+
+        if window.inner.har_captures.lock().contains_key(&tab_id) {
+            return Err(Error::invalid_argument(
+                "HAR capture already running on this tab",
+            ));
+        }
+
+        let recorder = Arc::new(Mutex::new(HarRecorder::new(options)));
+        let handler_recorder = Arc::clone(&recorder);
+        let raw_tab_id = tab_id.as_u32();
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            // Observes only: replies are left to deciding intercepts
+            match event.method.as_str() {
+                "network.requestHeaders" => {
+                    let request = parse_intercepted_request_headers(&event);
+                    if request.tab_id != raw_tab_id {
+                        return None;
+                    }
+                    handler_recorder.lock().record_request(
+                        &request.request_id,
+                        &request.url,
+                        &request.method,
+                        &request.headers,
+                    );
+                }
+                "network.requestBody" => {
+                    let request = parse_intercepted_request_body(&event);
+                    if request.tab_id != raw_tab_id {
+                        return None;
+                    }
+                    handler_recorder.lock().record_request_body(
+                        &request.request_id,
+                        &request.url,
+                        &request.method,
+                        &request.resource_type,
+                        request.body.as_ref(),
+                    );
+                }
+                "network.responseHeaders" => {
+                    let response = parse_intercepted_response(&event);
+                    if response.tab_id != raw_tab_id {
+                        return None;
+                    }
+                    handler_recorder.lock().record_response(
+                        &response.request_id,
+                        response.status,
+                        &response.status_text,
+                        &response.headers,
+                    );
+                }
+                "network.responseBody" => {
+                    let body = parse_intercepted_response_body(&event);
+                    if body.tab_id != raw_tab_id {
+                        return None;
+                    }
+                    handler_recorder
+                        .lock()
+                        .record_response_body(&body.request_id, body.body);
+                }
+                _ => {}
+            }
+
+            None
+        }));
+
+        let command = Command::Network(NetworkCommand::AddIntercept {
+            intercept_requests: false,
+            intercept_request_headers: true,
+            intercept_request_body: true,
+            intercept_responses: true,
+            intercept_response_body: options.include_bodies,
+            url_patterns: Vec::new(),
+            resource_types: Vec::new(),
+        });
+
+        let intercept_id = self
+            .add_intercept(handler_id, command, remove_intercept_command)
+            .await?;
+
+        window.inner.har_captures.lock().insert(
+            tab_id,
+            HarCapture {
+                recorder,
+                intercept_id,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Stops the HAR capture and returns the recorded log.
+    ///
+    /// Entries are ordered by request start. Requests still in flight are
+    /// included with whatever was recorded so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no capture is running on this tab.
+    pub async fn stop_har_capture(&self) -> Result<Har> {
+        debug!(tab_id = %self.inner.tab_id, "Stopping HAR capture");
+
+        let window = self.get_window()?;
+        let capture = window
+            .inner
+            .har_captures
+            .lock()
+            .remove(&self.inner.tab_id)
+            .ok_or_else(|| Error::invalid_argument("No HAR capture running on this tab"))?;
+
+        window.untrack_subscription(capture.intercept_id.as_str());
+
+        let command = Command::Network(NetworkCommand::RemoveIntercept {
+            intercept_id: capture.intercept_id,
+        });
+        self.send_command(command).await?;
+
+        let recorder = std::mem::replace(
+            &mut *capture.recorder.lock(),
+            HarRecorder::new(HarCaptureOptions::new()),
+        );
+        let har = recorder.finish();
+
+        debug!(tab_id = %self.inner.tab_id, entries = har.len(), "HAR capture stopped");
+        Ok(har)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::{Driver, HeadersAction, MockTransport};
+
+    #[tokio::test]
+    async fn test_har_capture_per_tab() {
+        let mock = MockTransport::new()
+            .respond("browsingContext.newTab", json!({ "tabId": 2 }))
+            .respond("network.addIntercept", json!({ "interceptId": "har-1" }))
+            .respond("network.addIntercept", json!({ "interceptId": "har-2" }))
+            .respond("network.removeIntercept", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let first = window.tab();
+        let second = window.new_tab().await.expect("new tab");
+
+        first.start_har_capture().await.expect("start first");
+        second.start_har_capture().await.expect("start second");
+
+        for (tab_id, url) in [
+            (first.tab_id().as_u32(), "https://first.test/"),
+            (second.tab_id().as_u32(), "https://second.test/"),
+        ] {
+            let reply = mock.emit_event(
+                "network.requestHeaders",
+                json!({
+                    "requestId": format!("req-{tab_id}"),
+                    "url": url,
+                    "method": "GET",
+                    "headers": {},
+                    "tabId": tab_id
+                }),
+            );
+            assert_eq!(reply.expect("reply").result, json!({ "action": "allow" }));
+        }
+
+        // Stopping one capture leaves the other recording
+        let har = second.stop_har_capture().await.expect("stop second");
+        let urls: Vec<_> = har
+            .log
+            .entries
+            .iter()
+            .map(|e| e.request.url.as_str())
+            .collect();
+        assert_eq!(urls, ["https://second.test/"]);
+
+        mock.emit_event(
+            "network.requestHeaders",
+            json!({
+                "requestId": "req-late",
+                "url": "https://first.test/late",
+                "method": "GET",
+                "headers": {},
+                "tabId": first.tab_id().as_u32()
+            }),
+        );
+
+        let har = first.stop_har_capture().await.expect("stop first");
+        let urls: Vec<_> = har
+            .log
+            .entries
+            .iter()
+            .map(|e| e.request.url.as_str())
+            .collect();
+        assert_eq!(urls, ["https://first.test/", "https://first.test/late"]);
+    }
+
+    #[tokio::test]
+    async fn test_har_capture_does_not_override_intercepts() {
+        let mock = MockTransport::new()
+            .respond("network.addIntercept", json!({ "interceptId": "har-1" }))
+            .respond("network.addIntercept", json!({ "interceptId": "hdr-1" }))
+            .respond("network.removeIntercept", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let tab = driver.window().spawn().await.expect("window").tab();
+
+        tab.start_har_capture().await.expect("start");
+        tab.intercept_request_headers(|_| {
+            HeadersAction::ModifyHeaders(HashMap::from([("x-job".to_string(), "1".to_string())]))
+        })
+        .await
+        .expect("intercept");
+
+        let reply = mock
+            .emit_event(
+                "network.requestHeaders",
+                json!({
+                    "requestId": "req-1",
+                    "url": "https://example.com/",
+                    "method": "GET",
+                    "headers": {},
+                    "tabId": tab.tab_id().as_u32()
+                }),
+            )
+            .expect("reply");
+        assert_eq!(
+            reply.result,
+            json!({ "action": "modifyHeaders", "headers": { "x-job": "1" } })
+        );
+
+        let har = tab.stop_har_capture().await;
+        assert_eq!(har.expect("stop").len(), 1);
+    }
+}
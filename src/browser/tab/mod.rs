@@ -10,12 +10,22 @@
 //! | `navigation` | URL navigation, history |
 //! | `frames` | Frame switching |
 //! | `script` | JavaScript execution |
-//! | `elements` | Element search and observation |
+//! | `elements` | Element search, observation, DOM snapshots |
 //! | `network` | Request interception, blocking |
-//! | `storage` | Cookies, localStorage, sessionStorage |
+//! | `har` | HAR capture |
+//! | `request_log` | Passive request log |
+//! | `mouse` | Human-like mouse movement |
+//! | `accessibility` | Accessibility tree snapshot |
+//! | `downloads` | Download directory and completion waits |
+//! | `storage` | Cookies, localStorage, sessionStorage, IndexedDB, browsing data |
+//! | `service_workers` | Service worker listing and blocking |
 //! | `proxy` | Tab-level proxy |
 //! | `screenshot` | Page and element screenshots |
 //! | `scroll` | Scroll control |
+//! | `retry` | Retry with backoff |
+//! | `websocket` | WebSocket frame interception |
+//! | `batch` | Command batching |
+//! | `emulation` | CSS media emulation |
 //!
 //! # Example
 //!
@@ -41,20 +51,38 @@
 // Submodules
 // ============================================================================
 
+mod accessibility;
+mod batch;
 mod core;
+mod downloads;
 mod elements;
+mod emulation;
 mod frames;
+mod har;
+mod mouse;
 mod navigation;
 mod network;
+mod performance;
 mod proxy;
+mod request_log;
+mod retry;
 mod screenshot;
 mod script;
 mod scroll;
+mod service_workers;
 mod storage;
+mod websocket;
 
 // ============================================================================
 // Re-exports
 // ============================================================================
 
-pub use core::{FrameInfo, Tab};
+pub use batch::BatchBuilder;
+pub use core::{FrameInfo, Tab, TabInfo};
+pub use elements::ElementSnapshot;
+pub use navigation::NavigationResponse;
+pub use performance::PerformanceTiming;
+pub use retry::RetryPolicy;
 pub use screenshot::{ImageFormat, ScreenshotBuilder};
+
+pub(crate) use screenshot::{decode_image, encode_image, pixel_rect};
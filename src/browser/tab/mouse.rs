@@ -0,0 +1,179 @@
+//! Mouse input methods.
+
+use tracing::debug;
+
+use crate::browser::mouse::{MousePathOptions, human_path};
+use crate::error::Result;
+use crate::protocol::{Command, InputCommand};
+use crate::transport::connection::DEFAULT_COMMAND_TIMEOUT;
+
+use super::Tab;
+
+// ============================================================================
+// Tab - Mouse (Coordinates)
+// ============================================================================
+
+/// Left mouse button.
+const LEFT_BUTTON: u8 = 0;
+
+impl Tab {
+    /// Clicks the left mouse button at viewport coordinates.
+    ///
+    /// Targets whatever is at the point, so it works for canvas and WebGL
+    /// content or map markers that are not DOM elements. Coordinates are
+    /// CSS pixels relative to the top-left corner of the visible viewport
+    /// (the space of [`Element::get_bounding_rect`]). Screenshot pixels are
+    /// device pixels: divide them by `window.devicePixelRatio` to get CSS
+    /// pixels on high-DPI displays or when zoomed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let canvas = tab.find_element("canvas").await?;
+    /// let (x, y, _, _) = canvas.get_bounding_rect().await?;
+    /// tab.click_at(x as i32 + 120, y as i32 + 80).await?;
+    /// ```
+    ///
+    /// [`Element::get_bounding_rect`]: crate::Element::get_bounding_rect
+    pub async fn click_at(&self, x: i32, y: i32) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, x, y, "Clicking at coordinates");
+
+        let command = Command::Input(InputCommand::MouseClick {
+            element_id: None,
+            x: Some(x),
+            y: Some(y),
+            button: LEFT_BUTTON,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        self.record_mouse_position(x, y);
+        Ok(())
+    }
+
+    /// Moves the mouse straight to viewport coordinates.
+    ///
+    /// Dispatches a single `mousemove`. See [`Tab::click_at`] for the
+    /// coordinate space and [`Tab::move_mouse_human`] for a curved path.
+    pub async fn mouse_move_to(&self, x: i32, y: i32) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, x, y, "Moving mouse to coordinates");
+
+        let command = Command::Input(InputCommand::MouseMove {
+            element_id: None,
+            x: Some(x),
+            y: Some(y),
+        });
+
+        self.send_command(command).await?.into_result()?;
+        self.record_mouse_position(x, y);
+        Ok(())
+    }
+
+    /// Presses a mouse button at viewport coordinates.
+    ///
+    /// Pair with [`Tab::mouse_up_at`] to drag across a canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`, `y` - Viewport coordinates in CSS pixels (see [`Tab::click_at`])
+    /// * `button` - Mouse button (0=left, 1=middle, 2=right)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.mouse_down_at(100, 200, 0).await?;
+    /// tab.mouse_move_to(300, 200).await?;
+    /// tab.mouse_up_at(300, 200, 0).await?;
+    /// ```
+    pub async fn mouse_down_at(&self, x: i32, y: i32, button: u8) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, x, y, button, "Mouse down at coordinates");
+
+        let command = Command::Input(InputCommand::MouseDown {
+            element_id: None,
+            x: Some(x),
+            y: Some(y),
+            button,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        self.record_mouse_position(x, y);
+        Ok(())
+    }
+
+    /// Releases a mouse button at viewport coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`, `y` - Viewport coordinates in CSS pixels (see [`Tab::click_at`])
+    /// * `button` - Mouse button (0=left, 1=middle, 2=right)
+    pub async fn mouse_up_at(&self, x: i32, y: i32, button: u8) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, x, y, button, "Mouse up at coordinates");
+
+        let command = Command::Input(InputCommand::MouseUp {
+            element_id: None,
+            x: Some(x),
+            y: Some(y),
+            button,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        self.record_mouse_position(x, y);
+        Ok(())
+    }
+
+    /// Remembers the pointer position so human-like paths start there.
+    fn record_mouse_position(&self, x: i32, y: i32) {
+        if let Some(window) = &self.inner.window {
+            window.set_mouse_position(self.inner.tab_id, (x, y));
+        }
+    }
+}
+
+// ============================================================================
+// Tab - Mouse (Human-like)
+// ============================================================================
+
+impl Tab {
+    /// Moves the mouse to viewport coordinates along a curved path.
+    ///
+    /// Dispatches a series of `mousemove` events from the last position
+    /// moved to in this tab (the top-left corner at first), with default
+    /// [`MousePathOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.move_mouse_human(640, 360).await?;
+    /// ```
+    pub async fn move_mouse_human(&self, x: i32, y: i32) -> Result<()> {
+        self.move_mouse_human_with(x, y, MousePathOptions::new())
+            .await
+    }
+
+    /// Moves the mouse to viewport coordinates with custom path options.
+    pub async fn move_mouse_human_with(
+        &self,
+        x: i32,
+        y: i32,
+        options: MousePathOptions,
+    ) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, x, y, steps = options.steps, "Moving mouse along path");
+
+        let window = self.get_window()?;
+        let from = window.mouse_position(self.inner.tab_id);
+        let points = human_path(from, (x, y), &options);
+        let duration = options
+            .interval
+            .saturating_mul(points.len().try_into().unwrap_or(u32::MAX));
+
+        let command = Command::Input(InputCommand::MouseMovePath {
+            points,
+            interval_ms: options.interval.as_millis() as u64,
+        });
+
+        self.send_command_with_timeout(command, DEFAULT_COMMAND_TIMEOUT.saturating_add(duration))
+            .await?
+            .into_result()?;
+        window.set_mouse_position(self.inner.tab_id, (x, y));
+        Ok(())
+    }
+}
@@ -1,12 +1,77 @@
 //! Tab navigation methods.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde_json::Value;
+use tokio::sync::oneshot;
+use tokio::time::timeout;
 use tracing::debug;
 
-use crate::error::Result;
-use crate::protocol::{BrowsingContextCommand, Command};
+use crate::error::{Error, Result};
+use crate::protocol::{BrowsingContextCommand, Command, Event, ParsedEvent, SessionCommand};
 
 use super::Tab;
 
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Error code the extension returns when a page fails to load.
+const NAVIGATION_FAILED: &str = "navigation failed";
+
+/// Event sent when a frame finishes loading.
+const LOAD_EVENT: &str = "browsingContext.load";
+
+/// Records the next same-document history traversal as a promise.
+///
+/// Traversals between entries of one document (`pushState` entries,
+/// fragments) fire `popstate` or `hashchange` but never `load`.
+const WATCH_SAME_DOCUMENT_SCRIPT: &str = "window.__firefoxWebdriverTraversal = \
+    new Promise((resolve) => { \
+        const done = () => resolve(true); \
+        addEventListener(\"popstate\", done, { once: true }); \
+        addEventListener(\"hashchange\", done, { once: true }); \
+    });";
+
+/// Settles once the traversal recorded by [`WATCH_SAME_DOCUMENT_SCRIPT`]
+/// happens, and never if it was not recorded.
+const AWAIT_SAME_DOCUMENT_SCRIPT: &str =
+    "return await (window.__firefoxWebdriverTraversal || new Promise(() => {}));";
+
+/// Reports whether session history has an entry before the current one.
+///
+/// The Navigation API knows the current position; without it, any
+/// history beyond the first entry is assumed to be behind us.
+const CAN_GO_BACK_SCRIPT: &str = "return window.navigation && \"canGoBack\" in navigation \
+    ? navigation.canGoBack \
+    : history.length > 1;";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// HTTP response of a main-document navigation.
+#[derive(Debug, Clone)]
+pub struct NavigationResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers (lowercase names).
+    pub headers: HashMap<String, String>,
+    /// URL after following redirects.
+    pub final_url: String,
+}
+
+impl NavigationResponse {
+    /// Returns `true` for 2xx status codes.
+    #[inline]
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
 // ============================================================================
 // Tab - Navigation
 // ============================================================================
@@ -20,7 +85,20 @@ impl Tab {
     ///
     /// # Errors
     ///
-    /// Returns an error if navigation fails.
+    /// Returns [`Error::Navigation`] if the page fails to load (DNS failure,
+    /// refused connection, TLS error, ...), or another error if the command
+    /// itself fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{Error, NavigationError};
+    ///
+    /// match tab.goto("https://dead-domain.invalid").await {
+    ///     Err(Error::Navigation { reason: NavigationError::DnsFailure, .. }) => skip(),
+    ///     other => other?,
+    /// }
+    /// ```
     pub async fn goto(&self, url: &str) -> Result<()> {
         debug!(url = %url, tab_id = %self.inner.tab_id, "Navigating");
 
@@ -28,13 +106,80 @@ impl Tab {
             url: url.to_string(),
         });
 
-        self.send_command(command).await?;
+        let response = self.send_command(command).await?;
+
+        if response.error.as_deref() == Some(NAVIGATION_FAILED) {
+            let code = response.message.unwrap_or_default();
+            debug!(url = %url, tab_id = %self.inner.tab_id, code = %code, "Navigation failed");
+            return Err(Error::navigation(url, code));
+        }
+
+        response.into_result()?;
         Ok(())
     }
 
+    /// Navigates to a URL and returns the main document's HTTP response.
+    ///
+    /// Use `final_url` to detect redirects and `status` to detect blocks
+    /// (403/429) or error pages served after a successful load.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Tab::goto`], plus [`Error::Protocol`] if the extension
+    /// recorded no main-document response (e.g. `about:` or `data:` URLs).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let response = tab.goto_with_response("https://example.com/item/1").await?;
+    /// if response.status == 429 {
+    ///     println!("Rate limited, redirected to {}", response.final_url);
+    /// }
+    /// ```
+    pub async fn goto_with_response(&self, url: &str) -> Result<NavigationResponse> {
+        self.goto(url).await?;
+        self.get_response()
+            .await?
+            .ok_or_else(|| Error::protocol(format!("No main document response recorded for {url}")))
+    }
+
+    /// Gets the HTTP response of the last main-document navigation.
+    ///
+    /// Returns `None` if the current page was not loaded over HTTP.
+    pub async fn get_response(&self) -> Result<Option<NavigationResponse>> {
+        debug!(tab_id = %self.inner.tab_id, "Getting main document response");
+
+        let command = Command::BrowsingContext(BrowsingContextCommand::GetMainResponse);
+        let response = self.send_command(command).await?;
+
+        let navigation_response = response
+            .result
+            .as_ref()
+            .and_then(|v| v.get("response"))
+            .and_then(parse_navigation_response);
+
+        debug!(
+            tab_id = %self.inner.tab_id,
+            status = navigation_response.as_ref().map(|r| r.status),
+            "Got main document response"
+        );
+        Ok(navigation_response)
+    }
+
+    /// Gets the HTTP status of the last main-document navigation.
+    ///
+    /// Returns `None` if the current page was not loaded over HTTP.
+    pub async fn get_response_status(&self) -> Result<Option<u16>> {
+        Ok(self.get_response().await?.map(|r| r.status))
+    }
+
     /// Loads HTML content directly into the page.
     ///
     /// Useful for testing with inline HTML without needing a server.
+    /// Replaces the body and appends head elements to the current
+    /// document; `<script>` elements are recreated so they run, in
+    /// document order. Relative URLs resolve against the current page.
+    /// Use [`Tab::set_content`] to replace the whole document instead.
     ///
     /// # Arguments
     ///
@@ -60,21 +205,36 @@ impl Tab {
                 const doc = parser.parseFromString(html, 'text/html');
                 const newTitle = doc.querySelector('title');
                 if (newTitle) {{ document.title = newTitle.textContent; }}
-                const newBody = doc.body;
-                if (newBody) {{
-                    document.body.innerHTML = newBody.innerHTML;
-                    for (const attr of newBody.attributes) {{
-                        document.body.setAttribute(attr.name, attr.value);
+                // Parsed scripts are inert; fresh elements run when inserted
+                const activate = (old) => {{
+                    const script = document.createElement('script');
+                    for (const attr of old.attributes) {{
+                        script.setAttribute(attr.name, attr.value);
                     }}
-                }}
+                    script.async = old.hasAttribute('async');
+                    script.textContent = old.textContent;
+                    return script;
+                }};
                 const newHead = doc.head;
                 if (newHead) {{
                     for (const child of newHead.children) {{
-                        if (child.tagName !== 'TITLE') {{
+                        if (child.tagName === 'SCRIPT') {{
+                            document.head.appendChild(activate(child));
+                        }} else if (child.tagName !== 'TITLE') {{
                             document.head.appendChild(child.cloneNode(true));
                         }}
                     }}
                 }}
+                const newBody = doc.body;
+                if (newBody) {{
+                    document.body.innerHTML = newBody.innerHTML;
+                    for (const attr of newBody.attributes) {{
+                        document.body.setAttribute(attr.name, attr.value);
+                    }}
+                    for (const old of document.body.querySelectorAll('script')) {{
+                        old.replaceWith(activate(old));
+                    }}
+                }}
             }})();"#,
             escaped_html
         );
@@ -83,6 +243,59 @@ impl Tab {
         Ok(())
     }
 
+    /// Replaces the whole document with `html`, as if it had been loaded.
+    ///
+    /// Writes the markup with `document.open()` / `document.write()`, so
+    /// the HTML parser runs it like a real page load: scripts execute in
+    /// order, `DOMContentLoaded` and `load` fire, and the previous
+    /// document's listeners and timers are dropped. Returns once `load`
+    /// has fired.
+    ///
+    /// The page URL does not change. Pass `base_url` to resolve relative
+    /// links, images and scripts against another location; it is added
+    /// as a `<base>` element at the start of `<head>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `html` - Full HTML document
+    /// * `base_url` - Base URL for relative references
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.set_content(
+    ///     r#"<html><head><script src="app.js"></script></head><body></body></html>"#,
+    ///     Some("https://example.com/static/"),
+    /// )
+    /// .await?;
+    /// ```
+    pub async fn set_content(&self, html: &str, base_url: Option<&str>) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, html_len = html.len(), base_url, "Setting page content");
+
+        let html = match base_url {
+            Some(base_url) => insert_base_element(html, base_url),
+            None => html.to_string(),
+        };
+
+        let script = format!(
+            r#"const html = {};
+await new Promise((resolve) => {{
+    document.open();
+    document.write(html);
+    document.close();
+    if (document.readyState === "complete") {{
+        resolve();
+    }} else {{
+        window.addEventListener("load", () => resolve(), {{ once: true }});
+    }}
+}});"#,
+            serde_json::to_string(&html)?
+        );
+
+        self.execute_async_script(&script).await?;
+        Ok(())
+    }
+
     /// Reloads the current page.
     pub async fn reload(&self) -> Result<()> {
         debug!(tab_id = %self.inner.tab_id, "Reloading page");
@@ -107,19 +320,165 @@ impl Tab {
         Ok(())
     }
 
+    /// Navigates back in history and waits for the page to load.
+    ///
+    /// Returns once the tab's top-level document fires `load`, so the
+    /// next command runs against the previous page rather than the one
+    /// being left. Going back within the same document (an entry added by
+    /// `pushState` or a fragment link) loads nothing, so it returns once
+    /// the page fires `popstate` or `hashchange` instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if no navigation completes within
+    /// `timeout`, including when there is no history entry to go back to
+    /// (see [`Tab::can_go_back`]).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// if tab.can_go_back().await? {
+    ///     tab.back_and_wait(Duration::from_secs(10)).await?;
+    /// }
+    /// ```
+    pub async fn back_and_wait(&self, timeout: Duration) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, timeout_ms = timeout.as_millis(), "Navigating back and waiting");
+        self.traverse_history_and_wait(BrowsingContextCommand::GoBack, "back_and_wait", timeout)
+            .await
+    }
+
+    /// Navigates forward in history and waits for the page to load.
+    ///
+    /// See [`Tab::back_and_wait`].
+    pub async fn forward_and_wait(&self, timeout: Duration) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, timeout_ms = timeout.as_millis(), "Navigating forward and waiting");
+        self.traverse_history_and_wait(
+            BrowsingContextCommand::GoForward,
+            "forward_and_wait",
+            timeout,
+        )
+        .await
+    }
+
+    /// Gets the number of session history entries (`history.length`).
+    pub async fn history_length(&self) -> Result<usize> {
+        debug!(tab_id = %self.inner.tab_id, "Getting history length");
+
+        let value = self.execute_script("return history.length;").await?;
+        let length = value
+            .as_u64()
+            .ok_or_else(|| Error::script_error("history.length is not a number"))?;
+
+        Ok(length as usize)
+    }
+
+    /// Returns `true` if there is a history entry to go back to.
+    ///
+    /// Exact where Firefox supports the Navigation API
+    /// (`navigation.canGoBack`). Otherwise it only reports whether the
+    /// history has more than one entry, which is also `true` after going
+    /// back to the first one.
+    pub async fn can_go_back(&self) -> Result<bool> {
+        debug!(tab_id = %self.inner.tab_id, "Checking back history");
+
+        let value = self.execute_script(CAN_GO_BACK_SCRIPT).await?;
+        Ok(value.as_bool().unwrap_or(false))
+    }
+
+    /// Sends a history traversal and waits for the main frame's `load`,
+    /// or for a same-document traversal.
+    async fn traverse_history_and_wait(
+        &self,
+        command: BrowsingContextCommand,
+        operation: &str,
+        timeout_duration: Duration,
+    ) -> Result<()> {
+        let window = self.get_window()?;
+        let expected_tab = self.inner.tab_id.as_u32();
+
+        let (tx, rx) = oneshot::channel::<()>();
+        let tx = Mutex::new(Some(tx));
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != LOAD_EVENT {
+                return None;
+            }
+
+            if let ParsedEvent::BrowsingContextLoad {
+                tab_id, frame_id, ..
+            } = event.parse()
+                && tab_id == expected_tab
+                && frame_id == 0
+                && let Some(tx) = tx.lock().take()
+            {
+                let _ = tx.send(());
+            }
+
+            None
+        }));
+
+        // Pages that block scripts (e.g. `about:` pages) only report loads
+        let watching = self
+            .execute_script(WATCH_SAME_DOCUMENT_SCRIPT)
+            .await
+            .is_ok();
+
+        let subscribe = Command::Session(SessionCommand::Subscribe {
+            events: vec![LOAD_EVENT.to_string()],
+            selectors: None,
+        });
+        let sent = match self.send_command(subscribe).await {
+            Ok(_) => self.send_command(Command::BrowsingContext(command)).await,
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = sent.and_then(|response| response.into_result()) {
+            window.remove_event_handler(handler_id);
+            return Err(e);
+        }
+
+        // A cross-document traversal destroys the page and fails this
+        // script, leaving the `load` event to finish the wait
+        let same_document = async {
+            let settled = watching
+                && self
+                    .execute_async_script_with_timeout(AWAIT_SAME_DOCUMENT_SCRIPT, timeout_duration)
+                    .await
+                    .is_ok();
+            if !settled {
+                std::future::pending::<()>().await;
+            }
+        };
+
+        let result = timeout(timeout_duration, async {
+            tokio::select! {
+                loaded = rx => loaded.is_ok(),
+                () = same_document => true,
+            }
+        })
+        .await;
+
+        window.remove_event_handler(handler_id);
+
+        match result {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(Error::protocol("Channel closed unexpectedly")),
+            Err(_) => Err(Error::timeout(
+                operation,
+                timeout_duration.as_millis() as u64,
+            )),
+        }
+    }
+
     /// Gets the current page title.
     pub async fn get_title(&self) -> Result<String> {
         debug!(tab_id = %self.inner.tab_id, "Getting page title");
         let command = Command::BrowsingContext(BrowsingContextCommand::GetTitle);
         let response = self.send_command(command).await?;
 
-        let title = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("title"))
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+        let title = response.require_str("title")?.to_string();
 
         debug!(tab_id = %self.inner.tab_id, title = %title, "Got page title");
         Ok(title)
@@ -131,13 +490,7 @@ impl Tab {
         let command = Command::BrowsingContext(BrowsingContextCommand::GetUrl);
         let response = self.send_command(command).await?;
 
-        let url = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("url"))
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
+        let url = response.require_str("url")?.to_string();
 
         debug!(tab_id = %self.inner.tab_id, url = %url, "Got page URL");
         Ok(url)
@@ -159,11 +512,28 @@ impl Tab {
         Ok(())
     }
 
+    /// Brings this tab to the front: focuses its window, then the tab.
+    ///
+    /// Shorthand for [`Tab::focus_window`] followed by [`Tab::focus`].
+    pub async fn bring_to_front(&self) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, "Bringing tab to front");
+        self.focus_window().await?;
+        self.focus().await
+    }
+
+    /// Opens a new tab in the same window as this tab.
+    ///
+    /// Shorthand for `window.new_tab().await`.
+    pub async fn new_tab(&self) -> Result<Tab> {
+        debug!(tab_id = %self.inner.tab_id, "Opening new tab");
+        self.get_window()?.new_tab().await
+    }
+
     /// Closes this tab.
     pub async fn close(&self) -> Result<()> {
         debug!(tab_id = %self.inner.tab_id, "Closing tab");
         let command = Command::BrowsingContext(BrowsingContextCommand::CloseTab);
-        self.send_command(command).await?;
+        self.send_command(command).await?.into_result()?;
         Ok(())
     }
 
@@ -176,3 +546,218 @@ impl Tab {
         Ok(result.as_str().unwrap_or("").to_string())
     }
 }
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Inserts `<base href>` at the start of `<head>`, adding a head if the
+/// markup has none.
+fn insert_base_element(html: &str, base_url: &str) -> String {
+    let href = base_url.replace('&', "&amp;").replace('"', "&quot;");
+    let base = format!(r#"<base href="{href}">"#);
+    // ASCII lowercasing keeps byte offsets valid for `html`
+    let lower = html.to_ascii_lowercase();
+
+    let (index, insert) = if let Some(index) = find_tag_end(&lower, "head") {
+        (index, base)
+    } else if let Some(index) = find_tag_end(&lower, "html") {
+        (index, format!("<head>{base}</head>"))
+    } else if lower.trim_start().starts_with("<!doctype") {
+        (lower.find('>').map_or(0, |i| i + 1), base)
+    } else {
+        (0, base)
+    };
+
+    let mut output = String::with_capacity(html.len() + insert.len());
+    output.push_str(&html[..index]);
+    output.push_str(&insert);
+    output.push_str(&html[index..]);
+    output
+}
+
+/// Returns the offset just past the first opening `<name>` tag in
+/// lowercased markup.
+fn find_tag_end(lower: &str, name: &str) -> Option<usize> {
+    let open = format!("<{name}");
+    let mut from = 0;
+
+    while let Some(pos) = lower[from..].find(&open) {
+        let after = from + pos + open.len();
+        match lower.as_bytes().get(after) {
+            Some(b'>' | b'/' | b' ' | b'\t' | b'\n' | b'\r') => {
+                return lower[after..].find('>').map(|i| after + i + 1);
+            }
+            _ => from = after,
+        }
+    }
+
+    None
+}
+
+/// Parses a main-document response from a `getMainResponse` result.
+fn parse_navigation_response(value: &Value) -> Option<NavigationResponse> {
+    let status = value.get("status").and_then(|v| v.as_u64())? as u16;
+
+    let headers = value
+        .get("headers")
+        .and_then(|v| v.as_object())
+        .map(|obj| {
+            obj.iter()
+                .filter_map(|(k, v)| v.as_str().map(|s| (k.to_lowercase(), s.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let final_url = value
+        .get("url")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+
+    Some(NavigationResponse {
+        status,
+        headers,
+        final_url,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{insert_base_element, parse_navigation_response};
+
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::error::Error;
+    use crate::{Driver, MockTransport};
+
+    #[tokio::test]
+    async fn test_back_and_wait() {
+        let mock = MockTransport::new()
+            .respond("session.subscribe", json!({}))
+            .respond("browsingContext.goBack", json!({}))
+            .respond("browsingContext.goForward", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+        let tab_id = tab.tab_id().as_u32();
+        mock.clear_requests();
+
+        let waiter = {
+            let tab = tab.clone();
+            tokio::spawn(async move { tab.back_and_wait(Duration::from_secs(5)).await })
+        };
+        while !mock
+            .methods()
+            .contains(&"browsingContext.goBack".to_string())
+        {
+            tokio::task::yield_now().await;
+        }
+
+        // Child frames and other tabs do not count
+        let load = |tab_id: u32, frame_id: u64| json!({ "tabId": tab_id, "frameId": frame_id, "url": "https://example.com/" });
+        mock.emit_event("browsingContext.load", load(tab_id, 3));
+        mock.emit_event("browsingContext.load", load(tab_id + 1, 0));
+        assert!(!waiter.is_finished());
+        mock.emit_event("browsingContext.load", load(tab_id, 0));
+
+        waiter.await.expect("join").expect("loaded");
+        assert_eq!(
+            mock.methods(),
+            [
+                "script.evaluate",
+                "session.subscribe",
+                "browsingContext.goBack"
+            ]
+        );
+
+        let timed_out = tab.forward_and_wait(Duration::from_millis(10)).await;
+        assert!(matches!(timed_out, Err(Error::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_back_and_wait_same_document() {
+        let mock = MockTransport::new()
+            .respond("script.evaluate", json!({ "value": null }))
+            .respond("script.evaluateAsync", json!({ "value": true }))
+            .respond("session.subscribe", json!({}))
+            .respond("browsingContext.goBack", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+        mock.clear_requests();
+
+        // No load event: the popstate watcher settles the wait
+        tab.back_and_wait(Duration::from_secs(5))
+            .await
+            .expect("traversed");
+        assert_eq!(
+            mock.methods(),
+            [
+                "script.evaluate",
+                "session.subscribe",
+                "browsingContext.goBack",
+                "script.evaluateAsync"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_base_element() {
+        let base = r#"<base href="https://example.com/a/">"#;
+
+        assert_eq!(
+            insert_base_element(
+                "<HTML><Head lang=en><title>x</title>",
+                "https://example.com/a/"
+            ),
+            format!("<HTML><Head lang=en>{base}<title>x</title>")
+        );
+        assert_eq!(
+            insert_base_element("<!DOCTYPE html><html><body>", "https://example.com/a/"),
+            format!("<!DOCTYPE html><html><head>{base}</head><body>")
+        );
+        assert_eq!(
+            insert_base_element("<!doctype html><p>hi", "https://example.com/a/"),
+            format!("<!doctype html>{base}<p>hi")
+        );
+        assert_eq!(
+            insert_base_element("<p>hi</p>", "https://example.com/?a=\"b\"&c"),
+            r#"<base href="https://example.com/?a=&quot;b&quot;&amp;c"><p>hi</p>"#
+        );
+        // <header> is not <head>
+        assert_eq!(
+            insert_base_element("<header>x</header>", "https://example.com/a/"),
+            format!("{base}<header>x</header>")
+        );
+    }
+
+    #[test]
+    fn test_parse_navigation_response() {
+        let value = json!({
+            "status": 403,
+            "headers": { "Content-Type": "text/html", "Server": "nginx" },
+            "url": "https://example.com/blocked"
+        });
+
+        let response = parse_navigation_response(&value).expect("response");
+        assert_eq!(response.status, 403);
+        assert!(!response.is_success());
+        assert_eq!(response.final_url, "https://example.com/blocked");
+        assert_eq!(
+            response.headers.get("content-type").map(String::as_str),
+            Some("text/html")
+        );
+    }
+
+    #[test]
+    fn test_parse_navigation_response_requires_status() {
+        assert!(parse_navigation_response(&json!({ "url": "about:blank" })).is_none());
+    }
+}
@@ -1,21 +1,26 @@
 //! Network interception and blocking methods.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as Base64Standard;
+use parking_lot::Mutex;
 use serde_json::Value;
+use tokio::sync::oneshot;
+use tokio::time::timeout;
 use tracing::debug;
 
 use crate::browser::network::{
     BodyAction, HeadersAction, InterceptedRequest, InterceptedRequestBody,
-    InterceptedRequestHeaders, InterceptedResponse, InterceptedResponseBody, RequestAction,
-    RequestBody,
+    InterceptedRequestHeaders, InterceptedResponse, InterceptedResponseBody, NetworkConditions,
+    RequestAction, RequestBody, ResourceType, SseMessage,
 };
 use crate::error::{Error, Result};
-use crate::identifiers::InterceptId;
-use crate::protocol::{Command, Event, EventReply, NetworkCommand, Response};
+use crate::identifiers::{InterceptId, SubscriptionId, TabId};
+use crate::protocol::{Command, Event, EventReply, NetworkCommand, ParsedEvent, Response};
+use crate::transport::EventHandlerId;
 
 use super::Tab;
 
@@ -48,7 +53,148 @@ impl Tab {
     pub async fn clear_block_rules(&self) -> Result<()> {
         debug!(tab_id = %self.inner.tab_id, "Clearing block rules");
         let command = Command::Network(NetworkCommand::ClearBlockRules);
-        self.send_command(command).await?;
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Blocks every request of the given resource types from this tab.
+    ///
+    /// Backed by `declarativeNetRequest` rules in the extension, so blocked
+    /// requests never reach the interception callbacks and cost no round
+    /// trip. Independent of [`Tab::set_block_rules`]: a request is blocked if
+    /// it matches either. Replaces the previous type list; an empty slice
+    /// unblocks all types.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::ResourceType;
+    ///
+    /// tab.block_resource_types(&[ResourceType::Image, ResourceType::Font, ResourceType::Media])
+    ///     .await?;
+    /// ```
+    pub async fn block_resource_types(&self, types: &[ResourceType]) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, types = ?types, "Blocking resource types");
+
+        let mut resource_types: Vec<String> = Vec::with_capacity(types.len());
+        for name in types.iter().map(ResourceType::as_str) {
+            if !resource_types.iter().any(|existing| existing == name) {
+                resource_types.push(name.to_string());
+            }
+        }
+
+        let command = Command::Network(NetworkCommand::SetBlockedResourceTypes { resource_types });
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Unblocks all resource types blocked via [`Tab::block_resource_types`].
+    pub async fn clear_blocked_resource_types(&self) -> Result<()> {
+        self.block_resource_types(&[]).await
+    }
+
+    /// Sets static headers to add to every request from this tab.
+    ///
+    /// Headers are injected by a declarative rule in the extension, so there
+    /// is no per-request round trip and the event handler stays free for
+    /// interception. Replaces any previously set extra headers.
+    ///
+    /// Header names are case-insensitive: an extra header replaces any
+    /// existing request header with the same name regardless of case. An
+    /// empty value removes the header from outgoing requests.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::collections::HashMap;
+    ///
+    /// let mut headers = HashMap::new();
+    /// headers.insert("X-Test-Run".to_string(), "42".to_string());
+    /// headers.insert("Referer".to_string(), String::new()); // strip Referer
+    ///
+    /// tab.set_extra_headers(headers).await?;
+    /// ```
+    pub async fn set_extra_headers(&self, headers: HashMap<String, String>) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, header_count = headers.len(), "Setting extra headers");
+
+        let command = Command::Network(NetworkCommand::SetExtraHeaders {
+            headers: normalize_header_names(headers),
+        });
+
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Clears all extra headers set via [`Tab::set_extra_headers`].
+    pub async fn clear_extra_headers(&self) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, "Clearing extra headers");
+        let command = Command::Network(NetworkCommand::ClearExtraHeaders);
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Emulates network conditions for this tab.
+    ///
+    /// Firefox has no built-in throttle, so the extension delays and chunks
+    /// responses to approximate the requested bandwidth and latency. With
+    /// `offline` set, every request fails. Replaces any previous conditions.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::NetworkConditions;
+    ///
+    /// tab.set_network_conditions(NetworkConditions::slow_3g()).await?;
+    /// tab.goto("https://example.com").await?;
+    /// tab.clear_network_conditions().await?;
+    /// ```
+    pub async fn set_network_conditions(&self, conditions: NetworkConditions) -> Result<()> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            offline = conditions.offline,
+            download_throughput = conditions.download_throughput,
+            upload_throughput = conditions.upload_throughput,
+            latency_ms = conditions.latency_ms,
+            "Setting network conditions"
+        );
+
+        let command = Command::Network(NetworkCommand::SetNetworkConditions {
+            offline: conditions.offline,
+            download_throughput: conditions.download_throughput,
+            upload_throughput: conditions.upload_throughput,
+            latency_ms: conditions.latency_ms,
+        });
+
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Clears emulated network conditions set via [`Tab::set_network_conditions`].
+    pub async fn clear_network_conditions(&self) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, "Clearing network conditions");
+        let command = Command::Network(NetworkCommand::ClearNetworkConditions);
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Toggles offline mode for this tab.
+    ///
+    /// While offline, every outgoing request is blocked by a catch-all block
+    /// rule, `navigator.onLine` reports `false` and the page receives an
+    /// `offline` event. Going back online lifts the block and fires `online`.
+    /// Block rules set via [`Tab::set_block_rules`] are kept either way.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.set_offline(true).await?;
+    /// let banner = tab.find_element(By::css(".offline-banner")).await?;
+    /// tab.set_offline(false).await?;
+    /// ```
+    pub async fn set_offline(&self, offline: bool) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, offline, "Setting offline mode");
+        let command = Command::Network(NetworkCommand::SetOffline { offline });
+        self.send_command(command).await?.into_result()?;
         Ok(())
     }
 
@@ -75,29 +221,76 @@ impl Tab {
     where
         F: Fn(InterceptedRequest) -> RequestAction + Send + Sync + 'static,
     {
-        debug!(tab_id = %self.inner.tab_id, "Enabling request interception");
+        self.intercept_request_filtered(&[], &[], callback).await
+    }
+
+    /// Intercepts only requests matching URL patterns and resource types.
+    ///
+    /// The extension filters before forwarding, so non-matching requests
+    /// proceed without a round trip to the callback. Patterns support
+    /// wildcards (`*`); resource types use WebExtension names (`main_frame`,
+    /// `script`, `xmlhttprequest`, ...). An empty list matches everything.
+    /// The filters are checked again before the callback runs, since events
+    /// forwarded for other intercepts on the tab reach every handler.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::RequestAction;
+    ///
+    /// let id = tab.intercept_request_filtered(&["*/api/*"], &["xmlhttprequest"], |req| {
+    ///     println!("API call: {}", req.url);
+    ///     RequestAction::allow()
+    /// }).await?;
+    /// ```
+    pub async fn intercept_request_filtered<F>(
+        &self,
+        url_patterns: &[&str],
+        resource_types: &[&str],
+        callback: F,
+    ) -> Result<InterceptId>
+    where
+        F: Fn(InterceptedRequest) -> RequestAction + Send + Sync + 'static,
+    {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            pattern_count = url_patterns.len(),
+            resource_type_count = resource_types.len(),
+            "Enabling request interception"
+        );
 
         let window = self.get_window()?;
         let callback = Arc::new(callback);
+        let tab_id = self.inner.tab_id;
+        let url_patterns: Vec<String> = url_patterns.iter().map(|s| (*s).to_string()).collect();
+        let resource_types: Vec<String> = resource_types.iter().map(|s| (*s).to_string()).collect();
+        let handler_url_patterns = url_patterns.clone();
+        let handler_resource_types = resource_types.clone();
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.beforeRequestSent" {
+                return None;
+            }
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "network.beforeRequestSent" {
-                    return None;
-                }
-
-                let request = parse_intercepted_request(&event);
-                let action = callback(request);
-                let result = request_action_to_json(&action);
-
-                Some(EventReply::new(
-                    event.id,
-                    "network.beforeRequestSent",
-                    result,
-                ))
-            }),
-        );
+            if !is_tab_event(&event, tab_id) {
+                return None;
+            }
+
+            // Other intercepts on this tab deliver their events here too
+            let request = parse_intercepted_request(&event);
+            if !matches_filters(&request, &handler_url_patterns, &handler_resource_types) {
+                return None;
+            }
+
+            let action = callback(request);
+            let result = request_action_to_json(&action);
+
+            Some(EventReply::new(
+                event.id,
+                "network.beforeRequestSent",
+                result,
+            ))
+        }));
 
         let command = Command::Network(NetworkCommand::AddIntercept {
             intercept_requests: true,
@@ -105,10 +298,12 @@ impl Tab {
             intercept_request_body: false,
             intercept_responses: false,
             intercept_response_body: false,
+            url_patterns,
+            resource_types,
         });
 
-        let response = self.send_command(command).await?;
-        extract_intercept_id(&response)
+        self.add_intercept(handler_id, command, remove_intercept_command)
+            .await
     }
 
     /// Intercepts request headers with a callback.
@@ -120,21 +315,23 @@ impl Tab {
 
         let window = self.get_window()?;
         let callback = Arc::new(callback);
+        let tab_id = self.inner.tab_id;
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "network.requestHeaders" {
-                    return None;
-                }
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.requestHeaders" {
+                return None;
+            }
 
-                let headers_data = parse_intercepted_request_headers(&event);
-                let action = callback(headers_data);
-                let result = headers_action_to_json(&action);
+            if !is_tab_event(&event, tab_id) {
+                return None;
+            }
 
-                Some(EventReply::new(event.id, "network.requestHeaders", result))
-            }),
-        );
+            let headers_data = parse_intercepted_request_headers(&event);
+            let action = callback(headers_data);
+            let result = headers_action_to_json(&action);
+
+            Some(EventReply::new(event.id, "network.requestHeaders", result))
+        }));
 
         let command = Command::Network(NetworkCommand::AddIntercept {
             intercept_requests: false,
@@ -142,10 +339,12 @@ impl Tab {
             intercept_request_body: false,
             intercept_responses: false,
             intercept_response_body: false,
+            url_patterns: Vec::new(),
+            resource_types: Vec::new(),
         });
 
-        let response = self.send_command(command).await?;
-        extract_intercept_id(&response)
+        self.add_intercept(handler_id, command, remove_intercept_command)
+            .await
     }
 
     /// Intercepts request body for logging (read-only).
@@ -157,24 +356,23 @@ impl Tab {
 
         let window = self.get_window()?;
         let callback = Arc::new(callback);
+        let tab_id = self.inner.tab_id;
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "network.requestBody" {
-                    return None;
-                }
-
-                let body_data = parse_intercepted_request_body(&event);
-                callback(body_data);
-
-                Some(EventReply::new(
-                    event.id,
-                    "network.requestBody",
-                    serde_json::json!({ "action": "allow" }),
-                ))
-            }),
-        );
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.requestBody" {
+                return None;
+            }
+
+            if !is_tab_event(&event, tab_id) {
+                return None;
+            }
+
+            let body_data = parse_intercepted_request_body(&event);
+            callback(body_data);
+
+            // Read-only: the request is allowed unless another intercept decides
+            None
+        }));
 
         let command = Command::Network(NetworkCommand::AddIntercept {
             intercept_requests: false,
@@ -182,10 +380,12 @@ impl Tab {
             intercept_request_body: true,
             intercept_responses: false,
             intercept_response_body: false,
+            url_patterns: Vec::new(),
+            resource_types: Vec::new(),
         });
 
-        let response = self.send_command(command).await?;
-        extract_intercept_id(&response)
+        self.add_intercept(handler_id, command, remove_intercept_command)
+            .await
     }
 
     /// Intercepts response headers with a callback.
@@ -197,21 +397,23 @@ impl Tab {
 
         let window = self.get_window()?;
         let callback = Arc::new(callback);
+        let tab_id = self.inner.tab_id;
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "network.responseHeaders" {
-                    return None;
-                }
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.responseHeaders" {
+                return None;
+            }
 
-                let resp = parse_intercepted_response(&event);
-                let action = callback(resp);
-                let result = headers_action_to_json(&action);
+            if !is_tab_event(&event, tab_id) {
+                return None;
+            }
 
-                Some(EventReply::new(event.id, "network.responseHeaders", result))
-            }),
-        );
+            let resp = parse_intercepted_response(&event);
+            let action = callback(resp);
+            let result = headers_action_to_json(&action);
+
+            Some(EventReply::new(event.id, "network.responseHeaders", result))
+        }));
 
         let command = Command::Network(NetworkCommand::AddIntercept {
             intercept_requests: false,
@@ -219,10 +421,12 @@ impl Tab {
             intercept_request_body: false,
             intercept_responses: true,
             intercept_response_body: false,
+            url_patterns: Vec::new(),
+            resource_types: Vec::new(),
         });
 
-        let response = self.send_command(command).await?;
-        extract_intercept_id(&response)
+        self.add_intercept(handler_id, command, remove_intercept_command)
+            .await
     }
 
     /// Intercepts response body with a callback.
@@ -234,21 +438,23 @@ impl Tab {
 
         let window = self.get_window()?;
         let callback = Arc::new(callback);
+        let tab_id = self.inner.tab_id;
 
-        window.inner.pool.set_event_handler(
-            window.inner.session_id,
-            Box::new(move |event: Event| {
-                if event.method.as_str() != "network.responseBody" {
-                    return None;
-                }
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.responseBody" {
+                return None;
+            }
 
-                let body_data = parse_intercepted_response_body(&event);
-                let action = callback(body_data);
-                let result = body_action_to_json(&action);
+            if !is_tab_event(&event, tab_id) {
+                return None;
+            }
 
-                Some(EventReply::new(event.id, "network.responseBody", result))
-            }),
-        );
+            let body_data = parse_intercepted_response_body(&event);
+            let action = callback(body_data);
+            let result = body_action_to_json(&action);
+
+            Some(EventReply::new(event.id, "network.responseBody", result))
+        }));
 
         let command = Command::Network(NetworkCommand::AddIntercept {
             intercept_requests: false,
@@ -256,10 +462,12 @@ impl Tab {
             intercept_request_body: false,
             intercept_responses: false,
             intercept_response_body: true,
+            url_patterns: Vec::new(),
+            resource_types: Vec::new(),
         });
 
-        let response = self.send_command(command).await?;
-        extract_intercept_id(&response)
+        self.add_intercept(handler_id, command, remove_intercept_command)
+            .await
     }
 
     /// Stops network interception.
@@ -271,10 +479,7 @@ impl Tab {
         debug!(tab_id = %self.inner.tab_id, %intercept_id, "Stopping interception");
 
         let window = self.get_window()?;
-        window
-            .inner
-            .pool
-            .clear_event_handler(window.inner.session_id);
+        window.untrack_subscription(intercept_id.as_str());
 
         let command = Command::Network(NetworkCommand::RemoveIntercept {
             intercept_id: intercept_id.clone(),
@@ -283,24 +488,427 @@ impl Tab {
         self.send_command(command).await?;
         Ok(())
     }
+
+    /// Waits for the first request from this tab whose URL matches `url_pattern`.
+    ///
+    /// A read-only, one-shot counterpart to [`Tab::intercept_request`]: the
+    /// request proceeds unchanged, and the intercept is removed once it
+    /// resolves or times out. Patterns support wildcards (`*`). Start
+    /// waiting before triggering the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if no matching request is sent in time.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (request, _) = tokio::try_join!(
+    ///     tab.wait_for_request("*/api/login*", Duration::from_secs(5)),
+    ///     submit.click(),
+    /// )?;
+    /// assert_eq!(request.method, "POST");
+    /// ```
+    pub async fn wait_for_request(
+        &self,
+        url_pattern: &str,
+        timeout_duration: Duration,
+    ) -> Result<InterceptedRequest> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            url_pattern,
+            timeout_ms = timeout_duration.as_millis(),
+            "Waiting for request"
+        );
+
+        let command = Command::Network(NetworkCommand::AddIntercept {
+            intercept_requests: true,
+            intercept_request_headers: false,
+            intercept_request_body: false,
+            intercept_responses: false,
+            intercept_response_body: false,
+            url_patterns: vec![url_pattern.to_string()],
+            resource_types: Vec::new(),
+        });
+
+        self.wait_for_network_event(
+            command,
+            "network.beforeRequestSent",
+            url_pattern,
+            |event| {
+                let request = parse_intercepted_request(event);
+                (request.tab_id, request)
+            },
+            timeout_duration,
+            format!("wait_for_request({url_pattern})"),
+        )
+        .await
+    }
+
+    /// Waits for the first response to this tab whose URL matches `url_pattern`.
+    ///
+    /// Resolves when the response headers arrive; the response proceeds
+    /// unchanged. See [`Tab::wait_for_request`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if no matching response arrives in time.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let response = tab.wait_for_response("*/api/cart*", Duration::from_secs(5)).await?;
+    /// assert_eq!(response.status, 200);
+    /// ```
+    pub async fn wait_for_response(
+        &self,
+        url_pattern: &str,
+        timeout_duration: Duration,
+    ) -> Result<InterceptedResponse> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            url_pattern,
+            timeout_ms = timeout_duration.as_millis(),
+            "Waiting for response"
+        );
+
+        let command = Command::Network(NetworkCommand::AddIntercept {
+            intercept_requests: false,
+            intercept_request_headers: false,
+            intercept_request_body: false,
+            intercept_responses: true,
+            intercept_response_body: false,
+            url_patterns: vec![url_pattern.to_string()],
+            resource_types: Vec::new(),
+        });
+
+        self.wait_for_network_event(
+            command,
+            "network.responseHeaders",
+            url_pattern,
+            |event| {
+                let response = parse_intercepted_response(event);
+                (response.tab_id, response)
+            },
+            timeout_duration,
+            format!("wait_for_response({url_pattern})"),
+        )
+        .await
+    }
+
+    /// Adds an intercept and resolves with the first event from this tab
+    /// whose URL matches `url_pattern`.
+    ///
+    /// The URL is checked here as well as in the extension, since events
+    /// forwarded for other intercepts on the tab reach every handler. The
+    /// handler only observes, so events proceed as other intercepts decide.
+    /// The handler and intercept are removed before returning.
+    async fn wait_for_network_event<T, P>(
+        &self,
+        command: Command,
+        event_method: &'static str,
+        url_pattern: &str,
+        parse: P,
+        timeout_duration: Duration,
+        operation: String,
+    ) -> Result<T>
+    where
+        T: Send + 'static,
+        P: Fn(&Event) -> (u32, T) + Send + Sync + 'static,
+    {
+        let window = self.get_window()?;
+        let raw_tab_id = self.inner.tab_id.as_u32();
+        let url_pattern = url_pattern.to_string();
+
+        let (tx, rx) = oneshot::channel::<T>();
+        let tx = Mutex::new(Some(tx));
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != event_method {
+                return None;
+            }
+
+            let url = event.params.get("url").and_then(Value::as_str);
+            if !url.is_some_and(|url| matches_url_pattern(&url_pattern, url)) {
+                return None;
+            }
+
+            let (tab_id, value) = parse(&event);
+            if tab_id != raw_tab_id {
+                return None;
+            }
+
+            if let Some(tx) = tx.lock().take() {
+                let _ = tx.send(value);
+            }
+            None
+        }));
+
+        let intercept_id = match self
+            .send_command(command)
+            .await
+            .and_then(|response| extract_intercept_id(&response))
+        {
+            Ok(intercept_id) => intercept_id,
+            Err(e) => {
+                window.remove_event_handler(handler_id);
+                return Err(e);
+            }
+        };
+
+        let result = timeout(timeout_duration, rx).await;
+
+        window.remove_event_handler(handler_id);
+
+        let command = Command::Network(NetworkCommand::RemoveIntercept { intercept_id });
+        if let Err(e) = self.send_command(command).await {
+            debug!(tab_id = %self.inner.tab_id, error = %e, "Failed to remove wait intercept");
+        }
+
+        match result {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => Err(Error::protocol("Channel closed unexpectedly")),
+            Err(_) => Err(Error::Timeout {
+                operation,
+                timeout_ms: timeout_duration.as_millis() as u64,
+            }),
+        }
+    }
+
+    /// Observes Server-Sent Events streams the page opens.
+    ///
+    /// The extension wraps `EventSource` in every frame and forwards messages
+    /// from streams whose URL matches `url_pattern` (wildcards `*`).
+    /// Observation is read-only; the page receives every message unchanged.
+    ///
+    /// # Returns
+    ///
+    /// Subscription ID for [`Tab::unsubscribe_sse`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id = tab.on_sse_message("*/events*", |msg| {
+    ///     println!("[{}] {}", msg.event, msg.data);
+    /// }).await?;
+    ///
+    /// tab.unsubscribe_sse(&id).await?;
+    /// ```
+    pub async fn on_sse_message<F>(&self, url_pattern: &str, callback: F) -> Result<SubscriptionId>
+    where
+        F: Fn(SseMessage) + Send + Sync + 'static,
+    {
+        debug!(tab_id = %self.inner.tab_id, url_pattern, "Subscribing to SSE messages");
+
+        let window = self.get_window()?;
+        let callback = Arc::new(callback);
+
+        // Installed before subscribing so no message is missed; until the
+        // subscription ID is known, messages are matched by tab
+        let expected_id = Arc::new(OnceLock::<String>::new());
+        let handler_expected_id = Arc::clone(&expected_id);
+        let raw_tab_id = self.inner.tab_id.as_u32();
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.sseMessage" {
+                return None;
+            }
+
+            if let ParsedEvent::NetworkSseMessage {
+                subscription_id,
+                url,
+                event,
+                data,
+                id,
+                tab_id,
+            } = event.parse()
+                && handler_expected_id
+                    .get()
+                    .map_or(tab_id == raw_tab_id, |expected| {
+                        *expected == subscription_id
+                    })
+            {
+                callback(SseMessage {
+                    url,
+                    event,
+                    data,
+                    id,
+                });
+            }
+
+            None
+        }));
+
+        let command = Command::Network(NetworkCommand::SubscribeSse {
+            url_pattern: url_pattern.to_string(),
+        });
+
+        let subscription_id = match self
+            .send_command(command)
+            .await
+            .and_then(|response| Ok(response.require_str("subscriptionId")?.to_string()))
+        {
+            Ok(subscription_id) => subscription_id,
+            Err(e) => {
+                window.remove_event_handler(handler_id);
+                return Err(e);
+            }
+        };
+
+        let _ = expected_id.set(subscription_id.clone());
+        let teardown = Command::Network(NetworkCommand::UnsubscribeSse {
+            subscription_id: subscription_id.clone(),
+        });
+        window.track_subscription(
+            subscription_id.as_str(),
+            self.inner.tab_id,
+            handler_id,
+            teardown,
+        );
+
+        Ok(SubscriptionId::new(subscription_id))
+    }
+
+    /// Stops observing Server-Sent Events streams.
+    pub async fn unsubscribe_sse(&self, subscription_id: &SubscriptionId) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, %subscription_id, "Unsubscribing from SSE messages");
+
+        let command = Command::Network(NetworkCommand::UnsubscribeSse {
+            subscription_id: subscription_id.as_str().to_string(),
+        });
+
+        self.send_command(command).await?;
+
+        if let Some(window) = &self.inner.window {
+            window.untrack_subscription(subscription_id.as_str());
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tab - Network (Internal)
+// ============================================================================
+
+impl Tab {
+    /// Adds an intercept whose events are served by `handler_id`.
+    ///
+    /// The handler is kept until [`Tab::stop_intercept`] and removed if the
+    /// intercept cannot be added. `remove` builds the command that removes
+    /// the intercept when the window is reset.
+    pub(super) async fn add_intercept(
+        &self,
+        handler_id: EventHandlerId,
+        command: Command,
+        remove: fn(InterceptId) -> Command,
+    ) -> Result<InterceptId> {
+        let window = self.get_window()?;
+
+        let result = self
+            .send_command(command)
+            .await
+            .and_then(|response| extract_intercept_id(&response));
+
+        match &result {
+            Ok(intercept_id) => window.track_subscription(
+                intercept_id.as_str(),
+                self.inner.tab_id,
+                handler_id,
+                remove(intercept_id.clone()),
+            ),
+            Err(_) => window.remove_event_handler(handler_id),
+        }
+        result
+    }
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Builds the command removing a network intercept.
+pub(super) fn remove_intercept_command(intercept_id: InterceptId) -> Command {
+    Command::Network(NetworkCommand::RemoveIntercept { intercept_id })
+}
+
+/// Returns `true` if an intercept event comes from `tab_id`.
+///
+/// Intercepts are added per tab, but every handler on the session sees
+/// every tab's events. Handlers that decide ignore other tabs' events;
+/// handlers that only observe never reply, so they cannot override a
+/// decision, and events nobody decides are allowed by the pool.
+pub(super) fn is_tab_event(event: &Event, tab_id: TabId) -> bool {
+    event.params.get("tabId").and_then(Value::as_u64) == Some(u64::from(tab_id.as_u32()))
+}
+
+/// Returns `true` if `url` matches a wildcard pattern.
+///
+/// `*` matches any run of characters (including none); everything else
+/// matches literally. Mirrors the extension's URL filter.
+pub(super) fn matches_url_pattern(pattern: &str, url: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let url = url.as_bytes();
+
+    let (mut p, mut u) = (0, 0);
+    // Position after the last `*` and the URL position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while u < url.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            p += 1;
+            backtrack = Some((p, u));
+        } else if p < pattern.len() && pattern[p] == url[u] {
+            p += 1;
+            u += 1;
+        } else if let Some((star_p, star_u)) = backtrack {
+            p = star_p;
+            u = star_u + 1;
+            backtrack = Some((star_p, star_u + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&b| b == b'*')
+}
+
+/// Returns `true` if a request passes an intercept's URL and type filters.
+///
+/// An empty list matches everything, as in the extension.
+fn matches_filters(
+    request: &InterceptedRequest,
+    url_patterns: &[String],
+    resource_types: &[String],
+) -> bool {
+    (url_patterns.is_empty()
+        || url_patterns
+            .iter()
+            .any(|pattern| matches_url_pattern(pattern, &request.url)))
+        && (resource_types.is_empty() || resource_types.contains(&request.resource_type))
+}
+
 /// Extracts intercept ID from response.
-fn extract_intercept_id(response: &Response) -> Result<InterceptId> {
-    let id = response
-        .result
-        .as_ref()
-        .and_then(|v| v.get("interceptId"))
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| Error::protocol("No interceptId in response"))?;
+pub(super) fn extract_intercept_id(response: &Response) -> Result<InterceptId> {
+    let id = response.require_str("interceptId")?;
 
     Ok(InterceptId::new(id))
 }
 
+/// Lowercases header names so case variants merge into one entry.
+///
+/// When several names differ only by case, the one sorting last wins so
+/// the result does not depend on `HashMap` iteration order.
+fn normalize_header_names(headers: HashMap<String, String>) -> HashMap<String, String> {
+    let mut entries: Vec<(String, String)> = headers.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    entries
+        .into_iter()
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value))
+        .collect()
+}
+
 /// Parses intercepted request from event.
 fn parse_intercepted_request(event: &Event) -> InterceptedRequest {
     InterceptedRequest {
@@ -343,7 +951,7 @@ fn parse_intercepted_request(event: &Event) -> InterceptedRequest {
 }
 
 /// Parses intercepted request headers from event.
-fn parse_intercepted_request_headers(event: &Event) -> InterceptedRequestHeaders {
+pub(super) fn parse_intercepted_request_headers(event: &Event) -> InterceptedRequestHeaders {
     InterceptedRequestHeaders {
         request_id: event
             .params
@@ -387,7 +995,7 @@ fn parse_intercepted_request_headers(event: &Event) -> InterceptedRequestHeaders
 }
 
 /// Parses intercepted request body from event.
-fn parse_intercepted_request_body(event: &Event) -> InterceptedRequestBody {
+pub(super) fn parse_intercepted_request_body(event: &Event) -> InterceptedRequestBody {
     InterceptedRequestBody {
         request_id: event
             .params
@@ -428,7 +1036,7 @@ fn parse_intercepted_request_body(event: &Event) -> InterceptedRequestBody {
 }
 
 /// Parses intercepted response from event.
-fn parse_intercepted_response(event: &Event) -> InterceptedResponse {
+pub(super) fn parse_intercepted_response(event: &Event) -> InterceptedResponse {
     InterceptedResponse {
         request_id: event
             .params
@@ -477,7 +1085,7 @@ fn parse_intercepted_response(event: &Event) -> InterceptedResponse {
 }
 
 /// Parses intercepted response body from event.
-fn parse_intercepted_response_body(event: &Event) -> InterceptedResponseBody {
+pub(super) fn parse_intercepted_response_body(event: &Event) -> InterceptedResponseBody {
     InterceptedResponseBody {
         request_id: event
             .params
@@ -566,6 +1174,24 @@ fn request_action_to_json(action: &RequestAction) -> Value {
         RequestAction::Allow => serde_json::json!({ "action": "allow" }),
         RequestAction::Block => serde_json::json!({ "action": "block" }),
         RequestAction::Redirect(url) => serde_json::json!({ "action": "redirect", "url": url }),
+        RequestAction::Delay { ms, then } => serde_json::json!({
+            "action": "delay",
+            "ms": ms,
+            "then": request_action_to_json(then),
+        }),
+        RequestAction::Modify { method, body, url } => {
+            let mut json = serde_json::json!({ "action": "modify" });
+            if let Some(method) = method {
+                json["method"] = Value::String(method.clone());
+            }
+            if let Some(body) = body {
+                json["body"] = Value::String(Base64Standard.encode(body));
+            }
+            if let Some(url) = url {
+                json["url"] = Value::String(url.clone());
+            }
+            json
+        }
     }
 }
 
@@ -586,3 +1212,344 @@ fn body_action_to_json(action: &BodyAction) -> Value {
         BodyAction::ModifyBody(b) => serde_json::json!({ "action": "modifyBody", "body": b }),
     }
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use parking_lot::Mutex;
+    use serde_json::json;
+
+    use super::{matches_url_pattern, normalize_header_names, request_action_to_json};
+    use crate::{Driver, Error, HeadersAction, MockTransport, NetworkConditions, RequestAction};
+
+    fn intercept_mock() -> MockTransport {
+        MockTransport::new()
+            .respond("network.addIntercept", json!({ "interceptId": "int-1" }))
+            .respond("network.removeIntercept", json!({}))
+    }
+
+    async fn wait_until_intercepting(mock: &MockTransport) {
+        while !mock.methods().iter().any(|m| m == "network.addIntercept") {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_on_sse_message() {
+        let mock = MockTransport::new()
+            .respond_error("network.subscribeSse", "script error", "blocked")
+            .respond("network.subscribeSse", json!({ "subscriptionId": "sse-1" }))
+            .respond("network.unsubscribeSse", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        let messages = Arc::new(Mutex::new(Vec::new()));
+
+        // A failed subscription leaves no handler behind
+        let sink = Arc::clone(&messages);
+        assert!(
+            tab.on_sse_message("*", move |msg| sink.lock().push(msg.data))
+                .await
+                .is_err()
+        );
+
+        let sink = Arc::clone(&messages);
+        let id = tab
+            .on_sse_message("*/events*", move |msg| sink.lock().push(msg.data))
+            .await
+            .expect("subscribe");
+
+        let message = |subscription_id: &str, data: &str| {
+            json!({
+                "subscriptionId": subscription_id,
+                "url": "https://a.test/events",
+                "data": data,
+                "tabId": 1
+            })
+        };
+        mock.emit_event("network.sseMessage", message("sse-1", "one"));
+        mock.emit_event("network.sseMessage", message("other", "two"));
+
+        tab.unsubscribe_sse(&id).await.expect("unsubscribe");
+        mock.emit_event("network.sseMessage", message("sse-1", "three"));
+
+        assert_eq!(*messages.lock(), ["one"]);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_request_matches_own_tab() {
+        let mock = intercept_mock();
+        let driver = Driver::with_mock(mock.clone());
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        let waiter = tokio::spawn(async move {
+            tab.wait_for_request("*/api/*", Duration::from_secs(5))
+                .await
+        });
+        wait_until_intercepting(&mock).await;
+
+        // Another tab's request is ignored and allowed through
+        let other_tab = json!({ "requestId": "r1", "url": "https://a.test/api/x", "tabId": 2 });
+        let reply = mock
+            .emit_event("network.beforeRequestSent", other_tab)
+            .expect("reply");
+        assert_eq!(reply.result, json!({ "action": "allow" }));
+
+        let own_tab = json!({
+            "requestId": "r2",
+            "url": "https://a.test/api/login",
+            "method": "POST",
+            "tabId": 1
+        });
+        mock.emit_event("network.beforeRequestSent", own_tab);
+
+        let request = waiter.await.expect("join").expect("request");
+        assert_eq!(request.request_id, "r2");
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            mock.methods().last().map(String::as_str),
+            Some("network.removeIntercept")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_response_ignores_other_intercepts_events() {
+        let mock = MockTransport::new()
+            .respond("network.addIntercept", json!({ "interceptId": "all-1" }))
+            .respond("network.addIntercept", json!({ "interceptId": "wait-1" }))
+            .respond("network.removeIntercept", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        tab.intercept_response(|_| HeadersAction::Allow)
+            .await
+            .expect("intercept");
+
+        let waiter = {
+            let tab = tab.clone();
+            tokio::spawn(async move {
+                tab.wait_for_response("*/api/*", Duration::from_secs(5))
+                    .await
+            })
+        };
+        while mock.methods().len() < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        let response = |request_id: &str, url: &str| {
+            json!({
+                "requestId": request_id,
+                "url": url,
+                "status": 200,
+                "headers": {},
+                "tabId": 1
+            })
+        };
+
+        // Forwarded for the catch-all intercept on the same tab
+        mock.emit_event(
+            "network.responseHeaders",
+            response("doc", "https://a.test/index.html"),
+        );
+        mock.emit_event(
+            "network.responseHeaders",
+            response("api", "https://a.test/api/cart"),
+        );
+
+        let response = waiter.await.expect("join").expect("response");
+        assert_eq!(response.request_id, "api");
+        assert_eq!(response.url, "https://a.test/api/cart");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_response_timeout() {
+        let mock = intercept_mock();
+        let driver = Driver::with_mock(mock.clone());
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        let result = tab
+            .wait_for_response("*/never*", Duration::from_millis(10))
+            .await;
+        assert!(matches!(result, Err(Error::Timeout { .. })));
+        assert_eq!(
+            mock.methods(),
+            ["network.addIntercept", "network.removeIntercept"]
+        );
+        let reply = mock
+            .emit_event("network.responseHeaders", json!({}))
+            .expect("reply");
+        assert_eq!(reply.result, json!({ "action": "allow" }));
+    }
+
+    #[tokio::test]
+    async fn test_extra_headers_error_response() {
+        let mock = MockTransport::new()
+            .respond_error("network.setExtraHeaders", "invalid argument", "bad name")
+            .respond_error("network.clearExtraHeaders", "unknown error", "rule failed");
+        let driver = Driver::with_mock(mock);
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        let headers = HashMap::from([("Bad Name".to_string(), "1".to_string())]);
+        assert!(tab.set_extra_headers(headers).await.is_err());
+        assert!(tab.clear_extra_headers().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_network_conditions_error_response() {
+        let mock = MockTransport::new()
+            .respond_error("network.setNetworkConditions", "unknown error", "refused")
+            .respond_error("network.clearNetworkConditions", "unknown error", "refused");
+        let driver = Driver::with_mock(mock);
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        assert!(
+            tab.set_network_conditions(NetworkConditions::slow_3g())
+                .await
+                .is_err()
+        );
+        assert!(tab.clear_network_conditions().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_set_offline_error_response() {
+        let mock =
+            MockTransport::new().respond_error("network.setOffline", "unknown error", "refused");
+        let driver = Driver::with_mock(mock);
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        assert!(tab.set_offline(true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_filtered_intercept_ignores_other_intercepts_events() {
+        let mock = MockTransport::new()
+            .respond("network.addIntercept", json!({ "interceptId": "api-1" }))
+            .respond("network.addIntercept", json!({ "interceptId": "img-1" }));
+        let driver = Driver::with_mock(mock.clone());
+        let tab = driver.window().spawn().await.expect("spawn").tab();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        tab.intercept_request_filtered(&["*/api/*"], &["xmlhttprequest"], move |req| {
+            sink.lock().push(req.url);
+            RequestAction::block()
+        })
+        .await
+        .expect("intercept");
+        tab.intercept_request_filtered(&["*.png"], &[], |_| RequestAction::allow())
+            .await
+            .expect("second intercept");
+
+        let request = |url: &str, resource_type: &str| {
+            json!({
+                "requestId": url,
+                "url": url,
+                "resourceType": resource_type,
+                "tabId": 1
+            })
+        };
+
+        // Outside the API intercept's filters; the image request is the
+        // other intercept's
+        for (url, resource_type) in [
+            ("https://a.test/logo.png", "image"),
+            ("https://a.test/api/page", "main_frame"),
+        ] {
+            let reply = mock
+                .emit_event("network.beforeRequestSent", request(url, resource_type))
+                .expect("reply");
+            assert_eq!(reply.result, json!({ "action": "allow" }));
+        }
+
+        let reply = mock
+            .emit_event(
+                "network.beforeRequestSent",
+                request("https://a.test/api/cart", "xmlhttprequest"),
+            )
+            .expect("reply");
+        assert_eq!(reply.result, json!({ "action": "block" }));
+        assert_eq!(*seen.lock(), ["https://a.test/api/cart"]);
+    }
+
+    #[test]
+    fn test_matches_url_pattern() {
+        assert!(matches_url_pattern("*", ""));
+        assert!(matches_url_pattern(
+            "*/api/*",
+            "https://a.test/api/cart?x=1"
+        ));
+        assert!(matches_url_pattern("https://a.test/*", "https://a.test/"));
+        assert!(matches_url_pattern("*.js", "https://a.test/app.min.js"));
+        assert!(matches_url_pattern("*a*b*", "xxaxxbxx"));
+        assert!(!matches_url_pattern("*/api/*", "https://a.test/apix/cart"));
+        assert!(!matches_url_pattern("*.js", "https://a.test/app.json"));
+        assert!(!matches_url_pattern("https://a.test/", "https://a.test/x"));
+    }
+
+    #[test]
+    fn test_normalize_header_names() {
+        let headers = HashMap::from([
+            ("Content-Type".to_string(), "text/html".to_string()),
+            (" Accept ".to_string(), "*/*".to_string()),
+            ("X-Token".to_string(), "upper".to_string()),
+            ("x-token".to_string(), "lower".to_string()),
+        ]);
+
+        let normalized = normalize_header_names(headers);
+        assert_eq!(normalized.len(), 3);
+        assert_eq!(normalized["content-type"], "text/html");
+        assert_eq!(normalized["accept"], "*/*");
+        // "x-token" sorts after "X-Token", so its value wins
+        assert_eq!(normalized["x-token"], "lower");
+    }
+
+    #[test]
+    fn test_request_action_modify_json() {
+        let action = RequestAction::Modify {
+            method: Some("POST".to_string()),
+            body: Some(b"a=1".to_vec()),
+            url: Some("https://b.test/submit".to_string()),
+        };
+        assert_eq!(
+            request_action_to_json(&action),
+            json!({
+                "action": "modify",
+                "method": "POST",
+                "body": "YT0x",
+                "url": "https://b.test/submit"
+            })
+        );
+
+        // Unchanged fields are omitted
+        assert_eq!(
+            request_action_to_json(&RequestAction::modify_method("PUT")),
+            json!({ "action": "modify", "method": "PUT" })
+        );
+    }
+
+    #[test]
+    fn test_request_action_delay_json() {
+        assert_eq!(
+            request_action_to_json(&RequestAction::delay(100)),
+            json!({ "action": "delay", "ms": 100, "then": { "action": "allow" } })
+        );
+        assert_eq!(
+            request_action_to_json(&RequestAction::delay_then(
+                250,
+                RequestAction::redirect("https://c.test/")
+            )),
+            json!({
+                "action": "delay",
+                "ms": 250,
+                "then": { "action": "redirect", "url": "https://c.test/" }
+            })
+        );
+    }
+}
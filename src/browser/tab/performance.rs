@@ -0,0 +1,137 @@
+//! Page performance metrics.
+
+use serde_json::Value;
+use tracing::debug;
+
+use crate::error::Result;
+
+use super::Tab;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Reads Navigation Timing and Paint Timing entries.
+///
+/// Times are milliseconds since navigation start; `0` means the event has
+/// not happened (or was not recorded) and becomes `null`.
+const PERFORMANCE_TIMING_SCRIPT: &str = r#"
+const nav = performance.getEntriesByType('navigation')[0];
+const ms = (v) => (typeof v === 'number' && v > 0 ? v : null);
+const paint = (name) => {
+    const entry = performance.getEntriesByName(name, 'paint')[0];
+    return entry ? ms(entry.startTime) : null;
+};
+return {
+    ttfb: nav ? ms(nav.responseStart) : null,
+    domContentLoaded: nav ? ms(nav.domContentLoadedEventEnd) : null,
+    loadEvent: nav ? ms(nav.loadEventEnd) : null,
+    firstPaint: paint('first-paint'),
+    firstContentfulPaint: paint('first-contentful-paint'),
+};
+"#;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Navigation and paint timings of the current page.
+///
+/// All values are milliseconds since navigation start, or `None` if the
+/// browser did not record the metric (e.g. `load_event_ms` before the
+/// `load` event fires, or paint metrics disabled by preferences).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PerformanceTiming {
+    /// Time to first byte (`responseStart`).
+    pub ttfb_ms: Option<f64>,
+    /// End of the `DOMContentLoaded` event (`domContentLoadedEventEnd`).
+    pub dom_content_loaded_ms: Option<f64>,
+    /// End of the `load` event (`loadEventEnd`).
+    pub load_event_ms: Option<f64>,
+    /// First paint (`first-paint` entry).
+    pub first_paint_ms: Option<f64>,
+    /// First contentful paint (`first-contentful-paint` entry).
+    pub first_contentful_paint_ms: Option<f64>,
+}
+
+// ============================================================================
+// Tab - Performance
+// ============================================================================
+
+impl Tab {
+    /// Gets Navigation Timing and Paint Timing metrics of the current page.
+    ///
+    /// Computed from `performance.getEntriesByType('navigation')` and the
+    /// `paint` entries. Metrics refer to the last full navigation; same-page
+    /// (history API) navigations do not reset them.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.goto("https://example.com").await?;
+    /// let timing = tab.get_performance_timing().await?;
+    /// if let Some(fcp) = timing.first_contentful_paint_ms {
+    ///     println!("FCP: {fcp:.0} ms");
+    /// }
+    /// ```
+    pub async fn get_performance_timing(&self) -> Result<PerformanceTiming> {
+        debug!(tab_id = %self.inner.tab_id, "Getting performance timing");
+
+        let value = self.execute_script(PERFORMANCE_TIMING_SCRIPT).await?;
+        let timing = parse_performance_timing(&value);
+
+        debug!(tab_id = %self.inner.tab_id, ?timing, "Got performance timing");
+        Ok(timing)
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Parses the result of [`PERFORMANCE_TIMING_SCRIPT`].
+fn parse_performance_timing(value: &Value) -> PerformanceTiming {
+    let ms = |key: &str| value.get(key).and_then(|v| v.as_f64());
+
+    PerformanceTiming {
+        ttfb_ms: ms("ttfb"),
+        dom_content_loaded_ms: ms("domContentLoaded"),
+        load_event_ms: ms("loadEvent"),
+        first_paint_ms: ms("firstPaint"),
+        first_contentful_paint_ms: ms("firstContentfulPaint"),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::parse_performance_timing;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_performance_timing() {
+        let timing = parse_performance_timing(&json!({
+            "ttfb": 42.5,
+            "domContentLoaded": 310.0,
+            "loadEvent": null,
+            "firstPaint": null,
+            "firstContentfulPaint": 280.25
+        }));
+
+        assert_eq!(timing.ttfb_ms, Some(42.5));
+        assert_eq!(timing.dom_content_loaded_ms, Some(310.0));
+        assert_eq!(timing.load_event_ms, None);
+        assert_eq!(timing.first_paint_ms, None);
+        assert_eq!(timing.first_contentful_paint_ms, Some(280.25));
+    }
+
+    #[test]
+    fn test_parse_performance_timing_empty() {
+        let timing = parse_performance_timing(&serde_json::Value::Null);
+        assert_eq!(timing, Default::default());
+    }
+}
@@ -34,6 +34,7 @@ impl Tab {
             username: config.username,
             password: config.password,
             proxy_dns: config.proxy_dns,
+            bypass: config.bypass,
         });
 
         self.send_command(command).await?;
@@ -0,0 +1,315 @@
+//! Passive request log methods.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tracing::{debug, warn};
+
+use crate::browser::request_log::{
+    RequestLogBuffer, RequestLogCapture, RequestLogOptions, RequestRecord,
+};
+use crate::error::{Error, Result};
+use crate::identifiers::SubscriptionId;
+use crate::protocol::{Command, Event, NetworkCommand, ParsedEvent};
+
+use super::Tab;
+
+// ============================================================================
+// Tab - Request Log
+// ============================================================================
+
+impl Tab {
+    /// Starts logging every request this tab makes.
+    ///
+    /// Records URL, method, status, resource type and timing of each
+    /// finished or failed request. Requests are never paused, so the log
+    /// is cheaper than interception or HAR capture. Keeps up to
+    /// [`DEFAULT_REQUEST_LOG_LIMIT`](crate::browser::request_log::DEFAULT_REQUEST_LOG_LIMIT)
+    /// records; see [`Tab::start_request_log_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request log is already running on this tab.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.start_request_log().await?;
+    /// tab.goto("https://example.com").await?;
+    ///
+    /// let failed = tab
+    ///     .take_request_log()
+    ///     .into_iter()
+    ///     .filter(|r| r.error.is_some() || r.status.is_some_and(|s| s >= 400))
+    ///     .count();
+    /// ```
+    pub async fn start_request_log(&self) -> Result<()> {
+        self.start_request_log_with(RequestLogOptions::new()).await
+    }
+
+    /// Starts logging every request this tab makes, with options.
+    ///
+    /// When the log is full, each new record drops the oldest one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request log is already running on this tab.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::RequestLogOptions;
+    ///
+    /// tab.start_request_log_with(RequestLogOptions::new().with_max_entries(500))
+    ///     .await?;
+    /// ```
+    pub async fn start_request_log_with(&self, options: RequestLogOptions) -> Result<()> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            max_entries = options.max_entries,
+            "Starting request log"
+        );
+
+        let window = self.get_window()?;
+        let tab_id = self.inner.tab_id;
+
+        if window.inner.request_logs.lock().contains_key(&tab_id) {
+            return Err(Error::invalid_argument(
+                "Request log already running on this tab",
+            ));
+        }
+
+        let command = Command::Network(NetworkCommand::SubscribeRequestLog);
+        let response = self.send_command(command).await?;
+
+        let subscription_id = response.require_str("subscriptionId")?.to_string();
+
+        let buffer = Arc::new(Mutex::new(RequestLogBuffer::new(options)));
+        let handler_buffer = Arc::clone(&buffer);
+        let expected_id = subscription_id.clone();
+        let raw_tab_id = tab_id.as_u32();
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "network.requestLogged" {
+                return None;
+            }
+
+            if let ParsedEvent::NetworkRequestLogged {
+                subscription_id,
+                url,
+                method,
+                resource_type,
+                status,
+                error,
+                start_time,
+                end_time,
+                tab_id,
+                ..
+            } = event.parse()
+                && subscription_id == expected_id
+                && tab_id == raw_tab_id
+            {
+                handler_buffer.lock().push(RequestRecord {
+                    url,
+                    method,
+                    status,
+                    resource_type,
+                    error,
+                    start_time_ms: start_time,
+                    duration_ms: (end_time - start_time).max(0.0),
+                });
+            }
+
+            None
+        }));
+        let teardown = Command::Network(NetworkCommand::UnsubscribeRequestLog {
+            subscription_id: subscription_id.clone(),
+        });
+        window.track_subscription(subscription_id.as_str(), tab_id, handler_id, teardown);
+
+        window.inner.request_logs.lock().insert(
+            tab_id,
+            RequestLogCapture {
+                buffer,
+                subscription_id: SubscriptionId::new(subscription_id),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Removes and returns the records logged so far.
+    ///
+    /// Logging continues; the next call returns only newer records.
+    /// Returns an empty list if no request log is running on this tab.
+    /// Logs a warning if records were dropped because the log was full.
+    #[must_use]
+    pub fn take_request_log(&self) -> Vec<RequestRecord> {
+        let Ok(window) = self.get_window() else {
+            return Vec::new();
+        };
+
+        let Some(buffer) = window
+            .inner
+            .request_logs
+            .lock()
+            .get(&self.inner.tab_id)
+            .map(|capture| Arc::clone(&capture.buffer))
+        else {
+            return Vec::new();
+        };
+
+        let (records, dropped) = buffer.lock().take();
+        if dropped > 0 {
+            warn!(tab_id = %self.inner.tab_id, dropped, "Request log was full; oldest records dropped");
+        }
+
+        debug!(tab_id = %self.inner.tab_id, records = records.len(), "Took request log");
+        records
+    }
+
+    /// Stops the request log and returns the records not yet taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no request log is running on this tab.
+    pub async fn stop_request_log(&self) -> Result<Vec<RequestRecord>> {
+        debug!(tab_id = %self.inner.tab_id, "Stopping request log");
+
+        let records = self.take_request_log();
+
+        let window = self.get_window()?;
+        let capture = window
+            .inner
+            .request_logs
+            .lock()
+            .remove(&self.inner.tab_id)
+            .ok_or_else(|| Error::invalid_argument("No request log running on this tab"))?;
+
+        window.untrack_subscription(capture.subscription_id.as_str());
+
+        let command = Command::Network(NetworkCommand::UnsubscribeRequestLog {
+            subscription_id: capture.subscription_id.as_str().to_string(),
+        });
+        self.send_command(command).await?;
+
+        Ok(records)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Driver, MockTransport, RequestLogOptions};
+
+    #[tokio::test]
+    async fn test_request_log_records_and_drops_oldest() {
+        let mock = MockTransport::new()
+            .respond(
+                "network.subscribeRequestLog",
+                json!({ "subscriptionId": "log-1" }),
+            )
+            .respond("network.unsubscribeRequestLog", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+
+        tab.start_request_log_with(RequestLogOptions::new().with_max_entries(2))
+            .await
+            .expect("start");
+        assert!(tab.start_request_log().await.is_err());
+
+        for (url, subscription_id, tab_id) in [
+            ("https://example.com/a", "log-1", 1),
+            ("https://example.com/b", "log-1", 1),
+            ("https://example.com/other-tab", "log-1", 2),
+            ("https://example.com/c", "other", 1),
+            ("https://example.com/d", "log-1", 1),
+        ] {
+            mock.emit_event(
+                "network.requestLogged",
+                json!({
+                    "subscriptionId": subscription_id,
+                    "url": url,
+                    "status": 200,
+                    "startTime": 100.0,
+                    "endTime": 150.0,
+                    "tabId": tab_id
+                }),
+            );
+        }
+
+        let records = tab.take_request_log();
+        let urls: Vec<_> = records.iter().map(|r| r.url.as_str()).collect();
+        assert_eq!(urls, ["https://example.com/b", "https://example.com/d"]);
+        assert_eq!(records[0].status, Some(200));
+        assert_eq!(records[0].duration_ms, 50.0);
+        assert!(tab.take_request_log().is_empty());
+
+        assert!(tab.stop_request_log().await.expect("stop").is_empty());
+        assert!(tab.stop_request_log().await.is_err());
+        assert_eq!(
+            mock.methods(),
+            [
+                "network.subscribeRequestLog",
+                "network.unsubscribeRequestLog"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_logs_in_two_tabs() {
+        let mock = MockTransport::new()
+            .respond("browsingContext.newTab", json!({ "tabId": 2 }))
+            .respond(
+                "network.subscribeRequestLog",
+                json!({ "subscriptionId": "log-1" }),
+            )
+            .respond(
+                "network.subscribeRequestLog",
+                json!({ "subscriptionId": "log-2" }),
+            )
+            .respond("network.unsubscribeRequestLog", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let first = window.tab();
+        let second = window.new_tab().await.expect("new tab");
+
+        first.start_request_log().await.expect("start first");
+        second.start_request_log().await.expect("start second");
+
+        let emit = |subscription_id: &str, tab_id: u32, url: &str| {
+            mock.emit_event(
+                "network.requestLogged",
+                json!({
+                    "subscriptionId": subscription_id,
+                    "url": url,
+                    "startTime": 0.0,
+                    "endTime": 1.0,
+                    "tabId": tab_id
+                }),
+            );
+        };
+        emit("log-1", first.tab_id().as_u32(), "https://first.test/a");
+        emit("log-2", second.tab_id().as_u32(), "https://second.test/a");
+
+        // Stopping one log leaves the other recording
+        let records = second.stop_request_log().await.expect("stop second");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].url, "https://second.test/a");
+
+        emit("log-1", first.tab_id().as_u32(), "https://first.test/b");
+
+        let urls: Vec<_> = first
+            .take_request_log()
+            .into_iter()
+            .map(|record| record.url)
+            .collect();
+        assert_eq!(urls, ["https://first.test/a", "https://first.test/b"]);
+    }
+}
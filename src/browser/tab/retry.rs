@@ -0,0 +1,211 @@
+//! Retry with backoff for transient failures.
+
+use std::future::Future;
+use std::time::Duration;
+
+use tracing::debug;
+
+use crate::error::{Error, Result};
+
+use super::Tab;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Retry policy with exponential backoff.
+///
+/// Only errors for which [`Error::is_retryable`](crate::Error::is_retryable)
+/// returns `true`, plus missing elements, are retried; anything else is
+/// returned immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first.
+    pub attempts: usize,
+    /// Delay before the first retry.
+    pub backoff: Duration,
+    /// Factor applied to the delay after each retry.
+    pub multiplier: u32,
+    /// Upper bound for a single delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(200))
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that doubles the delay after each retry.
+    #[inline]
+    #[must_use]
+    pub const fn new(attempts: usize, backoff: Duration) -> Self {
+        Self {
+            attempts,
+            backoff,
+            multiplier: 2,
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+
+    /// Sets the backoff multiplier (1 = constant delay).
+    #[inline]
+    #[must_use]
+    pub const fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the upper bound for a single delay.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Returns the delay before retry number `retry` (0-based).
+    #[must_use]
+    pub fn delay_for(&self, retry: usize) -> Duration {
+        let factor = self
+            .multiplier
+            .checked_pow(u32::try_from(retry).unwrap_or(u32::MAX))
+            .unwrap_or(u32::MAX);
+
+        self.backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+}
+
+// ============================================================================
+// Tab - Retry
+// ============================================================================
+
+impl Tab {
+    /// Runs an operation, retrying transient failures with exponential backoff.
+    ///
+    /// Retries on transient failures (see
+    /// [`Error::is_retryable`](crate::Error::is_retryable)) and on missing
+    /// elements, which appear once the page finishes rendering. Nothing else in
+    /// the crate retries automatically: only wrap operations that are safe to
+    /// repeat, such as reads and element lookups, never clicks or navigation.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - Total attempts, including the first
+    /// * `backoff` - Delay before the first retry (doubled each time)
+    /// * `f` - Operation to run
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let button = tab
+    ///     .retry(5, Duration::from_millis(200), || tab.find_element(By::css("#submit")))
+    ///     .await?;
+    /// ```
+    pub async fn retry<T, F, Fut>(&self, attempts: usize, backoff: Duration, f: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.retry_with(RetryPolicy::new(attempts, backoff), f)
+            .await
+    }
+
+    /// Runs an operation, retrying transient failures according to `policy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first non-retryable error, or the last error once all
+    /// attempts are used.
+    pub async fn retry_with<T, F, Fut>(&self, policy: RetryPolicy, mut f: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let attempts = policy.attempts.max(1);
+        let mut attempt = 1;
+
+        loop {
+            match f().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < attempts && should_retry(&e) => {
+                    let delay = policy.delay_for(attempt - 1);
+                    debug!(
+                        tab_id = %self.inner.tab_id,
+                        attempt,
+                        attempts,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %e,
+                        "Retrying after transient error"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Returns `true` if [`Tab::retry_with`] should try again after `error`.
+fn should_retry(error: &Error) -> bool {
+    error.is_retryable() || matches!(error, Error::ElementNotFound { .. })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{RetryPolicy, should_retry};
+
+    use std::time::Duration;
+
+    use crate::error::Error;
+    use crate::identifiers::{FrameId, TabId};
+
+    #[test]
+    fn test_should_retry() {
+        let not_found = Error::ElementNotFound {
+            selector: "#missing".into(),
+            tab_id: TabId::new(1).expect("valid tab id"),
+            frame_id: FrameId::main(),
+        };
+        assert!(should_retry(&not_found));
+        assert!(should_retry(&Error::ConnectionClosed));
+        assert!(!should_retry(&Error::invalid_argument("bad")));
+    }
+
+    #[test]
+    fn test_delay_doubles() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100));
+        assert_eq!(policy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_delay_capped() {
+        let policy =
+            RetryPolicy::new(50, Duration::from_secs(1)).with_max_backoff(Duration::from_secs(3));
+        assert_eq!(policy.delay_for(5), Duration::from_secs(3));
+        assert_eq!(policy.delay_for(40), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_constant_delay() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(50)).with_multiplier(1);
+        assert_eq!(policy.delay_for(4), Duration::from_millis(50));
+    }
+}
@@ -4,13 +4,33 @@ use std::path::Path;
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as Base64Standard;
+use futures_util::future::try_join_all;
+use image::DynamicImage;
+use image::codecs::jpeg::JpegEncoder;
 use tracing::debug;
 
+use crate::browser::Element;
 use crate::error::{Error, Result};
 use crate::protocol::command::{BrowsingContextCommand, Command};
 
 use super::Tab;
 
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// ID of the `<style>` element injected for the duration of a capture.
+const CAPTURE_STYLE_ID: &str = "__fxwd_capture_style";
+
+/// CSS freezing animations, transitions and the text caret.
+const FREEZE_ANIMATIONS_CSS: &str = "*, *::before, *::after { \
+    animation: none !important; \
+    transition: none !important; \
+    caret-color: transparent !important; }";
+
+/// CSS hiding scrollbars on the page and scrollable elements.
+const HIDE_SCROLLBARS_CSS: &str = "* { scrollbar-width: none !important; }";
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -59,7 +79,7 @@ impl ImageFormat {
     }
 
     /// Returns the format string for the protocol.
-    fn format_str(&self) -> &'static str {
+    pub(crate) fn format_str(&self) -> &'static str {
         match self {
             Self::Png => "png",
             Self::Jpeg(_) => "jpeg",
@@ -67,7 +87,7 @@ impl ImageFormat {
     }
 
     /// Returns the quality value if JPEG.
-    fn quality(&self) -> Option<u8> {
+    pub(crate) fn quality(&self) -> Option<u8> {
         match self {
             Self::Png => None,
             Self::Jpeg(q) => Some(*q),
@@ -92,10 +112,19 @@ impl ImageFormat {
 ///
 /// // Capture as JPEG and save to file
 /// tab.screenshot().jpeg(80).save("page.jpg").await?;
+///
+/// // Stable pixels for visual-regression tests
+/// tab.screenshot()
+///     .disable_animations(true)
+///     .hide_scrollbars(true)
+///     .save("baseline.png")
+///     .await?;
 /// ```
 pub struct ScreenshotBuilder<'a> {
     tab: &'a Tab,
     format: ImageFormat,
+    disable_animations: bool,
+    hide_scrollbars: bool,
 }
 
 impl<'a> ScreenshotBuilder<'a> {
@@ -104,6 +133,8 @@ impl<'a> ScreenshotBuilder<'a> {
         Self {
             tab,
             format: ImageFormat::Png,
+            disable_animations: false,
+            hide_scrollbars: false,
         }
     }
 
@@ -128,10 +159,55 @@ impl<'a> ScreenshotBuilder<'a> {
         self
     }
 
+    /// Freezes CSS animations and transitions and hides the text caret
+    /// while capturing.
+    ///
+    /// Animated elements are captured in their final, unanimated style.
+    /// The injected stylesheet is removed after the capture, even if it fails.
+    #[must_use]
+    pub fn disable_animations(mut self, disable: bool) -> Self {
+        self.disable_animations = disable;
+        self
+    }
+
+    /// Hides scrollbars while capturing.
+    ///
+    /// Scrolling still works; only the bars are not drawn.
+    #[must_use]
+    pub fn hide_scrollbars(mut self, hide: bool) -> Self {
+        self.hide_scrollbars = hide;
+        self
+    }
+
     /// Captures the screenshot and returns base64-encoded data.
     ///
     /// Uses the browser's native `captureVisibleTab` API for accurate capture.
     pub async fn capture(&self) -> Result<String> {
+        let Some(css) = self.capture_css() else {
+            return self.capture_visible().await;
+        };
+
+        let guard = CaptureStyleGuard::inject(self.tab, &css).await?;
+        let result = self.capture_visible().await;
+        guard.restore().await;
+        result
+    }
+
+    /// Returns the CSS to apply during capture, if any.
+    fn capture_css(&self) -> Option<String> {
+        let rules: Vec<&str> = [
+            (self.disable_animations, FREEZE_ANIMATIONS_CSS),
+            (self.hide_scrollbars, HIDE_SCROLLBARS_CSS),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, css)| enabled.then_some(css))
+        .collect();
+
+        (!rules.is_empty()).then(|| rules.join("\n"))
+    }
+
+    /// Sends `browsingContext.captureScreenshot`.
+    async fn capture_visible(&self) -> Result<String> {
         debug!(
             tab_id = %self.tab.inner.tab_id,
             format = ?self.format,
@@ -185,6 +261,70 @@ impl<'a> ScreenshotBuilder<'a> {
     }
 }
 
+// ============================================================================
+// CaptureStyleGuard
+// ============================================================================
+
+/// Removes the capture stylesheet from the page.
+///
+/// Call [`CaptureStyleGuard::restore`] once the capture is done. If the
+/// capture future is dropped first, the stylesheet is removed in a
+/// background task instead.
+struct CaptureStyleGuard {
+    /// Tab holding the stylesheet, `None` once restored.
+    tab: Option<Tab>,
+}
+
+impl CaptureStyleGuard {
+    /// Injects `css` into the page's top-level document.
+    async fn inject(tab: &Tab, css: &str) -> Result<Self> {
+        debug!(tab_id = %tab.inner.tab_id, "Injecting capture stylesheet");
+
+        let script = format!(
+            r#"let style = document.getElementById({id});
+            if (!style) {{
+                style = document.createElement("style");
+                style.id = {id};
+                (document.head || document.documentElement).appendChild(style);
+            }}
+            style.textContent = {css};
+            void getComputedStyle(document.documentElement).animationName;"#,
+            id = serde_json::to_string(CAPTURE_STYLE_ID)?,
+            css = serde_json::to_string(css)?,
+        );
+        tab.execute_script(&script).await?;
+
+        Ok(Self {
+            tab: Some(tab.clone()),
+        })
+    }
+
+    /// Removes the stylesheet, logging failures.
+    async fn restore(mut self) {
+        if let Some(tab) = self.tab.take() {
+            remove_capture_style(&tab).await;
+        }
+    }
+}
+
+impl Drop for CaptureStyleGuard {
+    fn drop(&mut self) {
+        if let Some(tab) = self.tab.take()
+            && let Ok(handle) = tokio::runtime::Handle::try_current()
+        {
+            handle.spawn(async move { remove_capture_style(&tab).await });
+        }
+    }
+}
+
+/// Removes the capture stylesheet from `tab`'s page.
+async fn remove_capture_style(tab: &Tab) {
+    let script = format!(r#"document.getElementById("{CAPTURE_STYLE_ID}")?.remove();"#);
+    if let Err(e) = tab.execute_script(&script).await {
+        debug!(tab_id = %tab.inner.tab_id, error = %e, "Failed to remove capture stylesheet");
+    }
+}
+
 // ============================================================================
 // Tab - Screenshot
 // ============================================================================
@@ -234,4 +374,233 @@ impl Tab {
 
         builder.save(path).await
     }
+
+    /// Captures PNG screenshots of several elements with one page capture.
+    ///
+    /// See [`Tab::screenshot_elements_format`].
+    pub async fn screenshot_elements(&self, elements: &[Element]) -> Result<Vec<Vec<u8>>> {
+        self.screenshot_elements_format(elements, ImageFormat::Png)
+            .await
+    }
+
+    /// Captures screenshots of several elements with one page capture.
+    ///
+    /// Captures the visible viewport once and crops each element's region
+    /// in Rust, which is much faster than one capture per element for
+    /// grids of thumbnails. Elements must be at least partly in the
+    /// viewport; regions are clipped to it.
+    ///
+    /// Returns encoded image bytes in the same order as `elements`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if an element lies entirely
+    /// outside the viewport or has zero size.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let thumbnails = tab.find_elements(".thumbnail").await?;
+    /// let images = tab.screenshot_elements(&thumbnails).await?;
+    ///
+    /// for (i, png) in images.iter().enumerate() {
+    ///     std::fs::write(format!("thumb_{i}.png"), png)?;
+    /// }
+    /// ```
+    pub async fn screenshot_elements_format(
+        &self,
+        elements: &[Element],
+        format: ImageFormat,
+    ) -> Result<Vec<Vec<u8>>> {
+        debug!(tab_id = %self.inner.tab_id, count = elements.len(), ?format, "Capturing element screenshots");
+
+        if elements.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rects = try_join_all(elements.iter().map(Element::get_bounding_rect)).await?;
+
+        let viewport_width = self
+            .execute_script("return window.innerWidth;")
+            .await?
+            .as_f64()
+            .filter(|width| *width > 0.0)
+            .ok_or_else(|| Error::script_error("Could not read viewport width"))?;
+
+        let page = decode_image(&self.screenshot().png().capture_bytes().await?)?;
+        let scale = f64::from(page.width()) / viewport_width;
+
+        let images = rects
+            .into_iter()
+            .enumerate()
+            .map(|(index, rect)| {
+                let (x, y, width, height) = pixel_rect(rect, scale, &page).ok_or_else(|| {
+                    Error::invalid_argument(format!(
+                        "Element {index} is outside the viewport or has zero size"
+                    ))
+                })?;
+                encode_image(&page.crop_imm(x, y, width, height), format)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        debug!(tab_id = %self.inner.tab_id, count = images.len(), "Element screenshots captured");
+        Ok(images)
+    }
+}
+
+// ============================================================================
+// Image Helpers
+// ============================================================================
+
+/// Decodes PNG or JPEG bytes.
+pub(crate) fn decode_image(bytes: &[u8]) -> Result<DynamicImage> {
+    image::load_from_memory(bytes)
+        .map_err(|e| Error::script_error(format!("Failed to load image: {}", e)))
+}
+
+/// Encodes an image in the given format.
+pub(crate) fn encode_image(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>> {
+    let mut output = std::io::Cursor::new(Vec::new());
+
+    match format {
+        ImageFormat::Png => image
+            .write_to(&mut output, image::ImageFormat::Png)
+            .map_err(|e| Error::script_error(format!("Failed to encode PNG: {}", e)))?,
+        ImageFormat::Jpeg(quality) => {
+            // JPEG has no alpha channel
+            let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+            rgb.write_with_encoder(JpegEncoder::new_with_quality(&mut output, quality.max(1)))
+                .map_err(|e| Error::script_error(format!("Failed to encode JPEG: {}", e)))?;
+        }
+    }
+
+    Ok(output.into_inner())
+}
+
+/// Converts a CSS-pixel rectangle `(x, y, width, height)` to image pixels,
+/// clipped to the image.
+///
+/// Returns `None` if nothing of the rectangle is inside the image.
+pub(crate) fn pixel_rect(
+    rect: (f64, f64, f64, f64),
+    scale: f64,
+    image: &DynamicImage,
+) -> Option<(u32, u32, u32, u32)> {
+    let (x, y, width, height) = rect;
+
+    let left = (x * scale).max(0.0).floor();
+    let top = (y * scale).max(0.0).floor();
+    let right = ((x + width) * scale).min(f64::from(image.width())).ceil();
+    let bottom = ((y + height) * scale).min(f64::from(image.height())).ceil();
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+
+    Some((
+        left as u32,
+        top as u32,
+        (right - left) as u32,
+        (bottom - top) as u32,
+    ))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use image::{DynamicImage, GenericImageView};
+    use serde_json::json;
+
+    use super::{ImageFormat, decode_image, encode_image, pixel_rect};
+    use crate::protocol::{Command, ScriptCommand};
+    use crate::{Driver, MockTransport};
+
+    #[test]
+    fn test_pixel_rect_scales_and_clips() {
+        let image = DynamicImage::new_rgba8(200, 100);
+
+        assert_eq!(
+            pixel_rect((10.0, 5.0, 20.0, 10.0), 2.0, &image),
+            Some((20, 10, 40, 20))
+        );
+        assert_eq!(
+            pixel_rect((-10.0, 90.0, 30.0, 30.0), 1.0, &image),
+            Some((0, 90, 20, 10))
+        );
+        assert_eq!(pixel_rect((300.0, 0.0, 10.0, 10.0), 1.0, &image), None);
+        assert_eq!(pixel_rect((10.0, 10.0, 0.0, 10.0), 1.0, &image), None);
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let image = DynamicImage::new_rgba8(8, 4);
+
+        let png = encode_image(&image, ImageFormat::Png).expect("png");
+        assert_eq!(decode_image(&png).expect("decode").dimensions(), (8, 4));
+
+        let jpeg = encode_image(&image, ImageFormat::jpeg(70)).expect("jpeg");
+        assert_eq!(&jpeg[..2], &[0xFF, 0xD8]);
+        assert_eq!(decode_image(&jpeg).expect("decode").dimensions(), (8, 4));
+    }
+
+    #[tokio::test]
+    async fn test_capture_style_restored_on_error() {
+        let mock = MockTransport::new()
+            .respond("script.evaluate", json!({ "value": null }))
+            .respond_error(
+                "browsingContext.captureScreenshot",
+                "unknown error",
+                "capture failed",
+            );
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+        mock.clear_requests();
+
+        let result = tab
+            .screenshot()
+            .disable_animations(true)
+            .hide_scrollbars(true)
+            .capture()
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            mock.methods(),
+            [
+                "script.evaluate",
+                "browsingContext.captureScreenshot",
+                "script.evaluate"
+            ]
+        );
+
+        let scripts: Vec<String> = mock
+            .requests()
+            .into_iter()
+            .filter_map(|request| match request.command {
+                Command::Script(ScriptCommand::Evaluate { script, .. }) => Some(script),
+                _ => None,
+            })
+            .collect();
+        assert!(scripts[0].contains("animation: none !important"));
+        assert!(scripts[0].contains("scrollbar-width: none"));
+        assert!(scripts[1].contains(".remove()"));
+    }
+
+    #[tokio::test]
+    async fn test_capture_without_style() {
+        let mock = MockTransport::new().respond(
+            "browsingContext.captureScreenshot",
+            json!({ "data": "AA==" }),
+        );
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        mock.clear_requests();
+
+        let data = window.tab().screenshot().capture().await.expect("capture");
+        assert_eq!(data, "AA==");
+        assert_eq!(mock.methods(), ["browsingContext.captureScreenshot"]);
+    }
 }
@@ -1,13 +1,38 @@
 //! JavaScript execution methods.
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use serde_json::Value;
+use tokio::time::Instant;
 use tracing::debug;
 
-use crate::error::Result;
-use crate::protocol::{Command, ScriptCommand};
+use crate::error::{Error, Result};
+use crate::identifiers::{ScriptId, TabId};
+use crate::protocol::{Command, Event, EventReply, ParsedEvent, Response, ScriptCommand};
 
 use super::Tab;
 
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Page-side limit for [`Tab::execute_async_script`].
+const DEFAULT_SCRIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Extra time the request waits beyond a script's timeout, so the
+/// extension's `script timeout` error arrives before the request times out.
+const SCRIPT_TIMEOUT_GRACE: Duration = Duration::from_secs(5);
+
+/// Error code the extension returns when it aborts a script on timeout.
+const SCRIPT_TIMEOUT: &str = "script timeout";
+
+/// Longest single in-page wait in [`Tab::wait_for_function`].
+const WAIT_FUNCTION_SLICE: Duration = Duration::from_secs(1);
+
+/// Delay before re-checking after a failed evaluation (e.g. mid-navigation).
+const WAIT_FUNCTION_RETRY_DELAY: Duration = Duration::from_millis(100);
+
 // ============================================================================
 // Tab - Script Execution
 // ============================================================================
@@ -17,6 +42,11 @@ impl Tab {
     ///
     /// The script should use `return` to return a value.
     ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ScriptError`] with the message and stack trace if
+    /// the script throws.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -24,55 +54,572 @@ impl Tab {
     /// ```
     pub async fn execute_script(&self, script: &str) -> Result<Value> {
         debug!(tab_id = %self.inner.tab_id, script_len = script.len(), "Executing script");
+        self.evaluate(script, false).await
+    }
+
+    /// Executes synchronous JavaScript with a time limit.
+    ///
+    /// The extension aborts the evaluation once `timeout` elapses instead
+    /// of leaving it running, so a runaway loop does not pin the page.
+    /// The request itself may take slightly longer than `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ScriptTimeout`] if the script is aborted or no
+    /// response arrives shortly after `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// match tab.execute_script_with_timeout("while (true) {}", Duration::from_secs(2)).await {
+    ///     Err(Error::ScriptTimeout { .. }) => println!("script aborted"),
+    ///     other => println!("{other:?}"),
+    /// }
+    /// ```
+    pub async fn execute_script_with_timeout(
+        &self,
+        script: &str,
+        timeout: Duration,
+    ) -> Result<Value> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            script_len = script.len(),
+            timeout_ms = timeout.as_millis(),
+            "Executing script with timeout"
+        );
 
         let command = Command::Script(ScriptCommand::Evaluate {
             script: script.to_string(),
             args: vec![],
+            isolated: false,
+            timeout: Some(timeout.as_millis() as u64),
         });
 
-        let response = self.send_command(command).await?;
+        self.evaluate_with_timeout(command, timeout).await
+    }
+
+    /// Executes synchronous JavaScript in an isolated world.
+    ///
+    /// The script runs in the extension's content-script world: it shares
+    /// the DOM with the page but not JavaScript globals, so page
+    /// monkey-patches (e.g. of `Function.prototype.toString`) do not affect
+    /// it and the page cannot see its variables.
+    ///
+    /// DOM mutations are visible in both worlds. Page-defined globals,
+    /// `window` properties and prototype changes are not visible here, and
+    /// values set on `window` from here are not visible to the page.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Reads the DOM without touching page globals
+    /// let count = tab
+    ///     .execute_script_isolated("return document.querySelectorAll('a').length")
+    ///     .await?;
+    /// ```
+    pub async fn execute_script_isolated(&self, script: &str) -> Result<Value> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            script_len = script.len(),
+            "Executing isolated script"
+        );
+        self.evaluate(script, true).await
+    }
+
+    /// Sends `script.evaluate` and extracts the returned value.
+    async fn evaluate(&self, script: &str, isolated: bool) -> Result<Value> {
+        let command = Command::Script(ScriptCommand::Evaluate {
+            script: script.to_string(),
+            args: vec![],
+            isolated,
+            timeout: None,
+        });
 
-        let value = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("value"))
-            .cloned()
-            .unwrap_or(Value::Null);
+        let response = self.send_command(command).await?;
+        let value = script_value(response)?;
 
-        debug!(tab_id = %self.inner.tab_id, "Script executed");
+        debug!(tab_id = %self.inner.tab_id, isolated, "Script executed");
         Ok(value)
     }
 
     /// Executes asynchronous JavaScript in the page context.
     ///
-    /// The script should return a Promise or use async/await.
+    /// The script should return a Promise or use async/await. The promise
+    /// gets 30 seconds to settle; use
+    /// [`execute_async_script_with_timeout`](Self::execute_async_script_with_timeout)
+    /// for longer-running scripts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ScriptTimeout`] if the promise does not settle in
+    /// time.
     pub async fn execute_async_script(&self, script: &str) -> Result<Value> {
-        debug!(tab_id = %self.inner.tab_id, script_len = script.len(), "Executing async script");
+        self.execute_async_script_with_timeout(script, DEFAULT_SCRIPT_TIMEOUT)
+            .await
+    }
+
+    /// Executes asynchronous JavaScript, giving the promise `timeout` to
+    /// settle.
+    ///
+    /// The extension abandons the evaluation once `timeout` elapses, so a
+    /// promise that never settles does not leak.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ScriptTimeout`] if the promise does not settle in
+    /// time or no response arrives shortly after `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let report = tab
+    ///     .execute_async_script_with_timeout(
+    ///         "return await window.runAudit()",
+    ///         Duration::from_secs(120),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn execute_async_script_with_timeout(
+        &self,
+        script: &str,
+        timeout: Duration,
+    ) -> Result<Value> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            script_len = script.len(),
+            timeout_ms = timeout.as_millis(),
+            "Executing async script"
+        );
 
         let command = Command::Script(ScriptCommand::EvaluateAsync {
             script: script.to_string(),
             args: vec![],
+            timeout: Some(timeout.as_millis() as u64),
         });
 
-        let response = self.send_command(command).await?;
-
-        let value = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("value"))
-            .cloned()
-            .unwrap_or(Value::Null);
+        let value = self.evaluate_with_timeout(command, timeout).await?;
 
         debug!(tab_id = %self.inner.tab_id, "Async script executed");
         Ok(value)
     }
+
+    /// Sends a script command bounded by `timeout` and extracts the value.
+    ///
+    /// Maps the extension's `script timeout` error, and a request that
+    /// timed out because a synchronous script blocked the content process
+    /// from answering, to [`Error::ScriptTimeout`].
+    async fn evaluate_with_timeout(&self, command: Command, timeout: Duration) -> Result<Value> {
+        let timeout_ms = timeout.as_millis() as u64;
+        let response = match self
+            .send_command_with_timeout(command, timeout + SCRIPT_TIMEOUT_GRACE)
+            .await
+        {
+            Ok(response) => response,
+            Err(Error::RequestTimeout { .. }) => {
+                debug!(tab_id = %self.inner.tab_id, "Script request timed out");
+                return Err(Error::script_timeout(timeout_ms));
+            }
+            Err(e) => return Err(e),
+        };
+
+        if response.error.as_deref() == Some(SCRIPT_TIMEOUT) {
+            debug!(tab_id = %self.inner.tab_id, "Script timed out");
+            return Err(Error::script_timeout(timeout_ms));
+        }
+
+        script_value(response)
+    }
+}
+
+// ============================================================================
+// Tab - Wait for Function
+// ============================================================================
+
+impl Tab {
+    /// Waits until a JavaScript expression is truthy and returns its value.
+    ///
+    /// The expression is re-evaluated on every animation frame inside the
+    /// page (every 50ms while the tab is hidden), so no round trip is made
+    /// per check. Promises are awaited, and exceptions count as falsy, so
+    /// the expression may reference globals that do not exist yet.
+    ///
+    /// Checks run in slices of at most a second; evaluations that fail
+    /// (for instance while the page navigates) are retried until the
+    /// timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `js_expr` - JavaScript expression, e.g. `window.app && window.app.ready`
+    /// * `timeout` - Maximum time to wait
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the expression is not truthy in time;
+    /// the operation names the last evaluation error, if any.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    ///
+    /// let version = tab
+    ///     .wait_for_function("window.app && window.app.version", Duration::from_secs(10))
+    ///     .await?;
+    /// ```
+    pub async fn wait_for_function(&self, js_expr: &str, timeout: Duration) -> Result<Value> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            expr_len = js_expr.len(),
+            timeout_ms = timeout.as_millis() as u64,
+            "Waiting for function"
+        );
+
+        let deadline = Instant::now() + timeout;
+        let mut last_error = None;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                let operation = match last_error {
+                    Some(e) => format!("wait_for_function (last error: {e})"),
+                    None => "wait_for_function".to_string(),
+                };
+                return Err(Error::Timeout {
+                    operation,
+                    timeout_ms: timeout.as_millis() as u64,
+                });
+            }
+
+            let command = Command::Script(ScriptCommand::EvaluateAsync {
+                script: wait_function_script(js_expr, remaining.min(WAIT_FUNCTION_SLICE)),
+                args: vec![],
+                timeout: None,
+            });
+
+            match self
+                .send_command(command)
+                .await
+                .and_then(|response| response.into_result())
+            {
+                Ok(result) => {
+                    let outcome = result.get("value").cloned().unwrap_or(Value::Null);
+                    if outcome.get("done").and_then(Value::as_bool) == Some(true) {
+                        debug!(tab_id = %self.inner.tab_id, "Function returned truthy");
+                        return Ok(outcome.get("value").cloned().unwrap_or(Value::Null));
+                    }
+                }
+                Err(e) => {
+                    debug!(tab_id = %self.inner.tab_id, error = %e, "Wait evaluation failed, retrying");
+                    last_error = Some(e);
+                    tokio::time::sleep(WAIT_FUNCTION_RETRY_DELAY.min(remaining)).await;
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tab - Preload Scripts
+// ============================================================================
+
+impl Tab {
+    /// Adds a script that runs before any page script on every navigation.
+    ///
+    /// The script is injected at `document_start` into every frame of this
+    /// tab and stays registered across navigations until removed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id = tab.add_preload_script(
+    ///     "Object.defineProperty(navigator, 'webdriver', { get: () => false });",
+    /// ).await?;
+    ///
+    /// tab.goto("https://example.com").await?;
+    /// tab.remove_preload_script(&id).await?;
+    /// ```
+    pub async fn add_preload_script(&self, script: &str) -> Result<ScriptId> {
+        debug!(tab_id = %self.inner.tab_id, script_len = script.len(), "Adding preload script");
+
+        let command = Command::Script(ScriptCommand::AddPreloadScript {
+            script: script.to_string(),
+        });
+
+        let response = self.send_command(command).await?;
+
+        let script_id = response.require_str("scriptId")?;
+
+        debug!(tab_id = %self.inner.tab_id, script_id, "Preload script added");
+        let script_id = ScriptId::new(script_id);
+        if let Some(window) = &self.inner.window {
+            window.track_preload_script(self.inner.tab_id, &script_id);
+        }
+        Ok(script_id)
+    }
+
+    /// Removes a preload script added via [`Tab::add_preload_script`].
+    ///
+    /// Pages already loaded keep whatever the script did; it just stops
+    /// running on later navigations.
+    pub async fn remove_preload_script(&self, script_id: &ScriptId) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, %script_id, "Removing preload script");
+
+        let command = Command::Script(ScriptCommand::RemovePreloadScript {
+            script_id: script_id.as_str().to_string(),
+        });
+
+        self.send_command(command).await?;
+
+        if let Some(window) = &self.inner.window {
+            window.untrack_subscription(script_id.as_str());
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tab - Exposed Functions
+// ============================================================================
+
+impl Tab {
+    /// Exposes a Rust function to page JavaScript as `window[name]`.
+    ///
+    /// Calling `window[name](...args)` in the page returns a Promise that
+    /// resolves with the handler's return value. The binding is installed
+    /// before page scripts and survives navigations within the tab.
+    ///
+    /// The handler runs on the event loop, so it should return quickly.
+    /// Exposing the same name again replaces the handler.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use serde_json::json;
+    ///
+    /// tab.expose_function("add", |args| {
+    ///     let a = args.first().and_then(|v| v.as_i64()).unwrap_or(0);
+    ///     let b = args.get(1).and_then(|v| v.as_i64()).unwrap_or(0);
+    ///     json!(a + b)
+    /// }).await?;
+    ///
+    /// let sum = tab.execute_async_script("return await window.add(2, 3)").await?;
+    /// ```
+    pub async fn expose_function<F>(&self, name: &str, handler: F) -> Result<()>
+    where
+        F: Fn(Vec<Value>) -> Value + Send + Sync + 'static,
+    {
+        debug!(tab_id = %self.inner.tab_id, name, "Exposing function");
+
+        let window = self.get_window()?;
+        let functions = Arc::clone(&window.inner.exposed_functions);
+
+        let first = {
+            let mut functions = functions.lock();
+            let first = functions.is_empty();
+            functions.insert((self.inner.tab_id, name.to_string()), Arc::new(handler));
+            first
+        };
+
+        // Every exposed function in the window and its popups shares one
+        // event handler, owned by the window holding the session
+        if first {
+            window
+                .opener()
+                .unwrap_or(window)
+                .add_event_handler(Box::new(move |event: Event| {
+                    if event.method.as_str() != "script.functionCalled" {
+                        return None;
+                    }
+
+                    let ParsedEvent::ScriptFunctionCalled { name, args, tab_id } = event.parse()
+                    else {
+                        return None;
+                    };
+
+                    let handler = TabId::new(tab_id)
+                        .and_then(|tab_id| functions.lock().get(&(tab_id, name.clone())).cloned());
+
+                    let result = match handler {
+                        Some(handler) => serde_json::json!({ "value": handler(args) }),
+                        None => {
+                            serde_json::json!({ "error": format!("Function not exposed: {name}") })
+                        }
+                    };
+
+                    Some(EventReply::new(event.id, "script.functionCalled", result))
+                }));
+        }
+
+        let command = Command::Script(ScriptCommand::ExposeFunction {
+            name: name.to_string(),
+        });
+
+        self.send_command(command).await?;
+        Ok(())
+    }
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Extracts a script's return value, surfacing thrown exceptions as
+/// [`Error::ScriptError`] instead of returning `null`.
+fn script_value(response: Response) -> Result<Value> {
+    let result = response.into_result()?;
+    Ok(result.get("value").cloned().unwrap_or(Value::Null))
+}
+
+/// Builds the in-page loop for [`Tab::wait_for_function`].
+///
+/// Resolves `{ done: true, value }` once the expression is truthy, or
+/// `{ done: false }` when the slice runs out.
+fn wait_function_script(js_expr: &str, slice: Duration) -> String {
+    format!(
+        r#"const predicate = async () => (
+{js_expr}
+);
+const deadline = performance.now() + {slice_ms};
+return await new Promise((resolve) => {{
+    const tick = async () => {{
+        let value;
+        try {{
+            value = await predicate();
+        }} catch (e) {{
+            value = undefined;
+        }}
+        if (value) {{
+            resolve({{ done: true, value: value }});
+        }} else if (performance.now() >= deadline) {{
+            resolve({{ done: false }});
+        }} else if (document.hidden) {{
+            setTimeout(tick, 50);
+        }} else {{
+            requestAnimationFrame(tick);
+        }}
+    }};
+    tick();
+}});"#,
+        slice_ms = slice.as_millis()
+    )
+}
+
 /// Escapes a string for safe use in JavaScript.
 pub(crate) fn json_string(s: &str) -> String {
     serde_json::to_string(s).unwrap_or_else(|_| format!("\"{}\"", s))
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::wait_function_script;
+
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::error::Error;
+    use crate::{Driver, MockTransport};
+
+    #[tokio::test]
+    async fn test_script_timeout() {
+        let mock = MockTransport::new()
+            .respond("script.evaluateAsync", json!({ "value": 42 }))
+            .respond_error(
+                "script.evaluateAsync",
+                "script timeout",
+                "Promise did not settle",
+            )
+            .respond_error("script.evaluate", "script timeout", "Script aborted");
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+        mock.clear_requests();
+
+        let value = tab.execute_async_script("return 42").await.expect("value");
+        assert_eq!(value, json!(42));
+        let request = mock.requests().pop().expect("request");
+        let json = serde_json::to_value(&request.command).expect("serialize");
+        assert_eq!(json["params"]["timeout"], 30_000);
+
+        let pending = tab
+            .execute_async_script_with_timeout(
+                "return new Promise(() => {})",
+                Duration::from_millis(50),
+            )
+            .await;
+        assert!(matches!(
+            pending,
+            Err(Error::ScriptTimeout { timeout_ms: 50 })
+        ));
+
+        let aborted = tab
+            .execute_script_with_timeout("return 1", Duration::from_millis(20))
+            .await;
+        assert!(matches!(
+            aborted,
+            Err(Error::ScriptTimeout { timeout_ms: 20 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_script_request_timeout_maps_to_script_timeout() {
+        let mock = MockTransport::new()
+            .respond_timeout("script.evaluate")
+            .respond_timeout("script.evaluateAsync");
+        let driver = Driver::with_mock(mock);
+        let window = driver.window().spawn().await.expect("window");
+        let tab = window.tab();
+
+        let result = tab
+            .execute_script_with_timeout("return 1", Duration::from_millis(20))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::ScriptTimeout { timeout_ms: 20 })
+        ));
+
+        let result = tab
+            .execute_async_script_with_timeout("return 1", Duration::from_millis(50))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::ScriptTimeout { timeout_ms: 50 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_script_exception() {
+        let mock = MockTransport::new().respond_error(
+            "script.evaluate",
+            "script error",
+            "ReferenceError: missing is not defined",
+        );
+        let driver = Driver::with_mock(mock);
+        let window = driver.window().spawn().await.expect("window");
+
+        let result = window.tab().execute_script("return missing.value").await;
+        match result {
+            Err(Error::ScriptError { message, .. }) => {
+                assert_eq!(message, "ReferenceError: missing is not defined");
+            }
+            other => panic!("expected script error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_wait_function_script_embeds_expression() {
+        let script = wait_function_script("window.ready === true", Duration::from_millis(750));
+        assert!(script.contains("(\nwindow.ready === true\n)"));
+        assert!(script.contains("performance.now() + 750"));
+        assert!(script.contains("requestAnimationFrame(tick)"));
+    }
+}
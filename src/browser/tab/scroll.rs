@@ -1,8 +1,11 @@
 //! Scroll control methods.
 
+use std::time::Duration;
+
 use tracing::debug;
 
 use crate::error::Result;
+use crate::protocol::{Command, InputCommand};
 
 use super::Tab;
 
@@ -59,6 +62,43 @@ impl Tab {
         Ok(())
     }
 
+    /// Dispatches a mouse wheel event at the current mouse position.
+    ///
+    /// Unlike [`Tab::scroll_by`], which sets the scroll offset directly,
+    /// this fires a real bubbling `WheelEvent` (`deltaMode` = pixel) at the
+    /// element under the pointer, so wheel-driven infinite scrollers and
+    /// maps react. The position is the last one moved to in this tab with
+    /// [`Tab::mouse_move_to`], [`Tab::click_at`] or a human-like move (the
+    /// top-left corner at first).
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_x` - Horizontal delta in pixels (positive = right)
+    /// * `delta_y` - Vertical delta in pixels (positive = down)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tab.mouse_move_to(640, 360).await?;
+    /// tab.scroll_wheel(0, 400).await?;
+    /// ```
+    pub async fn scroll_wheel(&self, delta_x: i32, delta_y: i32) -> Result<()> {
+        let (x, y) = self.get_window()?.mouse_position(self.inner.tab_id);
+        debug!(tab_id = %self.inner.tab_id, x, y, delta_x, delta_y, "Dispatching wheel event");
+
+        let command = Command::Input(InputCommand::Wheel {
+            element_id: None,
+            x: Some(x),
+            y: Some(y),
+            delta_x,
+            delta_y,
+            delta_mode: 0, // DOM_DELTA_PIXEL
+        });
+
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
     /// Scrolls to the top of the page.
     pub async fn scroll_to_top(&self) -> Result<()> {
         debug!(tab_id = %self.inner.tab_id, "Scrolling to top");
@@ -84,13 +124,122 @@ impl Tab {
             .execute_script("return { x: window.scrollX, y: window.scrollY };")
             .await?;
 
-        let x = result.get("x").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
-        let y = result.get("y").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+        // Scroll offsets are fractional under zoom or high-DPI scaling
+        let x = result
+            .get("x")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0)
+            .round() as i32;
+        let y = result
+            .get("y")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0)
+            .round() as i32;
 
         debug!(tab_id = %self.inner.tab_id, x = x, y = y, "Got scroll position");
         Ok((x, y))
     }
 
+    /// Gets the scrollable size of the document.
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (scrollWidth, scrollHeight) of the document element in pixels.
+    pub async fn get_scroll_size(&self) -> Result<(i32, i32)> {
+        let result = self
+            .execute_script(
+                r#"
+                const root = document.scrollingElement || document.documentElement;
+                return { width: root.scrollWidth, height: root.scrollHeight };
+                "#,
+            )
+            .await?;
+
+        let width = result.get("width").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+        let height = result.get("height").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+
+        debug!(tab_id = %self.inner.tab_id, width = width, height = height, "Got scroll size");
+        Ok((width, height))
+    }
+
+    /// Checks whether the page is scrolled to the bottom.
+    ///
+    /// Allows one pixel of slack for fractional scroll offsets.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// while !tab.is_scrolled_to_bottom().await? {
+    ///     tab.scroll_by(0, 800).await?;
+    /// }
+    /// ```
+    pub async fn is_scrolled_to_bottom(&self) -> Result<bool> {
+        let result = self
+            .execute_script(
+                r#"
+                const root = document.scrollingElement || document.documentElement;
+                return root.scrollTop + root.clientHeight >= root.scrollHeight - 1;
+                "#,
+            )
+            .await?;
+
+        let at_bottom = result.as_bool().unwrap_or(false);
+
+        debug!(tab_id = %self.inner.tab_id, at_bottom, "Checked scroll bottom");
+        Ok(at_bottom)
+    }
+
+    /// Scrolls down until the page stops growing.
+    ///
+    /// Repeatedly scrolls by `step_px`, waits `settle_ms` for lazy content to
+    /// load, and stops once the bottom is reached and the scroll height did
+    /// not change during the wait, or after `max_scrolls` iterations.
+    ///
+    /// # Returns
+    ///
+    /// Number of scroll iterations performed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Load every item of an infinite feed
+    /// let scrolls = tab.scroll_until_stable(1000, 750, 50).await?;
+    /// let items = tab.find_elements(By::css(".feed-item")).await?;
+    /// ```
+    pub async fn scroll_until_stable(
+        &self,
+        step_px: i32,
+        settle_ms: u64,
+        max_scrolls: usize,
+    ) -> Result<usize> {
+        debug!(
+            tab_id = %self.inner.tab_id,
+            step_px,
+            settle_ms,
+            max_scrolls,
+            "Scrolling until stable"
+        );
+
+        let settle = Duration::from_millis(settle_ms);
+        let mut scrolls = 0;
+
+        while scrolls < max_scrolls {
+            let (_, height_before) = self.get_scroll_size().await?;
+
+            self.scroll_by(0, step_px).await?;
+            scrolls += 1;
+            tokio::time::sleep(settle).await;
+
+            let (_, height_after) = self.get_scroll_size().await?;
+            if height_after == height_before && self.is_scrolled_to_bottom().await? {
+                break;
+            }
+        }
+
+        debug!(tab_id = %self.inner.tab_id, scrolls, "Scroll stabilized");
+        Ok(scrolls)
+    }
+
     /// Gets the page dimensions (scrollable area).
     ///
     /// # Returns
@@ -0,0 +1,110 @@
+//! Service worker listing, unregistration and blocking.
+
+use tracing::debug;
+
+use crate::browser::storage::ServiceWorkerInfo;
+use crate::error::Result;
+use crate::identifiers::ScriptId;
+
+use super::Tab;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Lists registrations for the page's origin.
+const LIST_SCRIPT: &str = r#"
+if (!navigator.serviceWorker) return [];
+const registrations = await navigator.serviceWorker.getRegistrations();
+return registrations.map((registration) => {
+    const worker = registration.installing || registration.waiting || registration.active;
+    return {
+        scope: registration.scope,
+        scriptUrl: worker ? worker.scriptURL : null,
+        state: worker ? worker.state : null
+    };
+});
+"#;
+
+/// Unregisters every registration for the page's origin and returns the count.
+const UNREGISTER_SCRIPT: &str = r#"
+if (!navigator.serviceWorker) return 0;
+const registrations = await navigator.serviceWorker.getRegistrations();
+const results = await Promise.all(registrations.map((registration) => registration.unregister()));
+return results.filter(Boolean).length;
+"#;
+
+/// Makes `navigator.serviceWorker.register()` reject.
+const BLOCK_SCRIPT: &str = r#"
+(() => {
+    const Container = window.ServiceWorkerContainer;
+    if (!Container) return;
+    Container.prototype.register = function register() {
+        return Promise.reject(new DOMException("Service workers are disabled", "SecurityError"));
+    };
+})();
+"#;
+
+// ============================================================================
+// Tab - Service Workers
+// ============================================================================
+
+impl Tab {
+    /// Lists service worker registrations for the current page's origin.
+    pub async fn list_service_workers(&self) -> Result<Vec<ServiceWorkerInfo>> {
+        debug!(tab_id = %self.inner.tab_id, "Listing service workers");
+
+        let value = self.execute_async_script(LIST_SCRIPT).await?;
+        let workers: Vec<ServiceWorkerInfo> = serde_json::from_value(value)?;
+
+        debug!(tab_id = %self.inner.tab_id, count = workers.len(), "Listed service workers");
+        Ok(workers)
+    }
+
+    /// Unregisters every service worker for the current page's origin.
+    ///
+    /// Pages already controlled by a worker stay controlled until they are
+    /// reloaded. Use [`Tab::clear_browser_data`] to remove workers for all
+    /// origins.
+    ///
+    /// Returns the number of registrations removed.
+    pub async fn unregister_service_workers(&self) -> Result<usize> {
+        debug!(tab_id = %self.inner.tab_id, "Unregistering service workers");
+
+        let value = self.execute_async_script(UNREGISTER_SCRIPT).await?;
+        let removed = value.as_u64().unwrap_or(0) as usize;
+
+        debug!(tab_id = %self.inner.tab_id, removed, "Unregistered service workers");
+        Ok(removed)
+    }
+
+    /// Prevents pages in this tab from registering service workers.
+    ///
+    /// Installs a preload script that makes
+    /// `navigator.serviceWorker.register()` reject on every navigation,
+    /// applies it to the current page, and unregisters existing workers for
+    /// the current origin. Remove the returned script with
+    /// [`Tab::remove_preload_script`] to allow registration again.
+    ///
+    /// To disable service workers for the whole browser instead, set the
+    /// `dom.serviceWorkers.enabled` preference to `false` when building the
+    /// driver.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let script_id = tab.disable_service_workers().await?;
+    /// tab.goto("https://example.com").await?;
+    /// assert!(tab.list_service_workers().await?.is_empty());
+    /// ```
+    pub async fn disable_service_workers(&self) -> Result<ScriptId> {
+        debug!(tab_id = %self.inner.tab_id, "Disabling service workers");
+
+        let script_id = self.add_preload_script(BLOCK_SCRIPT).await?;
+        self.execute_script(BLOCK_SCRIPT).await?;
+        self.unregister_service_workers().await?;
+
+        debug!(tab_id = %self.inner.tab_id, %script_id, "Service workers disabled");
+        Ok(script_id)
+    }
+}
@@ -1,10 +1,16 @@
 //! Cookie and web storage methods.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use serde_json::Value;
 use tracing::debug;
+use url::Url;
 
-use crate::error::Result;
-use crate::protocol::{Command, Cookie, StorageCommand};
+use crate::browser::storage::{ClearDataOptions, CookieChange};
+use crate::error::{Error, Result};
+use crate::identifiers::SubscriptionId;
+use crate::protocol::{Command, Cookie, Event, ParsedEvent, StorageCommand};
 
 use super::Tab;
 use super::script::json_string;
@@ -66,10 +72,38 @@ impl Tab {
     }
 
     /// Gets all cookies for the current page.
+    ///
+    /// Cookies marked HttpOnly are not visible to the page and are
+    /// omitted; use [`Tab::get_all_cookies_including_http_only`] to get
+    /// them too.
     pub async fn get_all_cookies(&self) -> Result<Vec<Cookie>> {
-        debug!(tab_id = %self.inner.tab_id, "Getting all cookies");
+        self.get_all_cookies_impl(false).await
+    }
+
+    /// Gets all cookies for the current page, including HttpOnly cookies.
+    ///
+    /// Reads the browser's cookie store instead of `document.cookie`, so
+    /// session cookies set by the server with `HttpOnly` are returned with
+    /// `http_only: Some(true)`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cookies = tab.get_all_cookies_including_http_only().await?;
+    /// let session = cookies.iter().find(|c| c.http_only == Some(true));
+    /// ```
+    pub async fn get_all_cookies_including_http_only(&self) -> Result<Vec<Cookie>> {
+        self.get_all_cookies_impl(true).await
+    }
 
-        let command = Command::Storage(StorageCommand::GetAllCookies { url: None });
+    /// Gets all cookies, optionally from the browser's cookie store.
+    async fn get_all_cookies_impl(&self, include_http_only: bool) -> Result<Vec<Cookie>> {
+        debug!(tab_id = %self.inner.tab_id, include_http_only, "Getting all cookies");
+
+        let command = Command::Storage(StorageCommand::GetAllCookies {
+            url: None,
+            include_http_only,
+        });
         let response = self.send_command(command).await?;
 
         let cookies: Vec<Cookie> = response
@@ -87,6 +121,150 @@ impl Tab {
         debug!(tab_id = %self.inner.tab_id, count = cookies.len(), "Got all cookies");
         Ok(cookies)
     }
+
+    /// Removes every cookie in the browser, including HttpOnly cookies.
+    ///
+    /// Unlike [`Window::reset`](crate::Window::reset), storage and cache are
+    /// left untouched.
+    ///
+    /// Returns the number of cookies removed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let removed = tab.clear_all_cookies().await?;
+    /// println!("removed {removed} cookies");
+    /// ```
+    pub async fn clear_all_cookies(&self) -> Result<u64> {
+        self.clear_cookies_impl(None).await
+    }
+
+    /// Removes cookies for a domain and its subdomains, including HttpOnly
+    /// cookies.
+    ///
+    /// A leading dot is ignored, so `example.com` and `.example.com` both
+    /// match cookies for `example.com` and `www.example.com`.
+    ///
+    /// Returns the number of cookies removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - Domain to clear (e.g. "example.com")
+    pub async fn clear_cookies_for_domain(&self, domain: &str) -> Result<u64> {
+        self.clear_cookies_impl(Some(domain.to_string())).await
+    }
+
+    /// Removes cookies, optionally restricted to a domain.
+    async fn clear_cookies_impl(&self, domain: Option<String>) -> Result<u64> {
+        debug!(tab_id = %self.inner.tab_id, domain = ?domain, "Clearing cookies");
+
+        let command = Command::Storage(StorageCommand::ClearCookies { domain });
+        let response = self.send_command(command).await?.into_result()?;
+
+        let removed = response.get("removed").and_then(Value::as_u64).unwrap_or(0);
+
+        debug!(tab_id = %self.inner.tab_id, removed, "Cleared cookies");
+        Ok(removed)
+    }
+}
+
+// ============================================================================
+// Tab - Storage (Cookie Changes)
+// ============================================================================
+
+impl Tab {
+    /// Observes cookie changes in the browser.
+    ///
+    /// Fires on `cookies.onChanged` for every cookie in the window's
+    /// profile, including HttpOnly cookies set by the server, so a freshly
+    /// issued token can be captured without polling. Filter on
+    /// `change.cookie.domain` for a particular site.
+    ///
+    /// # Returns
+    ///
+    /// Subscription ID for [`Tab::unsubscribe_cookie_changes`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let id = tab.on_cookie_changed(|change| {
+    ///     if !change.removed && change.cookie.name == "auth_token" {
+    ///         println!("New token: {}", change.cookie.value);
+    ///     }
+    /// }).await?;
+    ///
+    /// tab.unsubscribe_cookie_changes(&id).await?;
+    /// ```
+    pub async fn on_cookie_changed<F>(&self, callback: F) -> Result<SubscriptionId>
+    where
+        F: Fn(CookieChange) + Send + Sync + 'static,
+    {
+        debug!(tab_id = %self.inner.tab_id, "Subscribing to cookie changes");
+
+        let window = self.get_window()?;
+        let command = Command::Storage(StorageCommand::SubscribeCookieChanges);
+        let response = self.send_command(command).await?.into_result()?;
+
+        let subscription_id = response
+            .get("subscriptionId")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| Error::protocol("No subscriptionId in response"))?
+            .to_string();
+
+        let expected_id = subscription_id.clone();
+        let callback = Arc::new(callback);
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "storage.cookieChanged" {
+                return None;
+            }
+
+            if let ParsedEvent::StorageCookieChanged {
+                subscription_id,
+                cookie: Some(cookie),
+                cause,
+                removed,
+            } = event.parse()
+                && subscription_id == expected_id
+            {
+                callback(CookieChange {
+                    cookie,
+                    cause,
+                    removed,
+                });
+            }
+
+            None
+        }));
+        let teardown = Command::Storage(StorageCommand::UnsubscribeCookieChanges {
+            subscription_id: subscription_id.clone(),
+        });
+        window.track_subscription(
+            subscription_id.as_str(),
+            self.inner.tab_id,
+            handler_id,
+            teardown,
+        );
+
+        Ok(SubscriptionId::new(subscription_id))
+    }
+
+    /// Stops observing cookie changes.
+    pub async fn unsubscribe_cookie_changes(&self, subscription_id: &SubscriptionId) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, %subscription_id, "Unsubscribing from cookie changes");
+
+        let command = Command::Storage(StorageCommand::UnsubscribeCookieChanges {
+            subscription_id: subscription_id.as_str().to_string(),
+        });
+
+        self.send_command(command).await?;
+
+        if let Some(window) = &self.inner.window {
+            window.untrack_subscription(subscription_id.as_str());
+        }
+
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -141,6 +319,41 @@ impl Tab {
         self.execute_script("localStorage.clear();").await?;
         Ok(())
     }
+
+    /// Gets every localStorage entry in one call.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let snapshot = tab.local_storage_all().await?;
+    ///
+    /// // Later, or in another window on the same origin
+    /// other_tab.local_storage_clear().await?;
+    /// other_tab.local_storage_set_all(&snapshot).await?;
+    /// ```
+    pub async fn local_storage_all(&self) -> Result<HashMap<String, String>> {
+        debug!(tab_id = %self.inner.tab_id, "Getting all localStorage");
+
+        let value = self
+            .execute_script(&storage_dump_script("localStorage"))
+            .await?;
+        let items = parse_storage_items(value);
+
+        debug!(tab_id = %self.inner.tab_id, count = items.len(), "Got all localStorage");
+        Ok(items)
+    }
+
+    /// Sets several localStorage entries in one call.
+    ///
+    /// Keys not in `items` are kept; call [`Tab::local_storage_clear`]
+    /// first to restore an exact snapshot.
+    pub async fn local_storage_set_all(&self, items: &HashMap<String, String>) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, count = items.len(), "Setting localStorage entries");
+
+        self.execute_script(&storage_restore_script("localStorage", items)?)
+            .await?;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -195,4 +408,302 @@ impl Tab {
         self.execute_script("sessionStorage.clear();").await?;
         Ok(())
     }
+
+    /// Gets every sessionStorage entry in one call.
+    ///
+    /// See [`Tab::local_storage_all`].
+    pub async fn session_storage_all(&self) -> Result<HashMap<String, String>> {
+        debug!(tab_id = %self.inner.tab_id, "Getting all sessionStorage");
+
+        let value = self
+            .execute_script(&storage_dump_script("sessionStorage"))
+            .await?;
+        let items = parse_storage_items(value);
+
+        debug!(tab_id = %self.inner.tab_id, count = items.len(), "Got all sessionStorage");
+        Ok(items)
+    }
+
+    /// Sets several sessionStorage entries in one call.
+    ///
+    /// Keys not in `items` are kept.
+    pub async fn session_storage_set_all(&self, items: &HashMap<String, String>) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, count = items.len(), "Setting sessionStorage entries");
+
+        self.execute_script(&storage_restore_script("sessionStorage", items)?)
+            .await?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tab - Storage (IndexedDB)
+// ============================================================================
+
+impl Tab {
+    /// Lists the names of the page origin's IndexedDB databases.
+    pub async fn indexeddb_list_databases(&self) -> Result<Vec<String>> {
+        debug!(tab_id = %self.inner.tab_id, "Listing IndexedDB databases");
+
+        let value = self
+            .execute_async_script(
+                "return (await indexedDB.databases()).map((db) => db.name).filter(Boolean);",
+            )
+            .await?;
+        let names: Vec<String> = serde_json::from_value(value)?;
+
+        debug!(tab_id = %self.inner.tab_id, count = names.len(), "Listed IndexedDB databases");
+        Ok(names)
+    }
+
+    /// Removes all records from every object store in an IndexedDB database.
+    ///
+    /// The database and its schema are kept, so the page can keep using it
+    /// without an upgrade. Does nothing if the database does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for db in tab.indexeddb_list_databases().await? {
+    ///     tab.indexeddb_clear(&db).await?;
+    /// }
+    /// ```
+    pub async fn indexeddb_clear(&self, db: &str) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, db = %db, "Clearing IndexedDB database");
+
+        let script = format!(
+            r#"{open}
+if (!db) return;
+const stores = [...db.objectStoreNames];
+if (stores.length) {{
+    await new Promise((resolve, reject) => {{
+        const tx = db.transaction(stores, "readwrite");
+        stores.forEach((name) => tx.objectStore(name).clear());
+        tx.oncomplete = () => resolve();
+        tx.onerror = () => reject(tx.error);
+        tx.onabort = () => reject(tx.error);
+    }});
+}}
+db.close();"#,
+            open = indexeddb_open_script(db)
+        );
+
+        self.execute_async_script(&script).await?;
+        Ok(())
+    }
+
+    /// Reads a record from an IndexedDB object store (best effort).
+    ///
+    /// Returns `None` if the database, store or key does not exist. Values
+    /// that cannot be represented as JSON (e.g. `Blob`) are not preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Database name
+    /// * `store` - Object store name
+    /// * `key` - Record key (string, number or array)
+    pub async fn indexeddb_get(&self, db: &str, store: &str, key: &Value) -> Result<Option<Value>> {
+        debug!(tab_id = %self.inner.tab_id, db = %db, store = %store, "Getting IndexedDB record");
+
+        let script = format!(
+            r#"{open}
+if (!db) return null;
+try {{
+    if (!db.objectStoreNames.contains({store})) return null;
+    const value = await new Promise((resolve, reject) => {{
+        const request = db.transaction({store}, "readonly").objectStore({store}).get({key});
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    }});
+    return value === undefined ? null : {{ value }};
+}} finally {{
+    db.close();
+}}"#,
+            open = indexeddb_open_script(db),
+            store = json_string(store),
+            key = key
+        );
+
+        let value = self.execute_async_script(&script).await?;
+        let record = value.get("value").cloned();
+
+        debug!(tab_id = %self.inner.tab_id, db = %db, found = record.is_some(), "Got IndexedDB record");
+        Ok(record)
+    }
+}
+
+// ============================================================================
+// Tab - Storage (Browsing Data)
+// ============================================================================
+
+impl Tab {
+    /// Clears browsing data in one step.
+    ///
+    /// Uses the extension's `browsingData` API, which reaches HttpOnly
+    /// cookies and storage of every frame without running page scripts.
+    /// By default only the current page's hostname is cleared; Firefox
+    /// cannot clear the HTTP cache per site, so `cache` always clears it
+    /// for all origins.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if only the current origin is
+    /// requested but the page has no hostname (e.g. `about:blank`).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::ClearDataOptions;
+    ///
+    /// tab.clear_browser_data(ClearDataOptions::new()).await?;
+    /// tab.clear_browser_data(ClearDataOptions::new().with_all_origins(true)).await?;
+    /// ```
+    pub async fn clear_browser_data(&self, options: ClearDataOptions) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, ?options, "Clearing browser data");
+
+        let hostnames = if options.all_origins {
+            Vec::new()
+        } else {
+            let url = self.get_url().await?;
+            let hostname = url_hostname(&url).ok_or_else(|| {
+                Error::invalid_argument(format!(
+                    "Page {url} has no origin to clear; use all origins instead"
+                ))
+            })?;
+            vec![hostname]
+        };
+
+        let command = Command::Storage(StorageCommand::ClearBrowsingData {
+            data_types: options.data_types(),
+            hostnames,
+        });
+        self.send_command(command).await?.into_result()?;
+
+        debug!(tab_id = %self.inner.tab_id, "Browser data cleared");
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Returns the hostname of `url`, if it has one.
+fn url_hostname(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()?
+        .host_str()
+        .filter(|host| !host.is_empty())
+        .map(str::to_string)
+}
+
+/// Builds script returning every entry of a `Storage` object (`area`).
+fn storage_dump_script(area: &str) -> String {
+    format!(
+        r#"const items = {{}};
+for (let i = 0; i < {area}.length; i++) {{
+    const key = {area}.key(i);
+    items[key] = {area}.getItem(key);
+}}
+return items;"#
+    )
+}
+
+/// Builds script writing `items` into a `Storage` object (`area`).
+fn storage_restore_script(area: &str, items: &HashMap<String, String>) -> Result<String> {
+    Ok(format!(
+        r#"for (const [key, value] of Object.entries({items})) {{
+    {area}.setItem(key, value);
+}}"#,
+        items = serde_json::to_string(items)?
+    ))
+}
+
+/// Converts a storage dump to a map.
+///
+/// Storage holds only strings; anything else is kept as its JSON text.
+fn parse_storage_items(value: Value) -> HashMap<String, String> {
+    let Value::Object(items) = value else {
+        return HashMap::new();
+    };
+
+    items
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => (key, s),
+            other => (key, other.to_string()),
+        })
+        .collect()
+}
+
+/// Builds script opening an existing IndexedDB database as `db`.
+///
+/// `db` is `null` if the database does not exist; opening it directly would
+/// create an empty one.
+fn indexeddb_open_script(name: &str) -> String {
+    format!(
+        r#"const name = {name};
+const exists = (await indexedDB.databases()).some((info) => info.name === name);
+const db = exists
+    ? await new Promise((resolve, reject) => {{
+          const request = indexedDB.open(name);
+          request.onsuccess = () => resolve(request.result);
+          request.onerror = () => reject(request.error);
+          request.onblocked = () => reject(new Error("IndexedDB open blocked: " + name));
+      }})
+    : null;"#,
+        name = json_string(name)
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::{
+        indexeddb_open_script, parse_storage_items, storage_dump_script, storage_restore_script,
+        url_hostname,
+    };
+
+    #[test]
+    fn test_url_hostname() {
+        assert_eq!(
+            url_hostname("https://www.example.com:8443/path?q=1").as_deref(),
+            Some("www.example.com")
+        );
+        assert_eq!(url_hostname("about:blank"), None);
+        assert_eq!(url_hostname("not a url"), None);
+    }
+
+    #[test]
+    fn test_storage_scripts() {
+        assert!(storage_dump_script("sessionStorage").contains("sessionStorage.key(i)"));
+
+        let items = HashMap::from([("to\"ken".to_string(), "a`b".to_string())]);
+        let script = storage_restore_script("localStorage", &items).expect("script");
+        assert!(script.contains(r#"Object.entries({"to\"ken":"a`b"})"#));
+        assert!(script.contains("localStorage.setItem(key, value)"));
+    }
+
+    #[test]
+    fn test_parse_storage_items() {
+        let items = parse_storage_items(json!({ "token": "abc", "count": 3, "flag": null }));
+        assert_eq!(items["token"], "abc");
+        assert_eq!(items["count"], "3");
+        assert_eq!(items["flag"], "null");
+        assert!(parse_storage_items(json!(null)).is_empty());
+    }
+
+    #[test]
+    fn test_indexeddb_open_script_escapes_name() {
+        let script = indexeddb_open_script("app\"db");
+        assert!(script.contains(r#"const name = "app\"db";"#));
+        assert!(script.contains("indexedDB.databases()"));
+    }
 }
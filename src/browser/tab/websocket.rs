@@ -0,0 +1,230 @@
+//! WebSocket frame interception methods.
+
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as Base64Standard;
+use serde_json::Value;
+use tracing::debug;
+
+use crate::browser::network::{WsDirection, WsFrame, WsFrameAction};
+use crate::error::Result;
+use crate::identifiers::InterceptId;
+use crate::protocol::{Command, Event, EventReply, WebSocketCommand};
+
+use super::Tab;
+use super::network::is_tab_event;
+
+// ============================================================================
+// Tab - WebSocket
+// ============================================================================
+
+impl Tab {
+    /// Intercepts WebSocket frames with a callback.
+    ///
+    /// The callback sees every frame sent or received by sockets the page
+    /// opens and decides whether to deliver, drop or rewrite it.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{WsDirection, WsFrameAction};
+    ///
+    /// let id = tab.intercept_websocket(|frame| {
+    ///     if frame.direction == WsDirection::Received {
+    ///         println!("<- {}", frame.text().unwrap_or("<binary>"));
+    ///     }
+    ///     WsFrameAction::allow()
+    /// }).await?;
+    /// ```
+    pub async fn intercept_websocket<F>(&self, callback: F) -> Result<InterceptId>
+    where
+        F: Fn(WsFrame) -> WsFrameAction + Send + Sync + 'static,
+    {
+        debug!(tab_id = %self.inner.tab_id, "Enabling WebSocket interception");
+
+        let window = self.get_window()?;
+        let callback = Arc::new(callback);
+        let tab_id = self.inner.tab_id;
+
+        let handler_id = window.add_event_handler(Box::new(move |event: Event| {
+            let direction = match event.method.as_str() {
+                "ws.frameSent" => WsDirection::Sent,
+                "ws.frameReceived" => WsDirection::Received,
+                _ => return None,
+            };
+
+            if !is_tab_event(&event, tab_id) {
+                return None;
+            }
+
+            let frame = parse_ws_frame(&event, direction);
+            let action = callback(frame);
+            let result = ws_frame_action_to_json(&action);
+
+            Some(EventReply::new(event.id, event.method.clone(), result))
+        }));
+
+        let command = Command::WebSocket(WebSocketCommand::AddIntercept {
+            url_patterns: Vec::new(),
+        });
+
+        self.add_intercept(handler_id, command, |intercept_id| {
+            Command::WebSocket(WebSocketCommand::RemoveIntercept { intercept_id })
+        })
+        .await
+    }
+
+    /// Stops WebSocket frame interception.
+    ///
+    /// # Arguments
+    ///
+    /// * `intercept_id` - The intercept ID returned from [`Tab::intercept_websocket`]
+    pub async fn stop_websocket_intercept(&self, intercept_id: &InterceptId) -> Result<()> {
+        debug!(tab_id = %self.inner.tab_id, %intercept_id, "Stopping WebSocket interception");
+
+        let window = self.get_window()?;
+        window.untrack_subscription(intercept_id.as_str());
+
+        let command = Command::WebSocket(WebSocketCommand::RemoveIntercept {
+            intercept_id: intercept_id.clone(),
+        });
+
+        self.send_command(command).await?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parses a WebSocket frame from event.
+fn parse_ws_frame(event: &Event, direction: WsDirection) -> WsFrame {
+    WsFrame {
+        url: event
+            .params
+            .get("url")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        direction,
+        opcode: event
+            .params
+            .get("opcode")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(u64::from(WsFrame::OPCODE_TEXT)) as u8,
+        payload: event
+            .params
+            .get("payload")
+            .and_then(|v| v.as_str())
+            .and_then(|b64| Base64Standard.decode(b64).ok())
+            .unwrap_or_default(),
+        tab_id: event
+            .params
+            .get("tabId")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as u32,
+    }
+}
+
+/// Converts WebSocket frame action to JSON.
+fn ws_frame_action_to_json(action: &WsFrameAction) -> Value {
+    match action {
+        WsFrameAction::Allow => serde_json::json!({ "action": "allow" }),
+        WsFrameAction::Block => serde_json::json!({ "action": "block" }),
+        WsFrameAction::Modify(payload) => serde_json::json!({
+            "action": "modify",
+            "payload": Base64Standard.encode(payload),
+        }),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{parse_ws_frame, ws_frame_action_to_json};
+    use crate::browser::network::{WsDirection, WsFrame, WsFrameAction};
+    use crate::identifiers::RequestId;
+    use crate::protocol::Event;
+
+    fn frame_event(method: &str, params: Value) -> Event {
+        Event {
+            id: RequestId::generate(),
+            event_type: "event".to_string(),
+            method: method.to_string(),
+            params,
+        }
+    }
+
+    #[test]
+    fn test_parse_text_frame() {
+        let event = frame_event(
+            "ws.frameReceived",
+            json!({ "url": "wss://a.test/live", "opcode": 1, "payload": "aGVsbG8=", "tabId": 3 }),
+        );
+
+        let frame = parse_ws_frame(&event, WsDirection::Received);
+        assert_eq!(frame.url, "wss://a.test/live");
+        assert_eq!(frame.direction, WsDirection::Received);
+        assert!(frame.is_text());
+        assert_eq!(frame.text(), Some("hello"));
+        assert_eq!(frame.tab_id, 3);
+    }
+
+    #[test]
+    fn test_parse_binary_frame() {
+        let event = frame_event(
+            "ws.frameSent",
+            json!({ "url": "wss://a.test/live", "opcode": 2, "payload": "AP8=", "tabId": 3 }),
+        );
+
+        let frame = parse_ws_frame(&event, WsDirection::Sent);
+        assert_eq!(frame.opcode, WsFrame::OPCODE_BINARY);
+        assert!(!frame.is_text());
+        assert_eq!(frame.payload, [0x00, 0xff]);
+    }
+
+    #[test]
+    fn test_parse_close_frame() {
+        // Close frames carry a status code and no text
+        let event = frame_event(
+            "ws.frameReceived",
+            json!({ "url": "wss://a.test/live", "opcode": 8, "payload": "A+g=", "tabId": 3 }),
+        );
+
+        let frame = parse_ws_frame(&event, WsDirection::Received);
+        assert_eq!(frame.opcode, 8);
+        assert!(!frame.is_text());
+        assert_eq!(frame.payload, 1000_u16.to_be_bytes());
+    }
+
+    #[test]
+    fn test_parse_frame_defaults() {
+        let frame = parse_ws_frame(&frame_event("ws.frameSent", json!({})), WsDirection::Sent);
+        assert_eq!(frame.opcode, WsFrame::OPCODE_TEXT);
+        assert!(frame.payload.is_empty());
+        assert_eq!(frame.tab_id, 0);
+    }
+
+    #[test]
+    fn test_ws_frame_action_to_json() {
+        assert_eq!(
+            ws_frame_action_to_json(&WsFrameAction::allow()),
+            json!({ "action": "allow" })
+        );
+        assert_eq!(
+            ws_frame_action_to_json(&WsFrameAction::block()),
+            json!({ "action": "block" })
+        );
+        assert_eq!(
+            ws_frame_action_to_json(&WsFrameAction::modify("hi")),
+            json!({ "action": "modify", "payload": "aGk=" })
+        );
+    }
+}
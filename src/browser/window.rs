@@ -36,26 +36,50 @@
 // ============================================================================
 
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 use tokio::process::Child;
 use tracing::{debug, info};
 use uuid::Uuid;
 
-use crate::driver::{Driver, FirefoxOptions, Profile};
+use crate::driver::Capabilities;
+use crate::driver::core::{ProfileSource, parse_capabilities};
+use crate::driver::process;
+use crate::driver::{Driver, FirefoxOptions, PreferenceValue, Profile};
 use crate::error::{Error, Result};
-use crate::identifiers::{FrameId, SessionId, TabId};
+use crate::identifiers::{FrameId, ScriptId, SessionId, TabId};
+use crate::protocol::event::ParsedEvent;
 use crate::protocol::{
-    BrowsingContextCommand, Command, ProxyCommand, Request, Response, SessionCommand,
+    BrowsingContextCommand, Command, Event, ProxyCommand, Request, Response, ScriptCommand,
+    SessionCommand, StorageCommand,
 };
-use crate::transport::ConnectionPool;
+use crate::transport::{ConnectionPool, EventHandler, EventHandlerId};
 
-use super::Tab;
+use super::har::HarCapture;
+use super::permissions::{Permission, PermissionState};
 use super::proxy::ProxyConfig;
+use super::request_log::RequestLogCapture;
+use super::storage::ClearDataOptions;
+use super::{LogRecord, Tab, TabInfo};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Error code the extension returns for a method it does not handle.
+const UNKNOWN_COMMAND: &str = "unknown command";
+
+/// Time to wait for the extension to acknowledge a window close.
+const CLOSE_COMMAND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Time to wait for the extension to answer a health check.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
 // ============================================================================
 // ProcessGuard
@@ -80,6 +104,13 @@ impl ProcessGuard {
         }
     }
 
+    /// Creates a guard that does not own a process.
+    ///
+    /// Used for popup windows that live inside their opener's process.
+    fn detached(pid: u32) -> Self {
+        Self { child: None, pid }
+    }
+
     /// Kills the process and waits for it to exit.
     async fn kill(&mut self) -> Result<()> {
         if let Some(mut child) = self.child.take() {
@@ -95,6 +126,41 @@ impl ProcessGuard {
         Ok(())
     }
 
+    /// Waits for the process to exit on its own, killing it after `timeout`.
+    ///
+    /// Returns `true` if the process exited before the timeout.
+    async fn wait_or_kill(&mut self, timeout: Duration) -> bool {
+        let Some(child) = self.child.as_mut() else {
+            return true;
+        };
+
+        match tokio::time::timeout(timeout, child.wait()).await {
+            Ok(Ok(status)) => {
+                self.child = None;
+                info!(pid = self.pid, %status, "Process exited cleanly");
+                true
+            }
+            Ok(Err(e)) => {
+                debug!(pid = self.pid, error = %e, "Failed to wait for process");
+                let _ = self.kill().await;
+                false
+            }
+            Err(_) => {
+                debug!(pid = self.pid, "Process did not exit in time, killing");
+                let _ = self.kill().await;
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the owned process has exited.
+    fn has_exited(&mut self) -> bool {
+        match self.child.as_mut() {
+            Some(child) => !matches!(child.try_wait(), Ok(None)),
+            None => false,
+        }
+    }
+
     /// Returns the process ID.
     #[inline]
     fn pid(&self) -> u32 {
@@ -116,6 +182,180 @@ impl Drop for ProcessGuard {
 // Types
 // ============================================================================
 
+/// Callback invoked when a page opens a new browser window.
+pub(crate) type WindowOpenedCallback = Arc<dyn Fn(Window) + Send + Sync>;
+
+/// Settings applied to every [`WindowBuilder`] a driver creates.
+pub(crate) type WindowTemplate =
+    Arc<dyn for<'a> Fn(WindowBuilder<'a>) -> WindowBuilder<'a> + Send + Sync>;
+
+/// Rust function callable from page JavaScript.
+pub(crate) type ExposedFunction = Arc<dyn Fn(Vec<Value>) -> Value + Send + Sync>;
+
+/// Exposed functions by tab and name.
+pub(crate) type ExposedFunctions = Arc<Mutex<FxHashMap<(TabId, String), ExposedFunction>>>;
+
+/// Extension-side registration kept until it is stopped or the window reset.
+struct Subscription {
+    /// Tab the registration was made from.
+    tab_id: TabId,
+    /// Event handler serving it, if it sends events.
+    handler_id: Option<EventHandlerId>,
+    /// Command that removes it from the extension.
+    teardown: Command,
+}
+
+/// A tab crash reported by the extension.
+///
+/// Passed to [`Window::on_crash`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashInfo {
+    /// The crashed tab.
+    pub tab_id: TabId,
+    /// `crashed` for a content process crash, `unresponsive` when the tab
+    /// missed the extension's heartbeat.
+    pub reason: String,
+}
+
+/// Health diagnostics for a window's session.
+///
+/// Returned by [`Window::health`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionHealth {
+    /// Whether [`Window::close`] was called.
+    pub closed: bool,
+    /// Whether the Firefox process is still running.
+    pub process_running: bool,
+    /// Whether the session's WebSocket connection is in the pool.
+    pub connected: bool,
+    /// Round-trip time of the status request, if it succeeded.
+    pub latency: Option<Duration>,
+    /// Error from the status request, if it failed.
+    pub error: Option<String>,
+}
+
+impl SessionHealth {
+    /// Returns `true` if the process runs and the extension answered.
+    #[inline]
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        !self.closed && self.process_running && self.connected && self.latency.is_some()
+    }
+}
+
+/// Session status reported by the extension.
+///
+/// Returned by [`Window::status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionStatus {
+    /// Whether the extension is ready to accept commands.
+    pub ready: bool,
+    /// WebDriver extension version.
+    pub extension_version: String,
+    /// Firefox version.
+    pub firefox_version: String,
+    /// Milliseconds since the extension connected.
+    pub uptime_ms: u64,
+}
+
+/// Resource usage of a window's Firefox process.
+///
+/// Returned by [`Window::resource_usage`]. Covers the main process and its
+/// direct children (content processes).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceUsage {
+    /// Resident set size in bytes.
+    pub rss_bytes: u64,
+    /// CPU usage since the previous call (or since spawn), where 100.0 is
+    /// one fully used core.
+    pub cpu_percent: f64,
+    /// JavaScript heap in use by the initial tab, from
+    /// `performance.memory`. `None` when the page does not expose it.
+    pub js_heap_bytes: Option<u64>,
+}
+
+/// Options for [`Window::new_tab_with_url`].
+///
+/// The default opens the tab in the background at the end of the tab strip.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NewTabOptions {
+    /// Select the new tab. Defaults to `false` so background tabs don't
+    /// steal focus.
+    pub active: bool,
+    /// Position in the tab strip; `None` appends the tab.
+    pub index: Option<usize>,
+}
+
+impl NewTabOptions {
+    /// Creates options for a background tab appended to the tab strip.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            active: false,
+            index: None,
+        }
+    }
+
+    /// Sets whether the new tab is selected.
+    #[inline]
+    #[must_use]
+    pub const fn with_active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Sets the new tab's position in the tab strip.
+    #[inline]
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+/// Window state for [`Window::set_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowState {
+    /// Regular, resizable window.
+    Normal,
+    /// Minimized to the taskbar or dock.
+    Minimized,
+    /// Maximized to fill the screen's work area.
+    Maximized,
+    /// Fullscreen, without browser UI.
+    Fullscreen,
+}
+
+impl WindowState {
+    /// Returns the `browser.windows` state name.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Minimized => "minimized",
+            Self::Maximized => "maximized",
+            Self::Fullscreen => "fullscreen",
+        }
+    }
+}
+
+/// Screen position and outer size of a browser window.
+///
+/// Returned by [`Window::get_bounds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowBounds {
+    /// Left edge in screen pixels.
+    pub x: i32,
+    /// Top edge in screen pixels.
+    pub y: i32,
+    /// Outer width in pixels.
+    pub width: u32,
+    /// Outer height in pixels.
+    pub height: u32,
+}
+
 /// Internal shared state for a window.
 pub(crate) struct WindowInner {
     /// Unique identifier for this window.
@@ -126,13 +366,48 @@ pub(crate) struct WindowInner {
     process: Mutex<ProcessGuard>,
     /// Connection pool (shared with Driver and other Windows).
     pub pool: Arc<ConnectionPool>,
-    /// Profile directory.
+    /// Profile directory (None for popups, which share the opener's).
     #[allow(dead_code)]
-    profile: Profile,
+    profile: Option<Profile>,
     /// All tabs in this window.
     tabs: Mutex<FxHashMap<TabId, Tab>>,
     /// The initial tab created when Firefox opens.
     pub initial_tab_id: TabId,
+    /// Opener window if this is a popup (shares its process and session).
+    opener: Option<Window>,
+    /// Whether the window has been closed.
+    closed: AtomicBool,
+    /// Active HAR captures by tab.
+    pub har_captures: Mutex<FxHashMap<TabId, HarCapture>>,
+    /// Active request logs by tab.
+    pub request_logs: Mutex<FxHashMap<TabId, RequestLogCapture>>,
+    /// Active subscriptions, intercepts and preload scripts, by their ID.
+    subscriptions: Mutex<FxHashMap<String, Subscription>>,
+    /// Directory finished downloads are moved to ([`Tab::move_downloads_to`]).
+    pub download_target: Mutex<Option<PathBuf>>,
+    /// Functions exposed to page JavaScript.
+    pub exposed_functions: ExposedFunctions,
+    /// Last CPU time sample, used to compute CPU usage between calls.
+    cpu_sample: Mutex<(Instant, Duration)>,
+    /// Last human-like mouse position by tab, where the next path starts.
+    mouse_positions: Mutex<FxHashMap<TabId, (i32, i32)>>,
+    /// Event handler and callback installed by [`Window::watch_opened_windows`].
+    opened_windows_handler: Mutex<Option<(EventHandlerId, WindowOpenedCallback)>>,
+    /// Event handlers added through this window (popups share the session).
+    event_handlers: Mutex<FxHashSet<EventHandlerId>>,
+}
+
+impl Drop for WindowInner {
+    fn drop(&mut self) {
+        // Popups share the opener's session; the opener owns its cleanup
+        if self.opener.is_some() || self.closed.load(Ordering::SeqCst) {
+            return;
+        }
+
+        debug!(uuid = %self.uuid, session_id = %self.session_id, "Window dropped without close");
+        self.pool.remove(self.session_id);
+        // ProcessGuard kills the process when dropped
+    }
 }
 
 // ============================================================================
@@ -216,9 +491,104 @@ impl Window {
                 session_id,
                 process: Mutex::new(ProcessGuard::new(process)),
                 pool,
-                profile,
+                profile: Some(profile),
+                tabs: Mutex::new(tabs),
+                initial_tab_id,
+                opener: None,
+                closed: AtomicBool::new(false),
+                har_captures: Mutex::new(FxHashMap::default()),
+                request_logs: Mutex::new(FxHashMap::default()),
+                subscriptions: Mutex::new(FxHashMap::default()),
+                download_target: Mutex::new(None),
+                exposed_functions: Arc::new(Mutex::new(FxHashMap::default())),
+                cpu_sample: Mutex::new((Instant::now(), Duration::ZERO)),
+                mouse_positions: Mutex::new(FxHashMap::default()),
+                opened_windows_handler: Mutex::new(None),
+                event_handlers: Mutex::new(FxHashSet::default()),
+            }),
+        }
+    }
+
+    /// Creates a window handle without a process or profile.
+    ///
+    /// Used for windows served by a [`TransportFactory`](crate::TransportFactory)
+    /// transport.
+    pub(crate) fn new_detached(
+        pool: Arc<ConnectionPool>,
+        session_id: SessionId,
+        initial_tab_id: TabId,
+    ) -> Self {
+        let uuid = Uuid::new_v4();
+        let initial_tab = Tab::new(initial_tab_id, FrameId::main(), session_id, None);
+        let mut tabs = FxHashMap::default();
+        tabs.insert(initial_tab_id, initial_tab);
+
+        debug!(uuid = %uuid, session_id = %session_id, tab_id = %initial_tab_id, "Detached window created");
+
+        Self {
+            inner: Arc::new(WindowInner {
+                uuid,
+                session_id,
+                process: Mutex::new(ProcessGuard::detached(0)),
+                pool,
+                profile: None,
                 tabs: Mutex::new(tabs),
                 initial_tab_id,
+                opener: None,
+                closed: AtomicBool::new(false),
+                har_captures: Mutex::new(FxHashMap::default()),
+                request_logs: Mutex::new(FxHashMap::default()),
+                subscriptions: Mutex::new(FxHashMap::default()),
+                download_target: Mutex::new(None),
+                exposed_functions: Arc::new(Mutex::new(FxHashMap::default())),
+                cpu_sample: Mutex::new((Instant::now(), Duration::ZERO)),
+                mouse_positions: Mutex::new(FxHashMap::default()),
+                opened_windows_handler: Mutex::new(None),
+                event_handlers: Mutex::new(FxHashSet::default()),
+            }),
+        }
+    }
+
+    /// Creates a handle for a popup window opened by `opener`.
+    ///
+    /// The popup lives in the opener's Firefox process and is reached
+    /// through the opener's session, addressed by its own tab ID.
+    pub(crate) fn new_popup(opener: &Window, tab_id: TabId) -> Self {
+        let uuid = Uuid::new_v4();
+        let session_id = opener.inner.session_id;
+        let initial_tab = Tab::new(tab_id, FrameId::main(), session_id, None);
+        let mut tabs = FxHashMap::default();
+        tabs.insert(tab_id, initial_tab);
+
+        debug!(
+            uuid = %uuid,
+            opener = %opener.inner.uuid,
+            session_id = %session_id,
+            tab_id = %tab_id,
+            "Popup window created"
+        );
+
+        Self {
+            inner: Arc::new(WindowInner {
+                uuid,
+                session_id,
+                process: Mutex::new(ProcessGuard::detached(opener.pid())),
+                pool: Arc::clone(&opener.inner.pool),
+                profile: None,
+                tabs: Mutex::new(tabs),
+                initial_tab_id: tab_id,
+                opener: Some(opener.clone()),
+                closed: AtomicBool::new(false),
+                har_captures: Mutex::new(FxHashMap::default()),
+                request_logs: Mutex::new(FxHashMap::default()),
+                subscriptions: Mutex::new(FxHashMap::default()),
+                download_target: Mutex::new(None),
+                // One handler per session serves every exposed function
+                exposed_functions: Arc::clone(&opener.inner.exposed_functions),
+                cpu_sample: Mutex::new((Instant::now(), Duration::ZERO)),
+                mouse_positions: Mutex::new(FxHashMap::default()),
+                opened_windows_handler: Mutex::new(None),
+                event_handlers: Mutex::new(FxHashSet::default()),
             }),
         }
     }
@@ -256,6 +626,42 @@ impl Window {
     pub fn pid(&self) -> u32 {
         self.inner.process.lock().pid()
     }
+
+    /// Returns the opener window if this window is a popup.
+    #[inline]
+    #[must_use]
+    pub fn opener(&self) -> Option<&Window> {
+        self.inner.opener.as_ref()
+    }
+
+    /// Returns where the last human-like mouse path in a tab ended.
+    pub(crate) fn mouse_position(&self, tab_id: TabId) -> (i32, i32) {
+        self.inner
+            .mouse_positions
+            .lock()
+            .get(&tab_id)
+            .copied()
+            .unwrap_or((0, 0))
+    }
+
+    /// Records where a human-like mouse path in a tab ended.
+    pub(crate) fn set_mouse_position(&self, tab_id: TabId, position: (i32, i32)) {
+        self.inner.mouse_positions.lock().insert(tab_id, position);
+    }
+
+    /// Returns `true` if [`Window::close`] has been called.
+    #[inline]
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.inner.closed.load(Ordering::SeqCst)
+    }
+
+    /// Returns `true` if this window was opened by a page (popup).
+    #[inline]
+    #[must_use]
+    pub fn is_popup(&self) -> bool {
+        self.inner.opener.is_some()
+    }
 }
 
 // ============================================================================
@@ -263,27 +669,106 @@ impl Window {
 // ============================================================================
 
 impl Window {
-    /// Closes the window and kills the Firefox process.
+    /// Closes this window without affecting the driver or other windows.
+    ///
+    /// Asks the extension to close the browser window, removes the session
+    /// from the connection pool and kills this window's Firefox process.
+    /// Other windows' connections are untouched. Calling `close` again is
+    /// a no-op.
+    ///
+    /// Dropping the last handle to a window without calling `close`
+    /// performs the same cleanup.
+    ///
+    /// For popup windows, only the popup is closed; the opener's process
+    /// and session are left running.
     ///
     /// # Errors
     ///
     /// Returns an error if the process cannot be killed.
-    #[allow(clippy::await_holding_lock)]
     pub async fn close(&self) -> Result<()> {
+        if self.inner.closed.swap(true, Ordering::SeqCst) {
+            debug!(uuid = %self.inner.uuid, "Window already closed");
+            return Ok(());
+        }
+
         debug!(uuid = %self.inner.uuid, "Closing window");
 
+        // Ask the extension to close the browser window (best effort)
+        let command = Command::BrowsingContext(BrowsingContextCommand::CloseWindow);
+        let request = Request::new(self.inner.initial_tab_id, FrameId::main(), command);
+        if let Err(e) = self
+            .inner
+            .pool
+            .send_with_timeout(self.inner.session_id, request, CLOSE_COMMAND_TIMEOUT)
+            .await
+        {
+            debug!(uuid = %self.inner.uuid, error = %e, "Close command failed");
+        }
+
+        if self.is_popup() {
+            info!(uuid = %self.inner.uuid, "Popup window closed");
+            return Ok(());
+        }
+
         // Remove from pool first
         self.inner.pool.remove(self.inner.session_id);
 
         // Kill process
-        let mut guard = self.inner.process.lock();
-        guard.kill().await?;
+        self.take_process().kill().await?;
 
         info!(uuid = %self.inner.uuid, "Window closed");
         Ok(())
     }
 }
 
+impl Window {
+    /// Asks Firefox to quit cleanly, killing it only after `timeout`.
+    ///
+    /// Unlike [`Window::close`], Firefox gets a chance to flush profile
+    /// state (cookies, storage) to disk, which matters for persistent
+    /// profiles. Popups are closed like [`Window::close`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if Firefox exited on its own, `false` if it had to be killed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process cannot be killed.
+    pub async fn close_graceful(&self, timeout: Duration) -> Result<bool> {
+        if self.is_popup() {
+            self.close().await?;
+            return Ok(true);
+        }
+
+        if self.inner.closed.swap(true, Ordering::SeqCst) {
+            debug!(uuid = %self.inner.uuid, "Window already closed");
+            return Ok(true);
+        }
+
+        debug!(uuid = %self.inner.uuid, timeout_ms = timeout.as_millis(), "Closing window gracefully");
+
+        // Ask the extension to close all browser windows so Firefox exits
+        let command = Command::Session(SessionCommand::Quit);
+        let request = Request::new(self.inner.initial_tab_id, FrameId::main(), command);
+        if let Err(e) = self
+            .inner
+            .pool
+            .send_with_timeout(self.inner.session_id, request, CLOSE_COMMAND_TIMEOUT)
+            .await
+        {
+            debug!(uuid = %self.inner.uuid, error = %e, "Quit command failed");
+        }
+
+        let clean = self.take_process().wait_or_kill(timeout).await;
+
+        self.inner.pool.remove(self.inner.session_id);
+
+        info!(uuid = %self.inner.uuid, clean, "Window closed");
+        Ok(clean)
+    }
+}
+
 // ============================================================================
 // Window - Tab Management
 // ============================================================================
@@ -307,16 +792,66 @@ impl Window {
     /// Returns an error if tab creation fails.
     pub async fn new_tab(&self) -> Result<Tab> {
         let command = Command::BrowsingContext(BrowsingContextCommand::NewTab);
-        let response = self.send_command(command).await?;
+        self.open_tab(command).await
+    }
 
-        let tab_id_u32 = response
-            .result
-            .as_ref()
-            .and_then(|v| v.get("tabId"))
-            .and_then(|v| v.as_u64())
-            .ok_or_else(|| Error::protocol("Expected tabId in NewTab response"))?;
+    /// Opens a new tab that loads `url`.
+    ///
+    /// Saves the separate [`Tab::goto`] round trip of [`Window::new_tab`].
+    /// Resolves once the page has loaded. By default the tab opens in the
+    /// background, so the current tab keeps focus.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL to load
+    /// * `opts` - Focus and tab-strip position
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tab creation or navigation fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::NewTabOptions;
+    ///
+    /// // Background tab, appended
+    /// let tab = window
+    ///     .new_tab_with_url("https://example.com", NewTabOptions::default())
+    ///     .await?;
+    ///
+    /// // Selected tab, first in the strip
+    /// let tab = window
+    ///     .new_tab_with_url(
+    ///         "https://example.org",
+    ///         NewTabOptions::new().with_active(true).with_index(0),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn new_tab_with_url(&self, url: &str, opts: NewTabOptions) -> Result<Tab> {
+        debug!(
+            session_id = %self.inner.session_id,
+            url,
+            active = opts.active,
+            index = ?opts.index,
+            "Opening tab with URL"
+        );
+
+        let command = Command::BrowsingContext(BrowsingContextCommand::CreateTab {
+            url: url.to_string(),
+            active: opts.active,
+            index: opts.index,
+        });
+        self.open_tab(command).await
+    }
 
-        let new_tab_id = TabId::new(tab_id_u32 as u32)
+    /// Sends a tab-creating command and tracks the returned tab.
+    async fn open_tab(&self, command: Command) -> Result<Tab> {
+        let response = self.send_command(command).await?;
+
+        let new_tab_id = u32::try_from(response.require_u64("tabId")?)
+            .ok()
+            .and_then(TabId::new)
             .ok_or_else(|| Error::protocol("Invalid tabId in NewTab response"))?;
 
         let tab = Tab::new(
@@ -326,7 +861,7 @@ impl Window {
             Some(self.clone()),
         );
 
-        self.inner.tabs.lock().insert(new_tab_id, tab.clone());
+        self.track_tab(new_tab_id);
         debug!(session_id = %self.inner.session_id, tab_id = %new_tab_id, "New tab created");
         Ok(tab)
     }
@@ -338,76 +873,742 @@ impl Window {
         self.inner.tabs.lock().len()
     }
 
-    /// Steals logs from extension (returns and clears).
+    /// Lists all open tabs in this window with their URL and title.
     ///
-    /// Useful for debugging extension issues.
-    pub async fn steal_logs(&self) -> Result<Vec<Value>> {
-        let command = Command::Session(SessionCommand::StealLogs);
+    /// # Errors
+    ///
+    /// Returns an error if the tab list cannot be retrieved.
+    pub async fn list_tabs(&self) -> Result<Vec<TabInfo>> {
+        debug!(session_id = %self.inner.session_id, "Listing tabs");
+
+        let command = Command::BrowsingContext(BrowsingContextCommand::ListTabs);
         let response = self.send_command(command).await?;
-        let logs = response
+
+        let tabs: Vec<TabInfo> = response
             .result
             .as_ref()
-            .and_then(|v| v.get("logs"))
+            .and_then(|v| v.get("tabs"))
             .and_then(|v| v.as_array())
-            .cloned()
+            .map(|arr| arr.iter().filter_map(parse_tab_info).collect())
             .unwrap_or_default();
-        Ok(logs)
-    }
-}
 
-// ============================================================================
-// Window - Proxy
-// ============================================================================
+        debug!(session_id = %self.inner.session_id, count = tabs.len(), "Listed tabs");
+        Ok(tabs)
+    }
 
-impl Window {
-    /// Sets a proxy for all tabs in this window.
+    /// Returns handles to all open tabs in this window, in tab-strip order.
     ///
-    /// Window-level proxy applies to all tabs unless overridden by tab-level proxy.
+    /// Includes tabs opened by the page (e.g. `target="_blank"` links),
+    /// not just those created via [`Window::new_tab`].
     ///
-    /// # Example
+    /// # Errors
     ///
-    /// ```ignore
-    /// use firefox_webdriver::ProxyConfig;
+    /// Returns an error if the tab list cannot be retrieved.
     ///
-    /// // HTTP proxy for all tabs
-    /// window.set_proxy(ProxyConfig::http("proxy.example.com", 8080)).await?;
+    /// # Example
     ///
-    /// // SOCKS5 proxy with auth
-    /// window.set_proxy(
-    ///     ProxyConfig::socks5("proxy.example.com", 1080)
-    ///         .with_credentials("user", "pass")
-    ///         .with_proxy_dns(true)
-    /// ).await?;
+    /// ```ignore
+    /// for tab in window.tabs().await? {
+    ///     println!("{}", tab.get_url().await?);
+    /// }
     /// ```
-    pub async fn set_proxy(&self, config: ProxyConfig) -> Result<()> {
-        debug!(
-            session_id = %self.inner.session_id,
-            proxy_type = %config.proxy_type.as_str(),
-            host = %config.host,
-            port = config.port,
-            "Setting window proxy"
-        );
+    pub async fn tabs(&self) -> Result<Vec<Tab>> {
+        let infos = self.list_tabs().await?;
 
-        let command = Command::Proxy(ProxyCommand::SetWindowProxy {
-            proxy_type: config.proxy_type.as_str().to_string(),
-            host: config.host,
-            port: config.port,
-            username: config.username,
-            password: config.password,
-            proxy_dns: config.proxy_dns,
-        });
+        let tabs: Vec<Tab> = infos
+            .iter()
+            .map(|info| {
+                Tab::new(
+                    info.tab_id,
+                    FrameId::main(),
+                    self.inner.session_id,
+                    Some(self.clone()),
+                )
+            })
+            .collect();
 
-        self.send_command(command).await?;
-        Ok(())
+        self.inner.tabs.lock().clear();
+        for tab in &tabs {
+            self.track_tab(tab.tab_id());
+        }
+
+        Ok(tabs)
     }
 
-    /// Clears the proxy for this window.
+    /// Returns the tab at the given zero-based index in tab-strip order.
     ///
-    /// After clearing, all tabs use direct connection (unless they have tab-level proxy).
-    pub async fn clear_proxy(&self) -> Result<()> {
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the index is out of range.
+    pub async fn tab_at(&self, index: usize) -> Result<Tab> {
+        let mut tabs = self.tabs().await?;
+        let count = tabs.len();
+
+        if index >= count {
+            return Err(Error::invalid_argument(format!(
+                "Tab index {} out of range ({} tabs open)",
+                index, count
+            )));
+        }
+
+        Ok(tabs.swap_remove(index))
+    }
+
+    /// Registers a callback for when a new tab opens in this window.
+    ///
+    /// Fires for tabs opened by the page (`window.open`, `target="_blank"`)
+    /// as well as those created via [`Window::new_tab`]. The new tab is
+    /// tracked by the window before the callback runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// window.on_new_tab(|tab| {
+    ///     println!("New tab opened: {}", tab.tab_id());
+    /// }).await?;
+    ///
+    /// tab.find_element(By::Css("a[target=_blank]")).await?.click().await?;
+    /// ```
+    pub async fn on_new_tab<F>(&self, callback: F) -> Result<()>
+    where
+        F: Fn(Tab) + Send + Sync + 'static,
+    {
+        debug!(session_id = %self.inner.session_id, "Subscribing to browsingContext.tabCreated");
+
+        // Weak reference so the handler does not keep the window alive
+        let inner = Arc::downgrade(&self.inner);
+        let callback = Arc::new(callback);
+
+        self.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "browsingContext.tabCreated" {
+                return None;
+            }
+
+            if let ParsedEvent::BrowsingContextTabCreated { tab_id, .. } = event.parse()
+                && let Some(tab_id) = TabId::new(tab_id)
+                && let Some(inner) = inner.upgrade()
+            {
+                let window = Window { inner };
+                let tab = Tab::new(
+                    tab_id,
+                    FrameId::main(),
+                    window.inner.session_id,
+                    Some(window.clone()),
+                );
+                window.track_tab(tab_id);
+                callback(tab);
+            }
+
+            None
+        }));
+
+        let command = Command::Session(SessionCommand::Subscribe {
+            events: vec!["browsingContext.tabCreated".to_string()],
+            selectors: None,
+        });
+        self.send_command(command).await?;
+        Ok(())
+    }
+
+    /// Registers a callback for when a tab in this window crashes.
+    ///
+    /// Fires when the extension sees a tab's content process crash
+    /// (`about:tabcrashed`) or the tab miss its heartbeat. Commands in
+    /// flight for that tab fail with
+    /// [`Error::BrowserCrashed`](crate::Error::BrowserCrashed) instead of
+    /// timing out, whether or not a callback is registered. A supervisor
+    /// can close and respawn the window from the callback.
+    ///
+    /// The callback is kept apart from event handlers, so clearing them
+    /// (e.g. in [`Window::reset`]) does not drop it. Calling `on_crash`
+    /// again replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    /// window.on_crash(move |crash| {
+    ///     let _ = tx.send(crash);
+    /// }).await?;
+    ///
+    /// if let Some(crash) = rx.recv().await {
+    ///     println!("Tab {} {}", crash.tab_id, crash.reason);
+    ///     window.close().await?;
+    /// }
+    /// ```
+    pub async fn on_crash<F>(&self, callback: F) -> Result<()>
+    where
+        F: Fn(CrashInfo) + Send + Sync + 'static,
+    {
+        debug!(session_id = %self.inner.session_id, "Subscribing to browsingContext.tabCrashed");
+
+        self.inner.pool.set_crash_handler(
+            self.inner.session_id,
+            Box::new(move |tab_id, reason| callback(CrashInfo { tab_id, reason })),
+        );
+
+        let command = Command::Session(SessionCommand::Subscribe {
+            events: vec!["browsingContext.tabCrashed".to_string()],
+            selectors: None,
+        });
+        self.send_command(command).await?;
+        Ok(())
+    }
+
+    /// Installs a handler that wraps page-opened windows as popup [`Window`]s.
+    ///
+    /// Replaces the handler of an earlier call.
+    pub(crate) async fn watch_opened_windows(&self, callback: WindowOpenedCallback) -> Result<()> {
+        debug!(session_id = %self.inner.session_id, "Subscribing to browsingContext.windowCreated");
+
+        let previous = self.add_opened_windows_handler(callback);
+        if let Some(previous) = previous {
+            self.remove_event_handler(previous);
+        }
+
+        let command = Command::Session(SessionCommand::Subscribe {
+            events: vec!["browsingContext.windowCreated".to_string()],
+            selectors: None,
+        });
+        self.send_command(command).await?;
+        Ok(())
+    }
+
+    /// Adds the event handler wrapping opened windows for `callback`.
+    ///
+    /// Returns the handler it supersedes, if any.
+    fn add_opened_windows_handler(&self, callback: WindowOpenedCallback) -> Option<EventHandlerId> {
+        // Weak reference so the handler does not keep the window alive
+        let inner = Arc::downgrade(&self.inner);
+        let handler_callback = Arc::clone(&callback);
+
+        let handler_id = self.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "browsingContext.windowCreated" {
+                return None;
+            }
+
+            if let ParsedEvent::BrowsingContextWindowCreated { tab_id, .. } = event.parse()
+                && let Some(tab_id) = TabId::new(tab_id)
+                && let Some(inner) = inner.upgrade()
+            {
+                let opener = Window { inner };
+                handler_callback(Window::new_popup(&opener, tab_id));
+            }
+
+            None
+        }));
+
+        self.inner
+            .opened_windows_handler
+            .lock()
+            .replace((handler_id, callback))
+            .map(|(previous, _)| previous)
+    }
+
+    /// Steals logs from extension (returns and clears).
+    ///
+    /// Useful for debugging extension issues.
+    pub async fn steal_logs(&self) -> Result<Vec<Value>> {
+        let command = Command::Session(SessionCommand::StealLogs);
+        let response = self.send_command(command).await?;
+        let logs = response
+            .result
+            .as_ref()
+            .and_then(|v| v.get("logs"))
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+        Ok(logs)
+    }
+
+    /// Steals logs from extension as structured records (returns and clears).
+    pub async fn steal_log_records(&self) -> Result<Vec<LogRecord>> {
+        let logs = self.steal_logs().await?;
+        Ok(logs.iter().filter_map(LogRecord::from_value).collect())
+    }
+
+    /// Streams extension log lines to a callback as they are produced.
+    ///
+    /// Unlike [`Window::steal_logs`], which returns a one-shot dump, each
+    /// line is pushed via the `session.log` event as soon as it is logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// window.subscribe_logs(|record| {
+    ///     eprintln!("[{}] [{}] {}", record.level, record.target, record.message);
+    /// }).await?;
+    /// ```
+    pub async fn subscribe_logs<F>(&self, callback: F) -> Result<()>
+    where
+        F: Fn(LogRecord) + Send + Sync + 'static,
+    {
+        debug!(session_id = %self.inner.session_id, "Subscribing to session.log");
+
+        let callback = Arc::new(callback);
+
+        self.add_event_handler(Box::new(move |event: Event| {
+            if event.method.as_str() != "session.log" {
+                return None;
+            }
+
+            if let Some(record) = LogRecord::from_value(&event.params) {
+                callback(record);
+            }
+
+            None
+        }));
+
+        let command = Command::Session(SessionCommand::Subscribe {
+            events: vec!["session.log".to_string()],
+            selectors: None,
+        });
+        self.send_command(command).await?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Window - Proxy
+// ============================================================================
+
+impl Window {
+    /// Sets a proxy for all tabs in this window.
+    ///
+    /// Window-level proxy applies to all tabs unless overridden by tab-level proxy.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::ProxyConfig;
+    ///
+    /// // HTTP proxy for all tabs
+    /// window.set_proxy(ProxyConfig::http("proxy.example.com", 8080)).await?;
+    ///
+    /// // SOCKS5 proxy with auth
+    /// window.set_proxy(
+    ///     ProxyConfig::socks5("proxy.example.com", 1080)
+    ///         .with_credentials("user", "pass")
+    ///         .with_proxy_dns(true)
+    /// ).await?;
+    /// ```
+    pub async fn set_proxy(&self, config: ProxyConfig) -> Result<()> {
+        debug!(
+            session_id = %self.inner.session_id,
+            proxy_type = %config.proxy_type.as_str(),
+            host = %config.host,
+            port = config.port,
+            "Setting window proxy"
+        );
+
+        let command = Command::Proxy(ProxyCommand::SetWindowProxy {
+            proxy_type: config.proxy_type.as_str().to_string(),
+            host: config.host,
+            port: config.port,
+            username: config.username,
+            password: config.password,
+            proxy_dns: config.proxy_dns,
+            bypass: config.bypass,
+        });
+
+        self.send_command(command).await?;
+        Ok(())
+    }
+
+    /// Clears the proxy for this window.
+    ///
+    /// After clearing, all tabs use direct connection (unless they have tab-level proxy).
+    pub async fn clear_proxy(&self) -> Result<()> {
         debug!(session_id = %self.inner.session_id, "Clearing window proxy");
         let command = Command::Proxy(ProxyCommand::ClearWindowProxy);
-        self.send_command(command).await?;
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Window - State
+// ============================================================================
+
+impl Window {
+    /// Sets the window's minimized/maximized/fullscreen state.
+    ///
+    /// Uses `browser.windows.update`. Minimizing windows that are only
+    /// scraped reduces compositor load when many windows are open; use
+    /// [`WindowState::Normal`] before screenshots for consistent sizes.
+    /// Headless windows accept the call but have no visible state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the window cannot be updated.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::WindowState;
+    ///
+    /// window.set_state(WindowState::Minimized).await?;
+    /// ```
+    pub async fn set_state(&self, state: WindowState) -> Result<()> {
+        debug!(session_id = %self.inner.session_id, state = state.as_str(), "Setting window state");
+        let command = Command::BrowsingContext(BrowsingContextCommand::SetWindowState {
+            state: state.as_str().to_string(),
+        });
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Returns the window's screen position and outer size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bounds cannot be retrieved.
+    pub async fn get_bounds(&self) -> Result<WindowBounds> {
+        debug!(session_id = %self.inner.session_id, "Getting window bounds");
+        let command = Command::BrowsingContext(BrowsingContextCommand::GetWindowBounds);
+        let result = self.send_command(command).await?.into_result()?;
+
+        let bounds = parse_window_bounds(&result)
+            .ok_or_else(|| Error::protocol("Invalid window bounds in response"))?;
+
+        debug!(session_id = %self.inner.session_id, ?bounds, "Got window bounds");
+        Ok(bounds)
+    }
+
+    /// Moves and resizes the window.
+    ///
+    /// A minimized, maximized or fullscreen window is restored to
+    /// [`WindowState::Normal`] first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the window cannot be updated.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Keep a background window off-screen
+    /// window.set_bounds(-2000, 0, 1280, 720).await?;
+    /// ```
+    pub async fn set_bounds(&self, x: i32, y: i32, width: u32, height: u32) -> Result<()> {
+        debug!(session_id = %self.inner.session_id, x, y, width, height, "Setting window bounds");
+        let command = Command::BrowsingContext(BrowsingContextCommand::SetWindowBounds {
+            x,
+            y,
+            width,
+            height,
+        });
+        self.send_command(command).await?.into_result()?;
+        Ok(())
+    }
+
+    /// Moves the window, keeping its size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the window cannot be updated.
+    pub async fn set_position(&self, x: i32, y: i32) -> Result<()> {
+        let bounds = self.get_bounds().await?;
+        self.set_bounds(x, y, bounds.width, bounds.height).await
+    }
+}
+
+// ============================================================================
+// Window - Health
+// ============================================================================
+
+impl Window {
+    /// Checks that the window's process is running and its extension responds.
+    ///
+    /// Returns `false` for closed windows, crashed processes, and sessions
+    /// that do not answer a status request within a few seconds.
+    pub async fn is_alive(&self) -> bool {
+        self.health().await.is_healthy()
+    }
+
+    /// Returns the session status reported by the extension.
+    ///
+    /// Confirms round-trip connectivity and reports versions, which are
+    /// worth including in bug reports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the extension reports one.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let status = window.status().await?;
+    /// println!(
+    ///     "extension {} on Firefox {}, up {}ms",
+    ///     status.extension_version, status.firefox_version, status.uptime_ms
+    /// );
+    /// ```
+    pub async fn status(&self) -> Result<SessionStatus> {
+        let command = Command::Session(SessionCommand::Status);
+        let result = self.send_command(command).await?.into_result()?;
+        Ok(parse_session_status(&result))
+    }
+
+    /// Queries versions and supported commands for [`Driver::capabilities`].
+    ///
+    /// Falls back to `session.status` (with no command list) for
+    /// extensions that predate `session.getCapabilities`.
+    pub(crate) async fn capabilities(&self) -> Result<Capabilities> {
+        let command = Command::Session(SessionCommand::GetCapabilities);
+        let response = self.send_command(command).await?;
+
+        if response.error.as_deref() == Some(UNKNOWN_COMMAND) {
+            debug!(session_id = %self.inner.session_id, "Extension has no capability reporting");
+            let status = self.status().await?;
+            return Ok(Capabilities {
+                driver_version: Driver::version().to_string(),
+                extension_version: status.extension_version,
+                firefox_version: status.firefox_version,
+                supported_commands: Vec::new(),
+            });
+        }
+
+        Ok(parse_capabilities(&response.into_result()?))
+    }
+
+    /// Returns health diagnostics for this window's session.
+    ///
+    /// Checks the process handle and connection first, and only sends a
+    /// `session.status` request (with a short timeout) if both look fine.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let health = window.health().await;
+    /// if !health.is_healthy() {
+    ///     eprintln!("window {} unhealthy: {:?}", window.session_id(), health);
+    /// }
+    /// ```
+    pub async fn health(&self) -> SessionHealth {
+        let closed = self.is_closed();
+        let process_running = !closed && !self.inner.process.lock().has_exited();
+        let connected = self.inner.pool.has_session(self.inner.session_id);
+
+        let mut health = SessionHealth {
+            closed,
+            process_running,
+            connected,
+            latency: None,
+            error: None,
+        };
+
+        if closed || !process_running || !connected {
+            return health;
+        }
+
+        let command = Command::Session(SessionCommand::Status);
+        let request = Request::new(self.inner.initial_tab_id, FrameId::main(), command);
+        let started = Instant::now();
+
+        match self
+            .inner
+            .pool
+            .send_with_timeout(self.inner.session_id, request, HEALTH_CHECK_TIMEOUT)
+            .await
+            .and_then(|response| response.into_result())
+        {
+            Ok(_) => health.latency = Some(started.elapsed()),
+            Err(e) => health.error = Some(e.to_string()),
+        }
+
+        debug!(session_id = %self.inner.session_id, ?health, "Session status");
+        health
+    }
+}
+
+// ============================================================================
+// Window - Resources
+// ============================================================================
+
+impl Window {
+    /// Returns memory and CPU usage of this window's Firefox process.
+    ///
+    /// RSS and CPU time come from the operating system and include content
+    /// processes. CPU usage is averaged over the time since the previous
+    /// call, or since the window was created on the first call. The JS heap
+    /// is read from `performance.memory` in the initial tab and is `None`
+    /// where Firefox does not expose it.
+    ///
+    /// Popup windows report their opener's process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the window is closed or process statistics
+    /// cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let usage = window.resource_usage().await?;
+    /// println!(
+    ///     "{} MiB, {:.1}% CPU",
+    ///     usage.rss_bytes / 1024 / 1024,
+    ///     usage.cpu_percent
+    /// );
+    /// ```
+    pub async fn resource_usage(&self) -> Result<ResourceUsage> {
+        debug!(session_id = %self.inner.session_id, "Reading resource usage");
+
+        if self.is_closed() {
+            return Err(Error::invalid_argument("Window is closed"));
+        }
+
+        let sample = process::sample(self.pid()).await?;
+
+        let cpu_percent = {
+            let now = Instant::now();
+            let mut last = self.inner.cpu_sample.lock();
+            let (last_at, last_cpu) = *last;
+            *last = (now, sample.cpu_time);
+
+            let wall = now.duration_since(last_at).as_secs_f64();
+            let cpu = sample.cpu_time.saturating_sub(last_cpu).as_secs_f64();
+            if wall > 0.0 { cpu / wall * 100.0 } else { 0.0 }
+        };
+
+        Ok(ResourceUsage {
+            rss_bytes: sample.rss_bytes,
+            cpu_percent,
+            js_heap_bytes: self.js_heap_bytes().await,
+        })
+    }
+
+    /// Reads `performance.memory.usedJSHeapSize` from the initial tab.
+    async fn js_heap_bytes(&self) -> Option<u64> {
+        let script = "return performance.memory ? performance.memory.usedJSHeapSize : null";
+        match self.tab().execute_script(script).await {
+            Ok(value) => value.as_u64(),
+            Err(e) => {
+                debug!(session_id = %self.inner.session_id, error = %e, "JS heap size unavailable");
+                None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Window - Reuse
+// ============================================================================
+
+impl Window {
+    /// Returns the window to a clean state for the next job.
+    ///
+    /// Stops intercepts, subscriptions, HAR captures, request logs and
+    /// preload scripts in the extension and removes their event handlers
+    /// and exposed functions; a [`Driver::on_window_opened`] callback stays
+    /// installed. Closes every tab except the initial one, then
+    /// clears its extra headers, network conditions, offline mode, block
+    /// rules, blocked resource types and media emulation and navigates it
+    /// to `about:blank`. Finally clears cookies, storage, cache and service
+    /// workers for all sites, and the window proxy.
+    ///
+    /// Only event handlers added through this window are removed, so popups
+    /// opened from it keep theirs; exposed functions are shared with popups
+    /// and cleared for them too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any step fails; the window should then be
+    /// discarded rather than reused. Popups cannot be reset, as they share
+    /// their opener's session and profile; reset the opener instead.
+    pub async fn reset(&self) -> Result<()> {
+        if self.is_popup() {
+            return Err(Error::invalid_argument(
+                "Popup windows cannot be reset; reset their opener",
+            ));
+        }
+
+        debug!(session_id = %self.inner.session_id, "Resetting window");
+
+        let tabs = self.tabs().await?;
+
+        // Registrations on tabs closed since are already gone
+        let subscriptions: Vec<Subscription> = self
+            .inner
+            .subscriptions
+            .lock()
+            .drain()
+            .map(|(_, subscription)| subscription)
+            .collect();
+        for subscription in subscriptions {
+            if tabs.iter().any(|tab| tab.tab_id() == subscription.tab_id) {
+                let request =
+                    Request::new(subscription.tab_id, FrameId::main(), subscription.teardown);
+                self.inner
+                    .pool
+                    .send(self.inner.session_id, request)
+                    .await?
+                    .into_result()?;
+            }
+        }
+
+        let watcher = self
+            .inner
+            .opened_windows_handler
+            .lock()
+            .as_ref()
+            .map(|(id, _)| *id);
+        let handlers: Vec<EventHandlerId> = self
+            .inner
+            .event_handlers
+            .lock()
+            .iter()
+            .copied()
+            .filter(|id| Some(*id) != watcher)
+            .collect();
+        for id in handlers {
+            self.remove_event_handler(id);
+        }
+        self.inner.har_captures.lock().clear();
+        self.inner.request_logs.lock().clear();
+        *self.inner.download_target.lock() = None;
+        self.inner.exposed_functions.lock().clear();
+        self.inner.mouse_positions.lock().clear();
+
+        for tab in tabs {
+            if tab.tab_id() != self.inner.initial_tab_id {
+                tab.close().await?;
+            }
+        }
+        self.inner.tabs.lock().clear();
+        self.track_tab(self.inner.initial_tab_id);
+
+        let tab = self.tab();
+        tab.clear_extra_headers().await?;
+        tab.clear_network_conditions().await?;
+        tab.set_offline(false).await?;
+        tab.clear_block_rules().await?;
+        tab.clear_blocked_resource_types().await?;
+        tab.clear_media_emulation().await?;
+        tab.goto("about:blank").await?;
+
+        let command = Command::Storage(StorageCommand::ClearBrowsingData {
+            data_types: ClearDataOptions::new().data_types(),
+            hostnames: Vec::new(),
+        });
+        self.send_command(command).await?.into_result()?;
+
+        self.clear_proxy().await?;
+
+        debug!(session_id = %self.inner.session_id, "Window reset");
         Ok(())
     }
 }
@@ -417,6 +1618,93 @@ impl Window {
 // ============================================================================
 
 impl Window {
+    /// Takes ownership of the process, leaving a detached guard behind.
+    ///
+    /// Lets callers await on the process without holding the lock, which
+    /// keeps close futures `Send`.
+    fn take_process(&self) -> ProcessGuard {
+        let mut guard = self.inner.process.lock();
+        let pid = guard.pid();
+        std::mem::replace(&mut *guard, ProcessGuard::detached(pid))
+    }
+
+    /// Adds an event handler for this window's session.
+    pub(crate) fn add_event_handler(&self, handler: EventHandler) -> EventHandlerId {
+        let id = self
+            .inner
+            .pool
+            .add_event_handler(self.inner.session_id, handler);
+        self.inner.event_handlers.lock().insert(id);
+        id
+    }
+
+    /// Removes an event handler added with [`Window::add_event_handler`].
+    pub(crate) fn remove_event_handler(&self, id: EventHandlerId) {
+        self.inner.event_handlers.lock().remove(&id);
+        self.inner
+            .pool
+            .remove_event_handler(self.inner.session_id, id);
+    }
+
+    /// Keeps a subscription's event handler until it is untracked.
+    ///
+    /// [`Window::reset`] sends `teardown` for subscriptions still active.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Subscription or intercept ID
+    /// * `tab_id` - Tab the subscription was made from
+    /// * `id` - Handler serving the subscription
+    /// * `teardown` - Command ending the subscription in the extension
+    pub(crate) fn track_subscription(
+        &self,
+        key: impl Into<String>,
+        tab_id: TabId,
+        id: EventHandlerId,
+        teardown: Command,
+    ) {
+        let subscription = Subscription {
+            tab_id,
+            handler_id: Some(id),
+            teardown,
+        };
+        self.inner
+            .subscriptions
+            .lock()
+            .insert(key.into(), subscription);
+    }
+
+    /// Keeps a preload script so [`Window::reset`] can remove it.
+    pub(crate) fn track_preload_script(&self, tab_id: TabId, script_id: &ScriptId) {
+        let subscription = Subscription {
+            tab_id,
+            handler_id: None,
+            teardown: Command::Script(ScriptCommand::RemovePreloadScript {
+                script_id: script_id.as_str().to_string(),
+            }),
+        };
+        self.inner
+            .subscriptions
+            .lock()
+            .insert(script_id.as_str().to_string(), subscription);
+    }
+
+    /// Forgets a subscription or preload script, removing its event handler.
+    pub(crate) fn untrack_subscription(&self, key: &str) {
+        let subscription = self.inner.subscriptions.lock().remove(key);
+        if let Some(id) = subscription.and_then(|subscription| subscription.handler_id) {
+            self.remove_event_handler(id);
+        }
+    }
+
+    /// Records a tab as open in this window.
+    ///
+    /// Tracked handles carry no window reference to avoid a reference cycle.
+    fn track_tab(&self, tab_id: TabId) {
+        let tab = Tab::new(tab_id, FrameId::main(), self.inner.session_id, None);
+        self.inner.tabs.lock().insert(tab_id, tab);
+    }
+
     /// Sends a command via the connection pool and waits for the response.
     pub(crate) async fn send_command(&self, command: Command) -> Result<Response> {
         let request = Request::new(self.inner.initial_tab_id, FrameId::main(), command);
@@ -424,6 +1712,65 @@ impl Window {
     }
 }
 
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parses a session status response.
+fn parse_session_status(value: &Value) -> SessionStatus {
+    let string = |key: &str| {
+        value
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    SessionStatus {
+        ready: value
+            .get("ready")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        extension_version: string("extensionVersion"),
+        firefox_version: string("firefoxVersion"),
+        uptime_ms: value.get("uptimeMs").and_then(|v| v.as_u64()).unwrap_or(0),
+    }
+}
+
+/// Parses a GetWindowBounds response.
+fn parse_window_bounds(value: &Value) -> Option<WindowBounds> {
+    let int = |key: &str| value.get(key).and_then(|v| v.as_i64());
+
+    Some(WindowBounds {
+        x: i32::try_from(int("x")?).ok()?,
+        y: i32::try_from(int("y")?).ok()?,
+        width: u32::try_from(int("width")?).ok()?,
+        height: u32::try_from(int("height")?).ok()?,
+    })
+}
+
+/// Parses a tab entry from a ListTabs response.
+fn parse_tab_info(value: &Value) -> Option<TabInfo> {
+    let tab_id = value
+        .get("tabId")
+        .and_then(|v| v.as_u64())
+        .and_then(|v| TabId::new(v as u32))?;
+
+    let url = value
+        .get("url")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let title = value
+        .get("title")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    Some(TabInfo { tab_id, url, title })
+}
+
 // ============================================================================
 // WindowBuilder
 // ============================================================================
@@ -450,8 +1797,8 @@ pub struct WindowBuilder<'a> {
     driver: &'a Driver,
     /// Firefox launch options.
     options: FirefoxOptions,
-    /// Optional custom profile path.
-    profile: Option<PathBuf>,
+    /// Profile source.
+    profile: ProfileSource,
 }
 
 // ============================================================================
@@ -460,11 +1807,28 @@ pub struct WindowBuilder<'a> {
 
 impl<'a> WindowBuilder<'a> {
     /// Creates a new window builder.
+    ///
+    /// Starts headless if the driver was built with
+    /// [`DriverBuilder::default_headless`](crate::DriverBuilder::default_headless),
+    /// then applies the driver's
+    /// [`DriverBuilder::default_window`](crate::DriverBuilder::default_window)
+    /// template.
     pub(crate) fn new(driver: &'a Driver) -> Self {
-        Self {
+        let options = if driver.inner.default_headless {
+            FirefoxOptions::headless()
+        } else {
+            FirefoxOptions::new()
+        };
+
+        let builder = Self {
             driver,
-            options: FirefoxOptions::new(),
-            profile: None,
+            options,
+            profile: ProfileSource::Temp,
+        };
+
+        match &driver.inner.window_template {
+            Some(template) => template(builder),
+            None => builder,
         }
     }
 
@@ -477,6 +1841,14 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    /// Shows the window, overriding
+    /// [`DriverBuilder::default_headless`](crate::DriverBuilder::default_headless).
+    #[must_use]
+    pub fn headful(mut self) -> Self {
+        self.options = self.options.with_headful();
+        self
+    }
+
     /// Sets the window size.
     ///
     /// # Arguments
@@ -489,14 +1861,178 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    /// Sets the window's screen position.
+    ///
+    /// Applied as soon as the window connects, before `spawn` returns.
+    /// Ignored for headless windows. Useful for tiling many visible
+    /// windows or parking them off-screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Left edge in screen pixels
+    /// * `y` - Top edge in screen pixels
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for i in 0..4 {
+    ///     driver
+    ///         .window()
+    ///         .window_size(640, 480)
+    ///         .position((i % 2) * 640, (i / 2) * 480)
+    ///         .spawn()
+    ///         .await?;
+    /// }
+    /// ```
+    #[must_use]
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.options = self.options.with_window_position(x, y);
+        self
+    }
+
+    /// Adds a command-line argument for Firefox.
+    ///
+    /// Flags the driver controls (`--profile`, `--no-remote`,
+    /// `--new-instance`) are rejected when the window spawns.
+    ///
+    /// # Arguments
+    ///
+    /// * `arg` - Argument passed verbatim to Firefox
+    #[must_use]
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.options = self.options.with_arg(arg);
+        self
+    }
+
+    /// Adds multiple command-line arguments for Firefox.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Arguments passed verbatim to Firefox
+    #[must_use]
+    pub fn args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.options = self.options.with_args(args);
+        self
+    }
+
+    /// Sets an environment variable on the Firefox process.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Variable name (e.g. `MOZ_HEADLESS_WIDTH`)
+    /// * `value` - Variable value
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options = self.options.with_env(key, value);
+        self
+    }
+
+    /// Sets a Firefox preference for this window only.
+    ///
+    /// Overrides [`DriverBuilder::pref`](crate::DriverBuilder::pref) values
+    /// with the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Preference name
+    /// * `value` - Preference value
+    #[must_use]
+    pub fn pref(mut self, key: impl Into<String>, value: impl Into<PreferenceValue>) -> Self {
+        self.options = self.options.with_pref(key, value);
+        self
+    }
+
+    /// Sets what Firefox does when pages request a permission.
+    ///
+    /// Writes the browser-wide default preference
+    /// ([`Permission::default_pref`]) into the profile, so it applies to
+    /// every site in the window and cannot be detected by pages.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{Permission, PermissionState};
+    ///
+    /// let window = driver
+    ///     .window()
+    ///     .permission(Permission::Notifications, PermissionState::Denied)
+    ///     .spawn()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn permission(mut self, permission: Permission, state: PermissionState) -> Self {
+        self.options = self.options.with_permission(permission, state);
+        self
+    }
+
+    /// Sets several permissions at once.
+    ///
+    /// See [`WindowBuilder::permission`].
+    #[must_use]
+    pub fn permissions(mut self, permissions: &[(Permission, PermissionState)]) -> Self {
+        for &(permission, state) in permissions {
+            self.options = self.options.with_permission(permission, state);
+        }
+        self
+    }
+
+    /// Sets the directory Firefox saves downloads to.
+    ///
+    /// Download preferences are read when Firefox starts, so this is set
+    /// here rather than on a tab. Without it, downloads go to the profile's
+    /// `downloads` directory. The directory is created at launch.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = driver
+    ///     .window()
+    ///     .download_dir("./downloads")
+    ///     .spawn()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn download_dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.options = self.options.with_download_dir(path);
+        self
+    }
+
+    /// Replaces all launch options.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Firefox launch options
+    #[must_use]
+    pub fn options(mut self, options: FirefoxOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Uses a custom profile directory.
     ///
+    /// The directory is used in place: the extension and preferences are
+    /// written into it.
+    ///
     /// # Arguments
     ///
     /// * `path` - Path to profile directory
     #[must_use]
     pub fn profile(mut self, path: impl Into<PathBuf>) -> Self {
-        self.profile = Some(path.into());
+        self.profile = ProfileSource::Existing(path.into());
+        self
+    }
+
+    /// Uses a copy of an existing profile directory.
+    ///
+    /// Keeps cookies and saved logins without modifying the original.
+    /// See [`Profile::clone_from`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the profile to copy
+    #[must_use]
+    pub fn clone_profile(mut self, path: impl Into<PathBuf>) -> Self {
+        self.profile = ProfileSource::Clone(path.into());
         self
     }
 
@@ -504,10 +2040,29 @@ impl<'a> WindowBuilder<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if window creation fails.
+    /// Returns [`Error::Config`](crate::Error::Config) if the options are
+    /// invalid, or another error if window creation fails.
     pub async fn spawn(self) -> Result<Window> {
         self.driver.spawn_window(self.options, self.profile).await
     }
+
+    /// Returns the options and profile source for spawning several
+    /// windows from this builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if an existing profile directory was set,
+    /// since only one Firefox process can use it.
+    pub(crate) fn into_shared(self) -> Result<(FirefoxOptions, ProfileSource)> {
+        if let ProfileSource::Existing(path) = &self.profile {
+            return Err(Error::config(format!(
+                "Profile {} cannot be shared by several windows; use clone_profile() instead",
+                path.display()
+            )));
+        }
+
+        Ok((self.options, self.profile))
+    }
 }
 
 // ============================================================================
@@ -516,7 +2071,19 @@ impl<'a> WindowBuilder<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::Window;
+    use super::{
+        SessionHealth, Window, WindowBounds, WindowBuilder, parse_session_status, parse_tab_info,
+        parse_window_bounds,
+    };
+
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use parking_lot::Mutex;
+    use serde_json::json;
+
+    use crate::identifiers::TabId;
+    use crate::{Driver, Error, MockTransport};
 
     #[test]
     fn test_window_is_clone() {
@@ -529,4 +2096,240 @@ mod tests {
         fn assert_debug<T: std::fmt::Debug>() {}
         assert_debug::<Window>();
     }
+
+    #[test]
+    fn test_session_health_requires_response() {
+        let mut health = SessionHealth {
+            closed: false,
+            process_running: true,
+            connected: true,
+            latency: None,
+            error: Some("Request timed out".to_string()),
+        };
+        assert!(!health.is_healthy());
+
+        health.latency = Some(Duration::from_millis(3));
+        health.error = None;
+        assert!(health.is_healthy());
+
+        health.process_running = false;
+        assert!(!health.is_healthy());
+    }
+
+    #[test]
+    fn test_parse_window_bounds() {
+        let bounds = parse_window_bounds(&json!({ "x": -8, "y": 0, "width": 1280, "height": 720 }));
+        assert_eq!(
+            bounds,
+            Some(WindowBounds {
+                x: -8,
+                y: 0,
+                width: 1280,
+                height: 720
+            })
+        );
+
+        assert_eq!(
+            parse_window_bounds(&json!({ "x": 0, "y": 0, "width": -1, "height": 720 })),
+            None
+        );
+        assert_eq!(parse_window_bounds(&json!({ "x": 0 })), None);
+    }
+
+    #[test]
+    fn test_parse_session_status() {
+        let value = json!({
+            "ready": true,
+            "extensionVersion": "0.1.1",
+            "firefoxVersion": "148.0",
+            "uptimeMs": 1234,
+        });
+        let status = parse_session_status(&value);
+        assert!(status.ready);
+        assert_eq!(status.extension_version, "0.1.1");
+        assert_eq!(status.firefox_version, "148.0");
+        assert_eq!(status.uptime_ms, 1234);
+    }
+
+    #[tokio::test]
+    async fn test_js_heap_bytes() {
+        let mock = MockTransport::new()
+            .respond("script.evaluate", json!({ "value": 4096 }))
+            .respond("script.evaluate", json!({ "value": null }))
+            .respond_error("script.evaluate", "script error", "Permission denied");
+        let driver = Driver::with_mock(mock);
+        let window = driver.window().spawn().await.expect("spawn");
+
+        assert_eq!(window.js_heap_bytes().await, Some(4096));
+        // Firefox without performance.memory returns null
+        assert_eq!(window.js_heap_bytes().await, None);
+        // Pages that refuse scripts report no heap instead of failing
+        assert_eq!(window.js_heap_bytes().await, None);
+    }
+
+    #[test]
+    fn test_parse_tab_info() {
+        let value = json!({ "tabId": 3, "url": "https://example.com", "title": "Example" });
+        let info = parse_tab_info(&value).expect("tab info");
+        assert_eq!(info.tab_id.as_u32(), 3);
+        assert_eq!(info.url, "https://example.com");
+        assert_eq!(info.title, "Example");
+    }
+
+    #[test]
+    fn test_parse_tab_info_rejects_zero_id() {
+        assert!(parse_tab_info(&json!({ "tabId": 0 })).is_none());
+    }
+
+    #[test]
+    fn test_window_template() {
+        fn template(builder: WindowBuilder<'_>) -> WindowBuilder<'_> {
+            builder.window_size(800, 600).headless().arg("--kiosk")
+        }
+
+        let mut driver = Driver::with_mock(MockTransport::new());
+        Arc::get_mut(&mut driver.inner)
+            .expect("unique driver")
+            .window_template = Some(Arc::new(template));
+
+        let builder = driver.window();
+        assert!(builder.options.headless);
+        assert_eq!(builder.options.window_size, Some((800, 600)));
+        assert_eq!(builder.options.extra_args, ["--kiosk"]);
+
+        // Per-window calls override the template
+        let builder = driver.window().window_size(1920, 1080).headful();
+        assert!(!builder.options.headless);
+        assert_eq!(builder.options.window_size, Some((1920, 1080)));
+
+        // Batch spawns and pools share the templated options
+        let (options, _) = driver.window().into_shared().expect("shared");
+        assert_eq!(options.window_size, Some((800, 600)));
+    }
+
+    #[test]
+    fn test_into_shared_rejects_existing_profile() {
+        let driver = Driver::with_mock(MockTransport::new());
+
+        let err = driver
+            .window()
+            .profile("/tmp/profile")
+            .into_shared()
+            .unwrap_err();
+        assert!(err.to_string().contains("clone_profile"));
+        assert!(
+            driver
+                .window()
+                .clone_profile("/tmp/profile")
+                .into_shared()
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn_windows() {
+        let driver = Driver::with_mock(MockTransport::new());
+
+        let windows = driver
+            .spawn_windows(3, driver.window())
+            .await
+            .expect("windows");
+        assert_eq!(windows.len(), 3);
+        assert_eq!(driver.window_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_reset_leaves_popups_alone() {
+        let mock = MockTransport::new()
+            .respond(
+                "browsingContext.listTabs",
+                json!({ "tabs": [{ "tabId": 1 }] }),
+            )
+            .respond("browsingContext.navigate", json!({}))
+            .respond("browsingContext.clearEmulatedMedia", json!({}))
+            .respond("network.clearExtraHeaders", json!({}))
+            .respond("network.clearNetworkConditions", json!({}))
+            .respond("network.setOffline", json!({}))
+            .respond("network.clearBlockRules", json!({}))
+            .respond("network.setBlockedResourceTypes", json!({}))
+            .respond("storage.clearBrowsingData", json!({}))
+            .respond("proxy.clearWindowProxy", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+        let popup = Window::new_popup(&window, TabId::new(5).expect("tab id"));
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for (owner, name) in [(&window, "opener"), (&popup, "popup")] {
+            let sink = Arc::clone(&seen);
+            owner.add_event_handler(Box::new(move |_| {
+                sink.lock().push(name);
+                None
+            }));
+        }
+
+        let err = popup.reset().await.unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument { .. }));
+        assert!(
+            !mock
+                .methods()
+                .iter()
+                .any(|m| m == "storage.clearBrowsingData")
+        );
+
+        window.reset().await.expect("reset");
+        mock.emit_event("test.event", json!({}));
+        assert_eq!(*seen.lock(), ["popup"]);
+    }
+
+    #[tokio::test]
+    async fn test_on_crash() {
+        let mock = MockTransport::new().respond("session.subscribe", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+
+        let crashes = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&crashes);
+        window
+            .on_crash(move |crash| sink.lock().push(crash))
+            .await
+            .expect("on_crash");
+
+        // Clearing event handlers leaves the crash callback in place
+        window
+            .inner
+            .pool
+            .clear_event_handlers(window.inner.session_id);
+
+        mock.emit_event(
+            "browsingContext.tabCrashed",
+            json!({ "tabId": 5, "reason": "unresponsive" }),
+        );
+        mock.emit_event("browsingContext.tabCreated", json!({ "tabId": 6 }));
+
+        let crashes = crashes.lock();
+        assert_eq!(crashes.len(), 1);
+        assert_eq!(crashes[0].tab_id.as_u32(), 5);
+        assert_eq!(crashes[0].reason, "unresponsive");
+    }
+
+    #[tokio::test]
+    async fn test_set_position_keeps_size() {
+        let mock = MockTransport::new()
+            .respond(
+                "browsingContext.getWindowBounds",
+                json!({ "x": 0, "y": 0, "width": 1280, "height": 720 }),
+            )
+            .respond("browsingContext.setWindowBounds", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let window = driver.window().spawn().await.expect("window");
+
+        window.set_position(-2000, 40).await.expect("set position");
+
+        let request = mock.requests().pop().expect("request");
+        let json = serde_json::to_value(&request.command).expect("serialize");
+        assert_eq!(json["method"], "browsingContext.setWindowBounds");
+        assert_eq!(json["params"]["x"], -2000);
+        assert_eq!(json["params"]["y"], 40);
+        assert_eq!(json["params"]["width"], 1280);
+    }
 }
@@ -0,0 +1,529 @@
+//! Reusable pool of browser windows.
+//!
+//! A [`WindowPool`] pre-spawns windows and hands them out as
+//! [`PooledWindow`]s. When a pooled window is dropped it is reset (see
+//! [`Window::reset`]) and returned to the pool; windows that fail the
+//! reset or a health check are replaced with fresh ones.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use firefox_webdriver::{Driver, WindowPool};
+//!
+//! # async fn example() -> firefox_webdriver::Result<()> {
+//! let driver = Driver::builder()
+//!     .binary("/usr/bin/firefox")
+//!     .extension("./extension")
+//!     .build()
+//!     .await?;
+//!
+//! let pool = WindowPool::new(&driver, 4, driver.window().headless()).await?;
+//!
+//! for url in ["https://example.com", "https://example.org"] {
+//!     let window = pool.acquire().await?;
+//!     window.tab().goto(url).await?;
+//!     // Dropping `window` resets it and returns it to the pool
+//! }
+//!
+//! pool.close().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, info};
+
+use crate::driver::core::{DEFAULT_MAX_CONCURRENT_SPAWNS, ProfileSource};
+use crate::driver::{Driver, FirefoxOptions};
+use crate::error::{Error, Result};
+
+use super::{Window, WindowBuilder};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Internal shared state for a window pool.
+struct WindowPoolInner {
+    /// Driver used to spawn replacement windows.
+    driver: Driver,
+    /// Launch options for every window in the pool.
+    options: FirefoxOptions,
+    /// Profile source for every window in the pool.
+    profile: ProfileSource,
+    /// Windows ready to be handed out.
+    idle: Mutex<Vec<Window>>,
+    /// Limits windows handed out to the pool size.
+    permits: Arc<Semaphore>,
+    /// Number of windows the pool maintains.
+    size: usize,
+}
+
+// ============================================================================
+// WindowPool
+// ============================================================================
+
+/// A fixed-size pool of reusable browser windows.
+///
+/// Cloning is cheap and shares the same pool.
+#[derive(Clone)]
+pub struct WindowPool {
+    inner: Arc<WindowPoolInner>,
+}
+
+impl fmt::Debug for WindowPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowPool")
+            .field("size", &self.inner.size)
+            .field("idle", &self.idle_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WindowPool {
+    /// Spawns `size` windows and creates a pool around them.
+    ///
+    /// # Arguments
+    ///
+    /// * `driver` - Driver used to spawn and replace windows
+    /// * `size` - Number of windows to keep (at least 1)
+    /// * `window` - Builder from [`Driver::window`] whose settings every
+    ///   window (including replacements) uses
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] if `window` uses an existing profile directory
+    /// - [`Error::SpawnFailed`] if any initial window fails to start
+    pub async fn new(driver: &Driver, size: usize, window: WindowBuilder<'_>) -> Result<Self> {
+        let size = size.max(1);
+        info!(size, "Creating window pool");
+
+        let (options, profile) = window.into_shared()?;
+        let windows = driver
+            .spawn_batch(size, &options, &profile, DEFAULT_MAX_CONCURRENT_SPAWNS)
+            .await?;
+
+        Ok(Self {
+            inner: Arc::new(WindowPoolInner {
+                driver: driver.clone(),
+                options,
+                profile,
+                idle: Mutex::new(windows),
+                permits: Arc::new(Semaphore::new(size)),
+                size,
+            }),
+        })
+    }
+
+    /// Takes a window from the pool, waiting if all are in use.
+    ///
+    /// The window is health-checked first; a crashed or unresponsive one is
+    /// closed and replaced with a freshly spawned window.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool was closed or a replacement window
+    /// fails to spawn.
+    pub async fn acquire(&self) -> Result<PooledWindow> {
+        let permit = Arc::clone(&self.inner.permits)
+            .acquire_owned()
+            .await
+            .map_err(|_| Error::invalid_argument("Window pool is closed"))?;
+
+        let idle = self.inner.idle.lock().pop();
+        let window = match idle {
+            Some(window) if window.is_alive().await => window,
+            Some(window) => {
+                info!(session_id = %window.session_id(), "Replacing unhealthy pooled window");
+                let _ = window.close().await;
+                self.inner.spawn().await?
+            }
+            None => self.inner.spawn().await?,
+        };
+
+        debug!(session_id = %window.session_id(), "Acquired pooled window");
+        Ok(PooledWindow {
+            window: Some(window),
+            pool: Arc::clone(&self.inner),
+            permit: Some(permit),
+        })
+    }
+
+    /// Returns the number of windows the pool maintains.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.inner.size
+    }
+
+    /// Returns the number of windows waiting to be acquired.
+    #[inline]
+    #[must_use]
+    pub fn idle_count(&self) -> usize {
+        self.inner.idle.lock().len()
+    }
+
+    /// Closes all idle windows and stops handing out new ones.
+    ///
+    /// Windows still acquired are closed when they are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any window fails to close.
+    pub async fn close(&self) -> Result<()> {
+        self.inner.permits.close();
+
+        let windows = std::mem::take(&mut *self.inner.idle.lock());
+        info!(count = windows.len(), "Closing window pool");
+
+        for window in windows {
+            window.close().await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl WindowPoolInner {
+    /// Spawns a window with the pool's options.
+    async fn spawn(&self) -> Result<Window> {
+        self.driver
+            .spawn_window(self.options.clone(), self.profile.clone())
+            .await
+    }
+
+    /// Resets a returned window and puts it back, closing it if reset fails.
+    async fn recycle(&self, window: Window) {
+        if self.permits.is_closed() {
+            let _ = window.close().await;
+            return;
+        }
+
+        match window.reset().await {
+            Ok(()) => self.idle.lock().push(window),
+            Err(e) => {
+                info!(
+                    session_id = %window.session_id(),
+                    error = %e,
+                    "Pooled window reset failed, discarding"
+                );
+                // A replacement is spawned on the next acquire
+                let _ = window.close().await;
+            }
+        }
+    }
+}
+
+// ============================================================================
+// PooledWindow
+// ============================================================================
+
+/// A window borrowed from a [`WindowPool`].
+///
+/// Dereferences to [`Window`]. On drop, the window is reset in the
+/// background and returned to the pool; use [`PooledWindow::release`] to
+/// wait for that and see reset errors.
+pub struct PooledWindow {
+    window: Option<Window>,
+    pool: Arc<WindowPoolInner>,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl fmt::Debug for PooledWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledWindow")
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PooledWindow {
+    /// Resets the window and returns it to the pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reset error; the window is then closed and replaced on
+    /// the next [`WindowPool::acquire`].
+    pub async fn release(mut self) -> Result<()> {
+        let (Some(window), permit) = (self.window.take(), self.permit.take()) else {
+            return Ok(());
+        };
+
+        let result = window.reset().await;
+        match &result {
+            Ok(()) if !self.pool.permits.is_closed() => self.pool.idle.lock().push(window),
+            _ => {
+                let _ = window.close().await;
+            }
+        }
+
+        drop(permit);
+        result
+    }
+
+    /// Closes the window instead of returning it to the pool.
+    ///
+    /// A replacement is spawned on the next [`WindowPool::acquire`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the window fails to close.
+    pub async fn discard(mut self) -> Result<()> {
+        let permit = self.permit.take();
+        let result = match self.window.take() {
+            Some(window) => window.close().await,
+            None => Ok(()),
+        };
+
+        drop(permit);
+        result
+    }
+}
+
+impl Deref for PooledWindow {
+    type Target = Window;
+
+    fn deref(&self) -> &Window {
+        self.window
+            .as_ref()
+            .expect("pooled window is present until released")
+    }
+}
+
+impl Drop for PooledWindow {
+    fn drop(&mut self) {
+        let Some(window) = self.window.take() else {
+            return;
+        };
+        let permit = self.permit.take();
+
+        // Without a runtime the window is dropped, which kills its process
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let pool = Arc::clone(&self.pool);
+            handle.spawn(async move {
+                pool.recycle(window).await;
+                drop(permit);
+            });
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::WindowPool;
+
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use serde_json::json;
+
+    use crate::browser::RequestAction;
+    use crate::driver::Driver;
+    use crate::error::Result;
+    use crate::identifiers::SessionId;
+    use crate::transport::{MockTransport, ReadyData, Transport, TransportFactory};
+
+    /// Serves each session from its own mock, so closing one window
+    /// leaves the others connected.
+    #[derive(Clone, Default)]
+    struct PerSessionFactory {
+        mocks: Arc<Mutex<Vec<MockTransport>>>,
+    }
+
+    impl PerSessionFactory {
+        fn mock(&self, index: usize) -> MockTransport {
+            self.mocks.lock()[index].clone()
+        }
+
+        fn connected(&self) -> usize {
+            self.mocks.lock().len()
+        }
+    }
+
+    #[async_trait]
+    impl TransportFactory for PerSessionFactory {
+        async fn connect(&self, session_id: SessionId) -> Result<(Arc<dyn Transport>, ReadyData)> {
+            let mock = MockTransport::new()
+                .respond("session.status", json!({ "ready": true }))
+                .respond("session.subscribe", json!({}))
+                .respond(
+                    "browsingContext.listTabs",
+                    json!({ "tabs": [{ "tabId": 1 }] }),
+                )
+                .respond("browsingContext.navigate", json!({}))
+                .respond("browsingContext.clearEmulatedMedia", json!({}))
+                .respond("network.clearExtraHeaders", json!({}))
+                .respond("network.clearNetworkConditions", json!({}))
+                .respond("network.setOffline", json!({}))
+                .respond("network.clearBlockRules", json!({}))
+                .respond("network.setBlockedResourceTypes", json!({}))
+                .respond("storage.clearBrowsingData", json!({}))
+                .respond("proxy.clearWindowProxy", json!({}));
+            self.mocks.lock().push(mock.clone());
+            mock.connect(session_id).await
+        }
+    }
+
+    async fn pool(size: usize) -> (WindowPool, PerSessionFactory) {
+        let factory = PerSessionFactory::default();
+        let driver = Driver::with_transport(factory.clone());
+        let pool = WindowPool::new(&driver, size, driver.window())
+            .await
+            .expect("pool");
+        (pool, factory)
+    }
+
+    #[tokio::test]
+    async fn test_acquire_and_release() {
+        let (pool, factory) = pool(2).await;
+        assert_eq!(pool.size(), 2);
+        assert_eq!(pool.idle_count(), 2);
+
+        let window = pool.acquire().await.expect("acquire");
+        let session_id = window.session_id();
+        assert_eq!(pool.idle_count(), 1);
+
+        window.release().await.expect("release");
+        assert_eq!(pool.idle_count(), 2);
+
+        let window = pool.acquire().await.expect("acquire");
+        assert_eq!(window.session_id(), session_id);
+        assert_eq!(factory.connected(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_replaces_dead_window() {
+        let (pool, factory) = pool(1).await;
+        factory.mock(0).shutdown();
+
+        let window = pool.acquire().await.expect("acquire");
+        assert_eq!(factory.connected(), 2);
+        assert!(window.is_alive().await);
+    }
+
+    #[tokio::test]
+    async fn test_release_resets_window() {
+        let (pool, factory) = pool(1).await;
+        let mock = factory
+            .mock(0)
+            .respond("network.addIntercept", json!({ "interceptId": "icpt-1" }))
+            .respond("network.removeIntercept", json!({}))
+            .respond("network.setExtraHeaders", json!({}))
+            .respond("script.addPreloadScript", json!({ "scriptId": "pre-1" }))
+            .respond("script.removePreloadScript", json!({}));
+
+        let window = pool.acquire().await.expect("acquire");
+        let tab = window.tab();
+        tab.intercept_request(|_| RequestAction::block())
+            .await
+            .expect("intercept");
+        tab.add_preload_script("window.marked = true")
+            .await
+            .expect("preload");
+        tab.set_extra_headers([("X-Job".to_string(), "1".to_string())].into())
+            .await
+            .expect("headers");
+        mock.clear_requests();
+
+        window.release().await.expect("release");
+
+        let methods = mock.methods();
+        for method in [
+            "network.removeIntercept",
+            "script.removePreloadScript",
+            "network.clearExtraHeaders",
+            "network.clearNetworkConditions",
+            "network.setOffline",
+            "network.clearBlockRules",
+            "network.setBlockedResourceTypes",
+            "browsingContext.clearEmulatedMedia",
+            "browsingContext.navigate",
+            "storage.clearBrowsingData",
+            "proxy.clearWindowProxy",
+        ] {
+            assert!(methods.iter().any(|m| m == method), "{method} not sent");
+        }
+
+        // The blocking intercept is gone, so requests are allowed again
+        let reply = mock
+            .emit_event(
+                "network.beforeRequestSent",
+                json!({ "requestId": "r1", "url": "https://example.com", "tabId": 1 }),
+            )
+            .expect("reply");
+        assert_eq!(reply.result, json!({ "action": "allow" }));
+        assert_eq!(pool.idle_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_release_keeps_popup_watcher() {
+        let factory = PerSessionFactory::default();
+        let driver = Driver::with_transport(factory.clone());
+        let opened = Arc::new(Mutex::new(0));
+        let counter = Arc::clone(&opened);
+        driver
+            .on_window_opened(move |_| *counter.lock() += 1)
+            .await
+            .expect("watch");
+
+        let pool = WindowPool::new(&driver, 1, driver.window())
+            .await
+            .expect("pool");
+        pool.acquire()
+            .await
+            .expect("acquire")
+            .release()
+            .await
+            .expect("release");
+
+        factory.mock(0).emit_event(
+            "browsingContext.windowCreated",
+            json!({ "windowId": 2, "tabId": 5 }),
+        );
+        assert_eq!(*opened.lock(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_reset_discards_window() {
+        let (pool, factory) = pool(1).await;
+
+        let window = pool.acquire().await.expect("acquire");
+        factory.mock(0).shutdown();
+        assert!(window.release().await.is_err());
+        assert_eq!(pool.idle_count(), 0);
+        assert!(factory.mock(0).is_closed());
+
+        pool.acquire().await.expect("replacement");
+        assert_eq!(factory.connected(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_reset_step_discards_window() {
+        let (pool, factory) = pool(1).await;
+        let window = pool.acquire().await.expect("acquire");
+
+        // The scripted success is consumed first, so reset gets the error
+        let _ = factory
+            .mock(0)
+            .respond_error("network.setOffline", "unknown error", "refused");
+        window.tab().set_offline(true).await.expect("offline");
+
+        assert!(window.release().await.is_err());
+        assert_eq!(pool.idle_count(), 0);
+        assert!(factory.mock(0).is_closed());
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This module generates the HTML page that Firefox loads on startup to
 //! establish the WebSocket connection between the browser extension and
-//! the Rust driver.
+//! the Rust driver, and holds the bundled extension when the
+//! `bundled-extension` feature is enabled.
 //!
 //! # Connection Flow
 //!
@@ -11,6 +12,7 @@
 //! 3. Content script receives message, validates localhost URL
 //! 4. Content script forwards to background script
 //! 5. Background script connects to WebSocket server
+//! 6. Background script sends the access token, if any, then READY
 
 // ============================================================================
 // Imports
@@ -34,13 +36,18 @@ use crate::identifiers::SessionId;
 ///
 /// * `ws_url` - WebSocket server URL (e.g., "ws://127.0.0.1:12345")
 /// * `session_id` - Session identifier for this window
+/// * `access_token` - Token the extension presents before READY, if any
 ///
 /// # Returns
 ///
 /// A `data:text/html,...` URI that can be passed to Firefox.
 #[must_use]
-pub fn build_init_data_uri(ws_url: &str, session_id: &SessionId) -> String {
-    let config_json = build_config_json(ws_url, session_id);
+pub fn build_init_data_uri(
+    ws_url: &str,
+    session_id: &SessionId,
+    access_token: Option<&str>,
+) -> String {
+    let config_json = build_config_json(ws_url, session_id, access_token);
     let html = build_init_html(ws_url, session_id, &config_json);
 
     format!("data:text/html,{}", urlencoding::encode(&html))
@@ -51,13 +58,17 @@ pub fn build_init_data_uri(ws_url: &str, session_id: &SessionId) -> String {
 // ============================================================================
 
 /// Builds the JSON configuration object for the extension.
-fn build_config_json(ws_url: &str, session_id: &SessionId) -> String {
-    let config = json!({
+fn build_config_json(ws_url: &str, session_id: &SessionId, access_token: Option<&str>) -> String {
+    let mut config = json!({
         "type": "WEBDRIVER_INIT",
         "wsUrl": ws_url,
         "sessionId": session_id.as_u32(),
     });
 
+    if let Some(token) = access_token {
+        config["accessToken"] = json!(token);
+    }
+
     config.to_string()
 }
 
@@ -75,6 +86,31 @@ fn build_init_html(ws_url: &str, session_id: &SessionId, config_json: &str) -> S
 // Constants
 // ============================================================================
 
+/// Extension `.xpi` embedded at build time.
+///
+/// Set when the crate is built with the `bundled-extension` feature, from the
+/// file named by the `FIREFOX_WEBDRIVER_EXTENSION_XPI` environment variable
+/// (resolved by `build.rs`).
+#[cfg(bundled_xpi)]
+pub const BUNDLED_EXTENSION: Option<&[u8]> =
+    Some(include_bytes!(env!("FIREFOX_WEBDRIVER_BUNDLED_XPI")));
+
+/// Extension `.xpi` embedded at build time.
+///
+/// `None` unless the crate is built with the `bundled-extension` feature and
+/// `FIREFOX_WEBDRIVER_EXTENSION_XPI` set; `build.rs` warns when it is unset.
+#[cfg(not(bundled_xpi))]
+pub const BUNDLED_EXTENSION: Option<&[u8]> = None;
+
+#[cfg(bundled_xpi_error)]
+compile_error!(concat!(
+    "`bundled-extension`: ",
+    env!("FIREFOX_WEBDRIVER_BUNDLED_XPI_ERROR")
+));
+
+/// Crate version, used to replace bundled extensions installed by other versions.
+pub const BUNDLED_EXTENSION_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// HTML template for the initialization page.
 ///
 /// This page displays connection info and posts `WEBDRIVER_INIT` message
@@ -120,7 +156,7 @@ mod tests {
     #[test]
     fn test_build_init_data_uri_format() {
         let session_id = SessionId::next();
-        let uri = build_init_data_uri("ws://127.0.0.1:12345", &session_id);
+        let uri = build_init_data_uri("ws://127.0.0.1:12345", &session_id, None);
 
         assert!(uri.starts_with("data:text/html,"));
         assert!(uri.len() > 100); // Should have substantial content
@@ -129,18 +165,28 @@ mod tests {
     #[test]
     fn test_build_config_json_structure() {
         let session_id = SessionId::next();
-        let json_str = build_config_json("ws://127.0.0.1:12345", &session_id);
+        let json_str = build_config_json("ws://127.0.0.1:12345", &session_id, None);
 
         let parsed: Value = serde_json::from_str(&json_str).expect("valid json");
         assert_eq!(parsed["type"], "WEBDRIVER_INIT");
         assert_eq!(parsed["wsUrl"], "ws://127.0.0.1:12345");
         assert!(parsed["sessionId"].is_number());
+        assert!(parsed.get("accessToken").is_none());
+    }
+
+    #[test]
+    fn test_build_config_json_access_token() {
+        let session_id = SessionId::next();
+        let json_str = build_config_json("ws://10.0.0.5:9000", &session_id, Some("secret"));
+
+        let parsed: Value = serde_json::from_str(&json_str).expect("valid json");
+        assert_eq!(parsed["accessToken"], "secret");
     }
 
     #[test]
     fn test_build_init_html_contains_required_elements() {
         let session_id = SessionId::next();
-        let config_json = build_config_json("ws://127.0.0.1:12345", &session_id);
+        let config_json = build_config_json("ws://127.0.0.1:12345", &session_id, None);
         let html = build_init_html("ws://127.0.0.1:12345", &session_id, &config_json);
 
         assert!(html.contains("<!DOCTYPE html>"));
@@ -153,7 +199,7 @@ mod tests {
     #[test]
     fn test_data_uri_is_url_encoded() {
         let session_id = SessionId::next();
-        let uri = build_init_data_uri("ws://127.0.0.1:12345", &session_id);
+        let uri = build_init_data_uri("ws://127.0.0.1:12345", &session_id, None);
 
         // URL encoding should escape special characters
         assert!(!uri.contains('<'));
@@ -21,12 +21,26 @@
 // Imports
 // ============================================================================
 
+use std::fmt;
+use std::net::IpAddr;
 use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
 
+use tokio::process::Command;
+use tracing::debug;
+
+use crate::browser::WindowBuilder;
+use crate::browser::window::WindowTemplate;
 use crate::error::{Error, Result};
+#[cfg(feature = "tls")]
+use crate::transport::TlsConfig;
+use crate::transport::connection::DEFAULT_MAX_PENDING_REQUESTS;
+use crate::transport::pool::{CommandMetric, CommandMetricCallback, PoolConfig};
 
+use super::assets::BUNDLED_EXTENSION;
 use super::core::Driver;
-use super::profile::ExtensionSource;
+use super::profile::{ExtensionSource, FirefoxPreference, PreferenceValue};
 
 // ============================================================================
 // DriverBuilder
@@ -35,12 +49,54 @@ use super::profile::ExtensionSource;
 /// Builder for configuring a [`Driver`] instance.
 ///
 /// Use [`Driver::builder()`] to create a new builder.
-#[derive(Debug, Default, Clone)]
+#[derive(Default, Clone)]
 pub struct DriverBuilder {
     /// Path to Firefox binary.
     binary: Option<PathBuf>,
     /// Extension source.
     extension: Option<ExtensionSource>,
+    /// Preferences merged onto the defaults.
+    prefs: Vec<FirefoxPreference>,
+    /// Global limit on concurrent commands.
+    max_inflight_commands: Option<usize>,
+    /// Outstanding request limit per window connection.
+    max_pending_per_connection: Option<usize>,
+    /// Callback run after each command completes.
+    on_command_metric: Option<CommandMetricCallback>,
+    /// Address the control WebSocket binds to.
+    bind_address: Option<(IpAddr, u16)>,
+    /// Token extensions must present before READY.
+    access_token: Option<String>,
+    /// Certificate for serving the control WebSocket over TLS.
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+    /// Whether windows start headless unless the window builder says otherwise.
+    default_headless: bool,
+    /// Settings applied to every window builder.
+    window_template: Option<WindowTemplate>,
+}
+
+impl fmt::Debug for DriverBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DriverBuilder")
+            .field("binary", &self.binary)
+            .field("extension", &self.extension)
+            .field("prefs", &self.prefs)
+            .field("max_inflight_commands", &self.max_inflight_commands)
+            .field(
+                "max_pending_per_connection",
+                &self.max_pending_per_connection,
+            )
+            .field("on_command_metric", &self.on_command_metric.is_some())
+            .field("bind_address", &self.bind_address)
+            .field("default_headless", &self.default_headless)
+            .field("window_template", &self.window_template.is_some())
+            .field(
+                "access_token",
+                &self.access_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish_non_exhaustive()
+    }
 }
 
 // ============================================================================
@@ -57,6 +113,9 @@ impl DriverBuilder {
 
     /// Sets the path to the Firefox binary executable.
     ///
+    /// Overrides auto-detection. When unset, [`build()`](Self::build)
+    /// searches `PATH` and the platform's standard install locations.
+    ///
     /// # Arguments
     ///
     /// * `path` - Path to Firefox binary (e.g., "/usr/bin/firefox")
@@ -97,6 +156,17 @@ impl DriverBuilder {
         self
     }
 
+    /// Uses the extension bundled with the crate.
+    ///
+    /// This is the default when no extension is set and the crate was built
+    /// with the `bundled-extension` feature.
+    #[inline]
+    #[must_use]
+    pub fn bundled_extension(mut self) -> Self {
+        self.extension = Some(ExtensionSource::Bundled);
+        self
+    }
+
     /// Sets the extension source directly.
     ///
     /// # Arguments
@@ -109,21 +179,351 @@ impl DriverBuilder {
         self
     }
 
+    /// Sets a Firefox preference for every window.
+    ///
+    /// Merged onto [`Profile::default_prefs`](super::Profile::default_prefs);
+    /// user values win, and a key set twice keeps the last value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Preference name (e.g., "privacy.resistFingerprinting")
+    /// * `value` - Preference value
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{Driver, PreferenceValue};
+    ///
+    /// let driver = Driver::builder()
+    ///     .binary("/usr/bin/firefox")
+    ///     .extension("./extension")
+    ///     .pref("privacy.resistFingerprinting", PreferenceValue::Bool(true))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pref(mut self, key: impl Into<String>, value: impl Into<PreferenceValue>) -> Self {
+        self.prefs.push(FirefoxPreference::new(key, value));
+        self
+    }
+
+    /// Sets multiple Firefox preferences for every window.
+    ///
+    /// Same merge rules as [`pref()`](Self::pref).
+    ///
+    /// # Arguments
+    ///
+    /// * `prefs` - Preferences to merge onto the defaults
+    #[inline]
+    #[must_use]
+    pub fn prefs(mut self, prefs: Vec<FirefoxPreference>) -> Self {
+        self.prefs.extend(prefs);
+        self
+    }
+
+    /// Sets whether windows spawn headless by default.
+    ///
+    /// [`WindowBuilder::headless`](crate::browser::WindowBuilder::headless) and
+    /// [`WindowBuilder::headful`](crate::browser::WindowBuilder::headful) override it
+    /// per window. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::builder()
+    ///     .binary("/usr/bin/firefox")
+    ///     .extension("./extension")
+    ///     .default_headless(true)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let scraper = driver.window().spawn().await?; // headless
+    /// let debug = driver.window().headful().spawn().await?; // visible
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn default_headless(mut self, headless: bool) -> Self {
+        self.default_headless = headless;
+        self
+    }
+
+    /// Sets a template applied to every [`Driver::window`] builder.
+    ///
+    /// Runs when the builder is created, after
+    /// [`default_headless`](Self::default_headless), so later calls on an
+    /// individual builder still override it. Keeps settings such as window
+    /// size consistent across many windows, including those spawned by
+    /// [`Driver::spawn_windows`] and [`WindowPool`](crate::WindowPool).
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Applies the shared settings to a window builder
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::builder()
+    ///     .binary("/usr/bin/firefox")
+    ///     .extension("./extension")
+    ///     .default_window(|w| w.window_size(1366, 768).headless().env("TZ", "UTC"))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let worker = driver.window().spawn().await?; // 1366x768, headless
+    /// let wide = driver.window().window_size(1920, 1080).spawn().await?;
+    /// ```
+    #[must_use]
+    pub fn default_window<F>(mut self, template: F) -> Self
+    where
+        F: for<'a> Fn(WindowBuilder<'a>) -> WindowBuilder<'a> + Send + Sync + 'static,
+    {
+        self.window_template = Some(Arc::new(template));
+        self
+    }
+
+    /// Limits concurrent commands across all windows.
+    ///
+    /// Once `n` commands are awaiting a response, further commands wait
+    /// for a slot instead of being sent, so hundreds of sessions cannot
+    /// overwhelm the extensions. Waiting counts towards each command's
+    /// timeout. Unlimited by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Maximum concurrent commands (at least 1)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::builder()
+    ///     .binary("/usr/bin/firefox")
+    ///     .extension("./extension")
+    ///     .max_inflight_commands(256)
+    ///     .build()
+    ///     .await?;
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn max_inflight_commands(mut self, n: usize) -> Self {
+        self.max_inflight_commands = Some(n);
+        self
+    }
+
+    /// Sets how many requests one window may have awaiting a response.
+    ///
+    /// Further commands on that window wait for a slot instead of failing;
+    /// waiting counts towards each command's timeout. Defaults to
+    /// [`DEFAULT_MAX_PENDING_REQUESTS`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Maximum outstanding requests per window (at least 1)
+    #[inline]
+    #[must_use]
+    pub fn max_pending_per_connection(mut self, n: usize) -> Self {
+        self.max_pending_per_connection = Some(n);
+        self
+    }
+
+    /// Registers a callback run after every command completes.
+    ///
+    /// The callback receives the session, protocol method, duration and
+    /// whether the extension reported success, for all windows of the
+    /// driver. It runs on the sending task, so keep it cheap (e.g. record
+    /// into a histogram). Without a callback, sends carry no extra cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Called with each [`CommandMetric`]
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::builder()
+    ///     .on_command_metric(|metric| {
+    ///         if metric.duration > Duration::from_secs(1) {
+    ///             eprintln!("slow {}: {:?}", metric.method, metric.duration);
+    ///         }
+    ///     })
+    ///     .build()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn on_command_metric<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&CommandMetric) + Send + Sync + 'static,
+    {
+        self.on_command_metric = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sets the address the control WebSocket binds to.
+    ///
+    /// Defaults to `127.0.0.1` on a random port. Bind a non-loopback IP
+    /// (or `0.0.0.0`) when extensions on other machines must connect, and
+    /// pair it with `tls` (`tls` feature); a plain `ws://` channel to another
+    /// host is unencrypted. Non-loopback binds require an
+    /// [`access_token`](Self::access_token). A wildcard bind is advertised
+    /// to locally launched Firefox as `127.0.0.1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - IP address to bind
+    /// * `port` - Port to bind (0 for random)
+    #[inline]
+    #[must_use]
+    pub fn bind_address(mut self, ip: IpAddr, port: u16) -> Self {
+        self.bind_address = Some((ip, port));
+        self
+    }
+
+    /// Sets the token extensions must present before READY.
+    ///
+    /// Each connection's first message must be
+    /// `{"type": "auth", "token": ...}` with this token; other connections
+    /// are closed with code 1008 (policy violation). Windows launched by
+    /// the driver receive the token automatically.
+    ///
+    /// With `None` (the default), a random token is generated when
+    /// [`bind_address`](Self::bind_address) is not a loopback address, and
+    /// loopback connections are not authenticated. Read the token with
+    /// [`Driver::access_token`].
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Shared secret, or `None` for the default
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::builder()
+    ///     .bind_address("0.0.0.0".parse()?, 9443)
+    ///     .access_token(Some(std::env::var("GRID_TOKEN")?))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn access_token(mut self, token: Option<String>) -> Self {
+        self.access_token = token;
+        self
+    }
+
+    /// Serves the control WebSocket over TLS (`wss://`).
+    ///
+    /// Requires the `tls` feature. Firefox validates the certificate, so it
+    /// must be issued for [`TlsConfig::host`] and trusted by the profile
+    /// (e.g. signed by a CA imported into it).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::builder()
+    ///     .bind_address("0.0.0.0".parse()?, 9443)
+    ///     .tls(TlsConfig::from_pem_files("grid.pem", "grid.key")?.with_host("grid.internal"))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "tls")]
+    #[inline]
+    #[must_use]
+    pub fn tls(mut self, config: TlsConfig) -> Self {
+        self.tls = Some(config);
+        self
+    }
+
     /// Builds the driver with validation.
     ///
-    /// This is an async operation because it binds the WebSocket server.
+    /// This is an async operation because it binds the WebSocket server, so
+    /// it must be awaited inside a Tokio runtime.
     ///
     /// # Errors
     ///
-    /// - [`Error::Config`] if binary or extension not set
+    /// - [`Error::Config`] if no binary was set and none could be detected
+    /// - [`Error::Config`] if extension not set and none is bundled
     /// - [`Error::FirefoxNotFound`] if binary path doesn't exist
     /// - [`Error::Config`] if extension path doesn't exist
     /// - [`Error::Io`] if WebSocket server binding fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firefox_webdriver::{Driver, Result};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let _driver = Driver::builder()
+    ///         .binary("/usr/bin/firefox")
+    ///         .extension("./extension")
+    ///         .build()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
     pub async fn build(self) -> Result<Driver> {
         let binary = self.validate_binary()?;
         let extension = self.validate_extension()?;
 
-        Driver::new(binary, extension).await
+        let max_pending = self
+            .max_pending_per_connection
+            .unwrap_or(DEFAULT_MAX_PENDING_REQUESTS);
+
+        let mut pool_config = PoolConfig {
+            max_inflight: self.max_inflight_commands,
+            max_pending,
+            on_command_metric: self.on_command_metric,
+            access_token: self.access_token,
+            #[cfg(feature = "tls")]
+            tls: self.tls,
+            ..PoolConfig::default()
+        };
+        if let Some((ip, port)) = self.bind_address {
+            pool_config.ip = ip;
+            pool_config.port = port;
+        }
+
+        Driver::new(
+            binary,
+            extension,
+            self.prefs,
+            self.default_headless,
+            self.window_template,
+            pool_config,
+        )
+        .await
+    }
+
+    /// Returns the version string reported by the Firefox binary.
+    ///
+    /// Runs `firefox --version` on the configured binary, or on the
+    /// auto-detected one if none was set. Useful for confirming which
+    /// Firefox [`build()`](Self::build) will launch.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] if no binary was set and none could be detected
+    /// - [`Error::FirefoxNotFound`] if binary path doesn't exist
+    /// - [`Error::ProcessLaunchFailed`] if the binary cannot be run
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let version = Driver::builder().firefox_version().await?;
+    /// println!("{version}"); // "Mozilla Firefox 128.0"
+    /// ```
+    pub async fn firefox_version(&self) -> Result<String> {
+        let binary = self.validate_binary()?;
+
+        let output = Command::new(&binary)
+            .arg("--version")
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .await
+            .map_err(Error::process_launch_failed)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 }
 
@@ -133,13 +533,18 @@ impl DriverBuilder {
 
 impl DriverBuilder {
     /// Validates the binary path configuration.
+    ///
+    /// Falls back to auto-detection when no binary was set.
     fn validate_binary(&self) -> Result<PathBuf> {
-        let binary = self.binary.clone().ok_or_else(|| {
-            Error::config(
-                "Firefox binary path is required. Use .binary() to set it.\n\
-                 Example: Driver::builder().binary(\"/usr/bin/firefox\")",
-            )
-        })?;
+        self.validate_binary_in(search_locations)
+    }
+
+    /// Validates the binary path, detecting it among `locations` if unset.
+    fn validate_binary_in(&self, locations: fn() -> Vec<PathBuf>) -> Result<PathBuf> {
+        let binary = match &self.binary {
+            Some(binary) => binary.clone(),
+            None => detect_binary(&locations())?,
+        };
 
         if !binary.exists() {
             return Err(Error::firefox_not_found(&binary));
@@ -149,13 +554,27 @@ impl DriverBuilder {
     }
 
     /// Validates the extension configuration.
+    ///
+    /// Falls back to the bundled extension when none was set.
     fn validate_extension(&self) -> Result<ExtensionSource> {
-        let extension = self.extension.clone().ok_or_else(|| {
-            Error::config(
-                "Extension is required. Use .extension() or .extension_base64() to set it.\n\
-                 Example: Driver::builder().extension(\"./extension\")",
-            )
-        })?;
+        let extension = match &self.extension {
+            Some(extension) => extension.clone(),
+            None if BUNDLED_EXTENSION.is_some() => ExtensionSource::Bundled,
+            None => {
+                return Err(Error::config(
+                    "Extension is required. Use .extension() or .extension_base64() to set it,\n\
+                     or build with the `bundled-extension` feature.\n\
+                     Example: Driver::builder().extension(\"./extension\")",
+                ));
+            }
+        };
+
+        if extension.is_bundled() && BUNDLED_EXTENSION.is_none() {
+            return Err(Error::config(
+                "Extension bundling is disabled. Build with the `bundled-extension` feature\n\
+                 and FIREFOX_WEBDRIVER_EXTENSION_XPI set, or use .extension() instead.",
+            ));
+        }
 
         // Validate file-based extensions exist
         if let Some(path) = extension.path()
@@ -172,6 +591,108 @@ impl DriverBuilder {
     }
 }
 
+// ============================================================================
+// Binary Detection
+// ============================================================================
+
+/// Executable names searched for in `PATH`.
+#[cfg(windows)]
+const BINARY_NAMES: &[&str] = &["firefox.exe"];
+
+/// Executable names searched for in `PATH`.
+#[cfg(not(windows))]
+const BINARY_NAMES: &[&str] = &["firefox", "firefox-esr"];
+
+/// Returns the first existing Firefox binary among `locations`.
+fn detect_binary(locations: &[PathBuf]) -> Result<PathBuf> {
+    match locations.iter().find(|path| path.is_file()) {
+        Some(binary) => {
+            debug!(binary = %binary.display(), "Detected Firefox binary");
+            Ok(binary.clone())
+        }
+        None => Err(not_detected_error(locations)),
+    }
+}
+
+/// Returns candidate binary paths, `PATH` entries first.
+fn search_locations() -> Vec<PathBuf> {
+    let mut locations = Vec::new();
+
+    if let Some(path) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&path) {
+            locations.extend(BINARY_NAMES.iter().map(|name| dir.join(name)));
+        }
+    }
+
+    locations.extend(platform_locations());
+    locations
+}
+
+/// Returns the platform's standard Firefox install locations.
+#[cfg(target_os = "linux")]
+fn platform_locations() -> Vec<PathBuf> {
+    [
+        "/usr/bin/firefox",
+        "/usr/bin/firefox-esr",
+        "/usr/local/bin/firefox",
+        "/usr/lib/firefox/firefox",
+        "/usr/lib64/firefox/firefox",
+        "/opt/firefox/firefox",
+        "/snap/bin/firefox",
+    ]
+    .into_iter()
+    .map(PathBuf::from)
+    .collect()
+}
+
+/// Returns the platform's standard Firefox install locations.
+#[cfg(target_os = "macos")]
+fn platform_locations() -> Vec<PathBuf> {
+    let app_binary = "Firefox.app/Contents/MacOS/firefox";
+    let mut locations = vec![PathBuf::from("/Applications").join(app_binary)];
+
+    if let Some(home) = std::env::var_os("HOME") {
+        locations.push(PathBuf::from(home).join("Applications").join(app_binary));
+    }
+
+    locations
+}
+
+/// Returns the platform's standard Firefox install locations.
+#[cfg(windows)]
+fn platform_locations() -> Vec<PathBuf> {
+    ["ProgramFiles", "ProgramFiles(x86)", "LOCALAPPDATA"]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .map(|root| {
+            PathBuf::from(root)
+                .join("Mozilla Firefox")
+                .join("firefox.exe")
+        })
+        .collect()
+}
+
+/// Returns the platform's standard Firefox install locations.
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn platform_locations() -> Vec<PathBuf> {
+    vec![PathBuf::from("/usr/local/bin/firefox")]
+}
+
+/// Builds the error returned when detection finds nothing.
+fn not_detected_error(locations: &[PathBuf]) -> Error {
+    let searched = locations
+        .iter()
+        .map(|path| format!("  {}", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Error::config(format!(
+        "Firefox binary not found. Searched:\n{searched}\n\
+         Use .binary() to set it.\n\
+         Example: Driver::builder().binary(\"/usr/bin/firefox\")"
+    ))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -194,6 +715,28 @@ mod tests {
         assert!(builder.extension.is_none());
     }
 
+    #[test]
+    fn test_on_command_metric_sets_callback() {
+        let builder = DriverBuilder::new().on_command_metric(|_| {});
+        assert!(builder.on_command_metric.is_some());
+        assert!(format!("{builder:?}").contains("on_command_metric: true"));
+    }
+
+    #[test]
+    fn test_default_headless() {
+        assert!(!DriverBuilder::new().default_headless);
+        let builder = DriverBuilder::new().default_headless(true);
+        assert!(builder.default_headless);
+        assert!(format!("{builder:?}").contains("default_headless: true"));
+    }
+
+    #[test]
+    fn test_default_window() {
+        let builder = DriverBuilder::new().default_window(|w| w.window_size(800, 600));
+        assert!(builder.window_template.is_some());
+        assert!(format!("{builder:?}").contains("window_template: true"));
+    }
+
     #[test]
     fn test_binary_sets_path() {
         let builder = DriverBuilder::new().binary("/usr/bin/firefox");
@@ -227,14 +770,36 @@ mod tests {
 
     #[test]
     fn test_build_fails_without_binary() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        let result = rt.block_on(DriverBuilder::new().extension("./extension").build());
+        let builder = DriverBuilder::new().extension("./extension");
+        let result = builder.validate_binary_in(Vec::new);
         assert!(result.is_err());
 
         let err = result.unwrap_err();
         assert!(err.to_string().contains("binary"));
     }
 
+    #[test]
+    fn test_search_locations_not_empty() {
+        assert!(!search_locations().is_empty());
+    }
+
+    #[test]
+    fn test_not_detected_error_lists_locations() {
+        let locations = [PathBuf::from("/a/firefox"), PathBuf::from("/b/firefox")];
+        let message = not_detected_error(&locations).to_string();
+
+        assert!(message.contains("binary"));
+        assert!(message.contains("/a/firefox"));
+        assert!(message.contains("/b/firefox"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_explicit_binary_overrides_detection() {
+        let builder = DriverBuilder::new().binary("/bin/sh");
+        assert_eq!(builder.validate_binary().unwrap(), PathBuf::from("/bin/sh"));
+    }
+
     #[test]
     fn test_build_fails_without_extension() {
         let rt = tokio::runtime::Runtime::new().unwrap();
@@ -258,6 +823,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    #[cfg(not(bundled_xpi))]
+    fn test_bundled_extension_requires_feature() {
+        let builder = DriverBuilder::new().bundled_extension();
+        let err = builder.validate_extension().unwrap_err();
+        assert!(err.to_string().contains("bundled-extension"));
+    }
+
+    #[test]
+    fn test_pref_accumulates_in_order() {
+        let builder = DriverBuilder::new()
+            .pref("a", true)
+            .prefs(vec![FirefoxPreference::new("b", 1)])
+            .pref("a", false);
+
+        let keys: Vec<_> = builder.prefs.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(keys, ["a", "b", "a"]);
+    }
+
     #[test]
     fn test_builder_is_clone() {
         let builder = DriverBuilder::new().binary("/usr/bin/firefox");
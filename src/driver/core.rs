@@ -27,22 +27,28 @@
 use std::fmt;
 use std::path::PathBuf;
 use std::process::Stdio;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
+use futures_util::future::join_all;
+use futures_util::stream::{self, StreamExt};
 use parking_lot::Mutex;
 use rustc_hash::FxHashMap;
+use serde_json::Value;
 use tokio::process::{Child, Command};
 use tracing::{debug, info};
 
+use crate::browser::window::{WindowInner, WindowOpenedCallback, WindowTemplate};
 use crate::browser::{Window, WindowBuilder};
 use crate::error::{Error, Result};
 use crate::identifiers::{SessionId, TabId};
-use crate::transport::ConnectionPool;
+use crate::transport::pool::PoolConfig;
+use crate::transport::{ConnectionPool, MockTransport, TransportFactory};
 
 use super::assets;
 use super::builder::DriverBuilder;
 use super::options::FirefoxOptions;
-use super::profile::{ExtensionSource, Profile};
+use super::profile::{ExtensionSource, FirefoxPreference, Profile};
 
 // ============================================================================
 // Types
@@ -56,11 +62,73 @@ pub(crate) struct DriverInner {
     /// Extension source for WebDriver functionality.
     pub extension: ExtensionSource,
 
+    /// User preferences merged onto the defaults.
+    pub prefs: Vec<FirefoxPreference>,
+
+    /// Whether [`WindowBuilder`] starts headless.
+    pub default_headless: bool,
+
+    /// Settings applied to every [`WindowBuilder`].
+    pub window_template: Option<WindowTemplate>,
+
     /// Connection pool for multiplexed WebSocket connections.
     pub pool: Arc<ConnectionPool>,
 
     /// Active windows tracked by their internal UUID.
-    pub windows: Mutex<FxHashMap<uuid::Uuid, Window>>,
+    ///
+    /// Held weakly so dropping the last `Window` handle cleans it up.
+    pub windows: Mutex<FxHashMap<uuid::Uuid, Weak<WindowInner>>>,
+
+    /// Callback for popup windows opened by automated pages.
+    pub window_opened: Mutex<Option<WindowOpenedCallback>>,
+
+    /// Capabilities reported by the first window queried.
+    pub capabilities: Mutex<Option<Capabilities>>,
+
+    /// Opens each window's transport instead of launching Firefox, when
+    /// created by [`Driver::with_transport`].
+    pub transport_factory: Option<Arc<dyn TransportFactory>>,
+}
+
+/// Versions and protocol support of the driver, extension and browser.
+///
+/// Returned by [`Driver::capabilities`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Version of this crate.
+    pub driver_version: String,
+    /// WebDriver extension version.
+    pub extension_version: String,
+    /// Firefox version.
+    pub firefox_version: String,
+    /// Command methods the extension handles (e.g. `"network.setOffline"`).
+    ///
+    /// Empty if the extension predates capability reporting.
+    pub supported_commands: Vec<String>,
+}
+
+impl Capabilities {
+    /// Returns `true` if the extension handles the command `method`.
+    #[inline]
+    #[must_use]
+    pub fn supports(&self, method: &str) -> bool {
+        self.supported_commands.iter().any(|m| m == method)
+    }
+}
+
+/// Default limit on concurrent launches for [`Driver::spawn_windows`].
+pub const DEFAULT_MAX_CONCURRENT_SPAWNS: usize = 16;
+
+/// Where a window's profile comes from.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum ProfileSource {
+    /// Fresh temporary profile.
+    #[default]
+    Temp,
+    /// Existing directory, used in place.
+    Existing(PathBuf),
+    /// Existing directory, copied into a temporary profile.
+    Clone(PathBuf),
 }
 
 // ============================================================================
@@ -136,6 +204,67 @@ impl Driver {
         DriverBuilder::new()
     }
 
+    /// Creates a driver whose windows talk to `mock` instead of Firefox.
+    ///
+    /// No browser is launched and no port is bound: every window spawned
+    /// from this driver sends its commands to the mock, which answers from
+    /// scripted responses. Use it to unit-test automation logic. Windows
+    /// start with tab ID 1; browser options passed to the window builder
+    /// are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use firefox_webdriver::{Driver, MockTransport};
+    /// use serde_json::json;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let mock = MockTransport::new()
+    ///     .respond("browsingContext.getUrl", json!({ "url": "https://example.com/" }));
+    ///
+    /// let driver = Driver::with_mock(mock);
+    /// let tab = driver.window().spawn().await?.tab();
+    /// assert_eq!(tab.get_url().await?, "https://example.com/");
+    /// # Ok::<(), firefox_webdriver::Error>(())
+    /// # }).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_mock(mock: MockTransport) -> Self {
+        Self::with_transport(mock)
+    }
+
+    /// Creates a driver whose windows use transports opened by `factory`.
+    ///
+    /// No browser is launched and no port is bound: each spawned window
+    /// gets its own transport from [`TransportFactory::connect`], e.g. a
+    /// Unix socket or message queue bridge to a remote extension. Browser
+    /// options passed to the window builder are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let driver = Driver::with_transport(UnixSocketFactory::new("/run/ff.sock"));
+    /// let window = driver.window().spawn().await?;
+    /// ```
+    #[must_use]
+    pub fn with_transport(factory: impl TransportFactory + 'static) -> Self {
+        let inner = Arc::new(DriverInner {
+            binary: PathBuf::new(),
+            extension: ExtensionSource::Unpacked(PathBuf::new()),
+            prefs: Vec::new(),
+            default_headless: false,
+            window_template: None,
+            pool: ConnectionPool::detached(None),
+            windows: Mutex::new(FxHashMap::default()),
+            window_opened: Mutex::new(None),
+            capabilities: Mutex::new(None),
+            transport_factory: Some(Arc::new(factory)),
+        });
+
+        info!("Driver initialized with custom transport");
+        Self { inner }
+    }
+
     /// Creates a window builder for spawning new browser windows.
     ///
     /// # Example
@@ -157,11 +286,161 @@ impl Driver {
         WindowBuilder::new(self)
     }
 
+    /// Spawns several windows concurrently.
+    ///
+    /// Uses at most [`DEFAULT_MAX_CONCURRENT_SPAWNS`] launches in flight.
+    /// See [`Driver::spawn_windows_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SpawnFailed`] if any window fails to start.
+    pub async fn spawn_windows(
+        &self,
+        count: usize,
+        window: WindowBuilder<'_>,
+    ) -> Result<Vec<Window>> {
+        self.spawn_windows_with(count, window, DEFAULT_MAX_CONCURRENT_SPAWNS)
+            .await
+    }
+
+    /// Spawns several windows concurrently with a concurrency limit.
+    ///
+    /// Every window is configured like `window`, a builder from
+    /// [`Driver::window`] (so the
+    /// [`DriverBuilder::default_window`](crate::DriverBuilder::default_window)
+    /// template applies). Each gets its own temporary profile, copied from
+    /// the builder's cloned profile if one was set. Up to `max_concurrent`
+    /// Firefox processes are launched and awaited at once, which bounds the
+    /// file descriptors and CPU used during startup. Returns once every
+    /// window is connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of windows to spawn
+    /// * `window` - Builder whose settings all windows share
+    /// * `max_concurrent` - Maximum launches in flight (at least 1)
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Config`] if `window` uses an existing profile directory,
+    ///   which only one window can use
+    /// - [`Error::SpawnFailed`] with the error of each failed window.
+    ///   Windows that did start are closed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let windows = driver
+    ///     .spawn_windows_with(300, driver.window().headless(), 20)
+    ///     .await?;
+    /// ```
+    pub async fn spawn_windows_with(
+        &self,
+        count: usize,
+        window: WindowBuilder<'_>,
+        max_concurrent: usize,
+    ) -> Result<Vec<Window>> {
+        let (options, profile) = window.into_shared()?;
+        self.spawn_batch(count, &options, &profile, max_concurrent)
+            .await
+    }
+
+    /// Spawns `count` windows sharing `options` and `profile`.
+    pub(crate) async fn spawn_batch(
+        &self,
+        count: usize,
+        options: &FirefoxOptions,
+        profile: &ProfileSource,
+        max_concurrent: usize,
+    ) -> Result<Vec<Window>> {
+        let max_concurrent = max_concurrent.max(1);
+        info!(count, max_concurrent, "Spawning windows");
+
+        let results: Vec<Result<Window>> = stream::iter(0..count)
+            .map(|_| self.spawn_window(options.clone(), profile.clone()))
+            .buffer_unordered(max_concurrent)
+            .collect()
+            .await;
+
+        let mut windows = Vec::with_capacity(count);
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(window) => windows.push(window),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(windows);
+        }
+
+        info!(
+            failed = errors.len(),
+            spawned = windows.len(),
+            "Batch spawn failed, closing spawned windows"
+        );
+        for result in join_all(windows.iter().map(Window::close)).await {
+            if let Err(e) = result {
+                debug!(error = %e, "Error closing window after failed batch spawn");
+            }
+        }
+
+        Err(Error::spawn_failed(count, errors))
+    }
+
     /// Returns the number of active windows currently tracked.
-    #[inline]
+    ///
+    /// Windows that were closed or dropped are not counted.
     #[must_use]
     pub fn window_count(&self) -> usize {
-        self.inner.windows.lock().len()
+        self.live_windows().len()
+    }
+
+    /// Registers a callback for windows opened by automated pages.
+    ///
+    /// When a page calls `window.open` (e.g. an OAuth login popup), the
+    /// extension reports the new window and the callback receives it as a
+    /// popup [`Window`]. Popups share their opener's process and session;
+    /// closing a popup leaves the opener running.
+    ///
+    /// Applies to all current windows and any spawned afterwards. Calling it
+    /// again replaces the previous callback; other event handlers (e.g.
+    /// network intercepts) keep running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if subscribing on an existing window fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    /// driver.on_window_opened(move |popup| {
+    ///     let _ = tx.send(popup);
+    /// }).await?;
+    ///
+    /// tab.find_element(By::Id("login-with-google")).await?.click().await?;
+    ///
+    /// let popup = rx.recv().await.unwrap();
+    /// popup.tab().wait_for_element(By::Id("identifierId")).await?;
+    /// popup.close().await?;
+    /// ```
+    pub async fn on_window_opened<F>(&self, callback: F) -> Result<()>
+    where
+        F: Fn(Window) + Send + Sync + 'static,
+    {
+        let callback: WindowOpenedCallback = Arc::new(callback);
+        *self.inner.window_opened.lock() = Some(Arc::clone(&callback));
+
+        let windows = self.live_windows();
+        debug!(count = windows.len(), "Watching windows for popups");
+
+        for window in windows {
+            window.watch_opened_windows(Arc::clone(&callback)).await?;
+        }
+
+        Ok(())
     }
 
     /// Closes all active windows and shuts down the driver.
@@ -172,7 +451,10 @@ impl Driver {
     pub async fn close(&self) -> Result<()> {
         let windows: Vec<Window> = {
             let mut map = self.inner.windows.lock();
-            map.drain().map(|(_, w)| w).collect()
+            map.drain()
+                .filter_map(|(_, weak)| weak.upgrade())
+                .map(|inner| Window { inner })
+                .collect()
         };
 
         info!(count = windows.len(), "Shutting down all windows");
@@ -189,12 +471,152 @@ impl Driver {
         Ok(())
     }
 
+    /// Closes all windows gracefully and shuts down the driver.
+    ///
+    /// Each Firefox instance is asked to quit cleanly so it can flush
+    /// cookies and other profile state, and is only killed if it has not
+    /// exited within `timeout`. Windows are closed concurrently, so the
+    /// whole call takes at most about `timeout`.
+    ///
+    /// # Returns
+    ///
+    /// Session IDs of windows that did not exit cleanly and were killed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if shutdown fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let killed = driver.close_graceful(Duration::from_secs(5)).await?;
+    /// if !killed.is_empty() {
+    ///     eprintln!("Force-killed sessions: {:?}", killed);
+    /// }
+    /// ```
+    pub async fn close_graceful(&self, timeout: Duration) -> Result<Vec<SessionId>> {
+        let windows: Vec<Window> = {
+            let mut map = self.inner.windows.lock();
+            map.drain()
+                .filter_map(|(_, weak)| weak.upgrade())
+                .map(|inner| Window { inner })
+                .collect()
+        };
+
+        info!(
+            count = windows.len(),
+            timeout_ms = timeout.as_millis(),
+            "Gracefully shutting down all windows"
+        );
+
+        let results = join_all(windows.iter().map(|w| w.close_graceful(timeout))).await;
+
+        let mut killed = Vec::new();
+        for (window, result) in windows.iter().zip(results) {
+            match result {
+                Ok(true) => {}
+                Ok(false) => killed.push(window.session_id()),
+                Err(e) => {
+                    debug!(error = %e, "Error closing window during graceful shutdown");
+                    killed.push(window.session_id());
+                }
+            }
+        }
+
+        // Shutdown the connection pool
+        self.inner.pool.shutdown().await;
+
+        if !killed.is_empty() {
+            info!(count = killed.len(), "Some windows were force-killed");
+        }
+
+        Ok(killed)
+    }
+
+    /// Returns the version of this crate.
+    #[inline]
+    #[must_use]
+    pub const fn version() -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    /// Returns the driver, extension and Firefox versions and the commands
+    /// the extension supports.
+    ///
+    /// Queried from the first open window and cached, since every window
+    /// runs the same Firefox binary and extension. Check
+    /// [`Capabilities::supports`] before using commands added in newer
+    /// extension versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if no window is open, or an error if the
+    /// query fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let window = driver.window().headless().spawn().await?;
+    /// let caps = driver.capabilities().await?;
+    /// println!(
+    ///     "driver {} / extension {} / Firefox {}",
+    ///     caps.driver_version, caps.extension_version, caps.firefox_version
+    /// );
+    ///
+    /// if caps.supports("network.setOffline") {
+    ///     window.tab().set_offline(true).await?;
+    /// }
+    /// ```
+    pub async fn capabilities(&self) -> Result<Capabilities> {
+        if let Some(capabilities) = self.inner.capabilities.lock().clone() {
+            return Ok(capabilities);
+        }
+
+        let window = self
+            .live_windows()
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::config("Driver::capabilities requires an open window"))?;
+
+        debug!(session_id = %window.session_id(), "Querying capabilities");
+        let capabilities = window.capabilities().await?;
+
+        debug!(
+            extension_version = %capabilities.extension_version,
+            firefox_version = %capabilities.firefox_version,
+            commands = capabilities.supported_commands.len(),
+            "Got capabilities"
+        );
+
+        *self.inner.capabilities.lock() = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
     /// Returns the WebSocket port used by the connection pool.
     #[inline]
     #[must_use]
     pub fn port(&self) -> u16 {
         self.inner.pool.port()
     }
+
+    /// Returns the token extensions must present to connect, if any.
+    ///
+    /// See [`DriverBuilder::access_token`](super::DriverBuilder::access_token).
+    #[inline]
+    #[must_use]
+    pub fn access_token(&self) -> Option<&str> {
+        self.inner.pool.access_token()
+    }
+
+    /// Returns the number of commands awaiting a response across all windows.
+    ///
+    /// Includes commands queued behind
+    /// [`DriverBuilder::max_inflight_commands`](super::DriverBuilder::max_inflight_commands).
+    #[inline]
+    #[must_use]
+    pub fn inflight_count(&self) -> usize {
+        self.inner.pool.inflight_count()
+    }
 }
 
 // ============================================================================
@@ -208,19 +630,36 @@ impl Driver {
     ///
     /// * `binary` - Path to Firefox binary
     /// * `extension` - Extension source for WebDriver
+    /// * `prefs` - User preferences merged onto the defaults
+    /// * `default_headless` - Whether window builders start headless
+    /// * `window_template` - Settings applied to every window builder
+    /// * `pool_config` - Bind address, limits, metrics and TLS for the pool
     ///
     /// # Errors
     ///
     /// Returns an error if initialization fails.
-    pub(crate) async fn new(binary: PathBuf, extension: ExtensionSource) -> Result<Self> {
+    pub(crate) async fn new(
+        binary: PathBuf,
+        extension: ExtensionSource,
+        prefs: Vec<FirefoxPreference>,
+        default_headless: bool,
+        window_template: Option<WindowTemplate>,
+        pool_config: PoolConfig,
+    ) -> Result<Self> {
         // Create connection pool (binds WebSocket server)
-        let pool = ConnectionPool::new().await?;
+        let pool = ConnectionPool::with_config(pool_config).await?;
 
         let inner = Arc::new(DriverInner {
             binary,
             extension,
+            prefs,
+            default_headless,
+            window_template,
             pool,
             windows: Mutex::new(FxHashMap::default()),
+            window_opened: Mutex::new(None),
+            capabilities: Mutex::new(None),
+            transport_factory: None,
         });
 
         info!(
@@ -236,11 +675,12 @@ impl Driver {
     /// # Arguments
     ///
     /// * `options` - Firefox launch options
-    /// * `custom_profile` - Optional custom profile path
+    /// * `profile_source` - Where the window's profile comes from
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - Options are invalid (e.g. a reserved argument was passed)
     /// - Profile creation fails
     /// - Extension installation fails
     /// - Firefox process fails to spawn
@@ -248,17 +688,33 @@ impl Driver {
     pub(crate) async fn spawn_window(
         &self,
         options: FirefoxOptions,
-        custom_profile: Option<PathBuf>,
+        profile_source: ProfileSource,
     ) -> Result<Window> {
+        options.validate().map_err(Error::config)?;
+
+        if let Some(factory) = &self.inner.transport_factory {
+            return self.spawn_transport_window(factory.as_ref()).await;
+        }
+
+        // Merge driver, then window preferences onto the defaults
+        let mut prefs = Profile::merge_prefs(
+            Profile::merge_prefs(Profile::default_prefs(), &self.inner.prefs),
+            &options.prefs,
+        );
+
         // Create profile
-        let profile = self.prepare_profile(custom_profile)?;
+        let profile = self
+            .prepare_profile(profile_source)?
+            .allow_unsigned(Profile::prefs_allow_unsigned(&prefs));
+
+        // Save downloads inside the profile unless the user chose a directory
+        profile.apply_download_dir(&mut prefs)?;
 
         // Install extension
         profile.install_extension(&self.inner.extension)?;
         debug!("Installed WebDriver extension");
 
         // Write preferences
-        let prefs = Profile::default_prefs();
         profile.write_prefs(&prefs)?;
         debug!(pref_count = prefs.len(), "Wrote profile preferences");
 
@@ -267,21 +723,17 @@ impl Driver {
 
         // Use pool's ws_url (same for all windows)
         let ws_url = self.inner.pool.ws_url();
-        let data_uri = assets::build_init_data_uri(&ws_url, &session_id);
+        let data_uri =
+            assets::build_init_data_uri(&ws_url, &session_id, self.inner.pool.access_token());
         debug!(session_id = %session_id, url = %ws_url, "Using shared WebSocket server");
 
         // Spawn Firefox process
-        let child = self.spawn_firefox_process(&profile, &options, &data_uri)?;
+        let mut child = self.spawn_firefox_process(&profile, &options, &data_uri)?;
         let pid = child.id();
         info!(pid, session_id = %session_id, "Firefox process spawned");
 
         // Wait for this specific session to connect via pool
-        let ready_data = self.inner.pool.wait_for_session(session_id).await?;
-        debug!(session_id = %session_id, "Session connected via pool");
-
-        // Extract tab ID from ready message
-        let tab_id = TabId::new(ready_data.tab_id)
-            .ok_or_else(|| Error::protocol("Invalid tab_id in READY message"))?;
+        let tab_id = self.wait_for_tab(session_id, &mut child).await?;
         debug!(session_id = %session_id, tab_id = %tab_id, "Browser IDs assigned");
 
         // Create window with pool reference
@@ -293,11 +745,10 @@ impl Driver {
             tab_id,
         );
 
-        // Track window
-        self.inner
-            .windows
-            .lock()
-            .insert(*window.uuid(), window.clone());
+        if let Err(e) = self.finish_window(&window, &options).await {
+            let _ = window.close().await;
+            return Err(e);
+        }
 
         info!(
             session_id = %session_id,
@@ -308,22 +759,125 @@ impl Driver {
         Ok(window)
     }
 
+    /// Waits for a spawned process to connect and returns its initial tab.
+    ///
+    /// Kills and reaps `child` if the session never becomes ready, so a
+    /// failed spawn does not leave an orphaned Firefox behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session does not connect or the READY
+    /// message carries an invalid tab ID.
+    async fn wait_for_tab(&self, session_id: SessionId, child: &mut Child) -> Result<TabId> {
+        let result = match self.inner.pool.wait_for_session(session_id).await {
+            Ok(ready_data) => TabId::new(ready_data.tab_id)
+                .ok_or_else(|| Error::protocol("Invalid tab_id in READY message")),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(tab_id) => {
+                debug!(session_id = %session_id, "Session connected via pool");
+                Ok(tab_id)
+            }
+            Err(e) => {
+                debug!(pid = child.id(), session_id = %session_id, "Killing Firefox process after failed connect");
+                if let Err(kill_err) = child.kill().await {
+                    debug!(error = %kill_err, "Failed to kill process");
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Registers a connected window and applies launch-time settings.
+    async fn finish_window(&self, window: &Window, options: &FirefoxOptions) -> Result<()> {
+        self.register_window(window).await?;
+
+        // Firefox has no position flag; move the window once connected
+        if let Some((x, y)) = options.window_position
+            && !options.headless
+        {
+            window.set_position(x, y).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a window served by a factory transport instead of Firefox.
+    async fn spawn_transport_window(&self, factory: &dyn TransportFactory) -> Result<Window> {
+        let session_id = SessionId::next();
+        let (transport, ready_data) = factory.connect(session_id).await?;
+
+        let tab_id = TabId::new(ready_data.tab_id)
+            .ok_or_else(|| Error::protocol("Invalid tab_id in READY message"))?;
+
+        self.inner.pool.insert(session_id, transport);
+        let window = Window::new_detached(Arc::clone(&self.inner.pool), session_id, tab_id);
+
+        self.register_window(&window).await?;
+
+        debug!(session_id = %session_id, tab_id = %tab_id, "Transport window spawned");
+        Ok(window)
+    }
+
+    /// Tracks a new window and reports its popups if a callback is set.
+    async fn register_window(&self, window: &Window) -> Result<()> {
+        self.inner
+            .windows
+            .lock()
+            .insert(*window.uuid(), Arc::downgrade(&window.inner));
+
+        let window_opened = self.inner.window_opened.lock().clone();
+        if let Some(callback) = window_opened {
+            window.watch_opened_windows(callback).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns handles to tracked windows that are still open.
+    ///
+    /// Prunes entries for windows that were closed or dropped.
+    fn live_windows(&self) -> Vec<Window> {
+        let mut map = self.inner.windows.lock();
+        let mut windows = Vec::with_capacity(map.len());
+
+        map.retain(|_, weak| match weak.upgrade() {
+            Some(inner) => {
+                let window = Window { inner };
+                let open = !window.is_closed();
+                if open {
+                    windows.push(window);
+                }
+                open
+            }
+            None => false,
+        });
+
+        windows
+    }
+
     /// Prepares a Firefox profile for the window.
     ///
     /// # Arguments
     ///
-    /// * `custom_profile` - Optional path to existing profile
+    /// * `source` - Temporary, existing or cloned profile
     ///
     /// # Errors
     ///
     /// Returns an error if profile creation fails.
-    fn prepare_profile(&self, custom_profile: Option<PathBuf>) -> Result<Profile> {
-        match custom_profile {
-            Some(path) => {
+    fn prepare_profile(&self, source: ProfileSource) -> Result<Profile> {
+        match source {
+            ProfileSource::Existing(path) => {
                 debug!(path = %path.display(), "Using custom profile");
                 Profile::from_path(path)
             }
-            None => {
+            ProfileSource::Clone(path) => {
+                debug!(path = %path.display(), "Cloning profile");
+                Profile::clone_from(path)
+            }
+            ProfileSource::Temp => {
                 debug!("Creating temporary profile");
                 Profile::new_temp()
             }
@@ -357,6 +911,7 @@ impl Driver {
 
         // User-specified options
         cmd.args(options.to_args());
+        cmd.envs(options.env.iter().map(|(key, value)| (key, value)));
 
         // Initial page
         cmd.arg(data_uri);
@@ -370,13 +925,137 @@ impl Driver {
     }
 }
 
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parses a `session.getCapabilities` result.
+pub(crate) fn parse_capabilities(value: &Value) -> Capabilities {
+    let string = |key: &str| {
+        value
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let supported_commands = value
+        .get("commands")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Capabilities {
+        driver_version: Driver::version().to_string(),
+        extension_version: string("extensionVersion"),
+        firefox_version: string("firefoxVersion"),
+        supported_commands,
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::Driver;
+    use super::{Driver, parse_capabilities};
+
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use serde_json::json;
+
+    use crate::error::Result;
+    use crate::identifiers::SessionId;
+    use crate::transport::{MockTransport, ReadyData, Transport, TransportFactory};
+
+    /// Serves sessions from a mock, starting at tab 7.
+    struct TabSevenFactory(MockTransport);
+
+    #[async_trait]
+    impl TransportFactory for TabSevenFactory {
+        async fn connect(&self, session_id: SessionId) -> Result<(Arc<dyn Transport>, ReadyData)> {
+            let ready = ReadyData {
+                tab_id: 7,
+                session_id: session_id.as_u32(),
+            };
+            Ok((Arc::new(self.0.clone()), ready))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_transport_uses_factory() {
+        let mock = MockTransport::new();
+        let driver = Driver::with_transport(TabSevenFactory(mock.clone()));
+
+        let window = driver.window().spawn().await.expect("spawn");
+        assert_eq!(window.tab().tab_id().as_u32(), 7);
+        assert_eq!(driver.window_count(), 1);
+
+        window.close().await.expect("close");
+        assert!(mock.is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_on_window_opened_replaces_callback() {
+        let mock = MockTransport::new().respond("session.subscribe", json!({}));
+        let driver = Driver::with_mock(mock.clone());
+        let _window = driver.window().spawn().await.expect("spawn");
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        for name in ["first", "second"] {
+            let calls = Arc::clone(&calls);
+            driver
+                .on_window_opened(move |_| calls.lock().push(name))
+                .await
+                .expect("watch");
+        }
+
+        mock.emit_event(
+            "browsingContext.windowCreated",
+            json!({ "windowId": 2, "tabId": 5 }),
+        );
+        assert_eq!(*calls.lock(), ["second"]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_failed_connect_kills_process() {
+        let driver = Driver::with_mock(MockTransport::new());
+        driver.inner.pool.shutdown().await;
+
+        let mut child = tokio::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+
+        let result = driver.wait_for_tab(SessionId::next(), &mut child).await;
+
+        assert!(result.is_err());
+        assert!(child.try_wait().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_parse_capabilities() {
+        let caps = parse_capabilities(&json!({
+            "extensionVersion": "0.2.0",
+            "firefoxVersion": "128.0",
+            "commands": ["session.status", "network.setOffline", 7]
+        }));
+
+        assert_eq!(caps.driver_version, Driver::version());
+        assert_eq!(caps.extension_version, "0.2.0");
+        assert_eq!(caps.firefox_version, "128.0");
+        assert_eq!(caps.supported_commands.len(), 2);
+        assert!(caps.supports("network.setOffline"));
+        assert!(!caps.supports("network.setBlockedResourceTypes"));
+    }
 
     #[test]
     fn test_builder_returns_driver_builder() {
@@ -48,6 +48,9 @@ pub mod core;
 /// Firefox browser options and preferences.
 pub mod options;
 
+/// Operating system process statistics.
+pub(crate) mod process;
+
 /// Firefox profile management.
 pub mod profile;
 
@@ -56,6 +59,6 @@ pub mod profile;
 // ============================================================================
 
 pub use builder::DriverBuilder;
-pub use core::Driver;
+pub use core::{Capabilities, Driver};
 pub use options::FirefoxOptions;
-pub use profile::{ExtensionSource, Profile};
+pub use profile::{ExtensionSource, FirefoxPreference, PreferenceValue, Profile};
@@ -17,6 +17,37 @@
 //! // ["--headless", "--window-size", "1920,1080", "--private-window"]
 //! ```
 
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::path::Path;
+
+use crate::browser::permissions::{Permission, PermissionState};
+
+use super::profile::{FirefoxPreference, PreferenceValue};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Flags the driver sets itself when spawning Firefox.
+///
+/// Passing these through [`FirefoxOptions::with_arg`] would detach Firefox
+/// from the driver's profile or WebSocket server.
+const RESERVED_ARGS: &[&str] = &[
+    "--profile",
+    "-profile",
+    "-P",
+    "--P",
+    "--ProfileManager",
+    "-ProfileManager",
+    "--no-remote",
+    "-no-remote",
+    "--new-instance",
+    "-new-instance",
+];
+
 // ============================================================================
 // FirefoxOptions
 // ============================================================================
@@ -33,6 +64,10 @@ pub struct FirefoxOptions {
     /// Window dimensions in pixels (width, height).
     pub window_size: Option<(u32, u32)>,
 
+    /// Window screen position in pixels (x, y), applied once the window
+    /// connects. Ignored in headless mode.
+    pub window_position: Option<(i32, i32)>,
+
     /// Enable kiosk mode (fullscreen with restricted UI).
     pub kiosk: bool,
 
@@ -44,6 +79,12 @@ pub struct FirefoxOptions {
 
     /// Additional custom command-line arguments.
     pub extra_args: Vec<String>,
+
+    /// Environment variables set on the Firefox process.
+    pub env: Vec<(String, String)>,
+
+    /// Preferences written to this window's profile, over the driver's.
+    pub prefs: Vec<FirefoxPreference>,
 }
 
 // ============================================================================
@@ -58,10 +99,13 @@ impl FirefoxOptions {
         Self {
             headless: false,
             window_size: None,
+            window_position: None,
             kiosk: false,
             devtools: false,
             private: false,
             extra_args: Vec::new(),
+            env: Vec::new(),
+            prefs: Vec::new(),
         }
     }
 
@@ -89,6 +133,14 @@ impl FirefoxOptions {
         self
     }
 
+    /// Disables headless mode, showing the window.
+    #[inline]
+    #[must_use]
+    pub fn with_headful(mut self) -> Self {
+        self.headless = false;
+        self
+    }
+
     /// Sets window size in pixels.
     #[inline]
     #[must_use]
@@ -97,6 +149,18 @@ impl FirefoxOptions {
         self
     }
 
+    /// Sets the window's screen position in pixels.
+    ///
+    /// Firefox has no command-line flag for position, so the driver moves
+    /// the window with `browser.windows.update` as soon as it connects.
+    /// Negative or large values place it off-screen.
+    #[inline]
+    #[must_use]
+    pub fn with_window_position(mut self, x: i32, y: i32) -> Self {
+        self.window_position = Some((x, y));
+        self
+    }
+
     /// Enables kiosk mode.
     #[inline]
     #[must_use]
@@ -136,6 +200,66 @@ impl FirefoxOptions {
         self.extra_args.extend(args.into_iter().map(Into::into));
         self
     }
+
+    /// Sets an environment variable on the Firefox process.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let options = FirefoxOptions::new()
+    ///     .with_headless()
+    ///     .with_env("MOZ_HEADLESS_WIDTH", "1920")
+    ///     .with_env("MOZ_HEADLESS_HEIGHT", "1080");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets a preference in this window's profile.
+    ///
+    /// Applied after [`DriverBuilder::pref`](crate::DriverBuilder::pref)
+    /// values, so it overrides them for this window.
+    #[inline]
+    #[must_use]
+    pub fn with_pref(mut self, key: impl Into<String>, value: impl Into<PreferenceValue>) -> Self {
+        self.prefs.push(FirefoxPreference::new(key, value));
+        self
+    }
+
+    /// Sets Firefox's default for a permission, for every site.
+    ///
+    /// Writes the permission's [`Permission::default_pref`] into the
+    /// profile, so Firefox itself allows, blocks or prompts for requests
+    /// without page-visible patching.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use firefox_webdriver::{FirefoxOptions, Permission, PermissionState};
+    ///
+    /// let options = FirefoxOptions::headless()
+    ///     .with_permission(Permission::Geolocation, PermissionState::Granted);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_permission(self, permission: Permission, state: PermissionState) -> Self {
+        self.with_pref(permission.default_pref(), state.pref_value())
+    }
+
+    /// Sets the directory Firefox saves downloads to.
+    ///
+    /// Writes `browser.download.dir` into the profile. The directory is
+    /// created at launch and a relative path is resolved against the
+    /// current directory.
+    #[inline]
+    #[must_use]
+    pub fn with_download_dir(self, path: impl AsRef<Path>) -> Self {
+        let dir = path.as_ref().to_string_lossy().into_owned();
+        self.with_pref("browser.download.dir", dir)
+    }
 }
 
 // ============================================================================
@@ -177,13 +301,32 @@ impl FirefoxOptions {
     ///
     /// # Errors
     ///
-    /// Returns error message if validation fails.
+    /// Returns error message if validation fails, including when a custom
+    /// argument would override a flag the driver controls (profile path,
+    /// remote and instance flags).
     pub fn validate(&self) -> Result<(), String> {
         if let Some((width, height)) = self.window_size
             && (width == 0 || height == 0)
         {
             return Err("Window dimensions must be greater than zero".to_string());
         }
+
+        if let Some(arg) = self.extra_args.iter().find(|arg| is_reserved_arg(arg)) {
+            return Err(format!(
+                "Argument '{arg}' is reserved by the driver and cannot be overridden"
+            ));
+        }
+
+        if self
+            .env
+            .iter()
+            .any(|(key, _)| key.is_empty() || key.contains('='))
+        {
+            return Err(
+                "Environment variable names must be non-empty and contain no '='".to_string(),
+            );
+        }
+
         Ok(())
     }
 
@@ -202,6 +345,18 @@ impl FirefoxOptions {
     }
 }
 
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Returns `true` if `arg` is, or assigns, a reserved flag.
+fn is_reserved_arg(arg: &str) -> bool {
+    let flag = arg.split_once('=').map_or(arg, |(flag, _)| flag);
+    RESERVED_ARGS
+        .iter()
+        .any(|reserved| flag.eq_ignore_ascii_case(reserved))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -215,10 +370,12 @@ mod tests {
         let options = FirefoxOptions::new();
         assert!(!options.headless);
         assert!(options.window_size.is_none());
+        assert!(options.window_position.is_none());
         assert!(!options.kiosk);
         assert!(!options.devtools);
         assert!(!options.private);
         assert!(options.extra_args.is_empty());
+        assert!(options.env.is_empty());
     }
 
     #[test]
@@ -228,6 +385,16 @@ mod tests {
         assert!(options.is_headless());
     }
 
+    #[test]
+    fn test_headful_and_position() {
+        let options = FirefoxOptions::headless()
+            .with_headful()
+            .with_window_position(-2000, 40);
+        assert!(!options.is_headless());
+        assert_eq!(options.window_position, Some((-2000, 40)));
+        assert!(!options.to_args().iter().any(|arg| arg.contains("2000")));
+    }
+
     #[test]
     fn test_builder_chain() {
         let options = FirefoxOptions::new()
@@ -298,4 +465,64 @@ mod tests {
         let options = FirefoxOptions::new().with_window_size(800, 0);
         assert!(options.validate().is_err());
     }
+
+    #[test]
+    fn test_with_env() {
+        let options = FirefoxOptions::new().with_env("MOZ_HEADLESS_WIDTH", "1920");
+        assert_eq!(
+            options.env,
+            vec![("MOZ_HEADLESS_WIDTH".to_string(), "1920".to_string())]
+        );
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_permission() {
+        let options = FirefoxOptions::new()
+            .with_permission(Permission::Camera, PermissionState::Denied)
+            .with_permission(Permission::Geolocation, PermissionState::Granted);
+
+        assert_eq!(
+            options.prefs,
+            vec![
+                FirefoxPreference::new("permissions.default.camera", 2),
+                FirefoxPreference::new("permissions.default.geo", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_download_dir() {
+        let options = FirefoxOptions::new().with_download_dir("/srv/dl");
+        assert_eq!(
+            options.prefs,
+            vec![FirefoxPreference::new("browser.download.dir", "/srv/dl")]
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_reserved_args() {
+        for arg in [
+            "--profile",
+            "-profile",
+            "--profile=/tmp/x",
+            "--no-remote",
+            "-P",
+        ] {
+            let options = FirefoxOptions::new().with_arg(arg);
+            assert!(options.validate().is_err(), "{arg} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_validate_allows_custom_args() {
+        let options = FirefoxOptions::new().with_args(["--marionette", "--safe-mode"]);
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_env_name() {
+        let options = FirefoxOptions::new().with_env("", "1");
+        assert!(options.validate().is_err());
+    }
 }
@@ -0,0 +1,279 @@
+//! Operating system statistics for Firefox processes.
+//!
+//! Firefox runs content in child processes, so statistics cover the main
+//! process and its direct children.
+//!
+//! | Platform | Source |
+//! |----------|--------|
+//! | Linux | `/proc/<pid>/stat` and `/proc/<pid>/status` |
+//! | macOS / other Unix | `ps -A -o pid=,ppid=,rss=,time=` |
+//! | Windows | `Win32_Process` via PowerShell |
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Memory and accumulated CPU time of a process tree at one instant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ProcessSample {
+    /// Resident set size in bytes.
+    pub rss_bytes: u64,
+    /// User plus system CPU time.
+    pub cpu_time: Duration,
+}
+
+impl ProcessSample {
+    /// Adds another process's statistics to this sample.
+    fn add(&mut self, rss_bytes: u64, cpu_time: Duration) {
+        self.rss_bytes += rss_bytes;
+        self.cpu_time += cpu_time;
+    }
+}
+
+// ============================================================================
+// Sampling
+// ============================================================================
+
+/// Samples `pid` and its direct children.
+///
+/// # Errors
+///
+/// Returns an error if `pid` is 0 (no process, as for windows without a
+/// local Firefox), the process does not exist, or statistics cannot be
+/// read on this platform.
+pub(crate) async fn sample(pid: u32) -> Result<ProcessSample> {
+    if pid == 0 {
+        return Err(Error::invalid_argument("No process to sample (pid 0)"));
+    }
+
+    let sample = platform::sample(pid).await?;
+    if sample == ProcessSample::default() {
+        return Err(Error::invalid_argument(format!(
+            "No statistics for process {pid}"
+        )));
+    }
+    Ok(sample)
+}
+
+// ============================================================================
+// Linux
+// ============================================================================
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use std::time::Duration;
+
+    use super::ProcessSample;
+    use crate::error::{Error, Result};
+
+    /// Clock ticks per second for `/proc/<pid>/stat` times (`USER_HZ`).
+    ///
+    /// The kernel reports these times in `USER_HZ`, not the configured
+    /// tick rate, and `USER_HZ` is 100 on every architecture Firefox ships
+    /// for, so the value `sysconf(_SC_CLK_TCK)` would return is fixed.
+    const CLOCK_TICKS_PER_SEC: u64 = 100;
+
+    /// Walks `/proc` on the blocking thread pool.
+    pub(super) async fn sample(pid: u32) -> Result<ProcessSample> {
+        tokio::task::spawn_blocking(move || sample_blocking(pid))
+            .await
+            .map_err(|e| Error::Io(std::io::Error::other(e)))?
+    }
+
+    /// Sums statistics of `pid` and its direct children from `/proc`.
+    fn sample_blocking(pid: u32) -> Result<ProcessSample> {
+        let mut sample = ProcessSample::default();
+
+        for entry in fs::read_dir("/proc").map_err(Error::Io)? {
+            let Ok(entry) = entry else { continue };
+            let Some(entry_pid) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            // Processes may exit between listing and reading
+            let Ok(stat) = fs::read_to_string(entry.path().join("stat")) else {
+                continue;
+            };
+            let Some((ppid, ticks)) = parse_stat(&stat) else {
+                continue;
+            };
+            if entry_pid != pid && ppid != pid {
+                continue;
+            }
+
+            let rss_bytes = fs::read_to_string(entry.path().join("status"))
+                .ok()
+                .and_then(|status| parse_vm_rss(&status))
+                .unwrap_or(0);
+            let cpu_time = Duration::from_millis(ticks * 1000 / CLOCK_TICKS_PER_SEC);
+
+            sample.add(rss_bytes, cpu_time);
+        }
+
+        Ok(sample)
+    }
+
+    /// Parses parent PID and user + system clock ticks from `/proc/<pid>/stat`.
+    pub(super) fn parse_stat(stat: &str) -> Option<(u32, u64)> {
+        // The command name may contain spaces; fields follow the last ')'
+        let fields: Vec<&str> = stat
+            .get(stat.rfind(')')? + 1..)?
+            .split_whitespace()
+            .collect();
+
+        let ppid = fields.get(1)?.parse().ok()?;
+        let utime: u64 = fields.get(11)?.parse().ok()?;
+        let stime: u64 = fields.get(12)?.parse().ok()?;
+
+        Some((ppid, utime + stime))
+    }
+
+    /// Parses `VmRSS` from `/proc/<pid>/status`, in bytes.
+    pub(super) fn parse_vm_rss(status: &str) -> Option<u64> {
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kib * 1024)
+    }
+}
+
+// ============================================================================
+// Other Unix
+// ============================================================================
+
+#[cfg(all(unix, not(target_os = "linux")))]
+mod platform {
+    use std::time::Duration;
+
+    use tokio::process::Command;
+
+    use super::ProcessSample;
+    use crate::error::{Error, Result};
+
+    pub(super) async fn sample(pid: u32) -> Result<ProcessSample> {
+        let output = Command::new("ps")
+            .args(["-A", "-o", "pid=,ppid=,rss=,time="])
+            .output()
+            .await
+            .map_err(Error::Io)?;
+
+        let mut sample = ProcessSample::default();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [entry_pid, ppid, rss_kib, time] = fields[..] else {
+                continue;
+            };
+
+            if entry_pid.parse() != Ok(pid) && ppid.parse() != Ok(pid) {
+                continue;
+            }
+
+            let rss_bytes = rss_kib.parse::<u64>().unwrap_or(0) * 1024;
+            sample.add(rss_bytes, parse_cpu_time(time).unwrap_or_default());
+        }
+
+        Ok(sample)
+    }
+
+    /// Parses `ps` CPU time (`[[dd-]hh:]mm:ss[.cc]`).
+    fn parse_cpu_time(time: &str) -> Option<Duration> {
+        let (days, clock) = match time.split_once('-') {
+            Some((days, clock)) => (days.parse::<u64>().ok()?, clock),
+            None => (0, time),
+        };
+
+        let mut seconds = 0.0;
+        for part in clock.split(':') {
+            seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+        }
+
+        Some(Duration::from_secs(days * 86_400) + Duration::from_secs_f64(seconds))
+    }
+}
+
+// ============================================================================
+// Windows
+// ============================================================================
+
+#[cfg(windows)]
+mod platform {
+    use std::time::Duration;
+
+    use tokio::process::Command;
+
+    use super::ProcessSample;
+    use crate::error::{Error, Result};
+
+    pub(super) async fn sample(pid: u32) -> Result<ProcessSample> {
+        let script = format!(
+            "Get-CimInstance Win32_Process -Filter \"ProcessId={pid} or ParentProcessId={pid}\" | \
+             ForEach-Object {{ \"$($_.WorkingSetSize) $($_.KernelModeTime) $($_.UserModeTime)\" }}"
+        );
+
+        let output = Command::new("powershell")
+            .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+            .output()
+            .await
+            .map_err(Error::Io)?;
+
+        let mut sample = ProcessSample::default();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let values: Vec<u64> = line
+                .split_whitespace()
+                .filter_map(|value| value.parse().ok())
+                .collect();
+            let [working_set, kernel, user] = values[..] else {
+                continue;
+            };
+
+            // Times are in 100ns units
+            sample.add(working_set, Duration::from_nanos((kernel + user) * 100));
+        }
+
+        Ok(sample)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::platform::{parse_stat, parse_vm_rss};
+
+    #[test]
+    fn test_parse_stat_with_spaces_in_name() {
+        let stat = "1234 (Web Content) S 1000 1234 1234 0 -1 4194560 100 0 0 0 250 50 0 0 20 0";
+        assert_eq!(parse_stat(stat), Some((1000, 300)));
+    }
+
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tfirefox\nVmPeak:\t 900 kB\nVmRSS:\t  2048 kB\n";
+        assert_eq!(parse_vm_rss(status), Some(2048 * 1024));
+    }
+
+    #[tokio::test]
+    async fn test_sample_current_process() {
+        let sample = super::sample(std::process::id()).await.expect("sample");
+        assert!(sample.rss_bytes > 0);
+    }
+
+    #[tokio::test]
+    async fn test_sample_rejects_pid_zero() {
+        assert!(super::sample(0).await.is_err());
+    }
+}
@@ -1,12 +1,13 @@
 //! Firefox extension installation and management.
 //!
-//! Extensions can be provided in three formats:
+//! Extensions can be provided in four formats:
 //!
 //! | Format | Description |
 //! |--------|-------------|
 //! | Unpacked | Directory containing `manifest.json` |
 //! | Packed | `.xpi` or `.zip` archive |
 //! | Base64 | Base64-encoded `.xpi` content |
+//! | Bundled | `.xpi` embedded in the crate (`bundled-extension` feature) |
 //!
 //! # Example
 //!
@@ -62,6 +63,12 @@ pub enum ExtensionSource {
 
     /// Base64-encoded extension content.
     Base64(String),
+
+    /// Extension embedded in the crate at build time.
+    ///
+    /// Requires the `bundled-extension` feature. See
+    /// [`BUNDLED_EXTENSION`](crate::driver::assets::BUNDLED_EXTENSION).
+    Bundled,
 }
 
 // ============================================================================
@@ -103,6 +110,13 @@ impl ExtensionSource {
     pub fn base64(data: impl Into<String>) -> Self {
         Self::Base64(data.into())
     }
+
+    /// Creates a source for the extension bundled with the crate.
+    #[inline]
+    #[must_use]
+    pub fn bundled() -> Self {
+        Self::Bundled
+    }
 }
 
 // ============================================================================
@@ -112,13 +126,13 @@ impl ExtensionSource {
 impl ExtensionSource {
     /// Returns the path if this is a file-based source.
     ///
-    /// Returns `None` for base64-encoded sources.
+    /// Returns `None` for base64-encoded and bundled sources.
     #[inline]
     #[must_use]
     pub fn path(&self) -> Option<&PathBuf> {
         match self {
             Self::Unpacked(path) | Self::Packed(path) => Some(path),
-            Self::Base64(_) => None,
+            Self::Base64(_) | Self::Bundled => None,
         }
     }
 
@@ -142,6 +156,13 @@ impl ExtensionSource {
     pub fn is_base64(&self) -> bool {
         matches!(self, Self::Base64(_))
     }
+
+    /// Returns `true` if this is the bundled extension.
+    #[inline]
+    #[must_use]
+    pub fn is_bundled(&self) -> bool {
+        matches!(self, Self::Bundled)
+    }
 }
 
 // ============================================================================
@@ -209,6 +230,14 @@ mod tests {
         assert!(source.path().is_none());
     }
 
+    #[test]
+    fn test_bundled_constructor() {
+        let source = ExtensionSource::bundled();
+        assert!(source.is_bundled());
+        assert!(!source.is_base64());
+        assert!(source.path().is_none());
+    }
+
     #[test]
     fn test_path_accessor() {
         let unpacked = ExtensionSource::unpacked("./ext");
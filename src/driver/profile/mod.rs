@@ -32,6 +32,7 @@
 // ============================================================================
 
 use std::fs;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use base64::Engine;
@@ -41,6 +42,7 @@ use tempfile::TempDir;
 use tracing::debug;
 use zip::ZipArchive;
 
+use crate::driver::assets::{BUNDLED_EXTENSION, BUNDLED_EXTENSION_VERSION};
 use crate::error::{Error, Result};
 
 // ============================================================================
@@ -68,6 +70,56 @@ pub use preferences::{FirefoxPreference, PreferenceValue};
 const USER_JS_HEADER: &str = "// Firefox WebDriver user.js\n\
                               // Auto-generated preferences for automation\n\n";
 
+/// File recording which crate version installed the bundled extension.
+const BUNDLED_VERSION_FILE: &str = ".firefox-webdriver-version";
+
+/// Top-level profile entries skipped by [`Profile::clone_from`].
+///
+/// Lock files would make Firefox think the copy is in use; caches are
+/// large and rebuilt on demand.
+const CLONE_SKIPPED: &[&str] = &[
+    "lock",
+    ".parentlock",
+    "parent.lock",
+    "cache2",
+    "startupCache",
+    "shader-cache",
+    "thumbnails",
+    "crashes",
+    "minidumps",
+    "safebrowsing",
+    DOWNLOADS_DIR,
+];
+
+/// Archive entries whose presence marks an extension as signed.
+const SIGNATURE_ENTRIES: &[&str] = &["META-INF/mozilla.rsa", "META-INF/cose.sig"];
+
+/// Manifest versions the driver extension may use.
+const SUPPORTED_MANIFEST_VERSIONS: &[u64] = &[2, 3];
+
+/// Permissions the driver relies on (network interception, cookies).
+const REQUIRED_PERMISSIONS: &[&str] = &["webRequest", "cookies"];
+
+/// Additional permissions required by Manifest V3 extensions (script injection).
+const MV3_REQUIRED_PERMISSIONS: &[&str] = &["scripting"];
+
+/// Preference controlling extension signature enforcement.
+const SIGNATURES_REQUIRED_PREF: &str = "xpinstall.signatures.required";
+
+/// Preference holding the download directory.
+const DOWNLOAD_DIR_PREF: &str = "browser.download.dir";
+
+/// Profile subdirectory downloads are saved to by default.
+const DOWNLOADS_DIR: &str = "downloads";
+
+/// Profile files holding authenticated state.
+const CLONE_AUTH_FILES: &[&str] = &[
+    "cookies.sqlite",
+    "logins.json",
+    "key4.db",
+    "permissions.sqlite",
+];
+
 // ============================================================================
 // Profile
 // ============================================================================
@@ -91,6 +143,9 @@ pub struct Profile {
 
     /// Path to the profile directory.
     path: PathBuf,
+
+    /// Whether unsigned extensions may be installed.
+    allow_unsigned: bool,
 }
 
 // ============================================================================
@@ -128,6 +183,7 @@ impl Profile {
         Ok(Self {
             _temp_dir: Some(temp_dir),
             path,
+            allow_unsigned: true,
         })
     }
 
@@ -172,8 +228,99 @@ impl Profile {
         Ok(Self {
             _temp_dir: None,
             path,
+            allow_unsigned: true,
         })
     }
+
+    /// Copies an existing Firefox profile into a new temporary profile.
+    ///
+    /// Unlike [`Profile::from_path`], the source is never modified: the
+    /// driver's extension and preferences go into the copy. Cookies, saved
+    /// logins (`logins.json`, `key4.db`), permissions and bookmarks carry
+    /// over; lock files and caches are skipped.
+    ///
+    /// Close Firefox first: a running instance may not have flushed its
+    /// databases to disk yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Path to the profile to copy
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not a directory or copying fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firefox_webdriver::driver::profile::Profile;
+    ///
+    /// # fn example() -> firefox_webdriver::Result<()> {
+    /// let profile = Profile::clone_from("/home/me/.mozilla/firefox/abcd1234.default")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clone_from(source: impl AsRef<Path>) -> Result<Self> {
+        let source = source.as_ref();
+
+        if !source.is_dir() {
+            return Err(Error::profile(format!(
+                "Profile to clone not found at {}",
+                source.display()
+            )));
+        }
+
+        let profile = Self::new_temp()?;
+
+        for entry in fs::read_dir(source).map_err(Error::Io)? {
+            let entry = entry.map_err(Error::Io)?;
+            let name = entry.file_name();
+            let file_type = entry.file_type().map_err(Error::Io)?;
+
+            if file_type.is_symlink() || CLONE_SKIPPED.iter().any(|skip| name == *skip) {
+                continue;
+            }
+
+            let dst_path = profile.path.join(&name);
+            if file_type.is_dir() {
+                copy_dir_recursive(&entry.path(), &dst_path)?;
+            } else {
+                fs::copy(entry.path(), &dst_path).map_err(Error::Io)?;
+            }
+        }
+
+        let auth_files = CLONE_AUTH_FILES
+            .iter()
+            .filter(|name| profile.path.join(name).exists())
+            .count();
+
+        debug!(
+            source = %source.display(),
+            path = %profile.path.display(),
+            auth_files,
+            "Cloned profile"
+        );
+
+        Ok(profile)
+    }
+
+    /// Sets whether unsigned extensions may be installed.
+    ///
+    /// Defaults to `true`, matching [`Profile::default_prefs`]. Release and
+    /// ESR builds of Firefox ignore `xpinstall.signatures.required = false`;
+    /// disallow unsigned extensions there so an unsigned install fails
+    /// loudly instead of Firefox silently skipping it. Signed `.xpi` files
+    /// are installed either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow` - Whether unsigned extensions may be installed
+    #[inline]
+    #[must_use]
+    pub fn allow_unsigned(mut self, allow: bool) -> Self {
+        self.allow_unsigned = allow;
+        self
+    }
 }
 
 // ============================================================================
@@ -188,6 +335,13 @@ impl Profile {
         &self.path
     }
 
+    /// Returns `true` if unsigned extensions may be installed.
+    #[inline]
+    #[must_use]
+    pub fn allows_unsigned(&self) -> bool {
+        self.allow_unsigned
+    }
+
     /// Returns the extensions directory, creating it if necessary.
     fn extensions_dir(&self) -> PathBuf {
         let dir = self.path.join("extensions");
@@ -205,6 +359,9 @@ impl Profile {
 impl Profile {
     /// Writes preferences to `user.js`.
     ///
+    /// If unsigned extensions are disallowed (see [`Profile::allow_unsigned`]),
+    /// `xpinstall.signatures.required` is forced to `true`.
+    ///
     /// # Arguments
     ///
     /// * `prefs` - Slice of preferences to write
@@ -215,8 +372,18 @@ impl Profile {
     pub fn write_prefs(&self, prefs: &[FirefoxPreference]) -> Result<()> {
         let file_path = self.path.join("user.js");
 
+        let prefs = if self.allow_unsigned {
+            prefs.to_vec()
+        } else {
+            Self::merge_prefs(
+                prefs.to_vec(),
+                &[FirefoxPreference::new(SIGNATURES_REQUIRED_PREF, true)],
+            )
+        };
+
         let mut content = String::from(USER_JS_HEADER);
-        for pref in prefs {
+        for pref in &prefs {
+            pref.validate()?;
             content.push_str(&pref.to_user_pref_line());
             content.push('\n');
         }
@@ -238,6 +405,43 @@ impl Profile {
         Ok(())
     }
 
+    /// Returns `true` unless `prefs` sets `xpinstall.signatures.required`
+    /// to `true`.
+    #[must_use]
+    pub fn prefs_allow_unsigned(prefs: &[FirefoxPreference]) -> bool {
+        prefs
+            .iter()
+            .rfind(|pref| pref.key == SIGNATURES_REQUIRED_PREF)
+            .is_none_or(|pref| pref.value != PreferenceValue::Bool(true))
+    }
+
+    /// Merges preference overrides onto a base list.
+    ///
+    /// An override replaces the base entry with the same key in place; new
+    /// keys are appended in the order given. When an override key repeats,
+    /// the last one wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - Base preferences (usually [`Profile::default_prefs`])
+    /// * `overrides` - User preferences applied on top
+    #[must_use]
+    pub fn merge_prefs(
+        base: Vec<FirefoxPreference>,
+        overrides: &[FirefoxPreference],
+    ) -> Vec<FirefoxPreference> {
+        let mut merged = base;
+
+        for pref in overrides {
+            match merged.iter_mut().find(|existing| existing.key == pref.key) {
+                Some(existing) => *existing = pref.clone(),
+                None => merged.push(pref.clone()),
+            }
+        }
+
+        merged
+    }
+
     /// Returns the default preferences for WebDriver automation.
     ///
     /// These preferences configure Firefox for automation:
@@ -529,8 +733,60 @@ impl Profile {
                 .with_comment("Number of content processes"),
             Pref::new("browser.tabs.remote.autostart", Val::Bool(true))
                 .with_comment("Enable multi-process tabs"),
+            // ================================================================
+            // SECTION 12: Downloads (no dialogs)
+            // Source: browser/app/profile/firefox.js
+            // ================================================================
+            // 0 = desktop, 1 = downloads folder, 2 = browser.download.dir
+            Pref::new("browser.download.folderList", Val::Int(2))
+                .with_comment("0 = desktop, 1 = downloads folder, 2 = browser.download.dir"),
+            Pref::new("browser.download.useDownloadDir", Val::Bool(true))
+                .with_comment("Save without asking where"),
+            Pref::new(
+                "browser.download.always_ask_before_handling_new_types",
+                Val::Bool(false),
+            )
+            .with_comment("Ask what to do with unknown file types"),
+            Pref::new("browser.download.alwaysOpenPanel", Val::Bool(false))
+                .with_comment("Open the downloads panel when a download starts"),
         ]
     }
+
+    /// Returns the directory downloads are saved to unless
+    /// `browser.download.dir` is set.
+    #[inline]
+    #[must_use]
+    pub fn downloads_dir(&self) -> PathBuf {
+        self.path().join(DOWNLOADS_DIR)
+    }
+
+    /// Points `browser.download.dir` at [`Profile::downloads_dir`] unless
+    /// `prefs` already sets it, creating the directory.
+    ///
+    /// A directory already set in `prefs` is created too, and made absolute
+    /// since Firefox ignores relative download paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the directory cannot be created.
+    pub fn apply_download_dir(&self, prefs: &mut Vec<FirefoxPreference>) -> Result<()> {
+        if let Some(pref) = prefs.iter_mut().find(|pref| pref.key == DOWNLOAD_DIR_PREF) {
+            if let PreferenceValue::String(dir) = &pref.value {
+                let dir = std::path::absolute(dir)?;
+                fs::create_dir_all(&dir)?;
+                pref.value = PreferenceValue::String(dir.to_string_lossy().into_owned());
+            }
+            return Ok(());
+        }
+
+        let dir = self.downloads_dir();
+        fs::create_dir_all(&dir)?;
+        prefs.push(FirefoxPreference::new(
+            DOWNLOAD_DIR_PREF,
+            dir.to_string_lossy().into_owned(),
+        ));
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -540,13 +796,18 @@ impl Profile {
 impl Profile {
     /// Installs an extension into the profile.
     ///
+    /// The manifest must declare `manifest_version` 2 or 3 and the
+    /// permissions the driver relies on (`webRequest`, `cookies`, plus
+    /// `scripting` for Manifest V3).
+    ///
     /// # Arguments
     ///
-    /// * `source` - Extension source (unpacked, packed, or base64)
+    /// * `source` - Extension source (unpacked, packed, base64, or bundled)
     ///
     /// # Errors
     ///
-    /// Returns an error if installation fails.
+    /// Returns an error if the manifest is invalid, lacks required
+    /// permissions, or installation fails.
     pub fn install_extension(&self, source: &ExtensionSource) -> Result<()> {
         match source {
             ExtensionSource::Unpacked(path) => {
@@ -561,11 +822,28 @@ impl Profile {
                 debug!("Installing base64 extension");
                 self.install_base64(data)
             }
+            ExtensionSource::Bundled => {
+                debug!(
+                    version = BUNDLED_EXTENSION_VERSION,
+                    "Installing bundled extension"
+                );
+                let bytes = BUNDLED_EXTENSION.ok_or_else(|| {
+                    Error::profile(
+                        "Bundled extension not available. Build with the \
+                         `bundled-extension` feature.",
+                    )
+                })?;
+                self.install_bundled(bytes)
+            }
         }
     }
 
     /// Installs an unpacked extension directory.
+    ///
+    /// Unpacked extensions are never signed.
     fn install_unpacked(&self, src: &Path) -> Result<()> {
+        self.ensure_unsigned_allowed()?;
+
         let extension_id = self.read_manifest_id(src)?;
         let dest = self.extensions_dir().join(&extension_id);
 
@@ -581,16 +859,15 @@ impl Profile {
     }
 
     /// Installs a packed extension (.xpi or .zip).
+    ///
+    /// Signed archives are installed as-is so Firefox can verify them;
+    /// unsigned ones are extracted.
     fn install_packed(&self, src: &Path) -> Result<()> {
-        let file = fs::File::open(src).map_err(Error::Io)?;
-        let mut archive = ZipArchive::new(file)
-            .map_err(|e| Error::profile(format!("Invalid extension archive: {}", e)))?;
-
-        let temp_extract = TempDir::new().map_err(Error::Io)?;
-        archive
-            .extract(temp_extract.path())
-            .map_err(|e| Error::profile(format!("Failed to extract extension: {}", e)))?;
+        if let Some(extension_id) = signed_extension_id(src)? {
+            return self.install_signed(src, &extension_id);
+        }
 
+        let temp_extract = extract_archive(src)?;
         self.install_unpacked(temp_extract.path())
     }
 
@@ -610,6 +887,128 @@ impl Profile {
         self.install_packed(&temp_xpi)
     }
 
+    /// Installs a signed archive as `extensions/<id>.xpi`.
+    ///
+    /// `extensions.json` is not written directly. It is Firefox's cache of
+    /// the add-on database, not an install API: entries carry
+    /// version-specific fields and are dropped when they disagree with the
+    /// files, and `addonStartup.json.lz4` must match too. The profile's
+    /// `extensions/` directory is the supported install location for
+    /// automation (geckodriver and mozprofile use it): on startup Firefox
+    /// scans it, verifies the signature and writes the `extensions.json`
+    /// entry itself, enabled since the default prefs set
+    /// `extensions.autoDisableScopes` to `0`. This works on release and ESR
+    /// builds, where the signature check cannot be turned off.
+    ///
+    /// An unpacked copy with the same ID is removed, since it would shadow
+    /// the signed one. When an earlier install is replaced, the add-on
+    /// startup cache is cleared so Firefox rescans instead of loading the
+    /// old entry.
+    fn install_signed(&self, src: &Path, extension_id: &str) -> Result<()> {
+        let extensions_dir = self.extensions_dir();
+        let unpacked = extensions_dir.join(extension_id);
+        let dest = extensions_dir.join(format!("{extension_id}.xpi"));
+
+        let mut replaced = false;
+        if unpacked.is_dir() {
+            fs::remove_dir_all(&unpacked).map_err(Error::Io)?;
+            replaced = true;
+        }
+        if dest.is_file() {
+            replaced |= fs::read(&dest).map_err(Error::Io)? != fs::read(src).map_err(Error::Io)?;
+        }
+
+        fs::copy(src, &dest).map_err(Error::Io)?;
+        if replaced {
+            self.clear_addon_cache()?;
+        }
+
+        debug!(
+            extension_id = %extension_id,
+            dest = %dest.display(),
+            "Installed signed extension"
+        );
+
+        Ok(())
+    }
+
+    /// Fails if unsigned extensions are disallowed.
+    fn ensure_unsigned_allowed(&self) -> Result<()> {
+        if self.allow_unsigned {
+            return Ok(());
+        }
+
+        Err(Error::profile(
+            "Extension is unsigned, but unsigned extensions are disabled for this profile. \
+             Install a signed .xpi or use Profile::allow_unsigned(true).",
+        ))
+    }
+
+    /// Installs the extension bundled with the crate.
+    ///
+    /// A version marker is written next to the installed files. In a
+    /// persistent profile, a copy installed by another crate version is
+    /// removed together with Firefox's add-on startup cache, so the stale
+    /// extension is never reused.
+    fn install_bundled(&self, bytes: &[u8]) -> Result<()> {
+        let temp_dir = TempDir::new().map_err(Error::Io)?;
+        let temp_xpi = temp_dir.path().join("extension.xpi");
+        fs::write(&temp_xpi, bytes).map_err(Error::Io)?;
+
+        // Signed builds carry their own version; Firefox reinstalls on change
+        if let Some(extension_id) = signed_extension_id(&temp_xpi)? {
+            return self.install_signed(&temp_xpi, &extension_id);
+        }
+        self.ensure_unsigned_allowed()?;
+
+        let temp_extract = extract_archive(&temp_xpi)?;
+        let extension_id = self.read_manifest_id(temp_extract.path())?;
+        let dest = self.extensions_dir().join(&extension_id);
+        let marker = dest.join(BUNDLED_VERSION_FILE);
+
+        let installed = fs::read_to_string(&marker).ok();
+        if installed.as_deref() == Some(BUNDLED_EXTENSION_VERSION) {
+            debug!(extension_id = %extension_id, "Bundled extension already installed");
+            return Ok(());
+        }
+
+        if dest.exists() {
+            debug!(
+                extension_id = %extension_id,
+                installed = installed.as_deref().unwrap_or("unknown"),
+                "Replacing stale bundled extension"
+            );
+            fs::remove_dir_all(&dest).map_err(Error::Io)?;
+            self.clear_addon_cache()?;
+        }
+
+        copy_dir_recursive(temp_extract.path(), &dest)?;
+        fs::write(&marker, BUNDLED_EXTENSION_VERSION).map_err(Error::Io)?;
+
+        debug!(
+            extension_id = %extension_id,
+            dest = %dest.display(),
+            "Installed bundled extension"
+        );
+
+        Ok(())
+    }
+
+    /// Removes Firefox's cached add-on startup data.
+    fn clear_addon_cache(&self) -> Result<()> {
+        let startup = self.path.join("addonStartup.json.lz4");
+        if startup.exists() {
+            fs::remove_file(&startup).map_err(Error::Io)?;
+        }
+
+        let cache = self.path.join("startupCache");
+        if cache.exists() {
+            fs::remove_dir_all(&cache).map_err(Error::Io)?;
+        }
+
+        Ok(())
+    }
+
     /// Reads the extension ID from manifest.json.
     fn read_manifest_id(&self, dir: &Path) -> Result<String> {
         let manifest_path = dir.join("manifest.json");
@@ -621,25 +1020,7 @@ impl Profile {
             ))
         })?;
 
-        let json: Value = from_str(&content)
-            .map_err(|e| Error::profile(format!("Invalid manifest.json: {}", e)))?;
-
-        // Try standard WebExtension ID locations
-        if let Some(id) = json.pointer("/browser_specific_settings/gecko/id")
-            && let Some(id_str) = id.as_str()
-        {
-            return Ok(id_str.to_string());
-        }
-
-        if let Some(id) = json.pointer("/applications/gecko/id")
-            && let Some(id_str) = id.as_str()
-        {
-            return Ok(id_str.to_string());
-        }
-
-        Err(Error::profile(
-            "Extension manifest missing 'gecko.id' field".to_string(),
-        ))
+        manifest_id(&content)
     }
 }
 
@@ -647,6 +1028,99 @@ impl Profile {
 // Private Helpers
 // ============================================================================
 
+/// Validates manifest.json content and extracts the extension ID.
+///
+/// Checks the manifest version and the permissions the driver relies on,
+/// so a wrong or outdated extension fails at install instead of hanging
+/// later on an unanswered command.
+fn manifest_id(content: &str) -> Result<String> {
+    let json: Value =
+        from_str(content).map_err(|e| Error::profile(format!("Invalid manifest.json: {}", e)))?;
+
+    let version = json.get("manifest_version").and_then(Value::as_u64);
+    if !version.is_some_and(|v| SUPPORTED_MANIFEST_VERSIONS.contains(&v)) {
+        return Err(Error::profile(format!(
+            "Unsupported extension manifest_version: {}. Supported: 2, 3",
+            version.map_or_else(|| "missing".to_string(), |v| v.to_string())
+        )));
+    }
+
+    let granted: Vec<&str> = json
+        .get("permissions")
+        .and_then(Value::as_array)
+        .map(|permissions| permissions.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mv3 = (version == Some(3)).then_some(MV3_REQUIRED_PERMISSIONS);
+    let missing: Vec<&str> = REQUIRED_PERMISSIONS
+        .iter()
+        .chain(mv3.into_iter().flatten())
+        .filter(|permission| !granted.contains(permission))
+        .copied()
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(Error::profile(format!(
+            "Extension manifest is missing required permissions: {}",
+            missing.join(", ")
+        )));
+    }
+
+    // Try standard WebExtension ID locations
+    if let Some(id) = json.pointer("/browser_specific_settings/gecko/id")
+        && let Some(id_str) = id.as_str()
+    {
+        return Ok(id_str.to_string());
+    }
+
+    if let Some(id) = json.pointer("/applications/gecko/id")
+        && let Some(id_str) = id.as_str()
+    {
+        return Ok(id_str.to_string());
+    }
+
+    Err(Error::profile(
+        "Extension manifest missing 'gecko.id' field".to_string(),
+    ))
+}
+
+/// Returns the extension ID if the archive is signed.
+fn signed_extension_id(src: &Path) -> Result<Option<String>> {
+    let file = fs::File::open(src).map_err(Error::Io)?;
+    let mut archive = ZipArchive::new(file)
+        .map_err(|e| Error::profile(format!("Invalid extension archive: {}", e)))?;
+
+    let signed = archive
+        .file_names()
+        .any(|name| SIGNATURE_ENTRIES.contains(&name));
+    if !signed {
+        return Ok(None);
+    }
+
+    let mut content = String::new();
+    archive
+        .by_name("manifest.json")
+        .map_err(|e| Error::profile(format!("Extension manifest not found in archive: {}", e)))?
+        .read_to_string(&mut content)
+        .map_err(Error::Io)?;
+
+    manifest_id(&content).map(Some)
+}
+
+/// Extracts an extension archive into a temporary directory.
+fn extract_archive(src: &Path) -> Result<TempDir> {
+    let file = fs::File::open(src).map_err(Error::Io)?;
+    let mut archive = ZipArchive::new(file)
+        .map_err(|e| Error::profile(format!("Invalid extension archive: {}", e)))?;
+
+    let temp_extract = TempDir::new().map_err(Error::Io)?;
+    archive
+        .extract(temp_extract.path())
+        .map_err(|e| Error::profile(format!("Failed to extract extension: {}", e)))?;
+
+    Ok(temp_extract)
+}
+
 /// Recursively copies a directory and all its contents.
 fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     if !dst.exists() {
@@ -675,7 +1149,48 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::Profile;
+    use super::{
+        BUNDLED_EXTENSION_VERSION, BUNDLED_VERSION_FILE, ExtensionSource, FirefoxPreference,
+        PreferenceValue, Profile, manifest_id,
+    };
+
+    use std::fs;
+    use std::io::{Cursor, Write};
+
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    const TEST_MANIFEST: &str = r#"{
+        "manifest_version": 2,
+        "permissions": ["webRequest", "cookies"],
+        "browser_specific_settings": { "gecko": { "id": "test@example.com" } }
+    }"#;
+
+    fn test_xpi() -> Vec<u8> {
+        build_xpi(false)
+    }
+
+    fn build_xpi(signed: bool) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("manifest.json", SimpleFileOptions::default())
+            .expect("start file");
+        writer
+            .write_all(TEST_MANIFEST.as_bytes())
+            .expect("write manifest");
+        if signed {
+            writer
+                .start_file("META-INF/mozilla.rsa", SimpleFileOptions::default())
+                .expect("start file");
+        }
+        writer.finish().expect("finish zip").into_inner()
+    }
+
+    fn write_xpi(dir: &std::path::Path, signed: bool) -> std::path::PathBuf {
+        let path = dir.join("extension.xpi");
+        fs::write(&path, build_xpi(signed)).unwrap();
+        path
+    }
 
     #[test]
     fn test_new_temp_creates_directory() {
@@ -701,6 +1216,184 @@ mod tests {
         assert!(!prefs.is_empty());
     }
 
+    #[test]
+    fn test_merge_prefs_overrides_and_appends() {
+        let base = vec![
+            FirefoxPreference::new("a", 1),
+            FirefoxPreference::new("b", true),
+        ];
+        let overrides = [
+            FirefoxPreference::new("b", false),
+            FirefoxPreference::new("c", "x"),
+            FirefoxPreference::new("c", "y"),
+        ];
+
+        let merged = Profile::merge_prefs(base, &overrides);
+        let pairs: Vec<_> = merged.iter().map(|p| (p.key.as_str(), &p.value)).collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                ("a", &PreferenceValue::Int(1)),
+                ("b", &PreferenceValue::Bool(false)),
+                ("c", &PreferenceValue::String("y".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clone_from_skips_locks_and_caches() {
+        let source = tempfile::tempdir().expect("create temp dir");
+        fs::write(source.path().join("cookies.sqlite"), "cookies").unwrap();
+        fs::write(source.path().join(".parentlock"), "").unwrap();
+        fs::create_dir_all(source.path().join("cache2/entries")).unwrap();
+        fs::create_dir_all(source.path().join("storage/default")).unwrap();
+
+        let profile = Profile::clone_from(source.path()).expect("clone profile");
+
+        assert_ne!(profile.path(), source.path());
+        assert_eq!(
+            fs::read_to_string(profile.path().join("cookies.sqlite")).unwrap(),
+            "cookies"
+        );
+        assert!(profile.path().join("storage/default").is_dir());
+        assert!(!profile.path().join(".parentlock").exists());
+        assert!(!profile.path().join("cache2").exists());
+    }
+
+    #[test]
+    fn test_signed_extension_installed_as_xpi() {
+        let temp = tempfile::tempdir().unwrap();
+        let xpi = write_xpi(temp.path(), true);
+        let profile = Profile::new_temp().unwrap().allow_unsigned(false);
+
+        profile
+            .install_extension(&ExtensionSource::packed(&xpi))
+            .expect("install");
+
+        let extensions = profile.path().join("extensions");
+        assert!(extensions.join("test@example.com.xpi").is_file());
+        assert!(!extensions.join("test@example.com").exists());
+    }
+
+    #[test]
+    fn test_signed_extension_replaces_unpacked_copy() {
+        let temp = tempfile::tempdir().unwrap();
+        let xpi = write_xpi(temp.path(), true);
+        let profile = Profile::new_temp().unwrap();
+
+        let unpacked = profile.path().join("extensions/test@example.com");
+        fs::create_dir_all(&unpacked).unwrap();
+        fs::write(profile.path().join("addonStartup.json.lz4"), "").unwrap();
+
+        profile
+            .install_extension(&ExtensionSource::packed(&xpi))
+            .expect("install");
+
+        assert!(!unpacked.exists());
+        assert!(!profile.path().join("addonStartup.json.lz4").exists());
+
+        // Reinstalling the same archive keeps the cache
+        fs::write(profile.path().join("addonStartup.json.lz4"), "").unwrap();
+        profile
+            .install_extension(&ExtensionSource::packed(&xpi))
+            .expect("reinstall");
+        assert!(profile.path().join("addonStartup.json.lz4").exists());
+    }
+
+    #[test]
+    fn test_unsigned_extension_rejected_when_disallowed() {
+        let temp = tempfile::tempdir().unwrap();
+        let xpi = write_xpi(temp.path(), false);
+        let profile = Profile::new_temp().unwrap().allow_unsigned(false);
+
+        assert!(!profile.allows_unsigned());
+        assert!(
+            profile
+                .install_extension(&ExtensionSource::packed(&xpi))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_write_prefs_requires_signatures_when_disallowed() {
+        let profile = Profile::new_temp().unwrap().allow_unsigned(false);
+        profile.write_prefs(&Profile::default_prefs()).unwrap();
+
+        let user_js = fs::read_to_string(profile.path().join("user.js")).unwrap();
+        assert!(user_js.contains(r#"user_pref("xpinstall.signatures.required", true);"#));
+        assert!(!user_js.contains(r#"user_pref("xpinstall.signatures.required", false);"#));
+    }
+
+    #[test]
+    fn test_prefs_allow_unsigned() {
+        let mut prefs = Profile::default_prefs();
+        assert!(Profile::prefs_allow_unsigned(&prefs));
+
+        prefs.push(FirefoxPreference::new(
+            "xpinstall.signatures.required",
+            true,
+        ));
+        assert!(!Profile::prefs_allow_unsigned(&prefs));
+    }
+
+    #[test]
+    fn test_apply_download_dir() {
+        let profile = Profile::new_temp().expect("profile");
+        let mut prefs = Profile::default_prefs();
+        profile.apply_download_dir(&mut prefs).expect("apply");
+
+        let dir = profile.downloads_dir();
+        assert!(dir.is_dir());
+        let pref = prefs
+            .iter()
+            .find(|pref| pref.key == "browser.download.dir")
+            .expect("download dir pref");
+        assert_eq!(
+            pref.value,
+            PreferenceValue::String(dir.to_string_lossy().into_owned())
+        );
+
+        let chosen = profile.path().join("chosen");
+        let mut custom = vec![FirefoxPreference::new(
+            "browser.download.dir",
+            chosen.to_string_lossy().into_owned(),
+        )];
+        profile.apply_download_dir(&mut custom).expect("apply");
+        assert_eq!(custom.len(), 1);
+        assert!(chosen.is_dir());
+    }
+
+    #[test]
+    fn test_manifest_id_valid() {
+        assert_eq!(manifest_id(TEST_MANIFEST).unwrap(), "test@example.com");
+    }
+
+    #[test]
+    fn test_manifest_id_rejects_unsupported_version() {
+        let manifest = r#"{ "manifest_version": 1, "applications": { "gecko": { "id": "x@y" } } }"#;
+        let err = manifest_id(manifest).unwrap_err();
+        assert!(err.to_string().contains("manifest_version"));
+    }
+
+    #[test]
+    fn test_manifest_id_lists_missing_permissions() {
+        let manifest = r#"{
+            "manifest_version": 3,
+            "permissions": ["cookies"],
+            "browser_specific_settings": { "gecko": { "id": "x@y" } }
+        }"#;
+        let message = manifest_id(manifest).unwrap_err().to_string();
+        assert!(message.contains("webRequest"));
+        assert!(message.contains("scripting"));
+        assert!(!message.contains("cookies"));
+    }
+
+    #[test]
+    fn test_clone_from_missing_source() {
+        assert!(Profile::clone_from("/nonexistent/profile").is_err());
+    }
+
     #[test]
     fn test_from_path_creates_directory() {
         let temp = tempfile::tempdir().expect("create temp dir");
@@ -710,4 +1403,34 @@ mod tests {
         let profile = Profile::from_path(&profile_path).expect("create profile");
         assert!(profile.path().exists());
     }
+
+    #[test]
+    fn test_install_bundled_writes_version_marker() {
+        let profile = Profile::new_temp().expect("create temp profile");
+        profile.install_bundled(&test_xpi()).expect("install");
+
+        let dest = profile.extensions_dir().join("test@example.com");
+        assert!(dest.join("manifest.json").exists());
+        assert_eq!(
+            fs::read_to_string(dest.join(BUNDLED_VERSION_FILE)).unwrap(),
+            BUNDLED_EXTENSION_VERSION
+        );
+    }
+
+    #[test]
+    fn test_install_bundled_replaces_other_version() {
+        let profile = Profile::new_temp().expect("create temp profile");
+        let dest = profile.extensions_dir().join("test@example.com");
+
+        fs::create_dir_all(&dest).unwrap();
+        fs::write(dest.join(BUNDLED_VERSION_FILE), "0.0.0").unwrap();
+        fs::write(dest.join("stale.js"), "").unwrap();
+        fs::write(profile.path().join("addonStartup.json.lz4"), "").unwrap();
+
+        profile.install_bundled(&test_xpi()).expect("install");
+
+        assert!(!dest.join("stale.js").exists());
+        assert!(!profile.path().join("addonStartup.json.lz4").exists());
+        assert!(dest.join("manifest.json").exists());
+    }
 }
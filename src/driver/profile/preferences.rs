@@ -17,13 +17,21 @@
 //! assert!(pref.to_user_pref_line().contains("user_pref"));
 //! ```
 
+// ============================================================================
+// Imports
+// ============================================================================
+
+use crate::error::{Error, Result};
+
 // ============================================================================
 // PreferenceValue
 // ============================================================================
 
 /// A preference value in `user.js`.
 ///
-/// Firefox preferences can be booleans, integers, or strings.
+/// Firefox preferences can be booleans, integers, or strings. List-valued
+/// preferences are strings of comma-separated entries; [`StringList`]
+/// builds them.
 ///
 /// # Examples
 ///
@@ -33,7 +41,12 @@
 /// let bool_val = PreferenceValue::Bool(true);
 /// let int_val = PreferenceValue::Int(42);
 /// let str_val = PreferenceValue::String("value".to_string());
+///
+/// let bypass = PreferenceValue::from(vec!["localhost", "127.0.0.1", ".internal"]);
+/// assert_eq!(bypass.to_js_string(), r#""localhost, 127.0.0.1, .internal""#);
 /// ```
+///
+/// [`StringList`]: PreferenceValue::StringList
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PreferenceValue {
     /// Boolean value (true/false).
@@ -44,6 +57,12 @@ pub enum PreferenceValue {
 
     /// String value.
     String(String),
+
+    /// Comma-separated list written as a single string, e.g.
+    /// `network.proxy.no_proxies_on`.
+    ///
+    /// Entries must not be empty or contain commas.
+    StringList(Vec<String>),
 }
 
 // ============================================================================
@@ -56,13 +75,48 @@ impl PreferenceValue {
     /// - Booleans: `true` or `false`
     /// - Integers: numeric literal
     /// - Strings: quoted and escaped
+    /// - String lists: entries trimmed and joined with `", "`, then quoted
     #[must_use]
     pub fn to_js_string(&self) -> String {
         match self {
             Self::Bool(b) => b.to_string(),
             Self::Int(i) => i.to_string(),
             Self::String(s) => format!("\"{}\"", escape_js_string(s)),
+            Self::StringList(items) => {
+                let joined = items
+                    .iter()
+                    .map(|item| item.trim())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("\"{}\"", escape_js_string(&joined))
+            }
+        }
+    }
+
+    /// Checks that the value can be written to `user.js` unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Profile`] if a [`PreferenceValue::StringList`]
+    /// entry is empty or contains a comma, which would change the list
+    /// Firefox reads back.
+    pub fn validate(&self) -> Result<()> {
+        let Self::StringList(items) = self else {
+            return Ok(());
+        };
+
+        for item in items {
+            if item.trim().is_empty() {
+                return Err(Error::profile("Preference list entries must not be empty"));
+            }
+            if item.contains(',') {
+                return Err(Error::profile(format!(
+                    "Preference list entry {item:?} must not contain a comma"
+                )));
+            }
         }
+
+        Ok(())
     }
 }
 
@@ -98,6 +152,20 @@ impl From<&str> for PreferenceValue {
     }
 }
 
+impl From<Vec<String>> for PreferenceValue {
+    #[inline]
+    fn from(value: Vec<String>) -> Self {
+        Self::StringList(value)
+    }
+}
+
+impl From<Vec<&str>> for PreferenceValue {
+    #[inline]
+    fn from(value: Vec<&str>) -> Self {
+        Self::StringList(value.into_iter().map(str::to_string).collect())
+    }
+}
+
 // ============================================================================
 // FirefoxPreference
 // ============================================================================
@@ -116,7 +184,7 @@ impl From<&str> for PreferenceValue {
 /// let pref = FirefoxPreference::new("app.update.enabled", false)
 ///     .with_comment("Disable auto-updates");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FirefoxPreference {
     /// Preference name (e.g., "browser.startup.page").
     pub key: String,
@@ -161,9 +229,47 @@ impl FirefoxPreference {
         self
     }
 
+    /// Checks that the preference can be written to `user.js`.
+    ///
+    /// [`Profile::write_prefs`](super::Profile::write_prefs) calls this for
+    /// every preference before writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Profile`] if the key is empty or contains quotes,
+    /// backslashes or control characters, the comment spans several
+    /// lines, or the value is invalid (see [`PreferenceValue::validate`]).
+    pub fn validate(&self) -> Result<()> {
+        if self.key.is_empty() {
+            return Err(Error::profile("Preference name must not be empty"));
+        }
+        if self
+            .key
+            .chars()
+            .any(|c| c == '"' || c == '\\' || c.is_control())
+        {
+            return Err(Error::profile(format!(
+                "Preference name {:?} contains quotes, backslashes or control characters",
+                self.key
+            )));
+        }
+        if let Some(comment) = &self.comment
+            && comment.contains(['\n', '\r'])
+        {
+            return Err(Error::profile(format!(
+                "Comment for preference {} must be a single line",
+                self.key
+            )));
+        }
+
+        self.value.validate()
+    }
+
     /// Generates the `user_pref("key", value);` line.
     ///
-    /// If a comment is set, it appears on the line above.
+    /// If a comment is set, it appears on the line above. Call
+    /// [`FirefoxPreference::validate`] first for preferences built from
+    /// untrusted input.
     ///
     /// # Example Output
     ///
@@ -211,6 +317,7 @@ fn escape_js_string(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{FirefoxPreference, PreferenceValue, escape_js_string};
+    use crate::error::Error;
 
     // ------------------------------------------------------------------------
     // PreferenceValue Tests
@@ -372,4 +479,49 @@ mod tests {
         assert_eq!(pref.value, cloned.value);
         assert_eq!(pref.comment, cloned.comment);
     }
+
+    // ------------------------------------------------------------------------
+    // StringList and Validation Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_to_user_pref_line_string_list() {
+        let pref = FirefoxPreference::new(
+            "network.proxy.no_proxies_on",
+            vec!["localhost", " 127.0.0.1 ", "*.internal\"x"],
+        );
+        assert!(pref.validate().is_ok());
+        assert_eq!(
+            pref.to_user_pref_line(),
+            r#"user_pref("network.proxy.no_proxies_on", "localhost, 127.0.0.1, *.internal\"x");"#
+        );
+
+        let empty = FirefoxPreference::new("list.pref", Vec::<String>::new());
+        assert_eq!(empty.to_user_pref_line(), r#"user_pref("list.pref", "");"#);
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_preferences() {
+        let invalid = [
+            FirefoxPreference::new("list.pref", vec!["a,b"]),
+            FirefoxPreference::new("list.pref", vec!["a", "  "]),
+            FirefoxPreference::new("", true),
+            FirefoxPreference::new("bad\"name", true),
+            FirefoxPreference::new("bad\nname", true),
+            FirefoxPreference::new("ok.pref", true).with_comment("two\nlines"),
+        ];
+
+        for pref in invalid {
+            assert!(
+                matches!(pref.validate(), Err(Error::Profile { .. })),
+                "{pref:?} should be invalid"
+            );
+        }
+
+        assert!(
+            FirefoxPreference::new("ok.pref", "any \"quoted\"\nvalue")
+                .validate()
+                .is_ok()
+        );
+    }
 }
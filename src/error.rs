@@ -25,15 +25,16 @@
 //! | Connection | [`Error::Connection`], [`Error::ConnectionTimeout`], [`Error::ConnectionClosed`] |
 //! | Protocol | [`Error::UnknownCommand`], [`Error::InvalidArgument`], [`Error::Protocol`] |
 //! | Element | [`Error::ElementNotFound`], [`Error::StaleElement`] |
-//! | Navigation | [`Error::FrameNotFound`], [`Error::TabNotFound`] |
-//! | Execution | [`Error::ScriptError`], [`Error::Timeout`], [`Error::RequestTimeout`] |
+//! | Navigation | [`Error::FrameNotFound`], [`Error::TabNotFound`], [`Error::Navigation`] |
+//! | Execution | [`Error::ScriptError`], [`Error::ScriptTimeout`], [`Error::Timeout`], [`Error::BrowserCrashed`], [`Error::RequestTimeout`] |
 //! | External | [`Error::Io`], [`Error::Json`], [`Error::WebSocket`] |
 
 // ============================================================================
 // Imports
 // ============================================================================
 
-use std::io::Error as IoError;
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind};
 use std::path::PathBuf;
 use std::result::Result as StdResult;
 
@@ -101,6 +102,17 @@ pub enum Error {
         message: String,
     },
 
+    /// Batch window spawn failed.
+    ///
+    /// Returned when one or more windows in a batch fail to start.
+    #[error("Failed to spawn {} of {requested} windows", .errors.len())]
+    SpawnFailed {
+        /// Number of windows requested.
+        requested: usize,
+        /// Error for each window that failed.
+        errors: Vec<Error>,
+    },
+
     // ========================================================================
     // Connection Errors
     // ========================================================================
@@ -204,6 +216,19 @@ pub enum Error {
         tab_id: TabId,
     },
 
+    /// Page navigation failed.
+    ///
+    /// Returned when Firefox reports a load error for the requested URL.
+    #[error("Navigation to {url} failed: {reason} ({code})")]
+    Navigation {
+        /// URL being navigated to.
+        url: String,
+        /// Classified failure reason.
+        reason: NavigationError,
+        /// Raw Firefox error code (e.g. `NS_ERROR_UNKNOWN_HOST`).
+        code: String,
+    },
+
     // ========================================================================
     // Execution Errors
     // ========================================================================
@@ -214,6 +239,8 @@ pub enum Error {
     ScriptError {
         /// Error message from script execution.
         message: String,
+        /// JavaScript stack trace, if the extension reported one.
+        stack: Option<String>,
     },
 
     /// Operation timeout.
@@ -227,6 +254,27 @@ pub enum Error {
         timeout_ms: u64,
     },
 
+    /// Script did not finish in time.
+    ///
+    /// Returned when the extension aborts a script that ran longer than its
+    /// timeout, e.g. an endless loop or a promise that never settles. The
+    /// connection itself is healthy.
+    #[error("Script did not finish within {timeout_ms}ms")]
+    ScriptTimeout {
+        /// Milliseconds the script was allowed to run.
+        timeout_ms: u64,
+    },
+
+    /// Tab content process crashed.
+    ///
+    /// Returned for commands in flight when the extension reports that
+    /// their tab crashed, instead of waiting for them to time out.
+    #[error("Browser crashed: tab {tab_id}")]
+    BrowserCrashed {
+        /// The crashed tab.
+        tab_id: TabId,
+    },
+
     /// Command request timeout.
     ///
     /// Returned when WebSocket request times out.
@@ -279,6 +327,67 @@ pub enum Error {
     ChannelClosed(#[from] RecvError),
 }
 
+// ============================================================================
+// NavigationError
+// ============================================================================
+
+/// Reason a page navigation failed.
+///
+/// Classified from the Firefox network error code reported by
+/// `webNavigation.onErrorOccurred`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationError {
+    /// Host name could not be resolved.
+    DnsFailure,
+    /// Server refused or reset the connection.
+    ConnectionRefused,
+    /// TLS handshake or certificate validation failed.
+    TlsError,
+    /// Connection or response timed out.
+    Timeout,
+    /// Navigation was cancelled (e.g. by another navigation or a block rule).
+    Aborted,
+    /// Any other failure; see the raw error code.
+    Other,
+}
+
+impl NavigationError {
+    /// Classifies a Firefox error code.
+    #[must_use]
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "NS_ERROR_UNKNOWN_HOST" | "NS_ERROR_UNKNOWN_PROXY_HOST" => Self::DnsFailure,
+            "NS_ERROR_CONNECTION_REFUSED"
+            | "NS_ERROR_NET_RESET"
+            | "NS_ERROR_NET_INTERRUPT"
+            | "NS_ERROR_PROXY_CONNECTION_REFUSED" => Self::ConnectionRefused,
+            "NS_ERROR_NET_TIMEOUT" | "NS_ERROR_NET_TIMEOUT_EXTERNAL" => Self::Timeout,
+            "NS_BINDING_ABORTED" | "NS_ERROR_ABORT" => Self::Aborted,
+            code if code.starts_with("SSL_ERROR_")
+                || code.starts_with("SEC_ERROR_")
+                || code.starts_with("MOZILLA_PKIX_ERROR_") =>
+            {
+                Self::TlsError
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for NavigationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::DnsFailure => "DNS failure",
+            Self::ConnectionRefused => "connection refused",
+            Self::TlsError => "TLS error",
+            Self::Timeout => "timeout",
+            Self::Aborted => "aborted",
+            Self::Other => "other",
+        };
+        f.write_str(reason)
+    }
+}
+
 // ============================================================================
 // Error Constructors
 // ============================================================================
@@ -314,6 +423,12 @@ impl Error {
         }
     }
 
+    /// Creates a batch spawn failure error.
+    #[inline]
+    pub fn spawn_failed(requested: usize, errors: Vec<Error>) -> Self {
+        Self::SpawnFailed { requested, errors }
+    }
+
     /// Creates a connection error.
     #[inline]
     pub fn connection(message: impl Into<String>) -> Self {
@@ -376,11 +491,32 @@ impl Error {
         Self::TabNotFound { tab_id }
     }
 
+    /// Creates a navigation error from a Firefox error code.
+    #[inline]
+    pub fn navigation(url: impl Into<String>, code: impl Into<String>) -> Self {
+        let code = code.into();
+        Self::Navigation {
+            url: url.into(),
+            reason: NavigationError::from_code(&code),
+            code,
+        }
+    }
+
     /// Creates a script error.
     #[inline]
     pub fn script_error(message: impl Into<String>) -> Self {
         Self::ScriptError {
             message: message.into(),
+            stack: None,
+        }
+    }
+
+    /// Creates a script error for an exception thrown by page JavaScript.
+    #[inline]
+    pub fn script_exception(message: impl Into<String>, stack: Option<String>) -> Self {
+        Self::ScriptError {
+            message: message.into(),
+            stack,
         }
     }
 
@@ -393,6 +529,18 @@ impl Error {
         }
     }
 
+    /// Creates a script timeout error.
+    #[inline]
+    pub fn script_timeout(timeout_ms: u64) -> Self {
+        Self::ScriptTimeout { timeout_ms }
+    }
+
+    /// Creates a browser crashed error.
+    #[inline]
+    pub fn browser_crashed(tab_id: TabId) -> Self {
+        Self::BrowserCrashed { tab_id }
+    }
+
     /// Creates a request timeout error.
     #[inline]
     pub fn request_timeout(request_id: RequestId, timeout_ms: u64) -> Self {
@@ -428,7 +576,14 @@ impl Error {
     pub fn is_timeout(&self) -> bool {
         matches!(
             self,
-            Self::ConnectionTimeout { .. } | Self::Timeout { .. } | Self::RequestTimeout { .. }
+            Self::ConnectionTimeout { .. }
+                | Self::Timeout { .. }
+                | Self::ScriptTimeout { .. }
+                | Self::RequestTimeout { .. }
+                | Self::Navigation {
+                    reason: NavigationError::Timeout,
+                    ..
+                }
         )
     }
 
@@ -469,6 +624,33 @@ impl Error {
                 | Self::StaleElement { .. }
         )
     }
+
+    /// Returns `true` if the failure is transient and worth retrying.
+    ///
+    /// Covers timeouts (see [`Error::is_timeout`]), stale elements, a closed
+    /// connection and transient I/O errors (interrupted, reset, timed out).
+    /// Errors caused by the request itself, such as
+    /// [`Error::InvalidArgument`], [`Error::ElementNotFound`] or
+    /// [`Error::Json`], return `false`.
+    ///
+    /// [`Tab::retry`](crate::Tab::retry) additionally retries missing
+    /// elements.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Io(e) => matches!(
+                e.kind(),
+                ErrorKind::Interrupted
+                    | ErrorKind::WouldBlock
+                    | ErrorKind::TimedOut
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::BrokenPipe
+            ),
+            Self::ConnectionClosed => true,
+            _ => self.is_timeout() || self.is_recoverable(),
+        }
+    }
 }
 
 // ============================================================================
@@ -479,8 +661,6 @@ impl Error {
 mod tests {
     use super::*;
 
-    use std::io::ErrorKind;
-
     #[test]
     fn test_error_display() {
         let err = Error::connection("failed to connect");
@@ -500,6 +680,7 @@ mod tests {
 
         assert!(timeout_err.is_timeout());
         assert!(!other_err.is_timeout());
+        assert!(Error::script_timeout(500).is_timeout());
     }
 
     #[test]
@@ -527,6 +708,77 @@ mod tests {
         assert!(!config_err.is_recoverable());
     }
 
+    #[test]
+    fn test_navigation_error_from_code() {
+        assert_eq!(
+            NavigationError::from_code("NS_ERROR_UNKNOWN_HOST"),
+            NavigationError::DnsFailure
+        );
+        assert_eq!(
+            NavigationError::from_code("NS_ERROR_CONNECTION_REFUSED"),
+            NavigationError::ConnectionRefused
+        );
+        assert_eq!(
+            NavigationError::from_code("SSL_ERROR_BAD_CERT_DOMAIN"),
+            NavigationError::TlsError
+        );
+        assert_eq!(
+            NavigationError::from_code("NS_BINDING_ABORTED"),
+            NavigationError::Aborted
+        );
+        assert_eq!(
+            NavigationError::from_code("NS_ERROR_SOMETHING_NEW"),
+            NavigationError::Other
+        );
+    }
+
+    #[test]
+    fn test_navigation_error() {
+        let err = Error::navigation("https://nope.invalid", "NS_ERROR_NET_TIMEOUT");
+        assert!(err.is_timeout());
+        assert!(matches!(
+            err,
+            Error::Navigation {
+                reason: NavigationError::Timeout,
+                ref code,
+                ..
+            } if code == "NS_ERROR_NET_TIMEOUT"
+        ));
+        assert!(err.to_string().contains("https://nope.invalid"));
+    }
+
+    #[test]
+    fn test_spawn_failed_display() {
+        let err = Error::spawn_failed(
+            10,
+            vec![Error::connection_timeout(100), Error::ConnectionClosed],
+        );
+        assert_eq!(err.to_string(), "Failed to spawn 2 of 10 windows");
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let not_found = Error::ElementNotFound {
+            selector: "#missing".into(),
+            tab_id: TabId::new(1).expect("valid tab id"),
+            frame_id: FrameId::main(),
+        };
+
+        assert!(!not_found.is_retryable());
+        assert!(!not_found.is_recoverable());
+        assert!(!Error::config("test").is_retryable());
+        assert!(!Error::invalid_argument("bad").is_retryable());
+
+        assert!(Error::ConnectionClosed.is_retryable());
+        assert!(Error::connection_timeout(100).is_retryable());
+        assert!(Error::navigation("https://example.com", "NS_ERROR_NET_TIMEOUT").is_retryable());
+        assert!(Error::from(IoError::new(ErrorKind::ConnectionReset, "reset")).is_retryable());
+        assert!(!Error::from(IoError::new(ErrorKind::NotFound, "missing")).is_retryable());
+
+        let json_err = serde_json::from_str::<serde_json::Value>("{").expect_err("invalid json");
+        assert!(!Error::from(json_err).is_retryable());
+    }
+
     #[test]
     fn test_from_io_error() {
         let io_err = IoError::new(ErrorKind::NotFound, "file not found");
@@ -18,6 +18,22 @@
 //! | [`SubscriptionId`] | UUID v4 | Extension | Element observation |
 //! | [`InterceptId`] | UUID v4 | Extension | Network interception |
 //!
+//! # String and Serde Forms
+//!
+//! Every identifier has a stable canonical string form: [`Display`] writes
+//! it and [`FromStr`](std::str::FromStr) parses it back, so IDs can be logged, stored and
+//! reloaded (e.g. to reconnect to a session). The serde form matches the
+//! wire protocol and is equally stable.
+//!
+//! | ID | String form | Serde form |
+//! |----|-------------|------------|
+//! | [`SessionId`], [`TabId`] | Decimal, `> 0` (`"7"`) | Number |
+//! | [`FrameId`] | Decimal (`"0"` = main frame) | Number |
+//! | [`RequestId`] | Lowercase hyphenated UUID | String |
+//! | [`ElementId`], [`ScriptId`], [`SubscriptionId`], [`InterceptId`] | Raw string | String |
+//!
+//! [`Display`]: std::fmt::Display
+//!
 //! # Example
 //!
 //! ```ignore
@@ -36,14 +52,18 @@
 // Imports
 // ============================================================================
 
+use std::convert::Infallible;
 use std::fmt;
 use std::num::NonZeroU32;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use serde::de::Error as DeError;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
+use crate::error::Error;
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -126,6 +146,14 @@ impl fmt::Display for SessionId {
     }
 }
 
+impl FromStr for SessionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_non_zero(s, "session ID").map(Self)
+    }
+}
+
 impl Serialize for SessionId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -183,6 +211,15 @@ impl TabId {
         NonZeroU32::new(id).map(Self)
     }
 
+    /// Creates a TabId from a u32 value.
+    ///
+    /// Same as [`TabId::new`]; mirrors [`SessionId::from_u32`].
+    #[inline]
+    #[must_use]
+    pub fn from_u32(id: u32) -> Option<Self> {
+        Self::new(id)
+    }
+
     /// Returns the underlying `u32` value.
     #[inline]
     #[must_use]
@@ -197,6 +234,14 @@ impl fmt::Display for TabId {
     }
 }
 
+impl FromStr for TabId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_non_zero(s, "tab ID").map(Self)
+    }
+}
+
 impl Serialize for TabId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -284,6 +329,16 @@ impl fmt::Display for FrameId {
     }
 }
 
+impl FromStr for FrameId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(Self)
+            .map_err(|_| Error::invalid_argument(format!("Invalid frame ID: {s:?}")))
+    }
+}
+
 impl From<u64> for FrameId {
     #[inline]
     fn from(id: u64) -> Self {
@@ -361,6 +416,16 @@ impl fmt::Display for RequestId {
     }
 }
 
+impl FromStr for RequestId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| Error::invalid_argument(format!("Invalid request ID: {s:?}")))
+    }
+}
+
 impl From<Uuid> for RequestId {
     #[inline]
     fn from(uuid: Uuid) -> Self {
@@ -408,6 +473,14 @@ impl fmt::Display for ElementId {
     }
 }
 
+impl FromStr for ElementId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for ElementId {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -448,6 +521,14 @@ impl fmt::Display for ScriptId {
     }
 }
 
+impl FromStr for ScriptId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for ScriptId {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -488,6 +569,14 @@ impl fmt::Display for SubscriptionId {
     }
 }
 
+impl FromStr for SubscriptionId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for SubscriptionId {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -528,6 +617,14 @@ impl fmt::Display for InterceptId {
     }
 }
 
+impl FromStr for InterceptId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for InterceptId {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -535,6 +632,16 @@ impl AsRef<str> for InterceptId {
     }
 }
 
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Parses a decimal, non-zero `u32` identifier.
+fn parse_non_zero(s: &str, what: &str) -> Result<NonZeroU32, Error> {
+    s.parse()
+        .map_err(|_| Error::invalid_argument(format!("Invalid {what}: {s:?}")))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -675,6 +782,100 @@ mod tests {
         assert_eq!(id, parsed);
     }
 
+    #[test]
+    fn test_tab_id_from_u32() {
+        assert!(TabId::from_u32(0).is_none());
+        assert_eq!(TabId::from_u32(7).map(|id| id.as_u32()), Some(7));
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        let session = SessionId::from_u32(42).expect("valid");
+        assert_eq!(session.to_string().parse::<SessionId>().ok(), Some(session));
+
+        let tab = TabId::new(7).expect("valid");
+        assert_eq!(tab.to_string().parse::<TabId>().ok(), Some(tab));
+
+        let frame = FrameId::new(17179869185);
+        assert_eq!(frame.to_string().parse::<FrameId>().ok(), Some(frame));
+
+        let request = RequestId::generate();
+        assert_eq!(request.to_string().parse::<RequestId>().ok(), Some(request));
+
+        let element = ElementId::new("elem-uuid");
+        assert_eq!(element.to_string().parse::<ElementId>(), Ok(element));
+
+        let script = ScriptId::new("script-123");
+        assert_eq!(script.to_string().parse::<ScriptId>(), Ok(script));
+
+        let subscription = SubscriptionId::new("sub-456");
+        assert_eq!(
+            subscription.to_string().parse::<SubscriptionId>(),
+            Ok(subscription)
+        );
+
+        let intercept = InterceptId::new("intercept-789");
+        assert_eq!(intercept.to_string().parse::<InterceptId>(), Ok(intercept));
+    }
+
+    #[test]
+    fn test_from_str_rejects_invalid() {
+        assert!("0".parse::<SessionId>().is_err());
+        assert!("abc".parse::<SessionId>().is_err());
+        assert!("0".parse::<TabId>().is_err());
+        assert!("-1".parse::<TabId>().is_err());
+        assert!("1.5".parse::<FrameId>().is_err());
+        assert!("not-a-uuid".parse::<RequestId>().is_err());
+    }
+
+    #[test]
+    fn test_canonical_forms() {
+        assert_eq!(TabId::new(7).expect("valid").to_string(), "7");
+        assert_eq!(FrameId::main().to_string(), "0");
+        assert_eq!(
+            RequestId::ready().to_string(),
+            "00000000-0000-0000-0000-000000000000"
+        );
+        assert_eq!(
+            serde_json::to_string(&TabId::new(7).expect("valid")).expect("serialize"),
+            "7"
+        );
+        assert_eq!(
+            serde_json::to_string(&ElementId::new("e1")).expect("serialize"),
+            r#""e1""#
+        );
+    }
+
+    #[test]
+    fn test_serde_request_id() {
+        let id = RequestId::generate();
+        let json = serde_json::to_string(&id).expect("serialize");
+        assert_eq!(json, format!("\"{id}\""));
+        let parsed: RequestId = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(id, parsed);
+    }
+
+    #[test]
+    fn test_serde_string_ids() {
+        let script = ScriptId::new("script-123");
+        let json = serde_json::to_string(&script).expect("serialize");
+        assert_eq!(serde_json::from_str::<ScriptId>(&json).ok(), Some(script));
+
+        let subscription = SubscriptionId::new("sub-456");
+        let json = serde_json::to_string(&subscription).expect("serialize");
+        assert_eq!(
+            serde_json::from_str::<SubscriptionId>(&json).ok(),
+            Some(subscription)
+        );
+
+        let intercept = InterceptId::new("intercept-789");
+        let json = serde_json::to_string(&intercept).expect("serialize");
+        assert_eq!(
+            serde_json::from_str::<InterceptId>(&json).ok(),
+            Some(intercept)
+        );
+    }
+
     #[test]
     fn test_serde_element_id() {
         let id = ElementId::new("elem-uuid");
@@ -107,17 +107,32 @@ pub mod transport;
 
 // Browser types
 pub use browser::{
-    BodyAction, By, Cookie, Element, FrameInfo, HeadersAction, ImageFormat, InterceptedRequest,
+    AxNode, BatchBuilder, BodyAction, By, ClearDataOptions, ColorScheme, Cookie, CookieChange,
+    CrashInfo, DEFAULT_PROXY_BYPASS, DownloadInfo, Element, ElementSnapshot, FrameInfo, Har,
+    HarCaptureOptions, HarEntry, HeadersAction, ImageFormat, InterceptedRequest,
     InterceptedRequestBody, InterceptedRequestHeaders, InterceptedResponse,
-    InterceptedResponseBody, Key, ProxyConfig, ProxyType, RequestAction, RequestBody,
-    ResponseAction, ScreenshotBuilder, Tab, Window,
+    InterceptedResponseBody, Key, KeyInput, LogRecord, MediaFeature, MediaType, MousePathOptions,
+    NavigationResponse, NetworkConditions, NewTabOptions, PerformanceTiming, Permission,
+    PermissionState, PooledWindow, ProxyConfig, ProxyType, ReducedMotion, RequestAction,
+    RequestBody, RequestLogOptions, RequestRecord, ResourceType, ResourceUsage, ResponseAction,
+    RetryPolicy, ScreenshotBuilder, ScrollAlign, ServiceWorkerInfo, SessionHealth, SessionStatus,
+    SseMessage, Tab, TabInfo, Window, WindowBounds, WindowPool, WindowState, WsDirection, WsFrame,
+    WsFrameAction,
 };
 
 // Driver types
-pub use driver::{Driver, DriverBuilder, ExtensionSource, FirefoxOptions, Profile};
+pub use driver::{
+    Capabilities, Driver, DriverBuilder, ExtensionSource, FirefoxOptions, FirefoxPreference,
+    PreferenceValue, Profile,
+};
+
+// Transport types
+#[cfg(feature = "tls")]
+pub use transport::TlsConfig;
+pub use transport::{CommandMetric, MockTransport, ReadyData, Transport, TransportFactory};
 
 // Error types
-pub use error::{Error, Result};
+pub use error::{Error, NavigationError, Result};
 
 // Identifier types
 pub use identifiers::{
@@ -14,11 +14,15 @@
 //! | `proxy` | Proxy configuration |
 //! | `storage` | Cookies |
 //! | `session` | Status, subscriptions |
+//! | `ws` | WebSocket frame interception |
+//! | `downloads` | Download completion |
 
 // ============================================================================
 // Imports
 // ============================================================================
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -50,6 +54,28 @@ pub enum Command {
     Proxy(ProxyCommand),
     /// Storage module commands.
     Storage(StorageCommand),
+    /// WebSocket module commands.
+    WebSocket(WebSocketCommand),
+    /// Downloads module commands.
+    Downloads(DownloadsCommand),
+}
+
+impl Command {
+    /// Returns the protocol method name (e.g. `browsingContext.navigate`).
+    pub(crate) fn method_name(&self) -> &'static str {
+        match self {
+            Self::BrowsingContext(command) => command.method_name(),
+            Self::Element(command) => command.method_name(),
+            Self::Session(command) => command.method_name(),
+            Self::Script(command) => command.method_name(),
+            Self::Input(command) => command.method_name(),
+            Self::Network(command) => command.method_name(),
+            Self::Proxy(command) => command.method_name(),
+            Self::Storage(command) => command.method_name(),
+            Self::WebSocket(command) => command.method_name(),
+            Self::Downloads(command) => command.method_name(),
+        }
+    }
 }
 
 // ============================================================================
@@ -87,10 +113,30 @@ pub enum BrowsingContextCommand {
     #[serde(rename = "browsingContext.getUrl")]
     GetUrl,
 
+    /// Get the HTTP response of the last main-document navigation.
+    #[serde(rename = "browsingContext.getMainResponse")]
+    GetMainResponse,
+
     /// Create new tab.
     #[serde(rename = "browsingContext.newTab")]
     NewTab,
 
+    /// Create a tab and load a URL in it (`browser.tabs.create`).
+    #[serde(rename = "browsingContext.createTab")]
+    CreateTab {
+        /// URL to load.
+        url: String,
+        /// Whether the tab becomes the selected tab.
+        active: bool,
+        /// Position in the tab strip (appended if omitted).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        index: Option<usize>,
+    },
+
+    /// List all tabs in the window.
+    #[serde(rename = "browsingContext.listTabs")]
+    ListTabs,
+
     /// Close current tab.
     #[serde(rename = "browsingContext.closeTab")]
     CloseTab,
@@ -103,6 +149,35 @@ pub enum BrowsingContextCommand {
     #[serde(rename = "browsingContext.focusWindow")]
     FocusWindow,
 
+    /// Set the minimized/maximized/fullscreen state of the window
+    /// containing the tab.
+    #[serde(rename = "browsingContext.setWindowState")]
+    SetWindowState {
+        /// "normal", "minimized", "maximized" or "fullscreen".
+        state: String,
+    },
+
+    /// Get the position and size of the window containing the tab.
+    #[serde(rename = "browsingContext.getWindowBounds")]
+    GetWindowBounds,
+
+    /// Move and resize the window containing the tab.
+    #[serde(rename = "browsingContext.setWindowBounds")]
+    SetWindowBounds {
+        /// Left edge in screen pixels.
+        x: i32,
+        /// Top edge in screen pixels.
+        y: i32,
+        /// Outer width in pixels.
+        width: u32,
+        /// Outer height in pixels.
+        height: u32,
+    },
+
+    /// Close the browser window containing the tab.
+    #[serde(rename = "browsingContext.closeWindow")]
+    CloseWindow,
+
     /// Switch to frame by element reference.
     #[serde(rename = "browsingContext.switchToFrame")]
     SwitchToFrame {
@@ -126,6 +201,13 @@ pub enum BrowsingContextCommand {
         url_pattern: String,
     },
 
+    /// Switch to a child frame by iframe `name` or `id` attribute.
+    #[serde(rename = "browsingContext.switchToFrameByName")]
+    SwitchToFrameByName {
+        /// Value of the iframe's `name` or `id` attribute.
+        name: String,
+    },
+
     /// Switch to parent frame.
     #[serde(rename = "browsingContext.switchToParentFrame")]
     SwitchToParentFrame,
@@ -147,6 +229,24 @@ pub enum BrowsingContextCommand {
         #[serde(skip_serializing_if = "Option::is_none")]
         quality: Option<u8>,
     },
+
+    /// Emulate CSS media features (persists across navigations).
+    #[serde(rename = "browsingContext.setEmulatedMedia")]
+    SetEmulatedMedia {
+        /// `prefers-color-scheme`: "light" or "dark".
+        #[serde(rename = "colorScheme", skip_serializing_if = "Option::is_none")]
+        color_scheme: Option<String>,
+        /// `prefers-reduced-motion`: "no-preference" or "reduce".
+        #[serde(rename = "reducedMotion", skip_serializing_if = "Option::is_none")]
+        reduced_motion: Option<String>,
+        /// Media type: "screen" or "print".
+        #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
+        media_type: Option<String>,
+    },
+
+    /// Clear emulated media features.
+    #[serde(rename = "browsingContext.clearEmulatedMedia")]
+    ClearEmulatedMedia,
 }
 
 // ============================================================================
@@ -167,6 +267,9 @@ pub enum ElementCommand {
         /// Parent element ID (optional).
         #[serde(rename = "parentId", skip_serializing_if = "Option::is_none")]
         parent_id: Option<ElementId>,
+        /// Restrict matches to this tag name (optional).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
     },
 
     /// Find all elements by strategy.
@@ -179,6 +282,24 @@ pub enum ElementCommand {
         /// Parent element ID (optional).
         #[serde(rename = "parentId", skip_serializing_if = "Option::is_none")]
         parent_id: Option<ElementId>,
+        /// Restrict matches to this tag name (optional).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+    },
+
+    /// Count elements by strategy without registering handles.
+    #[serde(rename = "element.count")]
+    Count {
+        /// Selector strategy.
+        strategy: String,
+        /// Selector value.
+        value: String,
+        /// Parent element ID (optional).
+        #[serde(rename = "parentId", skip_serializing_if = "Option::is_none")]
+        parent_id: Option<ElementId>,
+        /// Restrict matches to this tag name (optional).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
     },
 
     /// Get property via `element[name]`.
@@ -203,6 +324,28 @@ pub enum ElementCommand {
         value: Value,
     },
 
+    /// Set `value` through the native prototype setter and dispatch
+    /// bubbling `input` and `change` events.
+    #[serde(rename = "element.setValue")]
+    SetValue {
+        /// Element ID.
+        #[serde(rename = "elementId")]
+        element_id: ElementId,
+        /// New value.
+        value: String,
+    },
+
+    /// Dispatch `dragenter`, `dragover` and `drop` with a `DataTransfer`
+    /// holding these files.
+    #[serde(rename = "element.dropFiles")]
+    DropFiles {
+        /// Element ID of the drop zone.
+        #[serde(rename = "elementId")]
+        element_id: ElementId,
+        /// Files to drop.
+        files: Vec<FilePayload>,
+    },
+
     /// Call method via `element[name](...args)`.
     #[serde(rename = "element.callMethod")]
     CallMethod {
@@ -223,6 +366,9 @@ pub enum ElementCommand {
         strategy: String,
         /// Selector value.
         value: String,
+        /// Restrict matches to this tag name (optional).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
         /// Auto-unsubscribe after first match.
         #[serde(rename = "oneShot")]
         one_shot: bool,
@@ -255,6 +401,18 @@ pub enum ElementCommand {
         element_id: ElementId,
     },
 
+    /// Remove an element from the content script's store.
+    #[serde(rename = "element.release")]
+    Release {
+        /// Element ID.
+        #[serde(rename = "elementId")]
+        element_id: ElementId,
+    },
+
+    /// Remove every element from the content script's store.
+    #[serde(rename = "element.releaseAll")]
+    ReleaseAll,
+
     /// Watch for attribute changes.
     #[serde(rename = "element.watchAttribute")]
     WatchAttribute {
@@ -300,10 +458,18 @@ pub enum SessionCommand {
     #[serde(rename = "session.status")]
     Status,
 
+    /// Get extension and Firefox versions and the supported command methods.
+    #[serde(rename = "session.getCapabilities")]
+    GetCapabilities,
+
     /// Get and clear extension logs.
     #[serde(rename = "session.stealLogs")]
     StealLogs,
 
+    /// Close all browser windows so Firefox exits cleanly.
+    #[serde(rename = "session.quit")]
+    Quit,
+
     /// Subscribe to events.
     #[serde(rename = "session.subscribe")]
     Subscribe {
@@ -320,6 +486,19 @@ pub enum SessionCommand {
         /// Subscription ID.
         subscription_id: String,
     },
+
+    /// Run several commands in order with one round trip.
+    ///
+    /// Result is `{ "responses": [...] }` with one `{ type, result, error,
+    /// message }` entry per executed command.
+    #[serde(rename = "session.batch")]
+    Batch {
+        /// Commands to run, in order, against the request's tab and frame.
+        commands: Vec<Command>,
+        /// Skip the remaining commands after the first error.
+        #[serde(default, rename = "stopOnError")]
+        stop_on_error: bool,
+    },
 }
 
 // ============================================================================
@@ -338,6 +517,12 @@ pub enum ScriptCommand {
         /// Script arguments.
         #[serde(default)]
         args: Vec<Value>,
+        /// Run in the content script's isolated world instead of the page's.
+        #[serde(default)]
+        isolated: bool,
+        /// Abort after this many milliseconds with `script timeout` (optional).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        timeout: Option<u64>,
     },
 
     /// Execute async script.
@@ -348,6 +533,10 @@ pub enum ScriptCommand {
         /// Script arguments.
         #[serde(default)]
         args: Vec<Value>,
+        /// Abort after this many milliseconds with `script timeout` if the
+        /// promise has not settled (optional).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        timeout: Option<u64>,
     },
 
     /// Add preload script.
@@ -361,8 +550,16 @@ pub enum ScriptCommand {
     #[serde(rename = "script.removePreloadScript")]
     RemovePreloadScript {
         /// Script ID.
+        #[serde(rename = "scriptId")]
         script_id: String,
     },
+
+    /// Expose a Rust function as `window[name]` in the page.
+    #[serde(rename = "script.exposeFunction")]
+    ExposeFunction {
+        /// Global function name.
+        name: String,
+    },
 }
 
 // ============================================================================
@@ -410,6 +607,14 @@ pub enum InputCommand {
         element_id: ElementId,
         /// Text to type.
         text: String,
+        /// Minimum pause between characters in milliseconds (optional).
+        #[serde(rename = "minDelayMs", skip_serializing_if = "Option::is_none")]
+        min_delay_ms: Option<u64>,
+        /// Maximum pause between characters in milliseconds (optional).
+        ///
+        /// Each pause is picked uniformly between the minimum and maximum.
+        #[serde(rename = "maxDelayMs", skip_serializing_if = "Option::is_none")]
+        max_delay_ms: Option<u64>,
     },
 
     /// Mouse click.
@@ -443,6 +648,19 @@ pub enum InputCommand {
         y: Option<i32>,
     },
 
+    /// Move mouse through a series of viewport points.
+    ///
+    /// The extension dispatches a `mousemove` at each point, pausing
+    /// `interval_ms` between them.
+    #[serde(rename = "input.mouseMovePath")]
+    MouseMovePath {
+        /// Points as `[x, y]` pairs, ending at the target.
+        points: Vec<(i32, i32)>,
+        /// Pause between points in milliseconds.
+        #[serde(rename = "intervalMs")]
+        interval_ms: u64,
+    },
+
     /// Mouse button down.
     #[serde(rename = "input.mouseDown")]
     MouseDown {
@@ -476,6 +694,30 @@ pub enum InputCommand {
         #[serde(default)]
         button: u8,
     },
+
+    /// Dispatch a bubbling `WheelEvent` at an element's center or at
+    /// coordinates.
+    #[serde(rename = "input.wheel")]
+    Wheel {
+        /// Element ID (optional).
+        #[serde(rename = "elementId", skip_serializing_if = "Option::is_none")]
+        element_id: Option<ElementId>,
+        /// X coordinate.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        x: Option<i32>,
+        /// Y coordinate.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        y: Option<i32>,
+        /// Horizontal delta (positive = right).
+        #[serde(rename = "deltaX")]
+        delta_x: i32,
+        /// Vertical delta (positive = down).
+        #[serde(rename = "deltaY")]
+        delta_y: i32,
+        /// `WheelEvent.deltaMode` (0 = pixel, 1 = line, 2 = page).
+        #[serde(rename = "deltaMode")]
+        delta_mode: u32,
+    },
 }
 
 // ============================================================================
@@ -504,6 +746,16 @@ pub enum NetworkCommand {
         /// Intercept response body.
         #[serde(default, rename = "interceptResponseBody")]
         intercept_response_body: bool,
+        /// URL patterns to forward (wildcards `*`; empty = all URLs).
+        #[serde(default, rename = "urlPatterns", skip_serializing_if = "Vec::is_empty")]
+        url_patterns: Vec<String>,
+        /// Resource types to forward (e.g. `xmlhttprequest`; empty = all types).
+        #[serde(
+            default,
+            rename = "resourceTypes",
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        resource_types: Vec<String>,
     },
 
     /// Remove network intercept.
@@ -524,6 +776,80 @@ pub enum NetworkCommand {
     /// Clear all block rules.
     #[serde(rename = "network.clearBlockRules")]
     ClearBlockRules,
+
+    /// Block requests by resource type (empty list unblocks all types).
+    #[serde(rename = "network.setBlockedResourceTypes")]
+    SetBlockedResourceTypes {
+        /// `declarativeNetRequest` resource type names.
+        #[serde(rename = "resourceTypes")]
+        resource_types: Vec<String>,
+    },
+
+    /// Set static headers added to every request.
+    #[serde(rename = "network.setExtraHeaders")]
+    SetExtraHeaders {
+        /// Lowercase header names to values (empty value removes the header).
+        headers: HashMap<String, String>,
+    },
+
+    /// Clear static extra headers.
+    #[serde(rename = "network.clearExtraHeaders")]
+    ClearExtraHeaders,
+
+    /// Emulate network conditions (offline, bandwidth, latency).
+    #[serde(rename = "network.setNetworkConditions")]
+    SetNetworkConditions {
+        /// Fail all requests.
+        offline: bool,
+        /// Download throughput in bytes per second (-1 = unlimited).
+        #[serde(rename = "downloadThroughput")]
+        download_throughput: i64,
+        /// Upload throughput in bytes per second (-1 = unlimited).
+        #[serde(rename = "uploadThroughput")]
+        upload_throughput: i64,
+        /// Added latency per request in milliseconds.
+        #[serde(rename = "latencyMs")]
+        latency_ms: u64,
+    },
+
+    /// Clear emulated network conditions.
+    #[serde(rename = "network.clearNetworkConditions")]
+    ClearNetworkConditions,
+
+    /// Observe Server-Sent Events streams opened by the page.
+    #[serde(rename = "network.subscribeSse")]
+    SubscribeSse {
+        /// URL pattern of streams to observe (wildcards `*`).
+        #[serde(rename = "urlPattern")]
+        url_pattern: String,
+    },
+
+    /// Stop observing Server-Sent Events streams.
+    #[serde(rename = "network.unsubscribeSse")]
+    UnsubscribeSse {
+        /// Subscription ID.
+        #[serde(rename = "subscriptionId")]
+        subscription_id: String,
+    },
+
+    /// Passively log finished requests in the tab (no allow/block decisions).
+    #[serde(rename = "network.subscribeRequestLog")]
+    SubscribeRequestLog,
+
+    /// Stop logging requests.
+    #[serde(rename = "network.unsubscribeRequestLog")]
+    UnsubscribeRequestLog {
+        /// Subscription ID.
+        #[serde(rename = "subscriptionId")]
+        subscription_id: String,
+    },
+
+    /// Toggle offline mode (blocks all requests, updates `navigator.onLine`).
+    #[serde(rename = "network.setOffline")]
+    SetOffline {
+        /// Whether the tab is offline.
+        offline: bool,
+    },
 }
 
 // ============================================================================
@@ -553,6 +879,9 @@ pub enum ProxyCommand {
         /// Proxy DNS (SOCKS only).
         #[serde(rename = "proxyDns", default)]
         proxy_dns: bool,
+        /// Hosts connected to directly (`network.proxy.no_proxies_on` syntax).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        bypass: Vec<String>,
     },
 
     /// Clear window-level proxy.
@@ -578,6 +907,9 @@ pub enum ProxyCommand {
         /// Proxy DNS (SOCKS only).
         #[serde(rename = "proxyDns", default)]
         proxy_dns: bool,
+        /// Hosts connected to directly (`network.proxy.no_proxies_on` syntax).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        bypass: Vec<String>,
     },
 
     /// Clear tab-level proxy.
@@ -629,6 +961,87 @@ pub enum StorageCommand {
         /// URL (optional).
         #[serde(skip_serializing_if = "Option::is_none")]
         url: Option<String>,
+        /// Read from the `browser.cookies` store so HttpOnly cookies are included.
+        #[serde(default, rename = "includeHttpOnly")]
+        include_http_only: bool,
+    },
+
+    /// Remove cookies from the browser cookie store, including HttpOnly.
+    #[serde(rename = "storage.clearCookies")]
+    ClearCookies {
+        /// Only remove cookies for this domain and its subdomains (all if `None`).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        domain: Option<String>,
+    },
+
+    /// Clear browsing data via the `browsingData` API.
+    #[serde(rename = "storage.clearBrowsingData")]
+    ClearBrowsingData {
+        /// Data types (`cookies`, `localStorage`, `sessionStorage`, `indexedDB`,
+        /// `cache`, `serviceWorkers`).
+        #[serde(rename = "dataTypes")]
+        data_types: Vec<String>,
+        /// Restrict removal to these hostnames (empty = all sites).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        hostnames: Vec<String>,
+    },
+
+    /// Observe cookie changes via `cookies.onChanged`.
+    #[serde(rename = "storage.subscribeCookieChanges")]
+    SubscribeCookieChanges,
+
+    /// Stop observing cookie changes.
+    #[serde(rename = "storage.unsubscribeCookieChanges")]
+    UnsubscribeCookieChanges {
+        /// Subscription ID.
+        #[serde(rename = "subscriptionId")]
+        subscription_id: String,
+    },
+}
+
+// ============================================================================
+// WebSocket Commands
+// ============================================================================
+
+/// WebSocket module commands for frame interception.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params")]
+pub enum WebSocketCommand {
+    /// Add WebSocket frame intercept.
+    #[serde(rename = "ws.addIntercept")]
+    AddIntercept {
+        /// URL patterns of sockets to intercept (wildcards `*`; empty = all).
+        #[serde(default, rename = "urlPatterns", skip_serializing_if = "Vec::is_empty")]
+        url_patterns: Vec<String>,
+    },
+
+    /// Remove WebSocket frame intercept.
+    #[serde(rename = "ws.removeIntercept")]
+    RemoveIntercept {
+        /// Intercept ID.
+        #[serde(rename = "interceptId")]
+        intercept_id: InterceptId,
+    },
+}
+
+// ============================================================================
+// Downloads Commands
+// ============================================================================
+
+/// Downloads module commands for observing file downloads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params")]
+pub enum DownloadsCommand {
+    /// Report finished downloads as `downloads.finished` events.
+    #[serde(rename = "downloads.subscribe")]
+    Subscribe,
+
+    /// Stop reporting finished downloads.
+    #[serde(rename = "downloads.unsubscribe")]
+    Unsubscribe {
+        /// Subscription ID.
+        #[serde(rename = "subscriptionId")]
+        subscription_id: String,
     },
 }
 
@@ -729,6 +1142,200 @@ impl Cookie {
     }
 }
 
+// ============================================================================
+// FilePayload
+// ============================================================================
+
+/// File contents sent to the extension to build a `File` object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilePayload {
+    /// File name (without directory).
+    pub name: String,
+    /// MIME type (`File.type`).
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+    /// Base64-encoded contents.
+    pub data: String,
+}
+
+// ============================================================================
+// Method Names
+// ============================================================================
+
+impl BrowsingContextCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::Navigate { .. } => "browsingContext.navigate",
+            Self::Reload { .. } => "browsingContext.reload",
+            Self::GoBack { .. } => "browsingContext.goBack",
+            Self::GoForward { .. } => "browsingContext.goForward",
+            Self::GetTitle { .. } => "browsingContext.getTitle",
+            Self::GetUrl { .. } => "browsingContext.getUrl",
+            Self::GetMainResponse { .. } => "browsingContext.getMainResponse",
+            Self::NewTab { .. } => "browsingContext.newTab",
+            Self::CreateTab { .. } => "browsingContext.createTab",
+            Self::ListTabs { .. } => "browsingContext.listTabs",
+            Self::CloseTab { .. } => "browsingContext.closeTab",
+            Self::FocusTab { .. } => "browsingContext.focusTab",
+            Self::FocusWindow { .. } => "browsingContext.focusWindow",
+            Self::SetWindowState { .. } => "browsingContext.setWindowState",
+            Self::GetWindowBounds { .. } => "browsingContext.getWindowBounds",
+            Self::SetWindowBounds { .. } => "browsingContext.setWindowBounds",
+            Self::CloseWindow { .. } => "browsingContext.closeWindow",
+            Self::SwitchToFrame { .. } => "browsingContext.switchToFrame",
+            Self::SwitchToFrameByIndex { .. } => "browsingContext.switchToFrameByIndex",
+            Self::SwitchToFrameByUrl { .. } => "browsingContext.switchToFrameByUrl",
+            Self::SwitchToFrameByName { .. } => "browsingContext.switchToFrameByName",
+            Self::SwitchToParentFrame { .. } => "browsingContext.switchToParentFrame",
+            Self::GetFrameCount { .. } => "browsingContext.getFrameCount",
+            Self::GetAllFrames { .. } => "browsingContext.getAllFrames",
+            Self::CaptureScreenshot { .. } => "browsingContext.captureScreenshot",
+            Self::SetEmulatedMedia { .. } => "browsingContext.setEmulatedMedia",
+            Self::ClearEmulatedMedia { .. } => "browsingContext.clearEmulatedMedia",
+        }
+    }
+}
+
+impl ElementCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::Find { .. } => "element.find",
+            Self::FindAll { .. } => "element.findAll",
+            Self::Count { .. } => "element.count",
+            Self::GetProperty { .. } => "element.getProperty",
+            Self::SetProperty { .. } => "element.setProperty",
+            Self::SetValue { .. } => "element.setValue",
+            Self::DropFiles { .. } => "element.dropFiles",
+            Self::CallMethod { .. } => "element.callMethod",
+            Self::Subscribe { .. } => "element.subscribe",
+            Self::Unsubscribe { .. } => "element.unsubscribe",
+            Self::WatchRemoval { .. } => "element.watchRemoval",
+            Self::UnwatchRemoval { .. } => "element.unwatchRemoval",
+            Self::Release { .. } => "element.release",
+            Self::ReleaseAll { .. } => "element.releaseAll",
+            Self::WatchAttribute { .. } => "element.watchAttribute",
+            Self::UnwatchAttribute { .. } => "element.unwatchAttribute",
+            Self::CaptureScreenshot { .. } => "element.captureScreenshot",
+        }
+    }
+}
+
+impl SessionCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::Status { .. } => "session.status",
+            Self::GetCapabilities { .. } => "session.getCapabilities",
+            Self::StealLogs { .. } => "session.stealLogs",
+            Self::Quit { .. } => "session.quit",
+            Self::Subscribe { .. } => "session.subscribe",
+            Self::Unsubscribe { .. } => "session.unsubscribe",
+            Self::Batch { .. } => "session.batch",
+        }
+    }
+}
+
+impl ScriptCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::Evaluate { .. } => "script.evaluate",
+            Self::EvaluateAsync { .. } => "script.evaluateAsync",
+            Self::AddPreloadScript { .. } => "script.addPreloadScript",
+            Self::RemovePreloadScript { .. } => "script.removePreloadScript",
+            Self::ExposeFunction { .. } => "script.exposeFunction",
+        }
+    }
+}
+
+impl InputCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::TypeKey { .. } => "input.typeKey",
+            Self::TypeText { .. } => "input.typeText",
+            Self::MouseClick { .. } => "input.mouseClick",
+            Self::MouseMove { .. } => "input.mouseMove",
+            Self::MouseMovePath { .. } => "input.mouseMovePath",
+            Self::MouseDown { .. } => "input.mouseDown",
+            Self::MouseUp { .. } => "input.mouseUp",
+            Self::Wheel { .. } => "input.wheel",
+        }
+    }
+}
+
+impl NetworkCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::AddIntercept { .. } => "network.addIntercept",
+            Self::RemoveIntercept { .. } => "network.removeIntercept",
+            Self::SetBlockRules { .. } => "network.setBlockRules",
+            Self::ClearBlockRules { .. } => "network.clearBlockRules",
+            Self::SetBlockedResourceTypes { .. } => "network.setBlockedResourceTypes",
+            Self::SetExtraHeaders { .. } => "network.setExtraHeaders",
+            Self::ClearExtraHeaders { .. } => "network.clearExtraHeaders",
+            Self::SetNetworkConditions { .. } => "network.setNetworkConditions",
+            Self::ClearNetworkConditions { .. } => "network.clearNetworkConditions",
+            Self::SubscribeSse { .. } => "network.subscribeSse",
+            Self::UnsubscribeSse { .. } => "network.unsubscribeSse",
+            Self::SubscribeRequestLog { .. } => "network.subscribeRequestLog",
+            Self::UnsubscribeRequestLog { .. } => "network.unsubscribeRequestLog",
+            Self::SetOffline { .. } => "network.setOffline",
+        }
+    }
+}
+
+impl ProxyCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::SetWindowProxy { .. } => "proxy.setWindowProxy",
+            Self::ClearWindowProxy { .. } => "proxy.clearWindowProxy",
+            Self::SetTabProxy { .. } => "proxy.setTabProxy",
+            Self::ClearTabProxy { .. } => "proxy.clearTabProxy",
+        }
+    }
+}
+
+impl StorageCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::GetCookie { .. } => "storage.getCookie",
+            Self::SetCookie { .. } => "storage.setCookie",
+            Self::DeleteCookie { .. } => "storage.deleteCookie",
+            Self::GetAllCookies { .. } => "storage.getAllCookies",
+            Self::ClearCookies { .. } => "storage.clearCookies",
+            Self::ClearBrowsingData { .. } => "storage.clearBrowsingData",
+            Self::SubscribeCookieChanges { .. } => "storage.subscribeCookieChanges",
+            Self::UnsubscribeCookieChanges { .. } => "storage.unsubscribeCookieChanges",
+        }
+    }
+}
+
+impl WebSocketCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::AddIntercept { .. } => "ws.addIntercept",
+            Self::RemoveIntercept { .. } => "ws.removeIntercept",
+        }
+    }
+}
+
+impl DownloadsCommand {
+    /// Returns the protocol method name.
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::Subscribe { .. } => "downloads.subscribe",
+            Self::Unsubscribe { .. } => "downloads.unsubscribe",
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -747,18 +1354,146 @@ mod tests {
         assert!(json.contains("https://example.com"));
     }
 
+    #[test]
+    fn test_browsing_context_get_main_response() {
+        let cmd = BrowsingContextCommand::GetMainResponse;
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.getMainResponse"));
+    }
+
+    #[test]
+    fn test_command_method_name() {
+        let cmd = Command::BrowsingContext(BrowsingContextCommand::Reload);
+        assert_eq!(cmd.method_name(), "browsingContext.reload");
+
+        let cmd = Command::Session(SessionCommand::Status);
+        assert_eq!(cmd.method_name(), "session.status");
+    }
+
+    #[test]
+    fn test_method_name_matches_serialized_method() {
+        let commands = [
+            Command::BrowsingContext(BrowsingContextCommand::GoBack),
+            Command::Element(ElementCommand::ReleaseAll),
+            Command::Session(SessionCommand::StealLogs),
+            Command::Network(NetworkCommand::ClearExtraHeaders),
+            Command::Proxy(ProxyCommand::ClearWindowProxy),
+            Command::Storage(StorageCommand::SubscribeCookieChanges),
+            Command::Downloads(DownloadsCommand::Subscribe),
+        ];
+
+        for command in commands {
+            let json = serde_json::to_value(&command).expect("serialize");
+            assert_eq!(json["method"], command.method_name());
+        }
+    }
+
+    #[test]
+    fn test_browsing_context_list_tabs() {
+        let cmd = BrowsingContextCommand::ListTabs;
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.listTabs"));
+    }
+
+    #[test]
+    fn test_browsing_context_create_tab() {
+        let cmd = BrowsingContextCommand::CreateTab {
+            url: "https://example.com".to_string(),
+            active: false,
+            index: None,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.createTab"));
+        assert!(json.contains(r#""active":false"#));
+        assert!(!json.contains("index"));
+    }
+
+    #[test]
+    fn test_browsing_context_window_state_and_bounds() {
+        let cmd = BrowsingContextCommand::SetWindowState {
+            state: "minimized".to_string(),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.setWindowState"));
+        assert!(json.contains(r#""state":"minimized""#));
+
+        let cmd = BrowsingContextCommand::SetWindowBounds {
+            x: -10,
+            y: 0,
+            width: 1280,
+            height: 720,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.setWindowBounds"));
+        assert!(json.contains(r#""x":-10"#));
+        assert!(json.contains(r#""width":1280"#));
+    }
+
+    #[test]
+    fn test_session_get_capabilities() {
+        let cmd = Command::Session(SessionCommand::GetCapabilities);
+        assert_eq!(cmd.method_name(), "session.getCapabilities");
+    }
+
+    #[test]
+    fn test_session_quit() {
+        let cmd = SessionCommand::Quit;
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("session.quit"));
+    }
+
+    #[test]
+    fn test_session_batch() {
+        let cmd = SessionCommand::Batch {
+            commands: vec![
+                Command::BrowsingContext(BrowsingContextCommand::Reload),
+                Command::Element(ElementCommand::GetProperty {
+                    element_id: ElementId::new("elem-uuid"),
+                    name: "textContent".to_string(),
+                }),
+            ],
+            stop_on_error: true,
+        };
+        let json = serde_json::to_value(&cmd).expect("serialize");
+        assert_eq!(json["method"], "session.batch");
+        assert_eq!(json["params"]["stopOnError"], true);
+        assert_eq!(
+            json["params"]["commands"][0]["method"],
+            "browsingContext.reload"
+        );
+        assert_eq!(
+            json["params"]["commands"][1]["method"],
+            "element.getProperty"
+        );
+    }
+
     #[test]
     fn test_element_find() {
         let cmd = ElementCommand::Find {
             strategy: "css".to_string(),
             value: "button.submit".to_string(),
             parent_id: None,
+            tag: None,
         };
         let json = serde_json::to_string(&cmd).expect("serialize");
         assert!(json.contains("element.find"));
         assert!(json.contains("button.submit"));
     }
 
+    #[test]
+    fn test_element_count() {
+        let cmd = ElementCommand::Count {
+            strategy: "css".to_string(),
+            value: ".result".to_string(),
+            parent_id: None,
+            tag: None,
+        };
+        let json = serde_json::to_value(&cmd).expect("serialize");
+        assert_eq!(json["method"], "element.count");
+        assert_eq!(json["params"]["value"], ".result");
+        assert!(json["params"].get("parentId").is_none());
+    }
+
     #[test]
     fn test_element_get_property() {
         let cmd = ElementCommand::GetProperty {
@@ -771,6 +1506,45 @@ mod tests {
         assert!(json.contains("textContent"));
     }
 
+    #[test]
+    fn test_element_set_value() {
+        let cmd = ElementCommand::SetValue {
+            element_id: ElementId::new("test-uuid"),
+            value: "alice".to_string(),
+        };
+        let json = serde_json::to_value(&cmd).expect("serialize");
+        assert_eq!(json["method"], "element.setValue");
+        assert_eq!(json["params"]["elementId"], "test-uuid");
+        assert_eq!(json["params"]["value"], "alice");
+    }
+
+    #[test]
+    fn test_downloads_unsubscribe() {
+        let cmd = Command::Downloads(DownloadsCommand::Unsubscribe {
+            subscription_id: "sub-1".to_string(),
+        });
+        assert_eq!(cmd.method_name(), "downloads.unsubscribe");
+        let json = serde_json::to_value(&cmd).expect("serialize");
+        assert_eq!(json["params"]["subscriptionId"], "sub-1");
+    }
+
+    #[test]
+    fn test_element_drop_files() {
+        let cmd = ElementCommand::DropFiles {
+            element_id: ElementId::new("zone"),
+            files: vec![FilePayload {
+                name: "a.txt".to_string(),
+                mime_type: "text/plain".to_string(),
+                data: "aGk=".to_string(),
+            }],
+        };
+        let json = serde_json::to_value(&cmd).expect("serialize");
+        assert_eq!(json["method"], "element.dropFiles");
+        assert_eq!(json["params"]["elementId"], "zone");
+        assert_eq!(json["params"]["files"][0]["mimeType"], "text/plain");
+        assert_eq!(json["params"]["files"][0]["data"], "aGk=");
+    }
+
     #[test]
     fn test_cookie_builder() {
         let cookie = Cookie::new("session", "abc123")
@@ -794,9 +1568,250 @@ mod tests {
             intercept_request_body: false,
             intercept_responses: false,
             intercept_response_body: false,
+            url_patterns: Vec::new(),
+            resource_types: Vec::new(),
         };
         let json = serde_json::to_string(&cmd).expect("serialize");
         assert!(json.contains("network.addIntercept"));
+        assert!(!json.contains("urlPatterns"));
+        assert!(!json.contains("resourceTypes"));
+    }
+
+    #[test]
+    fn test_network_add_intercept_filtered() {
+        let cmd = NetworkCommand::AddIntercept {
+            intercept_requests: true,
+            intercept_request_headers: false,
+            intercept_request_body: false,
+            intercept_responses: false,
+            intercept_response_body: false,
+            url_patterns: vec!["*/api/*".to_string()],
+            resource_types: vec!["xmlhttprequest".to_string()],
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains(r#""urlPatterns":["*/api/*"]"#));
+        assert!(json.contains(r#""resourceTypes":["xmlhttprequest"]"#));
+    }
+
+    #[test]
+    fn test_network_set_extra_headers() {
+        let mut headers = HashMap::new();
+        headers.insert("x-test".to_string(), "1".to_string());
+        let cmd = NetworkCommand::SetExtraHeaders { headers };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("network.setExtraHeaders"));
+        assert!(json.contains(r#""x-test":"1""#));
+    }
+
+    #[test]
+    fn test_network_set_network_conditions() {
+        let cmd = NetworkCommand::SetNetworkConditions {
+            offline: false,
+            download_throughput: 50_000,
+            upload_throughput: -1,
+            latency_ms: 2_000,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("network.setNetworkConditions"));
+        assert!(json.contains(r#""downloadThroughput":50000"#));
+        assert!(json.contains(r#""uploadThroughput":-1"#));
+        assert!(json.contains(r#""latencyMs":2000"#));
+    }
+
+    #[test]
+    fn test_script_remove_preload_script() {
+        let cmd = ScriptCommand::RemovePreloadScript {
+            script_id: "script-1".to_string(),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("script.removePreloadScript"));
+        assert!(json.contains(r#""scriptId":"script-1""#));
+    }
+
+    #[test]
+    fn test_input_type_text_delays() {
+        let cmd = InputCommand::TypeText {
+            element_id: ElementId::new("el-1"),
+            text: "hi".to_string(),
+            min_delay_ms: Some(50),
+            max_delay_ms: Some(150),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("input.typeText"));
+        assert!(json.contains(r#""minDelayMs":50"#));
+        assert!(json.contains(r#""maxDelayMs":150"#));
+
+        let cmd = InputCommand::TypeText {
+            element_id: ElementId::new("el-1"),
+            text: "hi".to_string(),
+            min_delay_ms: None,
+            max_delay_ms: None,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(!json.contains("DelayMs"));
+    }
+
+    #[test]
+    fn test_input_mouse_click_at_coordinates() {
+        let cmd = InputCommand::MouseClick {
+            element_id: None,
+            x: Some(120),
+            y: Some(80),
+            button: 0,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("input.mouseClick"));
+        assert!(json.contains(r#""x":120"#));
+        assert!(!json.contains("elementId"));
+    }
+
+    #[test]
+    fn test_input_wheel() {
+        let cmd = InputCommand::Wheel {
+            element_id: None,
+            x: Some(10),
+            y: Some(20),
+            delta_x: 0,
+            delta_y: 240,
+            delta_mode: 0,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("input.wheel"));
+        assert!(json.contains(r#""deltaY":240"#));
+        assert!(json.contains(r#""deltaMode":0"#));
+    }
+
+    #[test]
+    fn test_input_mouse_move_path() {
+        let cmd = InputCommand::MouseMovePath {
+            points: vec![(1, 2), (3, 4)],
+            interval_ms: 8,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("input.mouseMovePath"));
+        assert!(json.contains(r#""points":[[1,2],[3,4]]"#));
+        assert!(json.contains(r#""intervalMs":8"#));
+    }
+
+    #[test]
+    fn test_get_all_cookies_include_http_only() {
+        let cmd = StorageCommand::GetAllCookies {
+            url: None,
+            include_http_only: true,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("storage.getAllCookies"));
+        assert!(json.contains(r#""includeHttpOnly":true"#));
+    }
+
+    #[test]
+    fn test_clear_cookies_domain() {
+        let all = serde_json::to_string(&StorageCommand::ClearCookies { domain: None })
+            .expect("serialize");
+        assert!(all.contains("storage.clearCookies"));
+        assert!(!all.contains("domain"));
+
+        let cmd = StorageCommand::ClearCookies {
+            domain: Some("example.com".to_string()),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains(r#""domain":"example.com""#));
+    }
+
+    #[test]
+    fn test_cookie_change_subscription() {
+        let cmd = StorageCommand::SubscribeCookieChanges;
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("storage.subscribeCookieChanges"));
+
+        let cmd = StorageCommand::UnsubscribeCookieChanges {
+            subscription_id: "sub-1".to_string(),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("storage.unsubscribeCookieChanges"));
+        assert!(json.contains(r#""subscriptionId":"sub-1""#));
+    }
+
+    #[test]
+    fn test_clear_browsing_data() {
+        let cmd = StorageCommand::ClearBrowsingData {
+            data_types: vec!["cookies".to_string(), "cache".to_string()],
+            hostnames: vec!["example.com".to_string()],
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("storage.clearBrowsingData"));
+        assert!(json.contains(r#""dataTypes":["cookies","cache"]"#));
+        assert!(json.contains(r#""hostnames":["example.com"]"#));
+    }
+
+    #[test]
+    fn test_switch_to_frame_by_name() {
+        let cmd = BrowsingContextCommand::SwitchToFrameByName {
+            name: "checkout".to_string(),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.switchToFrameByName"));
+        assert!(json.contains(r#""name":"checkout""#));
+    }
+
+    #[test]
+    fn test_script_evaluate_isolated() {
+        let cmd = ScriptCommand::Evaluate {
+            script: "return 1".to_string(),
+            args: vec![],
+            isolated: true,
+            timeout: None,
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("script.evaluate"));
+        assert!(json.contains(r#""isolated":true"#));
+        assert!(!json.contains("timeout"));
+
+        let cmd = ScriptCommand::EvaluateAsync {
+            script: "return new Promise(() => {})".to_string(),
+            args: vec![],
+            timeout: Some(500),
+        };
+        let json = serde_json::to_value(&cmd).expect("serialize");
+        assert_eq!(json["params"]["timeout"], 500);
+    }
+
+    #[test]
+    fn test_script_expose_function() {
+        let cmd = ScriptCommand::ExposeFunction {
+            name: "solveCaptcha".to_string(),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("script.exposeFunction"));
+        assert!(json.contains(r#""name":"solveCaptcha""#));
+    }
+
+    #[test]
+    fn test_ws_add_intercept() {
+        let cmd = Command::WebSocket(WebSocketCommand::AddIntercept {
+            url_patterns: Vec::new(),
+        });
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("ws.addIntercept"));
+        assert!(!json.contains("urlPatterns"));
+    }
+
+    #[test]
+    fn test_network_set_blocked_resource_types() {
+        let cmd = NetworkCommand::SetBlockedResourceTypes {
+            resource_types: vec!["image".to_string(), "font".to_string()],
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("network.setBlockedResourceTypes"));
+        assert!(json.contains(r#""resourceTypes":["image","font"]"#));
+    }
+
+    #[test]
+    fn test_network_set_offline() {
+        let cmd = NetworkCommand::SetOffline { offline: true };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("network.setOffline"));
+        assert!(json.contains(r#""offline":true"#));
     }
 
     #[test]
@@ -817,6 +1832,20 @@ mod tests {
         assert!(json_jpeg.contains("\"quality\":85"));
     }
 
+    #[test]
+    fn test_browsing_context_set_emulated_media() {
+        let cmd = BrowsingContextCommand::SetEmulatedMedia {
+            color_scheme: Some("dark".to_string()),
+            reduced_motion: None,
+            media_type: Some("print".to_string()),
+        };
+        let json = serde_json::to_string(&cmd).expect("serialize");
+        assert!(json.contains("browsingContext.setEmulatedMedia"));
+        assert!(json.contains(r#""colorScheme":"dark""#));
+        assert!(json.contains(r#""mediaType":"print""#));
+        assert!(!json.contains("reducedMotion"));
+    }
+
     #[test]
     fn test_element_capture_screenshot() {
         let cmd = ElementCommand::CaptureScreenshot {
@@ -9,9 +9,11 @@
 //!
 //! | Module | Events |
 //! |--------|--------|
-//! | `browsingContext` | `load`, `domContentLoaded`, `navigationStarted`, `navigationFailed` |
+//! | `browsingContext` | `load`, `domContentLoaded`, `navigationStarted`, `navigationFailed`, `tabCreated`, `tabCrashed`, `windowCreated` |
 //! | `element` | `added`, `removed`, `attributeChanged` |
-//! | `network` | `beforeRequestSent`, `responseStarted`, `responseCompleted` |
+//! | `network` | `beforeRequestSent`, `responseStarted`, `responseCompleted`, `sseMessage` |
+//! | `script` | `functionCalled` |
+//! | `session` | `log` |
 
 // ============================================================================
 // Imports
@@ -22,6 +24,8 @@ use serde_json::{Value, json};
 
 use crate::identifiers::RequestId;
 
+use super::Cookie;
+
 // ============================================================================
 // Event
 // ============================================================================
@@ -207,6 +211,36 @@ pub enum ParsedEvent {
         error: String,
     },
 
+    /// New tab opened in the window.
+    BrowsingContextTabCreated {
+        /// Tab ID of the new tab.
+        tab_id: u32,
+        /// Tab ID of the opener (if opened by a page).
+        opener_tab_id: Option<u32>,
+        /// Initial URL.
+        url: String,
+    },
+
+    /// A tab's content process crashed or stopped responding.
+    BrowsingContextTabCrashed {
+        /// Tab ID of the crashed tab.
+        tab_id: u32,
+        /// Why the extension considers the tab crashed (`crashed`, `unresponsive`).
+        reason: String,
+    },
+
+    /// New browser window opened by a page (e.g. `window.open`).
+    BrowsingContextWindowCreated {
+        /// Browser window ID.
+        window_id: u32,
+        /// Tab ID of the window's first tab.
+        tab_id: u32,
+        /// Tab ID of the opener (if known).
+        opener_tab_id: Option<u32>,
+        /// Initial URL.
+        url: String,
+    },
+
     /// Element added to DOM.
     ElementAdded {
         /// Selector strategy (css, xpath, text, etc.).
@@ -283,6 +317,84 @@ pub enum ParsedEvent {
         status: u16,
     },
 
+    /// Server-Sent Events message received by a page `EventSource`.
+    NetworkSseMessage {
+        /// Subscription that matched the stream.
+        subscription_id: String,
+        /// Stream URL.
+        url: String,
+        /// Event type (`message` unless the server set one).
+        event: String,
+        /// Event data.
+        data: String,
+        /// Last event ID, if the server sent one.
+        id: Option<String>,
+        /// Tab ID.
+        tab_id: u32,
+    },
+
+    /// A request finished or failed while a request log was active.
+    NetworkRequestLogged {
+        /// Subscription that logged the request.
+        subscription_id: String,
+        /// Request ID.
+        request_id: String,
+        /// Request URL.
+        url: String,
+        /// HTTP method.
+        method: String,
+        /// Resource type.
+        resource_type: String,
+        /// HTTP status code, `None` if the request failed.
+        status: Option<u16>,
+        /// Network error, if the request failed.
+        error: Option<String>,
+        /// Request start in milliseconds since the Unix epoch.
+        start_time: f64,
+        /// Completion or failure time in milliseconds since the Unix epoch.
+        end_time: f64,
+        /// Tab ID.
+        tab_id: u32,
+    },
+
+    /// A download completed or was interrupted.
+    DownloadFinished {
+        /// Download URL.
+        url: String,
+        /// Absolute path of the saved file.
+        path: String,
+        /// File size in bytes.
+        bytes: u64,
+        /// MIME type reported by the server.
+        mime: Option<String>,
+        /// Final state: `complete` or `interrupted`.
+        state: String,
+        /// Interrupt reason (e.g. `NETWORK_FAILED`), if interrupted.
+        error: Option<String>,
+    },
+
+    /// Page called a function exposed via `script.exposeFunction`.
+    ScriptFunctionCalled {
+        /// Function name.
+        name: String,
+        /// Call arguments.
+        args: Vec<Value>,
+        /// Tab ID.
+        tab_id: u32,
+    },
+
+    /// A cookie was set, changed or removed (`cookies.onChanged`).
+    StorageCookieChanged {
+        /// Subscription that received the change.
+        subscription_id: String,
+        /// The cookie after the change (before it, when removed).
+        cookie: Option<Cookie>,
+        /// Firefox change cause (`explicit`, `overwrite`, `expired`, ...).
+        cause: String,
+        /// Whether the cookie was removed.
+        removed: bool,
+    },
+
     /// Unknown event type.
     Unknown {
         /// Event method.
@@ -325,6 +437,24 @@ impl Event {
                 error: self.get_string("error"),
             },
 
+            "browsingContext.tabCreated" => ParsedEvent::BrowsingContextTabCreated {
+                tab_id: self.get_u32("tabId"),
+                opener_tab_id: self.get_optional_u32("openerTabId"),
+                url: self.get_string("url"),
+            },
+
+            "browsingContext.tabCrashed" => ParsedEvent::BrowsingContextTabCrashed {
+                tab_id: self.get_u32("tabId"),
+                reason: self.get_string("reason"),
+            },
+
+            "browsingContext.windowCreated" => ParsedEvent::BrowsingContextWindowCreated {
+                window_id: self.get_u32("windowId"),
+                tab_id: self.get_u32("tabId"),
+                opener_tab_id: self.get_optional_u32("openerTabId"),
+                url: self.get_string("url"),
+            },
+
             "element.added" => ParsedEvent::ElementAdded {
                 strategy: self.get_string("strategy"),
                 value: self.get_string("value"),
@@ -369,6 +499,62 @@ impl Event {
                 status: self.get_u16("status"),
             },
 
+            "network.sseMessage" => ParsedEvent::NetworkSseMessage {
+                subscription_id: self.get_string("subscriptionId"),
+                url: self.get_string("url"),
+                event: self.get_string_or("event", "message"),
+                data: self.get_string("data"),
+                id: self.get_optional_string("lastEventId"),
+                tab_id: self.get_u32("tabId"),
+            },
+
+            "network.requestLogged" => ParsedEvent::NetworkRequestLogged {
+                subscription_id: self.get_string("subscriptionId"),
+                request_id: self.get_string("requestId"),
+                url: self.get_string("url"),
+                method: self.get_string_or("method", "GET"),
+                resource_type: self.get_string_or("resourceType", "other"),
+                status: self.get_optional_u32("status").map(|s| s as u16),
+                error: self.get_optional_string("error"),
+                start_time: self.get_f64("startTime"),
+                end_time: self.get_f64("endTime"),
+                tab_id: self.get_u32("tabId"),
+            },
+
+            "downloads.finished" => ParsedEvent::DownloadFinished {
+                url: self.get_string("url"),
+                path: self.get_string("filename"),
+                bytes: self.get_u64("fileSize"),
+                mime: self.get_optional_string("mime"),
+                state: self.get_string_or("state", "complete"),
+                error: self.get_optional_string("error"),
+            },
+
+            "script.functionCalled" => ParsedEvent::ScriptFunctionCalled {
+                name: self.get_string("name"),
+                args: self
+                    .params
+                    .get("args")
+                    .and_then(|v| v.as_array())
+                    .cloned()
+                    .unwrap_or_default(),
+                tab_id: self.get_u32("tabId"),
+            },
+
+            "storage.cookieChanged" => ParsedEvent::StorageCookieChanged {
+                subscription_id: self.get_string("subscriptionId"),
+                cookie: self
+                    .params
+                    .get("cookie")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok()),
+                cause: self.get_string("cause"),
+                removed: self
+                    .params
+                    .get("removed")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or_default(),
+            },
+
             _ => ParsedEvent::Unknown {
                 method: self.method.clone(),
                 params: self.params.clone(),
@@ -414,6 +600,15 @@ impl Event {
             .unwrap_or_default() as u32
     }
 
+    /// Gets an optional u32 from params.
+    #[inline]
+    fn get_optional_u32(&self, key: &str) -> Option<u32> {
+        self.params
+            .get(key)
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+    }
+
     /// Gets a u64 from params.
     #[inline]
     fn get_u64(&self, key: &str) -> u64 {
@@ -431,6 +626,15 @@ impl Event {
             .and_then(|v| v.as_u64())
             .unwrap_or_default() as u16
     }
+
+    /// Gets an f64 from params.
+    #[inline]
+    fn get_f64(&self, key: &str) -> f64 {
+        self.params
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .unwrap_or_default()
+    }
 }
 
 // ============================================================================
@@ -536,6 +740,258 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tab_created_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "browsingContext.tabCreated",
+            "params": {
+                "tabId": 7,
+                "openerTabId": 1,
+                "url": "about:blank"
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+        let parsed = event.parse();
+
+        match parsed {
+            ParsedEvent::BrowsingContextTabCreated {
+                tab_id,
+                opener_tab_id,
+                url,
+            } => {
+                assert_eq!(tab_id, 7);
+                assert_eq!(opener_tab_id, Some(1));
+                assert_eq!(url, "about:blank");
+            }
+            _ => panic!("unexpected parsed event type"),
+        }
+    }
+
+    #[test]
+    fn test_tab_crashed_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "browsingContext.tabCrashed",
+            "params": { "tabId": 3, "reason": "crashed" }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+        match event.parse() {
+            ParsedEvent::BrowsingContextTabCrashed { tab_id, reason } => {
+                assert_eq!(tab_id, 3);
+                assert_eq!(reason, "crashed");
+            }
+            _ => panic!("unexpected parsed event type"),
+        }
+    }
+
+    #[test]
+    fn test_window_created_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "browsingContext.windowCreated",
+            "params": {
+                "windowId": 4,
+                "tabId": 9,
+                "url": "https://auth.example.com/login"
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+        let parsed = event.parse();
+
+        match parsed {
+            ParsedEvent::BrowsingContextWindowCreated {
+                window_id,
+                tab_id,
+                opener_tab_id,
+                url,
+            } => {
+                assert_eq!(window_id, 4);
+                assert_eq!(tab_id, 9);
+                assert_eq!(opener_tab_id, None);
+                assert_eq!(url, "https://auth.example.com/login");
+            }
+            _ => panic!("unexpected parsed event type"),
+        }
+    }
+
+    #[test]
+    fn test_sse_message_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "network.sseMessage",
+            "params": {
+                "subscriptionId": "sub-1",
+                "url": "https://example.com/stream",
+                "data": "{\"price\":42}",
+                "tabId": 3
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+        let parsed = event.parse();
+
+        match parsed {
+            ParsedEvent::NetworkSseMessage {
+                subscription_id,
+                event,
+                data,
+                id,
+                tab_id,
+                ..
+            } => {
+                assert_eq!(subscription_id, "sub-1");
+                assert_eq!(event, "message");
+                assert_eq!(data, r#"{"price":42}"#);
+                assert!(id.is_none());
+                assert_eq!(tab_id, 3);
+            }
+            _ => panic!("Expected NetworkSseMessage"),
+        }
+    }
+
+    #[test]
+    fn test_request_logged_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "network.requestLogged",
+            "params": {
+                "subscriptionId": "sub-1",
+                "requestId": "42",
+                "url": "https://example.com/missing",
+                "resourceType": "image",
+                "error": "NS_ERROR_UNKNOWN_HOST",
+                "startTime": 1000.5,
+                "endTime": 1012.5,
+                "tabId": 3
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+
+        match event.parse() {
+            ParsedEvent::NetworkRequestLogged {
+                method,
+                resource_type,
+                status,
+                error,
+                start_time,
+                end_time,
+                tab_id,
+                ..
+            } => {
+                assert_eq!(method, "GET");
+                assert_eq!(resource_type, "image");
+                assert!(status.is_none());
+                assert_eq!(error.as_deref(), Some("NS_ERROR_UNKNOWN_HOST"));
+                assert_eq!(end_time - start_time, 12.0);
+                assert_eq!(tab_id, 3);
+            }
+            _ => panic!("Expected NetworkRequestLogged"),
+        }
+    }
+
+    #[test]
+    fn test_download_finished_parsing() {
+        let event = Event {
+            id: RequestId::generate(),
+            event_type: "event".to_string(),
+            method: "downloads.finished".to_string(),
+            params: serde_json::json!({
+                "url": "https://example.com/report.pdf",
+                "filename": "/tmp/profile/downloads/report.pdf",
+                "fileSize": 2048,
+                "mime": "application/pdf"
+            }),
+        };
+
+        match event.parse() {
+            ParsedEvent::DownloadFinished {
+                path,
+                bytes,
+                mime,
+                state,
+                error,
+                ..
+            } => {
+                assert_eq!(path, "/tmp/profile/downloads/report.pdf");
+                assert_eq!(bytes, 2048);
+                assert_eq!(mime.as_deref(), Some("application/pdf"));
+                assert_eq!(state, "complete");
+                assert!(error.is_none());
+            }
+            _ => panic!("Expected DownloadFinished"),
+        }
+    }
+
+    #[test]
+    fn test_cookie_changed_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "storage.cookieChanged",
+            "params": {
+                "subscriptionId": "sub-1",
+                "cookie": { "name": "token", "value": "abc", "domain": "example.com", "httpOnly": true },
+                "cause": "explicit",
+                "removed": false
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+
+        match event.parse() {
+            ParsedEvent::StorageCookieChanged {
+                subscription_id,
+                cookie,
+                cause,
+                removed,
+            } => {
+                assert_eq!(subscription_id, "sub-1");
+                let cookie = cookie.expect("cookie");
+                assert_eq!(cookie.name, "token");
+                assert_eq!(cookie.http_only, Some(true));
+                assert_eq!(cause, "explicit");
+                assert!(!removed);
+            }
+            _ => panic!("Expected StorageCookieChanged"),
+        }
+    }
+
+    #[test]
+    fn test_function_called_parsing() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "script.functionCalled",
+            "params": {
+                "name": "add",
+                "args": [1, 2],
+                "tabId": 1
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(json_str).expect("parse event");
+        let parsed = event.parse();
+
+        match parsed {
+            ParsedEvent::ScriptFunctionCalled { name, args, tab_id } => {
+                assert_eq!(name, "add");
+                assert_eq!(args, vec![json!(1), json!(2)]);
+                assert_eq!(tab_id, 1);
+            }
+            _ => panic!("Expected ScriptFunctionCalled"),
+        }
+    }
+
     #[test]
     fn test_unknown_event() {
         let json_str = r#"{
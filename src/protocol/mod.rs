@@ -48,8 +48,9 @@ pub mod request;
 // ============================================================================
 
 pub use command::{
-    BrowsingContextCommand, Command, Cookie, ElementCommand, InputCommand, NetworkCommand,
-    ProxyCommand, ScriptCommand, SessionCommand, StorageCommand,
+    BrowsingContextCommand, Command, Cookie, DownloadsCommand, ElementCommand, FilePayload,
+    InputCommand, NetworkCommand, ProxyCommand, ScriptCommand, SessionCommand, StorageCommand,
+    WebSocketCommand,
 };
 pub use event::{Event, EventReply, ParsedEvent};
 pub use request::{Request, Response, ResponseType};
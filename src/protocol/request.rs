@@ -9,6 +9,7 @@
 // Imports
 // ============================================================================
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -17,6 +18,13 @@ use crate::identifiers::{FrameId, RequestId, TabId};
 
 use super::Command;
 
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Error code the extension returns when page JavaScript throws.
+const SCRIPT_ERROR: &str = "script error";
+
 // ============================================================================
 // Request
 // ============================================================================
@@ -124,6 +132,10 @@ pub struct Response {
     /// Error message (if error).
     #[serde(default)]
     pub message: Option<String>,
+
+    /// JavaScript stack trace (if a script threw).
+    #[serde(default)]
+    pub stack: Option<String>,
 }
 
 impl Response {
@@ -145,13 +157,17 @@ impl Response {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Protocol`] if the response was an error.
+    /// Returns [`Error::ScriptError`] if page JavaScript threw, or
+    /// [`Error::Protocol`] for any other error response.
     pub fn into_result(self) -> Result<Value> {
         match self.response_type {
             ResponseType::Success => Ok(self.result.unwrap_or(Value::Null)),
             ResponseType::Error => {
                 let error_code = self.error.unwrap_or_else(|| "unknown error".to_string());
                 let message = self.message.unwrap_or_else(|| error_code.clone());
+                if error_code == SCRIPT_ERROR {
+                    return Err(Error::script_exception(message, self.stack));
+                }
                 Err(Error::protocol(message))
             }
         }
@@ -196,6 +212,87 @@ impl Response {
             .and_then(|v| v.as_bool())
             .unwrap_or_default()
     }
+
+    /// Gets a string value from the result, failing if it is absent.
+    ///
+    /// Prefer this over [`Response::get_string`] when the key is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Protocol`] if the key is missing or not a string.
+    pub fn require_str(&self, key: &str) -> Result<&str> {
+        self.require(key)?
+            .as_str()
+            .ok_or_else(|| Error::protocol(format!("Expected string for '{key}' in response")))
+    }
+
+    /// Gets a u64 value from the result, failing if it is absent.
+    ///
+    /// Prefer this over [`Response::get_u64`] when the key is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Protocol`] if the key is missing or not an unsigned
+    /// integer.
+    pub fn require_u64(&self, key: &str) -> Result<u64> {
+        self.require(key)?.as_u64().ok_or_else(|| {
+            Error::protocol(format!("Expected unsigned integer for '{key}' in response"))
+        })
+    }
+
+    /// Gets a boolean value from the result, failing if it is absent.
+    ///
+    /// Prefer this over [`Response::get_bool`] when the key is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Protocol`] if the key is missing or not a boolean.
+    pub fn require_bool(&self, key: &str) -> Result<bool> {
+        self.require(key)?
+            .as_bool()
+            .ok_or_else(|| Error::protocol(format!("Expected boolean for '{key}' in response")))
+    }
+
+    /// Gets an array value from the result, failing if it is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Protocol`] if the key is missing or not an array.
+    pub fn get_array(&self, key: &str) -> Result<&Vec<Value>> {
+        self.require(key)?
+            .as_array()
+            .ok_or_else(|| Error::protocol(format!("Expected array for '{key}' in response")))
+    }
+
+    /// Deserializes a value from the result into `T`.
+    ///
+    /// Covers numbers and booleans where a missing value is an error
+    /// rather than `0` or `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Protocol`] if the key is missing or does not
+    /// deserialize into `T`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let tab_id: u32 = response.decode("tabId")?;
+    /// let bounds: WindowBounds = response.decode("bounds")?;
+    /// ```
+    pub fn decode<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        T::deserialize(self.require(key)?)
+            .map_err(|e| Error::protocol(format!("Invalid '{key}' in response: {e}")))
+    }
+
+    /// Looks up a non-null result value.
+    fn require(&self, key: &str) -> Result<&Value> {
+        self.result
+            .as_ref()
+            .and_then(|v| v.get(key))
+            .filter(|v| !v.is_null())
+            .ok_or_else(|| Error::protocol(format!("Missing '{key}' in response")))
+    }
 }
 
 // ============================================================================
@@ -304,6 +401,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_into_result_script_error() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "error",
+            "error": "script error",
+            "message": "TypeError: foo is undefined",
+            "stack": "@https://example.com/:1:5"
+        }"#;
+
+        let response: Response = serde_json::from_str(json_str).expect("parse");
+        match response.into_result() {
+            Err(Error::ScriptError { message, stack }) => {
+                assert_eq!(message, "TypeError: foo is undefined");
+                assert_eq!(stack.as_deref(), Some("@https://example.com/:1:5"));
+            }
+            other => panic!("expected script error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_response_get_helpers() {
         let json_str = r#"{
@@ -326,4 +443,36 @@ mod tests {
         assert_eq!(response.get_u64("missing"), 0);
         assert!(!response.get_bool("missing"));
     }
+
+    #[test]
+    fn test_response_strict_helpers() {
+        let json_str = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "success",
+            "result": {
+                "name": "test",
+                "count": 42,
+                "enabled": true,
+                "items": [1, 2],
+                "empty": null
+            }
+        }"#;
+
+        let response: Response = serde_json::from_str(json_str).expect("parse");
+        assert_eq!(response.require_str("name").expect("name"), "test");
+        assert_eq!(response.require_u64("count").expect("count"), 42);
+        assert!(response.require_bool("enabled").expect("enabled"));
+        assert_eq!(response.get_array("items").expect("items").len(), 2);
+        assert_eq!(response.decode::<u32>("count").expect("count"), 42);
+        assert_eq!(response.decode::<Vec<u8>>("items").expect("items"), [1, 2]);
+
+        let err = response.require_str("count").expect_err("not a string");
+        assert!(err.to_string().contains("'count'"));
+        assert!(response.require_str("missing").is_err());
+        assert!(response.require_u64("name").is_err());
+        assert!(response.require_bool("count").is_err());
+        assert!(response.require_u64("empty").is_err());
+        assert!(response.get_array("empty").is_err());
+        assert!(response.decode::<bool>("name").is_err());
+    }
 }
@@ -0,0 +1,115 @@
+//! Transport abstraction used by the connection pool.
+//!
+//! [`ConnectionPool`](crate::transport::ConnectionPool) routes each session's requests
+//! to a [`Transport`]. Firefox sessions use a WebSocket
+//! [`Connection`](crate::transport::Connection); tests can use a
+//! [`MockTransport`](super::MockTransport) that answers from scripted
+//! responses without launching a browser.
+//!
+//! Other channels (e.g. a Unix socket or message queue) plug in through a
+//! [`TransportFactory`] passed to
+//! [`Driver::with_transport`](crate::Driver::with_transport), which opens
+//! one transport per spawned window.
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::identifiers::SessionId;
+use crate::protocol::{Request, Response};
+
+use super::connection::DEFAULT_COMMAND_TIMEOUT;
+use super::{CrashHandler, EventHandler, ReadyData};
+
+// ============================================================================
+// Transport
+// ============================================================================
+
+/// Request/response channel to one session's remote end.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Sends a request and waits for its response.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::ConnectionClosed`](crate::Error::ConnectionClosed) if the
+    ///   transport is closed
+    /// - [`Error::RequestTimeout`](crate::Error::RequestTimeout) if no
+    ///   response arrives within `request_timeout`
+    async fn send_with_timeout(
+        &self,
+        request: Request,
+        request_timeout: Duration,
+    ) -> Result<Response>;
+
+    /// Sends a request with the default timeout (30s).
+    ///
+    /// # Errors
+    ///
+    /// See [`Transport::send_with_timeout`].
+    async fn send(&self, request: Request) -> Result<Response> {
+        self.send_with_timeout(request, DEFAULT_COMMAND_TIMEOUT)
+            .await
+    }
+
+    /// Sets the callback for events from the remote end.
+    fn set_event_handler(&self, handler: EventHandler);
+
+    /// Clears the event callback.
+    fn clear_event_handler(&self);
+
+    /// Sets the callback for tab crashes reported by the remote end.
+    ///
+    /// Separate from the event callback, which the pool replaces with its
+    /// dispatcher. Transports that cannot report crashes ignore it.
+    fn set_crash_handler(&self, handler: CrashHandler) {
+        let _ = handler;
+    }
+
+    /// Closes the transport.
+    fn shutdown(&self);
+}
+
+// ============================================================================
+// TransportFactory
+// ============================================================================
+
+/// Opens a [`Transport`] for each window a driver spawns.
+///
+/// Used by [`Driver::with_transport`](crate::Driver::with_transport) in
+/// place of launching Firefox.
+///
+/// # Example
+///
+/// ```ignore
+/// struct UnixSocketFactory { path: PathBuf }
+///
+/// #[async_trait]
+/// impl TransportFactory for UnixSocketFactory {
+///     async fn connect(&self, session_id: SessionId) -> Result<(Arc<dyn Transport>, ReadyData)> {
+///         let transport = UnixSocketTransport::connect(&self.path, session_id).await?;
+///         let ready = transport.wait_ready().await?;
+///         Ok((Arc::new(transport), ready))
+///     }
+/// }
+///
+/// let driver = Driver::with_transport(UnixSocketFactory { path });
+/// ```
+#[async_trait]
+pub trait TransportFactory: Send + Sync {
+    /// Opens a transport for a new session.
+    ///
+    /// Returns the transport and the session's READY data, whose `tab_id`
+    /// becomes the window's initial tab.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remote end cannot be reached.
+    async fn connect(&self, session_id: SessionId) -> Result<(Arc<dyn Transport>, ReadyData)>;
+}
@@ -21,30 +21,33 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
 use parking_lot::Mutex;
 use rustc_hash::FxHashMap;
 use serde_json::{from_str, to_string};
-use tokio::net::TcpStream;
-use tokio::sync::{mpsc, oneshot};
-use tokio::time::timeout;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::{Semaphore, mpsc, oneshot};
+use tokio::time::{Instant, timeout, timeout_at};
 use tokio_tungstenite::WebSocketStream;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, trace, warn};
 
 use crate::error::{Error, Result};
-use crate::identifiers::RequestId;
-use crate::protocol::{Event, EventReply, Request, Response};
+use crate::identifiers::{RequestId, TabId};
+use crate::protocol::{Event, EventReply, ParsedEvent, Request, Response};
+
+use super::Transport;
 
 // ============================================================================
 // Constants
 // ============================================================================
 
 /// Default timeout for command execution (30s per spec).
-const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+pub(crate) const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
 
-/// Maximum pending requests before rejecting new ones.
-const MAX_PENDING_REQUESTS: usize = 100;
+/// Default maximum pending requests per connection before senders wait.
+pub const DEFAULT_MAX_PENDING_REQUESTS: usize = 100;
 
 /// Timeout for READY handshake.
 const READY_TIMEOUT: Duration = Duration::from_secs(30);
@@ -53,8 +56,19 @@ const READY_TIMEOUT: Duration = Duration::from_secs(30);
 // Types
 // ============================================================================
 
-/// Map of request IDs to response channels.
-type CorrelationMap = FxHashMap<RequestId, oneshot::Sender<Result<Response>>>;
+/// Event the extension sends when a tab's content process crashes.
+const TAB_CRASHED_EVENT: &str = "browsingContext.tabCrashed";
+
+/// Map of request IDs to pending requests.
+type CorrelationMap = FxHashMap<RequestId, PendingRequest>;
+
+/// A request awaiting its response.
+struct PendingRequest {
+    /// Tab the request targets (`None` for the READY handshake).
+    tab_id: Option<TabId>,
+    /// Response channel.
+    response_tx: oneshot::Sender<Result<Response>>,
+}
 
 /// Event handler callback type.
 ///
@@ -62,6 +76,12 @@ type CorrelationMap = FxHashMap<RequestId, oneshot::Sender<Result<Response>>>;
 /// Return `Some(EventReply)` to send a reply (for network interception).
 pub type EventHandler = Box<dyn Fn(Event) -> Option<EventReply> + Send + Sync>;
 
+/// Crash handler callback type.
+///
+/// Called with the tab ID and reason when a tab's content process crashes,
+/// after the tab's pending requests have failed.
+pub type CrashHandler = Box<dyn Fn(TabId, String) + Send + Sync>;
+
 // ============================================================================
 // ReadyData
 // ============================================================================
@@ -115,6 +135,10 @@ pub struct Connection {
     correlation: Arc<Mutex<CorrelationMap>>,
     /// Event handler (shared with event loop).
     event_handler: Arc<Mutex<Option<EventHandler>>>,
+    /// Crash handler (shared with event loop).
+    crash_handler: Arc<Mutex<Option<CrashHandler>>>,
+    /// Slots for pending requests; senders wait when none are free.
+    pending_slots: Arc<Semaphore>,
 }
 
 impl Clone for Connection {
@@ -123,6 +147,8 @@ impl Clone for Connection {
             command_tx: self.command_tx.clone(),
             correlation: Arc::clone(&self.correlation),
             event_handler: Arc::clone(&self.event_handler),
+            crash_handler: Arc::clone(&self.crash_handler),
+            pending_slots: Arc::clone(&self.pending_slots),
         }
     }
 }
@@ -130,27 +156,37 @@ impl Clone for Connection {
 impl Connection {
     /// Creates a new connection from a WebSocket stream.
     ///
-    /// Spawns the event loop task internally.
-    pub(crate) fn new(ws_stream: WebSocketStream<TcpStream>) -> Self {
+    /// The stream is plain TCP or TLS. Spawns the event loop task
+    /// internally. At most `max_pending` requests are outstanding; further
+    /// senders wait for a slot.
+    pub(crate) fn new<S>(ws_stream: WebSocketStream<S>, max_pending: usize) -> Self
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
         let (command_tx, command_rx) = mpsc::unbounded_channel();
         let correlation = Arc::new(Mutex::new(CorrelationMap::default()));
         let event_handler: Arc<Mutex<Option<EventHandler>>> = Arc::new(Mutex::new(None));
+        let crash_handler: Arc<Mutex<Option<CrashHandler>>> = Arc::new(Mutex::new(None));
 
         // Spawn event loop task
         let correlation_clone = Arc::clone(&correlation);
         let event_handler_clone = Arc::clone(&event_handler);
+        let crash_handler_clone = Arc::clone(&crash_handler);
 
         tokio::spawn(Self::run_event_loop(
             ws_stream,
             command_rx,
             correlation_clone,
             event_handler_clone,
+            crash_handler_clone,
         ));
 
         Self {
             command_tx,
             correlation,
             event_handler,
+            crash_handler,
+            pending_slots: Arc::new(Semaphore::new(max_pending.max(1))),
         }
     }
 
@@ -163,13 +199,20 @@ impl Connection {
     ///
     /// - [`Error::ConnectionTimeout`] if READY not received within 30s
     /// - [`Error::ConnectionClosed`] if connection closes before READY
+    /// - [`Error::Protocol`] if READY lacks a valid `tabId` or `sessionId`
     pub async fn wait_ready(&self) -> Result<ReadyData> {
         let (tx, rx) = oneshot::channel();
 
         // Register correlation for READY (nil UUID)
         {
             let mut correlation = self.correlation.lock();
-            correlation.insert(RequestId::ready(), tx);
+            correlation.insert(
+                RequestId::ready(),
+                PendingRequest {
+                    tab_id: None,
+                    response_tx: tx,
+                },
+            );
         }
 
         // Wait for READY with timeout
@@ -179,9 +222,8 @@ impl Connection {
 
         let response = response?;
 
-        // Extract data from READY response using helper methods
-        let tab_id = response.get_u64("tabId").max(1) as u32;
-        let session_id = response.get_u64("sessionId").max(1) as u32;
+        let tab_id = ready_id(&response, "tabId")?;
+        let session_id = ready_id(&response, "sessionId")?;
 
         debug!(tab_id, session_id, "READY handshake completed");
 
@@ -203,13 +245,21 @@ impl Connection {
         *guard = None;
     }
 
+    /// Sets the crash handler callback.
+    ///
+    /// Kept apart from the event handler so replacing or clearing event
+    /// handlers never drops crash notifications.
+    pub fn set_crash_handler(&self, handler: CrashHandler) {
+        let mut guard = self.crash_handler.lock();
+        *guard = Some(handler);
+    }
+
     /// Sends a request and waits for response with default timeout (30s).
     ///
     /// # Errors
     ///
     /// - [`Error::ConnectionClosed`] if connection is closed
     /// - [`Error::RequestTimeout`] if response not received within timeout
-    /// - [`Error::Protocol`] if too many pending requests
     pub async fn send(&self, request: Request) -> Result<Response> {
         self.send_with_timeout(request, DEFAULT_COMMAND_TIMEOUT)
             .await
@@ -217,6 +267,9 @@ impl Connection {
 
     /// Sends a request and waits for response with custom timeout.
     ///
+    /// If the connection already has its maximum of pending requests, waits
+    /// for a slot first; the wait counts towards `request_timeout`.
+    ///
     /// # Arguments
     ///
     /// * `request` - The request to send
@@ -225,31 +278,32 @@ impl Connection {
     /// # Errors
     ///
     /// - [`Error::ConnectionClosed`] if connection is closed
-    /// - [`Error::RequestTimeout`] if response not received within timeout
-    /// - [`Error::Protocol`] if too many pending requests
+    /// - [`Error::RequestTimeout`] if no slot or response within timeout
     pub async fn send_with_timeout(
         &self,
         request: Request,
         request_timeout: Duration,
     ) -> Result<Response> {
         let request_id = request.id;
-
-        // Check pending request limit
-        {
-            let correlation = self.correlation.lock();
-            if correlation.len() >= MAX_PENDING_REQUESTS {
-                warn!(
-                    pending = correlation.len(),
-                    max = MAX_PENDING_REQUESTS,
-                    "Too many pending requests"
-                );
-                return Err(Error::protocol(format!(
-                    "Too many pending requests: {}/{}",
-                    correlation.len(),
-                    MAX_PENDING_REQUESTS
-                )));
-            }
+        let deadline = Instant::now() + request_timeout;
+
+        // Wait for a pending request slot
+        if self.pending_slots.available_permits() == 0 {
+            debug!(
+                pending = self.pending_count(),
+                "Pending request limit reached, waiting"
+            );
         }
+        let _slot = match timeout_at(deadline, self.pending_slots.acquire()).await {
+            Ok(Ok(permit)) => permit,
+            Ok(Err(_)) => return Err(Error::ConnectionClosed),
+            Err(_) => {
+                return Err(Error::request_timeout(
+                    request_id,
+                    request_timeout.as_millis() as u64,
+                ));
+            }
+        };
 
         // Create response channel
         let (response_tx, response_rx) = oneshot::channel();
@@ -263,7 +317,7 @@ impl Connection {
             .map_err(|_| Error::ConnectionClosed)?;
 
         // Wait for response with timeout
-        match timeout(request_timeout, response_rx).await {
+        match timeout_at(deadline, response_rx).await {
             Ok(Ok(result)) => result,
             Ok(Err(_)) => Err(Error::ConnectionClosed),
             Err(_) => {
@@ -291,16 +345,21 @@ impl Connection {
     ///
     /// This is called automatically on drop.
     pub fn shutdown(&self) {
+        // Wake senders waiting for a slot
+        self.pending_slots.close();
         let _ = self.command_tx.send(ConnectionCommand::Shutdown);
     }
 
     /// Event loop that handles WebSocket I/O.
-    async fn run_event_loop(
-        ws_stream: WebSocketStream<TcpStream>,
+    async fn run_event_loop<S>(
+        ws_stream: WebSocketStream<S>,
         mut command_rx: mpsc::UnboundedReceiver<ConnectionCommand>,
         correlation: Arc<Mutex<CorrelationMap>>,
         event_handler: Arc<Mutex<Option<EventHandler>>>,
-    ) {
+        crash_handler: Arc<Mutex<Option<CrashHandler>>>,
+    ) where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
         let (mut ws_write, mut ws_read) = ws_stream.split();
 
         loop {
@@ -313,6 +372,7 @@ impl Connection {
                                 &text,
                                 &correlation,
                                 &event_handler,
+                                &crash_handler,
                             );
 
                             // Send event reply if needed
@@ -387,13 +447,14 @@ impl Connection {
         text: &str,
         correlation: &Arc<Mutex<CorrelationMap>>,
         event_handler: &Arc<Mutex<Option<EventHandler>>>,
+        crash_handler: &Arc<Mutex<Option<CrashHandler>>>,
     ) -> Option<EventReply> {
         // Try to parse as Response first
         if let Ok(response) = from_str::<Response>(text) {
-            let tx = correlation.lock().remove(&response.id);
+            let pending = correlation.lock().remove(&response.id);
 
-            if let Some(tx) = tx {
-                let _ = tx.send(Ok(response));
+            if let Some(pending) = pending {
+                let _ = pending.response_tx.send(Ok(response));
             } else {
                 warn!(id = %response.id, "Response for unknown request");
             }
@@ -403,6 +464,18 @@ impl Connection {
 
         // Try to parse as Event
         if let Ok(event) = from_str::<Event>(text) {
+            // Fail the crashed tab's requests instead of letting them time out
+            if event.method == TAB_CRASHED_EVENT
+                && let ParsedEvent::BrowsingContextTabCrashed { tab_id, reason } = event.parse()
+                && let Some(tab_id) = TabId::new(tab_id)
+            {
+                Self::fail_tab_requests(correlation, tab_id);
+
+                if let Some(ref handler) = *crash_handler.lock() {
+                    handler(tab_id, reason);
+                }
+            }
+
             let handler = event_handler.lock();
             if let Some(ref handler) = *handler {
                 return handler(event);
@@ -415,13 +488,16 @@ impl Connection {
     }
 
     /// Handles a send command from the Rust API.
-    async fn handle_send_command(
+    async fn handle_send_command<S>(
         request: Request,
         response_tx: oneshot::Sender<Result<Response>>,
-        ws_write: &mut futures_util::stream::SplitSink<WebSocketStream<TcpStream>, Message>,
+        ws_write: &mut futures_util::stream::SplitSink<WebSocketStream<S>, Message>,
         correlation: &Arc<Mutex<CorrelationMap>>,
-    ) {
+    ) where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
         let request_id = request.id;
+        let tab_id = request.tab_id;
 
         // Serialize request
         let json = match to_string(&request) {
@@ -433,13 +509,21 @@ impl Connection {
         };
 
         // Store correlation before sending
-        correlation.lock().insert(request_id, response_tx);
+        correlation.lock().insert(
+            request_id,
+            PendingRequest {
+                tab_id: Some(tab_id),
+                response_tx,
+            },
+        );
 
         // Send over WebSocket
         if let Err(e) = ws_write.send(Message::Text(json.into())).await {
             // Remove correlation and notify caller
-            if let Some(tx) = correlation.lock().remove(&request_id) {
-                let _ = tx.send(Err(Error::connection(e.to_string())));
+            if let Some(pending) = correlation.lock().remove(&request_id) {
+                let _ = pending
+                    .response_tx
+                    .send(Err(Error::connection(e.to_string())));
             }
         }
 
@@ -451,14 +535,62 @@ impl Connection {
         let pending: Vec<_> = correlation.lock().drain().collect();
         let count = pending.len();
 
-        for (_, tx) in pending {
-            let _ = tx.send(Err(Error::ConnectionClosed));
+        for (_, pending) in pending {
+            let _ = pending.response_tx.send(Err(Error::ConnectionClosed));
         }
 
         if count > 0 {
             debug!(count, "Failed pending requests on shutdown");
         }
     }
+
+    /// Fails pending requests for a crashed tab with BrowserCrashed error.
+    fn fail_tab_requests(correlation: &Arc<Mutex<CorrelationMap>>, tab_id: TabId) {
+        let crashed: Vec<_> = {
+            let mut correlation = correlation.lock();
+            let ids: Vec<_> = correlation
+                .iter()
+                .filter(|(_, pending)| pending.tab_id == Some(tab_id))
+                .map(|(id, _)| *id)
+                .collect();
+            ids.iter().filter_map(|id| correlation.remove(id)).collect()
+        };
+
+        warn!(%tab_id, count = crashed.len(), "Tab crashed, failing its pending requests");
+
+        for pending in crashed {
+            let _ = pending
+                .response_tx
+                .send(Err(Error::browser_crashed(tab_id)));
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for Connection {
+    async fn send_with_timeout(
+        &self,
+        request: Request,
+        request_timeout: Duration,
+    ) -> Result<Response> {
+        Connection::send_with_timeout(self, request, request_timeout).await
+    }
+
+    fn set_event_handler(&self, handler: EventHandler) {
+        Connection::set_event_handler(self, handler);
+    }
+
+    fn clear_event_handler(&self) {
+        Connection::clear_event_handler(self);
+    }
+
+    fn set_crash_handler(&self, handler: CrashHandler) {
+        Connection::set_crash_handler(self, handler);
+    }
+
+    fn shutdown(&self) {
+        Connection::shutdown(self);
+    }
 }
 
 impl Drop for Connection {
@@ -472,6 +604,19 @@ impl Drop for Connection {
     }
 }
 
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Reads a non-zero 32-bit ID from the READY response.
+fn ready_id(response: &Response, key: &str) -> Result<u32> {
+    let id = response.require_u64(key)?;
+    u32::try_from(id)
+        .ok()
+        .filter(|&id| id > 0)
+        .ok_or_else(|| Error::protocol(format!("Invalid '{key}' in READY: {id}")))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -483,10 +628,25 @@ mod tests {
     #[test]
     fn test_constants() {
         assert_eq!(DEFAULT_COMMAND_TIMEOUT.as_secs(), 30);
-        assert_eq!(MAX_PENDING_REQUESTS, 100);
+        assert_eq!(DEFAULT_MAX_PENDING_REQUESTS, 100);
         assert_eq!(READY_TIMEOUT.as_secs(), 30);
     }
 
+    #[test]
+    fn test_ready_id_is_strict() {
+        let response: Response = serde_json::from_value(serde_json::json!({
+            "id": RequestId::ready(),
+            "type": "success",
+            "result": { "tabId": 3, "sessionId": 0, "big": 4_294_967_296_u64 }
+        }))
+        .expect("response");
+
+        assert_eq!(ready_id(&response, "tabId").expect("tab id"), 3);
+        assert!(ready_id(&response, "sessionId").is_err());
+        assert!(ready_id(&response, "big").is_err());
+        assert!(ready_id(&response, "missing").is_err());
+    }
+
     #[test]
     fn test_ready_data() {
         let data = ReadyData {
@@ -496,4 +656,126 @@ mod tests {
         assert_eq!(data.tab_id, 1);
         assert_eq!(data.session_id, 2);
     }
+
+    #[test]
+    fn test_tab_crash_fails_its_requests() {
+        let correlation = Arc::new(Mutex::new(CorrelationMap::default()));
+        let event_handler: Arc<Mutex<Option<EventHandler>>> = Arc::new(Mutex::new(None));
+        let crashes = Arc::new(Mutex::new(Vec::new()));
+        let crash_handler: Arc<Mutex<Option<CrashHandler>>> = {
+            let crashes = Arc::clone(&crashes);
+            Arc::new(Mutex::new(Some(Box::new(move |tab_id, reason| {
+                crashes.lock().push((tab_id.as_u32(), reason));
+            }))))
+        };
+
+        let pending = |tab_id: u32| {
+            let (tx, rx) = oneshot::channel();
+            correlation.lock().insert(
+                RequestId::generate(),
+                PendingRequest {
+                    tab_id: TabId::new(tab_id),
+                    response_tx: tx,
+                },
+            );
+            rx
+        };
+        let mut crashed = pending(3);
+        let mut other = pending(4);
+
+        let event = r#"{
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "type": "event",
+            "method": "browsingContext.tabCrashed",
+            "params": { "tabId": 3, "reason": "crashed" }
+        }"#;
+        Connection::handle_incoming_message(event, &correlation, &event_handler, &crash_handler);
+
+        assert!(matches!(
+            crashed.try_recv(),
+            Ok(Err(Error::BrowserCrashed { tab_id })) if tab_id.as_u32() == 3
+        ));
+        assert!(other.try_recv().is_err());
+        assert_eq!(correlation.lock().len(), 1);
+        assert_eq!(*crashes.lock(), [(3, "crashed".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_send_waits_for_pending_slot() {
+        use serde_json::{Value, json};
+        use tokio_tungstenite::tungstenite::protocol::Role;
+
+        use crate::identifiers::FrameId;
+        use crate::protocol::{Command, SessionCommand};
+
+        let (local, remote) = tokio::io::duplex(64 * 1024);
+        let local = WebSocketStream::from_raw_socket(local, Role::Server, None).await;
+        let mut remote = WebSocketStream::from_raw_socket(remote, Role::Client, None).await;
+        let connection = Connection::new(local, 1);
+
+        let request = || {
+            Request::new(
+                TabId::new(1).expect("tab id"),
+                FrameId::main(),
+                Command::Session(SessionCommand::StealLogs),
+            )
+        };
+        let next_request_id = |message: Option<std::result::Result<Message, _>>| {
+            let Some(Ok(Message::Text(text))) = message else {
+                panic!("expected a request, got {message:?}");
+            };
+            let request: Value = serde_json::from_str(&text).expect("request json");
+            request["id"].clone()
+        };
+        let success = |id: Value| {
+            Message::Text(
+                json!({ "id": id, "type": "success", "result": {} })
+                    .to_string()
+                    .into(),
+            )
+        };
+
+        // Occupy the only slot
+        let first = tokio::spawn({
+            let connection = connection.clone();
+            let request = request();
+            async move {
+                connection
+                    .send_with_timeout(request, Duration::from_secs(5))
+                    .await
+            }
+        });
+        let first_id = next_request_id(remote.next().await);
+
+        // With no slot free, a send times out without reaching the remote end
+        let result = connection
+            .send_with_timeout(request(), Duration::from_millis(50))
+            .await;
+        assert!(matches!(result, Err(Error::RequestTimeout { .. })));
+
+        let second = tokio::spawn({
+            let connection = connection.clone();
+            let request = request();
+            async move {
+                connection
+                    .send_with_timeout(request, Duration::from_secs(5))
+                    .await
+            }
+        });
+        assert!(
+            timeout(Duration::from_millis(50), remote.next())
+                .await
+                .is_err()
+        );
+
+        // Answering the first request frees the slot for the waiting send
+        remote.send(success(first_id)).await.expect("reply");
+        assert!(first.await.expect("join").expect("first").is_success());
+
+        let second_id = next_request_id(remote.next().await);
+        remote.send(success(second_id)).await.expect("reply");
+        assert!(second.await.expect("join").expect("second").is_success());
+
+        connection.shutdown();
+    }
 }
@@ -0,0 +1,457 @@
+//! In-memory transport for testing without Firefox.
+//!
+//! A [`MockTransport`] answers each command from scripted responses keyed
+//! by method name and records every request it receives. Combined with
+//! [`Driver::with_mock`](crate::Driver::with_mock), automation logic built
+//! on [`Window`](crate::Window), [`Tab`](crate::Tab) and
+//! [`Element`](crate::Element) runs deterministically in unit tests.
+//!
+//! # Example
+//!
+//! ```
+//! use firefox_webdriver::{Driver, MockTransport};
+//! use serde_json::json;
+//!
+//! # tokio_test::block_on(async {
+//! let mock = MockTransport::new()
+//!     .respond("browsingContext.navigate", json!({}))
+//!     .respond("browsingContext.getTitle", json!({ "title": "Example Domain" }));
+//!
+//! let driver = Driver::with_mock(mock.clone());
+//! let window = driver.window().spawn().await?;
+//! let tab = window.tab();
+//!
+//! tab.goto("https://example.com").await?;
+//! assert_eq!(tab.get_title().await?, "Example Domain");
+//! assert_eq!(
+//!     mock.methods(),
+//!     ["browsingContext.navigate", "browsingContext.getTitle"]
+//! );
+//! # Ok::<(), firefox_webdriver::Error>(())
+//! # }).unwrap();
+//! ```
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+use tracing::debug;
+
+use crate::error::{Error, Result};
+use crate::identifiers::{RequestId, SessionId, TabId};
+use crate::protocol::{Event, EventReply, ParsedEvent, Request, Response, ResponseType};
+
+use super::{CrashHandler, EventHandler, ReadyData, Transport, TransportFactory};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Error code returned for methods without a scripted response, matching
+/// the extension's answer to a method it does not handle.
+const UNKNOWN_COMMAND: &str = "unknown command";
+
+/// Initial tab ID of every session served by a mock.
+const INITIAL_TAB_ID: u32 = 1;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A scripted answer to one command.
+#[derive(Debug, Clone)]
+enum MockResponse {
+    /// Success with this `result`.
+    Success(Value),
+    /// Error with this code and message.
+    Error { error: String, message: String },
+    /// No answer: the request fails with [`Error::RequestTimeout`].
+    Timeout,
+}
+
+/// State shared by all clones of a [`MockTransport`].
+#[derive(Default)]
+struct MockState {
+    /// Scripted responses by method, consumed in order.
+    responses: Mutex<FxHashMap<String, VecDeque<MockResponse>>>,
+    /// Requests received, in order.
+    requests: Mutex<Vec<Request>>,
+    /// Event handler set by the window's subscriptions.
+    event_handler: Mutex<Option<EventHandler>>,
+    /// Crash handler set by [`Window::on_crash`](crate::Window::on_crash).
+    crash_handler: Mutex<Option<CrashHandler>>,
+    /// Whether [`Transport::shutdown`] was called.
+    closed: AtomicBool,
+}
+
+// ============================================================================
+// MockTransport
+// ============================================================================
+
+/// Transport that answers commands from scripted responses.
+///
+/// Responses are keyed by protocol method (e.g. `"element.find"`; see
+/// ARCHITECTURE.md for the command list). Several responses for the same
+/// method are returned in order, and the last one repeats. A method with
+/// no scripted response fails with the `unknown command` error code.
+///
+/// Clones share responses and recorded requests, so keep a clone to
+/// inspect traffic after handing one to
+/// [`Driver::with_mock`](crate::Driver::with_mock).
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    /// Shared state.
+    state: Arc<MockState>,
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTransport")
+            .field("requests", &self.state.requests.lock().len())
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+// ============================================================================
+// MockTransport - Scripting
+// ============================================================================
+
+impl MockTransport {
+    /// Creates a transport with no scripted responses.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a success response with `result` for `method`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use firefox_webdriver::MockTransport;
+    /// use serde_json::json;
+    ///
+    /// let mock = MockTransport::new()
+    ///     .respond("element.find", json!({ "elementId": "el-1" }))
+    ///     .respond("element.getProperty", json!({ "value": "Sign in" }));
+    /// ```
+    #[must_use]
+    pub fn respond(self, method: impl Into<String>, result: Value) -> Self {
+        self.push(method.into(), MockResponse::Success(result));
+        self
+    }
+
+    /// Queues an error response for `method`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - Protocol method
+    /// * `error` - Error code (e.g. `"no such element"`, see ARCHITECTURE.md)
+    /// * `message` - Human-readable message
+    #[must_use]
+    pub fn respond_error(
+        self,
+        method: impl Into<String>,
+        error: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.push(
+            method.into(),
+            MockResponse::Error {
+                error: error.into(),
+                message: message.into(),
+            },
+        );
+        self
+    }
+
+    /// Queues a response that never arrives for `method`.
+    ///
+    /// The request fails with [`Error::RequestTimeout`] right away, as if
+    /// its timeout had elapsed.
+    #[must_use]
+    pub fn respond_timeout(self, method: impl Into<String>) -> Self {
+        self.push(method.into(), MockResponse::Timeout);
+        self
+    }
+
+    /// Adds a scripted response to a method's queue.
+    fn push(&self, method: String, response: MockResponse) {
+        self.state
+            .responses
+            .lock()
+            .entry(method)
+            .or_default()
+            .push_back(response);
+    }
+}
+
+// ============================================================================
+// MockTransport - Inspection
+// ============================================================================
+
+impl MockTransport {
+    /// Returns the requests received so far, in order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.requests.lock().clone()
+    }
+
+    /// Returns the methods of the requests received so far, in order.
+    #[must_use]
+    pub fn methods(&self) -> Vec<String> {
+        self.state
+            .requests
+            .lock()
+            .iter()
+            .map(|request| request.command.method_name().to_string())
+            .collect()
+    }
+
+    /// Forgets the recorded requests.
+    pub fn clear_requests(&self) {
+        self.state.requests.lock().clear();
+    }
+
+    /// Delivers an event to the registered event handler.
+    ///
+    /// Returns the handler's reply (e.g. a network intercept decision), or
+    /// `None` if no handler is registered or it did not reply.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// mock.emit_event("element.added", json!({ "subscriptionId": id, "elementId": "el-2" }));
+    /// ```
+    pub fn emit_event(&self, method: impl Into<String>, params: Value) -> Option<EventReply> {
+        let event = Event {
+            id: RequestId::generate(),
+            event_type: "event".to_string(),
+            method: method.into(),
+            params,
+        };
+
+        debug!(method = %event.method, "Emitting mock event");
+
+        if event.method == "browsingContext.tabCrashed"
+            && let ParsedEvent::BrowsingContextTabCrashed { tab_id, reason } = event.parse()
+            && let Some(tab_id) = TabId::new(tab_id)
+            && let Some(ref handler) = *self.state.crash_handler.lock()
+        {
+            handler(tab_id, reason);
+        }
+
+        let handler = self.state.event_handler.lock();
+        handler.as_ref().and_then(|handler| handler(event))
+    }
+
+    /// Returns `true` once the session was closed or removed from the pool.
+    #[inline]
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.state.closed.load(Ordering::SeqCst)
+    }
+
+    /// Answers a request from the scripted responses.
+    fn answer(&self, request: &Request, request_timeout: Duration) -> Result<Response> {
+        let method = request.command.method_name();
+
+        let scripted = {
+            let mut responses = self.state.responses.lock();
+            responses.get_mut(method).and_then(|queue| {
+                if queue.len() > 1 {
+                    queue.pop_front()
+                } else {
+                    queue.front().cloned()
+                }
+            })
+        };
+
+        let (response_type, result, error, message) = match scripted {
+            Some(MockResponse::Success(result)) => {
+                (ResponseType::Success, Some(result), None, None)
+            }
+            Some(MockResponse::Error { error, message }) => {
+                (ResponseType::Error, None, Some(error), Some(message))
+            }
+            Some(MockResponse::Timeout) => {
+                return Err(Error::request_timeout(
+                    request.id,
+                    request_timeout.as_millis() as u64,
+                ));
+            }
+            None => (
+                ResponseType::Error,
+                None,
+                Some(UNKNOWN_COMMAND.to_string()),
+                Some(format!("No mock response for {method}")),
+            ),
+        };
+
+        Ok(Response {
+            id: request.id,
+            response_type,
+            result,
+            error,
+            message,
+            stack: None,
+        })
+    }
+}
+
+// ============================================================================
+// MockTransport - Transport
+// ============================================================================
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send_with_timeout(
+        &self,
+        request: Request,
+        request_timeout: Duration,
+    ) -> Result<Response> {
+        if self.is_closed() {
+            return Err(Error::ConnectionClosed);
+        }
+
+        let response = self.answer(&request, request_timeout);
+        debug!(
+            method = %request.command.method_name(),
+            success = response.as_ref().is_ok_and(Response::is_success),
+            "Mock answered request"
+        );
+
+        self.state.requests.lock().push(request);
+        response
+    }
+
+    fn set_event_handler(&self, handler: EventHandler) {
+        *self.state.event_handler.lock() = Some(handler);
+    }
+
+    fn clear_event_handler(&self) {
+        *self.state.event_handler.lock() = None;
+    }
+
+    fn set_crash_handler(&self, handler: CrashHandler) {
+        *self.state.crash_handler.lock() = Some(handler);
+    }
+
+    fn shutdown(&self) {
+        self.state.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Serves every session from this mock, starting at tab ID 1.
+#[async_trait]
+impl TransportFactory for MockTransport {
+    async fn connect(&self, session_id: SessionId) -> Result<(Arc<dyn Transport>, ReadyData)> {
+        let ready = ReadyData {
+            tab_id: INITIAL_TAB_ID,
+            session_id: session_id.as_u32(),
+        };
+        Ok((Arc::new(self.clone()), ready))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{MockTransport, Transport};
+
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::error::Error;
+    use crate::identifiers::{FrameId, TabId};
+    use crate::protocol::{BrowsingContextCommand, Command, EventReply, Request};
+
+    fn title_request() -> Request {
+        Request::new(
+            TabId::new(1).expect("tab id"),
+            FrameId::main(),
+            Command::BrowsingContext(BrowsingContextCommand::GetTitle),
+        )
+    }
+
+    async fn send(mock: &MockTransport) -> crate::Result<crate::protocol::Response> {
+        mock.send_with_timeout(title_request(), Duration::from_secs(1))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_responses_in_order_last_repeats() {
+        let mock = MockTransport::new()
+            .respond("browsingContext.getTitle", json!({ "title": "first" }))
+            .respond("browsingContext.getTitle", json!({ "title": "second" }));
+
+        for expected in ["first", "second", "second"] {
+            let response = send(&mock).await.expect("response");
+            assert_eq!(response.require_str("title").expect("title"), expected);
+        }
+        assert_eq!(mock.methods().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_unscripted_and_error_responses() {
+        let mock = MockTransport::new();
+        let response = send(&mock).await.expect("response");
+        assert_eq!(response.error.as_deref(), Some("unknown command"));
+
+        let mock = mock.respond_error("browsingContext.getTitle", "no such frame", "gone");
+        let response = send(&mock).await.expect("response");
+        assert_eq!(response.error.as_deref(), Some("no such frame"));
+        assert_eq!(response.message.as_deref(), Some("gone"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_closes() {
+        let mock = MockTransport::new();
+        mock.shutdown();
+        assert!(mock.is_closed());
+        assert!(matches!(send(&mock).await, Err(Error::ConnectionClosed)));
+    }
+
+    #[test]
+    fn test_emit_event() {
+        let mock = MockTransport::new();
+        assert!(
+            mock.emit_event("network.beforeRequestSent", json!({}))
+                .is_none()
+        );
+
+        mock.set_event_handler(Box::new(|event| {
+            Some(EventReply::new(
+                event.id,
+                event.method,
+                json!({ "action": "allow" }),
+            ))
+        }));
+        let reply = mock
+            .emit_event("network.beforeRequestSent", json!({}))
+            .expect("reply");
+        assert_eq!(reply.result, json!({ "action": "allow" }));
+
+        mock.clear_event_handler();
+        assert!(
+            mock.emit_event("network.beforeRequestSent", json!({}))
+                .is_none()
+        );
+    }
+}
@@ -27,26 +27,50 @@
 //! 4. `Connection` - Send commands, receive responses/events
 //! 5. `ConnectionPool::remove` - Clean up on window close
 //!
+//! The pool talks to each session through the [`Transport`] trait, so
+//! `Window` and `Tab` never depend on WebSocket details. A
+//! [`MockTransport`] replaces Firefox in unit tests (see
+//! [`Driver::with_mock`](crate::Driver::with_mock)); other channels are
+//! provided by a [`TransportFactory`] (see
+//! [`Driver::with_transport`](crate::Driver::with_transport)).
+//!
 //! # Modules
 //!
 //! | Module | Description |
 //! |--------|-------------|
+//! | `backend` | `Transport` and `TransportFactory` traits |
 //! | `connection` | WebSocket connection and event loop |
+//! | `mock` | In-memory transport for tests |
 //! | `pool` | Connection pool for multiplexed connections |
+//! | `tls` | TLS configuration for `wss://` (`tls` feature) |
 
 // ============================================================================
 // Submodules
 // ============================================================================
 
+/// Transport trait implemented by real and mock sessions.
+pub mod backend;
+
 /// WebSocket connection and event loop.
 pub mod connection;
 
+/// In-memory transport for tests.
+pub mod mock;
+
 /// Connection pool for multiplexed WebSocket connections.
 pub mod pool;
 
+/// TLS configuration for `wss://` (requires the `tls` feature).
+#[cfg(feature = "tls")]
+pub mod tls;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
-pub use connection::{Connection, EventHandler, ReadyData};
-pub use pool::ConnectionPool;
+pub use backend::{Transport, TransportFactory};
+pub use connection::{Connection, CrashHandler, EventHandler, ReadyData};
+pub use mock::MockTransport;
+pub use pool::{CommandMetric, ConnectionPool, EventHandlerId};
+#[cfg(feature = "tls")]
+pub use tls::TlsConfig;
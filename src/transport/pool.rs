@@ -23,21 +23,31 @@
 
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
+use futures_util::StreamExt;
 use parking_lot::{Mutex, RwLock};
 use rustc_hash::FxHashMap;
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::TcpListener;
-use tokio::sync::oneshot;
+use tokio::sync::{Semaphore, SemaphorePermit, oneshot};
 use tokio::time::timeout;
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
 use tracing::{debug, error, info, warn};
 
 use crate::error::{Error, Result};
-use crate::identifiers::SessionId;
-use crate::protocol::{Request, Response};
-use crate::transport::Connection;
-use crate::transport::connection::ReadyData;
+use crate::identifiers::{SessionId, TabId};
+use crate::protocol::{Event, EventReply, Request, Response};
+#[cfg(feature = "tls")]
+use crate::transport::TlsConfig;
+use crate::transport::connection::{
+    DEFAULT_COMMAND_TIMEOUT, DEFAULT_MAX_PENDING_REQUESTS, ReadyData,
+};
+use crate::transport::{Connection, CrashHandler, EventHandler, Transport};
 
 // ============================================================================
 // Constants
@@ -49,6 +59,148 @@ const DEFAULT_BIND_IP: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
 /// Timeout for waiting for a session to connect.
 const SESSION_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Timeout for the auth message on a pool with an access token.
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Close reason sent when a connection fails authentication.
+const AUTH_REJECTED_REASON: &str = "invalid access token";
+
+/// Intercept events the extension holds until it gets a reply.
+///
+/// Answered with `{ "action": "allow" }` when no handler decides, so
+/// observers (HAR capture, waits) never have to reply.
+const INTERCEPT_EVENTS: [&str; 7] = [
+    "network.beforeRequestSent",
+    "network.requestHeaders",
+    "network.requestBody",
+    "network.responseHeaders",
+    "network.responseBody",
+    "ws.frameSent",
+    "ws.frameReceived",
+];
+
+// ============================================================================
+// CommandMetric
+// ============================================================================
+
+/// Timing of one command, reported after each send completes.
+///
+/// See [`DriverBuilder::on_command_metric`](crate::DriverBuilder::on_command_metric).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandMetric {
+    /// Session the command was sent to.
+    pub session_id: SessionId,
+
+    /// Protocol method (e.g. `element.find`).
+    pub method: String,
+
+    /// Time from send to response, including time waiting for a slot.
+    pub duration: Duration,
+
+    /// `true` if the extension returned a success response.
+    pub success: bool,
+}
+
+/// Callback invoked with each [`CommandMetric`].
+pub(crate) type CommandMetricCallback = Arc<dyn Fn(&CommandMetric) + Send + Sync>;
+
+// ============================================================================
+// EventHandlerId
+// ============================================================================
+
+/// Handle to an event handler registered with
+/// [`ConnectionPool::add_event_handler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventHandlerId(u64);
+
+/// Event handler shared between a session's router and its dispatcher.
+type SharedEventHandler = Arc<dyn Fn(Event) -> Option<EventReply> + Send + Sync>;
+
+/// Crash handler kept by the pool so it survives transport replacement.
+type SharedCrashHandler = Arc<dyn Fn(TabId, String) + Send + Sync>;
+
+/// Fans a session's events out to every registered handler.
+#[derive(Default)]
+struct EventRouter {
+    /// Handlers in registration order.
+    handlers: RwLock<Vec<(EventHandlerId, SharedEventHandler)>>,
+}
+
+impl EventRouter {
+    /// Calls every handler with the event and returns the first reply.
+    ///
+    /// Intercept events no handler answers are allowed through.
+    fn dispatch(&self, event: Event) -> Option<EventReply> {
+        // Snapshot so handlers can add or remove handlers without deadlocking
+        let handlers: Vec<SharedEventHandler> = self
+            .handlers
+            .read()
+            .iter()
+            .map(|(_, handler)| Arc::clone(handler))
+            .collect();
+
+        let mut reply = None;
+        for handler in handlers {
+            if let Some(handler_reply) = handler(event.clone())
+                && reply.is_none()
+            {
+                reply = Some(handler_reply);
+            }
+        }
+
+        if reply.is_none() && INTERCEPT_EVENTS.contains(&event.method.as_str()) {
+            return Some(EventReply::allow(event.id, event.method));
+        }
+        reply
+    }
+
+    /// Builds the transport-level handler that feeds this router.
+    fn dispatcher(self: &Arc<Self>) -> EventHandler {
+        let router = Arc::clone(self);
+        Box::new(move |event| router.dispatch(event))
+    }
+}
+
+// ============================================================================
+// PoolConfig
+// ============================================================================
+
+/// Settings for binding a [`ConnectionPool`].
+#[derive(Clone)]
+pub(crate) struct PoolConfig {
+    /// IP address to bind.
+    pub ip: IpAddr,
+    /// Port to bind (0 for random).
+    pub port: u16,
+    /// Global limit on in-flight commands.
+    pub max_inflight: Option<usize>,
+    /// Pending request limit for each connection.
+    pub max_pending: usize,
+    /// Called after each send completes, if set.
+    pub on_command_metric: Option<CommandMetricCallback>,
+    /// Token connections must present; generated for non-loopback binds
+    /// when unset.
+    pub access_token: Option<String>,
+    /// Serve `wss://` with this certificate.
+    #[cfg(feature = "tls")]
+    pub tls: Option<TlsConfig>,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            ip: DEFAULT_BIND_IP,
+            port: 0,
+            max_inflight: None,
+            max_pending: DEFAULT_MAX_PENDING_REQUESTS,
+            on_command_metric: None,
+            access_token: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+}
+
 // ============================================================================
 // ConnectionPool
 // ============================================================================
@@ -74,14 +226,59 @@ pub struct ConnectionPool {
     /// WebSocket server port.
     port: u16,
 
-    /// Active connections by session ID.
-    connections: RwLock<FxHashMap<SessionId, Connection>>,
+    /// Host in the WebSocket URL handed to Firefox.
+    url_host: String,
+
+    /// Token every connection must present before READY, if any.
+    access_token: Option<String>,
+
+    /// TLS acceptor when serving `wss://`.
+    #[cfg(feature = "tls")]
+    tls: Option<tokio_rustls::TlsAcceptor>,
+
+    /// Active transports by session ID.
+    connections: RwLock<FxHashMap<SessionId, Arc<dyn Transport>>>,
+
+    /// Event handlers by session ID.
+    event_routers: RwLock<FxHashMap<SessionId, Arc<EventRouter>>>,
+
+    /// Crash handlers by session ID, re-applied when a transport is replaced.
+    crash_handlers: RwLock<FxHashMap<SessionId, SharedCrashHandler>>,
+
+    /// Next event handler ID.
+    next_handler_id: AtomicU64,
 
     /// Waiters for pending sessions (spawn_window waiting for Firefox to connect).
     waiters: Mutex<FxHashMap<SessionId, oneshot::Sender<ReadyData>>>,
 
     /// Shutdown flag.
     shutdown: AtomicBool,
+
+    /// Commands currently in flight across all sessions.
+    inflight: AtomicUsize,
+
+    /// Optional global limit on in-flight commands.
+    inflight_limit: Option<Semaphore>,
+
+    /// Pending request limit for each connection.
+    max_pending: usize,
+
+    /// Called after each send completes, if set.
+    on_command_metric: Option<CommandMetricCallback>,
+}
+
+/// Tracks one in-flight command and releases its slot on drop.
+struct InflightGuard<'a> {
+    /// Counter decremented on drop.
+    inflight: &'a AtomicUsize,
+    /// Global limit permit, if a limit is set.
+    permit: Option<SemaphorePermit<'a>>,
+}
+
+impl Drop for InflightGuard<'_> {
+    fn drop(&mut self) {
+        self.inflight.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 // ============================================================================
@@ -124,17 +321,149 @@ impl ConnectionPool {
     ///
     /// Returns [`Error::Io`] if binding fails.
     pub async fn with_ip_port(ip: IpAddr, port: u16) -> Result<Arc<Self>> {
-        let addr = SocketAddr::new(ip, port);
+        Self::with_config(PoolConfig {
+            ip,
+            port,
+            ..PoolConfig::default()
+        })
+        .await
+    }
+
+    /// Creates a new connection pool serving `wss://` on localhost.
+    ///
+    /// Requires the `tls` feature. Binds to `localhost:0`; use
+    /// [`DriverBuilder::bind_address`](crate::DriverBuilder::bind_address)
+    /// with [`DriverBuilder::tls`](crate::DriverBuilder::tls) to serve
+    /// other hosts.
+    ///
+    /// # Arguments
+    ///
+    /// * `tls` - Server certificate and key
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if binding fails.
+    #[cfg(feature = "tls")]
+    pub async fn with_tls(tls: TlsConfig) -> Result<Arc<Self>> {
+        Self::with_config(PoolConfig {
+            tls: Some(tls),
+            ..PoolConfig::default()
+        })
+        .await
+    }
+
+    /// Creates a new connection pool that limits in-flight commands.
+    ///
+    /// At most `max_inflight` commands are outstanding across all sessions;
+    /// further sends wait for a slot instead of failing.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_inflight` - Maximum concurrent commands (at least 1)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if binding fails.
+    pub async fn with_max_inflight(max_inflight: usize) -> Result<Arc<Self>> {
+        Self::with_limits(Some(max_inflight), DEFAULT_MAX_PENDING_REQUESTS).await
+    }
+
+    /// Creates a new connection pool with global and per-connection limits.
+    ///
+    /// Commands over either limit wait for a slot instead of failing.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_inflight` - Maximum concurrent commands across all sessions
+    /// * `max_pending` - Maximum outstanding requests per connection
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if binding fails.
+    pub async fn with_limits(max_inflight: Option<usize>, max_pending: usize) -> Result<Arc<Self>> {
+        Self::with_config(PoolConfig {
+            max_inflight,
+            max_pending,
+            ..PoolConfig::default()
+        })
+        .await
+    }
+
+    /// Creates a pool without a WebSocket server.
+    ///
+    /// Sessions are added with [`ConnectionPool::insert`]; used with
+    /// [`MockTransport`](crate::transport::MockTransport). [`port`](Self::port)
+    /// returns 0.
+    pub(crate) fn detached(on_command_metric: Option<CommandMetricCallback>) -> Arc<Self> {
+        debug!("ConnectionPool created without WebSocket server");
+
+        Arc::new(Self {
+            port: 0,
+            url_host: DEFAULT_BIND_IP.to_string(),
+            access_token: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            connections: RwLock::new(FxHashMap::default()),
+            event_routers: RwLock::new(FxHashMap::default()),
+            crash_handlers: RwLock::new(FxHashMap::default()),
+            next_handler_id: AtomicU64::new(0),
+            waiters: Mutex::new(FxHashMap::default()),
+            shutdown: AtomicBool::new(false),
+            inflight: AtomicUsize::new(0),
+            inflight_limit: None,
+            max_pending: DEFAULT_MAX_PENDING_REQUESTS,
+            on_command_metric,
+        })
+    }
+
+    /// Binds the WebSocket server and starts the accept loop.
+    pub(crate) async fn with_config(config: PoolConfig) -> Result<Arc<Self>> {
+        let addr = SocketAddr::new(config.ip, config.port);
         let listener = TcpListener::bind(addr).await?;
         let actual_port = listener.local_addr()?.port();
 
-        debug!(port = actual_port, "ConnectionPool WebSocket server bound");
+        debug!(port = actual_port, ip = %config.ip, "ConnectionPool WebSocket server bound");
+
+        #[cfg(feature = "tls")]
+        let (url_host, tls) = match &config.tls {
+            Some(tls) => (tls.host().to_string(), Some(tls.acceptor())),
+            None => (url_host(config.ip), None),
+        };
+        #[cfg(not(feature = "tls"))]
+        let url_host = url_host(config.ip);
+
+        #[cfg(feature = "tls")]
+        let secure = tls.is_some();
+        #[cfg(not(feature = "tls"))]
+        let secure = false;
+
+        if !config.ip.is_loopback() && !secure {
+            warn!(
+                ip = %config.ip,
+                "Control channel bound to a non-loopback address without TLS"
+            );
+        }
+
+        let access_token = config
+            .access_token
+            .or_else(|| (!config.ip.is_loopback()).then(generate_access_token));
 
         let pool = Arc::new(Self {
             port: actual_port,
+            url_host,
+            access_token,
+            #[cfg(feature = "tls")]
+            tls,
             connections: RwLock::new(FxHashMap::default()),
+            event_routers: RwLock::new(FxHashMap::default()),
+            crash_handlers: RwLock::new(FxHashMap::default()),
+            next_handler_id: AtomicU64::new(0),
             waiters: Mutex::new(FxHashMap::default()),
             shutdown: AtomicBool::new(false),
+            inflight: AtomicUsize::new(0),
+            inflight_limit: config.max_inflight.map(|max| Semaphore::new(max.max(1))),
+            max_pending: config.max_pending,
+            on_command_metric: config.on_command_metric,
         });
 
         // Spawn accept loop
@@ -156,11 +485,33 @@ impl ConnectionPool {
 impl ConnectionPool {
     /// Returns the WebSocket URL for this pool.
     ///
-    /// Format: `ws://127.0.0.1:{port}`
+    /// Format: `ws://127.0.0.1:{port}`, or `wss://{host}:{port}` with TLS.
+    /// A pool bound to a specific non-loopback IP uses that IP as host.
     #[inline]
     #[must_use]
     pub fn ws_url(&self) -> String {
-        format!("ws://127.0.0.1:{}", self.port)
+        let scheme = if self.is_secure() { "wss" } else { "ws" };
+        format!("{scheme}://{}:{}", self.url_host, self.port)
+    }
+
+    /// Returns the token connections must present, if authentication is on.
+    ///
+    /// Extensions launched by the driver receive it automatically; pass it
+    /// to extensions started elsewhere.
+    #[inline]
+    #[must_use]
+    pub fn access_token(&self) -> Option<&str> {
+        self.access_token.as_deref()
+    }
+
+    /// Returns `true` if the pool serves `wss://`.
+    #[inline]
+    #[must_use]
+    pub fn is_secure(&self) -> bool {
+        #[cfg(feature = "tls")]
+        return self.tls.is_some();
+        #[cfg(not(feature = "tls"))]
+        return false;
     }
 
     /// Returns the port the pool is bound to.
@@ -170,6 +521,13 @@ impl ConnectionPool {
         self.port
     }
 
+    /// Returns `true` if the session has an active connection.
+    #[inline]
+    #[must_use]
+    pub fn has_session(&self, session_id: SessionId) -> bool {
+        self.connections.read().contains_key(&session_id)
+    }
+
     /// Returns the number of active connections.
     #[inline]
     #[must_use]
@@ -177,6 +535,15 @@ impl ConnectionPool {
         self.connections.read().len()
     }
 
+    /// Returns the number of commands awaiting a response across all sessions.
+    ///
+    /// Includes commands queued for a slot when a limit is set.
+    #[inline]
+    #[must_use]
+    pub fn inflight_count(&self) -> usize {
+        self.inflight.load(Ordering::SeqCst)
+    }
+
     /// Waits for a specific session to connect.
     ///
     /// Called by `spawn_window` after launching Firefox.
@@ -232,19 +599,15 @@ impl ConnectionPool {
     /// - [`Error::ConnectionClosed`] if connection is closed
     /// - [`Error::RequestTimeout`] if response not received within timeout
     pub async fn send(&self, session_id: SessionId, request: Request) -> Result<Response> {
-        let connection = {
-            let connections = self.connections.read();
-            connections
-                .get(&session_id)
-                .ok_or_else(|| Error::session_not_found(session_id))?
-                .clone()
-        };
-
-        connection.send(request).await
+        self.send_with_timeout(session_id, request, DEFAULT_COMMAND_TIMEOUT)
+            .await
     }
 
     /// Sends a request with custom timeout.
     ///
+    /// With an in-flight limit, time spent waiting for a slot counts
+    /// towards the timeout.
+    ///
     /// # Arguments
     ///
     /// * `session_id` - Target session
@@ -261,16 +624,78 @@ impl ConnectionPool {
         session_id: SessionId,
         request: Request,
         request_timeout: Duration,
+    ) -> Result<Response> {
+        let Some(on_command_metric) = &self.on_command_metric else {
+            return self.dispatch(session_id, request, request_timeout).await;
+        };
+
+        let method = request.command.method_name();
+        let started = Instant::now();
+        let result = self.dispatch(session_id, request, request_timeout).await;
+
+        on_command_metric(&CommandMetric {
+            session_id,
+            method: method.to_string(),
+            duration: started.elapsed(),
+            success: matches!(&result, Ok(response) if response.is_success()),
+        });
+
+        result
+    }
+
+    /// Sends a request to its session's connection.
+    async fn dispatch(
+        &self,
+        session_id: SessionId,
+        request: Request,
+        request_timeout: Duration,
     ) -> Result<Response> {
         let connection = {
             let connections = self.connections.read();
             connections
                 .get(&session_id)
+                .map(Arc::clone)
                 .ok_or_else(|| Error::session_not_found(session_id))?
-                .clone()
         };
 
-        connection.send_with_timeout(request, request_timeout).await
+        let started = Instant::now();
+        let _guard = match timeout(request_timeout, self.acquire_inflight()).await {
+            Ok(guard) => guard?,
+            Err(_) => {
+                return Err(Error::request_timeout(
+                    request.id,
+                    request_timeout.as_millis() as u64,
+                ));
+            }
+        };
+
+        let remaining = request_timeout.saturating_sub(started.elapsed());
+        connection.send_with_timeout(request, remaining).await
+    }
+
+    /// Reserves an in-flight slot, waiting if the global limit is reached.
+    async fn acquire_inflight(&self) -> Result<InflightGuard<'_>> {
+        self.inflight.fetch_add(1, Ordering::SeqCst);
+        let mut guard = InflightGuard {
+            inflight: &self.inflight,
+            permit: None,
+        };
+
+        let Some(limit) = &self.inflight_limit else {
+            return Ok(guard);
+        };
+
+        if limit.available_permits() == 0 {
+            debug!(
+                inflight = self.inflight_count(),
+                "Waiting for in-flight slot"
+            );
+        }
+
+        let permit = limit.acquire().await.map_err(|_| Error::ConnectionClosed)?;
+
+        guard.permit = Some(permit);
+        Ok(guard)
     }
 }
 
@@ -279,33 +704,119 @@ impl ConnectionPool {
 // ============================================================================
 
 impl ConnectionPool {
-    /// Sets the event handler for a session.
+    /// Adds an event handler for a session.
+    ///
+    /// Every handler registered for the session sees every event; the
+    /// first reply returned for an event is sent back to the remote end.
+    /// Handlers that only observe should return `None`: intercept events
+    /// nobody answers are allowed through.
     ///
     /// # Arguments
     ///
     /// * `session_id` - Target session
     /// * `handler` - Event handler callback
-    pub fn set_event_handler(
+    ///
+    /// # Returns
+    ///
+    /// ID for [`ConnectionPool::remove_event_handler`].
+    pub fn add_event_handler(
         &self,
         session_id: SessionId,
-        handler: crate::transport::EventHandler,
-    ) {
-        let connections = self.connections.read();
-        if let Some(connection) = connections.get(&session_id) {
-            connection.set_event_handler(handler);
+        handler: EventHandler,
+    ) -> EventHandlerId {
+        let id = EventHandlerId(self.next_handler_id.fetch_add(1, Ordering::Relaxed));
+
+        let (router, created) = {
+            let mut routers = self.event_routers.write();
+            let created = !routers.contains_key(&session_id);
+            let router = Arc::clone(routers.entry(session_id).or_default());
+            (router, created)
+        };
+
+        router.handlers.write().push((id, Arc::from(handler)));
+
+        if created {
+            self.install_dispatcher(session_id, &router);
+        }
+
+        debug!(session_id = %session_id, ?id, "Event handler added");
+        id
+    }
+
+    /// Removes an event handler added with [`ConnectionPool::add_event_handler`].
+    ///
+    /// Does nothing if the handler was already removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Target session
+    /// * `id` - Handler to remove
+    pub fn remove_event_handler(&self, session_id: SessionId, id: EventHandlerId) {
+        let routers = self.event_routers.read();
+        if let Some(router) = routers.get(&session_id) {
+            router
+                .handlers
+                .write()
+                .retain(|(handler_id, _)| *handler_id != id);
+        }
+    }
+
+    /// Removes every event handler for a session.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Target session
+    pub fn clear_event_handlers(&self, session_id: SessionId) {
+        let routers = self.event_routers.read();
+        if let Some(router) = routers.get(&session_id) {
+            router.handlers.write().clear();
         }
     }
 
-    /// Clears the event handler for a session.
+    /// Sets the crash handler for a session.
+    ///
+    /// Runs alongside the event handlers and is not affected by
+    /// [`ConnectionPool::clear_event_handlers`]. Follows the session to a
+    /// replacement transport.
     ///
     /// # Arguments
     ///
     /// * `session_id` - Target session
-    pub fn clear_event_handler(&self, session_id: SessionId) {
+    /// * `handler` - Crash handler callback
+    pub fn set_crash_handler(&self, session_id: SessionId, handler: CrashHandler) {
+        self.crash_handlers
+            .write()
+            .insert(session_id, Arc::from(handler));
+        self.install_crash_handler(session_id);
+    }
+
+    /// Points the session's transport at its crash handler, if one is set.
+    fn install_crash_handler(&self, session_id: SessionId) {
+        let Some(handler) = self.crash_handlers.read().get(&session_id).cloned() else {
+            return;
+        };
+
         let connections = self.connections.read();
         if let Some(connection) = connections.get(&session_id) {
-            connection.clear_event_handler();
+            connection.set_crash_handler(Box::new(move |tab_id, reason| handler(tab_id, reason)));
+        }
+    }
+
+    /// Points the session's transport at its event router.
+    fn install_dispatcher(&self, session_id: SessionId, router: &Arc<EventRouter>) {
+        let connections = self.connections.read();
+        if let Some(connection) = connections.get(&session_id) {
+            connection.set_event_handler(router.dispatcher());
+        }
+    }
+
+    /// Re-attaches a session's handlers after its transport is replaced.
+    fn reinstall_handlers(&self, session_id: SessionId) {
+        let router = self.event_routers.read().get(&session_id).cloned();
+        if let Some(router) = router {
+            self.install_dispatcher(session_id, &router);
         }
+        self.install_crash_handler(session_id);
     }
 }
 
@@ -314,6 +825,26 @@ impl ConnectionPool {
 // ============================================================================
 
 impl ConnectionPool {
+    /// Registers a transport for a session.
+    ///
+    /// Replaces (and shuts down) any transport the session already had.
+    /// Sessions from Firefox are registered automatically when they connect;
+    /// this is for other [`Transport`] implementations.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Session the transport serves
+    /// * `transport` - Transport for the session's requests
+    pub fn insert(&self, session_id: SessionId, transport: Arc<dyn Transport>) {
+        let replaced = self.connections.write().insert(session_id, transport);
+        self.reinstall_handlers(session_id);
+
+        if let Some(old) = replaced {
+            old.shutdown();
+        }
+        debug!(session_id = %session_id, "Transport registered");
+    }
+
     /// Removes a session from the pool.
     ///
     /// Called when a Window closes.
@@ -326,6 +857,8 @@ impl ConnectionPool {
             let mut connections = self.connections.write();
             connections.remove(&session_id)
         };
+        self.event_routers.write().remove(&session_id);
+        self.crash_handlers.write().remove(&session_id);
 
         if let Some(connection) = removed {
             connection.shutdown();
@@ -345,6 +878,8 @@ impl ConnectionPool {
             let mut map = self.connections.write();
             map.drain().collect()
         };
+        self.event_routers.write().clear();
+        self.crash_handlers.write().clear();
 
         for (session_id, connection) in connections {
             connection.shutdown();
@@ -410,15 +945,41 @@ impl ConnectionPool {
     ) -> Result<()> {
         debug!(?addr, "New TCP connection");
 
+        #[cfg(feature = "tls")]
+        if let Some(acceptor) = &self.tls {
+            let stream = acceptor
+                .accept(stream)
+                .await
+                .map_err(|e| Error::connection(format!("TLS handshake failed: {e}")))?;
+            return self.register_stream(stream, addr).await;
+        }
+
+        self.register_stream(stream, addr).await
+    }
+
+    /// Upgrades an accepted stream to WebSocket and registers its session.
+    async fn register_stream<S>(&self, stream: S, addr: SocketAddr) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
         // Upgrade to WebSocket
-        let ws_stream = tokio_tungstenite::accept_async(stream)
+        let mut ws_stream: WebSocketStream<S> = tokio_tungstenite::accept_async(stream)
             .await
             .map_err(|e| Error::connection(format!("WebSocket upgrade failed: {e}")))?;
 
-        info!(?addr, "WebSocket connection established");
+        info!(
+            ?addr,
+            secure = self.is_secure(),
+            "WebSocket connection established"
+        );
+
+        if let Some(token) = &self.access_token {
+            Self::authenticate(&mut ws_stream, token).await?;
+            debug!(?addr, "Connection authenticated");
+        }
 
         // Create Connection and wait for READY
-        let connection = Connection::new(ws_stream);
+        let connection = Connection::new(ws_stream, self.max_pending);
         let ready_data = connection.wait_ready().await?;
 
         let session_id = SessionId::from_u32(ready_data.session_id)
@@ -429,8 +990,9 @@ impl ConnectionPool {
         // Store connection
         {
             let mut connections = self.connections.write();
-            connections.insert(session_id, connection);
+            connections.insert(session_id, Arc::new(connection));
         }
+        self.reinstall_handlers(session_id);
 
         // Notify waiter if any
         {
@@ -442,6 +1004,77 @@ impl ConnectionPool {
 
         Ok(())
     }
+
+    /// Checks the connection's first message for the access token.
+    ///
+    /// Closes the connection with a policy violation code on mismatch.
+    async fn authenticate<S>(ws_stream: &mut WebSocketStream<S>, token: &str) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let presented = match timeout(AUTH_TIMEOUT, ws_stream.next()).await {
+            Ok(Some(Ok(Message::Text(text)))) => parse_auth_token(&text),
+            Ok(_) => None,
+            Err(_) => {
+                warn!("Connection sent no auth message");
+                None
+            }
+        };
+
+        if presented.is_some_and(|presented| tokens_match(&presented, token)) {
+            return Ok(());
+        }
+
+        warn!("Rejecting connection with invalid access token");
+        let close = CloseFrame {
+            code: CloseCode::Policy,
+            reason: AUTH_REJECTED_REASON.into(),
+        };
+        let _ = ws_stream.close(Some(close)).await;
+
+        Err(Error::connection(
+            "Connection rejected: invalid access token",
+        ))
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Generates a random access token (32 hex characters).
+fn generate_access_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+/// Extracts the token from an `{"type": "auth", "token": ...}` message.
+fn parse_auth_token(text: &str) -> Option<String> {
+    let message: serde_json::Value = serde_json::from_str(text).ok()?;
+    if message.get("type")?.as_str()? != "auth" {
+        return None;
+    }
+    message.get("token")?.as_str().map(str::to_string)
+}
+
+/// Compares tokens in time independent of where they differ.
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Returns the host Firefox should dial for a pool bound to `ip`.
+///
+/// Loopback and wildcard binds are reached via `127.0.0.1`.
+fn url_host(ip: IpAddr) -> String {
+    match ip {
+        ip if ip.is_loopback() || ip.is_unspecified() => DEFAULT_BIND_IP.to_string(),
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(ip) => format!("[{ip}]"),
+    }
 }
 
 // ============================================================================
@@ -470,6 +1103,179 @@ mod tests {
         pool.shutdown().await;
     }
 
+    #[tokio::test]
+    async fn test_event_handlers_multiplexed() {
+        use serde_json::json;
+
+        use crate::transport::MockTransport;
+
+        let pool = ConnectionPool::new().await.expect("pool creation");
+        let session_id = SessionId::from_u32(1).expect("session id");
+        let mock = MockTransport::new();
+        pool.insert(session_id, Arc::new(mock.clone()));
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let observer = {
+            let seen = Arc::clone(&seen);
+            pool.add_event_handler(
+                session_id,
+                Box::new(move |_| {
+                    seen.fetch_add(1, Ordering::SeqCst);
+                    None
+                }),
+            )
+        };
+        let replier = pool.add_event_handler(
+            session_id,
+            Box::new(|event| {
+                Some(EventReply::new(
+                    event.id,
+                    event.method.clone(),
+                    json!({ "action": "allow" }),
+                ))
+            }),
+        );
+
+        let reply = mock.emit_event("test.event", json!({})).expect("reply");
+        assert_eq!(reply.result, json!({ "action": "allow" }));
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+
+        pool.remove_event_handler(session_id, replier);
+        assert!(mock.emit_event("test.event", json!({})).is_none());
+        assert_eq!(seen.load(Ordering::SeqCst), 2);
+
+        // Intercept events without a deciding handler are allowed
+        let reply = mock
+            .emit_event("network.beforeRequestSent", json!({}))
+            .expect("default reply");
+        assert_eq!(reply.result, json!({ "action": "allow" }));
+        assert_eq!(seen.load(Ordering::SeqCst), 3);
+
+        pool.remove_event_handler(session_id, observer);
+        mock.emit_event("test.event", json!({}));
+        assert_eq!(seen.load(Ordering::SeqCst), 3);
+
+        // Handlers follow the session to a replacement transport
+        let counter = Arc::clone(&seen);
+        pool.add_event_handler(
+            session_id,
+            Box::new(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                None
+            }),
+        );
+        let replacement = MockTransport::new();
+        pool.insert(session_id, Arc::new(replacement.clone()));
+        replacement.emit_event("test.event", json!({}));
+        assert_eq!(seen.load(Ordering::SeqCst), 4);
+
+        pool.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_crash_handler_survives_replacement() {
+        use serde_json::json;
+
+        use crate::transport::MockTransport;
+
+        let pool = ConnectionPool::new().await.expect("pool creation");
+        let session_id = SessionId::from_u32(1).expect("session id");
+        pool.insert(session_id, Arc::new(MockTransport::new()));
+
+        let crashes = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&crashes);
+        pool.set_crash_handler(
+            session_id,
+            Box::new(move |tab_id, reason| sink.lock().push((tab_id.as_u32(), reason))),
+        );
+
+        let replacement = MockTransport::new();
+        pool.insert(session_id, Arc::new(replacement.clone()));
+        replacement.emit_event(
+            "browsingContext.tabCrashed",
+            json!({ "tabId": 4, "reason": "oom" }),
+        );
+        assert_eq!(*crashes.lock(), [(4, "oom".to_string())]);
+
+        pool.shutdown().await;
+    }
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(url_host(IpAddr::V4(Ipv4Addr::UNSPECIFIED)), "127.0.0.1");
+        assert_eq!(
+            url_host(IpAddr::V6(std::net::Ipv6Addr::LOCALHOST)),
+            "127.0.0.1"
+        );
+        assert_eq!(url_host("10.0.0.5".parse().unwrap()), "10.0.0.5");
+        assert_eq!(url_host("fd00::1".parse().unwrap()), "[fd00::1]");
+    }
+
+    #[test]
+    fn test_parse_auth_token() {
+        assert_eq!(
+            parse_auth_token(r#"{"type":"auth","token":"secret"}"#).as_deref(),
+            Some("secret")
+        );
+        assert!(parse_auth_token(r#"{"type":"ready","token":"secret"}"#).is_none());
+        assert!(parse_auth_token("not json").is_none());
+    }
+
+    #[test]
+    fn test_tokens_match() {
+        assert!(tokens_match("secret", "secret"));
+        assert!(!tokens_match("secreT", "secret"));
+        assert!(!tokens_match("secret1", "secret"));
+        assert_eq!(generate_access_token().len(), 32);
+    }
+
+    #[tokio::test]
+    async fn test_access_token_handshake() {
+        use futures_util::SinkExt;
+
+        let pool = ConnectionPool::with_config(PoolConfig {
+            access_token: Some("secret".to_string()),
+            ..PoolConfig::default()
+        })
+        .await
+        .expect("pool creation");
+        assert_eq!(pool.access_token(), Some("secret"));
+
+        // Wrong token: closed with policy violation
+        let (mut ws, _) = tokio_tungstenite::connect_async(pool.ws_url())
+            .await
+            .expect("connect");
+        ws.send(Message::Text(r#"{"type":"auth","token":"guess"}"#.into()))
+            .await
+            .expect("auth");
+        match ws.next().await {
+            Some(Ok(Message::Close(Some(frame)))) => assert_eq!(frame.code, CloseCode::Policy),
+            other => panic!("expected close frame, got {other:?}"),
+        }
+
+        // Right token, then READY: session registered
+        let session_id = SessionId::next();
+        let (mut ws, _) = tokio_tungstenite::connect_async(pool.ws_url())
+            .await
+            .expect("connect");
+        ws.send(Message::Text(r#"{"type":"auth","token":"secret"}"#.into()))
+            .await
+            .expect("auth");
+        let ready = serde_json::json!({
+            "id": "00000000-0000-0000-0000-000000000000",
+            "type": "success",
+            "result": { "tabId": 1, "sessionId": session_id.as_u32() }
+        });
+        ws.send(Message::Text(ready.to_string().into()))
+            .await
+            .expect("ready");
+
+        pool.wait_for_session(session_id).await.expect("session");
+        assert!(pool.has_session(session_id));
+
+        pool.shutdown().await;
+    }
+
     #[tokio::test]
     async fn test_send_to_unknown_session() {
         let pool = ConnectionPool::new().await.expect("pool creation");
@@ -513,4 +1319,26 @@ mod tests {
 
         pool.shutdown().await;
     }
+
+    #[tokio::test]
+    async fn test_inflight_limit_queues() {
+        let pool = ConnectionPool::with_max_inflight(1)
+            .await
+            .expect("pool creation");
+
+        let guard = pool.acquire_inflight().await.expect("first slot");
+        assert_eq!(pool.inflight_count(), 1);
+
+        let queued = timeout(Duration::from_millis(50), pool.acquire_inflight()).await;
+        assert!(queued.is_err(), "second command should wait for a slot");
+        assert_eq!(pool.inflight_count(), 1);
+
+        drop(guard);
+        let guard = pool.acquire_inflight().await.expect("freed slot");
+        assert_eq!(pool.inflight_count(), 1);
+        drop(guard);
+        assert_eq!(pool.inflight_count(), 0);
+
+        pool.shutdown().await;
+    }
 }